@@ -0,0 +1,70 @@
+#!/usr/bin/env python3
+"""Generates `src/agl/glyph_list.rs` from the Adobe Glyph List.
+
+Both files can be found in https://github.com/adobe-type-tools/agl-aglfn.
+Usage:
+
+    python3 scripts/gen_agl.py glyphlist.txt aglfn.txt > src/agl/glyph_list.rs
+
+`glyphlist.txt` provides the name to Unicode mapping, `aglfn.txt` the
+preferred name of each code point.
+"""
+
+import sys
+
+
+def parse_glyph_list(path):
+    names = {}
+    with open(path, encoding="utf-8") as f:
+        for line in f:
+            line = line.strip()
+            if not line or line.startswith("#"):
+                continue
+            name, codes = line.split(";")
+            # Keep the first mapping of double-mapped names.
+            if name in names:
+                continue
+            names[name] = [int(code, 16) for code in codes.split()]
+    return names
+
+
+def parse_aglfn(path):
+    preferred = {}
+    with open(path, encoding="utf-8") as f:
+        for line in f:
+            line = line.strip()
+            if not line or line.startswith("#"):
+                continue
+            code, name = line.split(";")[:2]
+            preferred.setdefault(int(code, 16), name)
+    return preferred
+
+
+def char_literal(code):
+    return "'\\u{%X}'" % code
+
+
+def str_literal(codes):
+    return '"%s"' % "".join("\\u{%X}" % code for code in codes)
+
+
+def main():
+    names = parse_glyph_list(sys.argv[1])
+    preferred = parse_aglfn(sys.argv[2])
+
+    print("// THIS FILE WAS AUTO-GENERATED, DO NOT EDIT MANUALLY!")
+    print("use phf::phf_map;")
+    print()
+    print("pub(crate) static GLYPH_LIST: phf::Map<&'static str, &'static str> = phf_map! {")
+    for name, codes in sorted(names.items()):
+        print('    "%s" => %s,' % (name, str_literal(codes)))
+    print("};")
+    print()
+    print("pub(crate) static PREFERRED_NAMES: &[(char, &str)] = &[")
+    for code, name in sorted(preferred.items()):
+        print('    (%s, "%s"),' % (char_literal(code), name))
+    print("];")
+
+
+if __name__ == "__main__":
+    main()
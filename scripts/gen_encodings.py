@@ -0,0 +1,172 @@
+#!/usr/bin/env python3
+"""Generates the predefined encodings in `src/encoding`.
+
+The tables follow Annex D of PDF 32000-1:2008. Unused WinAnsiEncoding
+codes map to `bullet`, as most viewers do. Usage:
+
+    python3 scripts/gen_encodings.py src/encoding
+
+Each table lists the glyph names of 16 codes per row, `-` marks unused codes.
+"""
+
+import os
+import sys
+
+STANDARD = {
+    0x20: "space exclam quotedbl numbersign dollar percent ampersand quoteright parenleft parenright asterisk plus comma hyphen period slash",
+    0x30: "zero one two three four five six seven eight nine colon semicolon less equal greater question",
+    0x40: "at A B C D E F G H I J K L M N O",
+    0x50: "P Q R S T U V W X Y Z bracketleft backslash bracketright asciicircum underscore",
+    0x60: "quoteleft a b c d e f g h i j k l m n o",
+    0x70: "p q r s t u v w x y z braceleft bar braceright asciitilde -",
+    0xA0: "- exclamdown cent sterling fraction yen florin section currency quotesingle quotedblleft guillemotleft guilsinglleft guilsinglright fi fl",
+    0xB0: "- endash dagger daggerdbl periodcentered - paragraph bullet quotesinglbase quotedblbase quotedblright guillemotright ellipsis perthousand - questiondown",
+    0xC0: "- grave acute circumflex tilde macron breve dotaccent dieresis - ring cedilla - hungarumlaut ogonek caron",
+    0xD0: "emdash - - - - - - - - - - - - - - -",
+    0xE0: "- AE - ordfeminine - - - - Lslash Oslash OE ordmasculine - - - -",
+    0xF0: "- ae - - - dotlessi - - lslash oslash oe germandbls - - - -",
+}
+
+WIN_ANSI = {
+    0x20: "space exclam quotedbl numbersign dollar percent ampersand quotesingle parenleft parenright asterisk plus comma hyphen period slash",
+    0x30: "zero one two three four five six seven eight nine colon semicolon less equal greater question",
+    0x40: "at A B C D E F G H I J K L M N O",
+    0x50: "P Q R S T U V W X Y Z bracketleft backslash bracketright asciicircum underscore",
+    0x60: "grave a b c d e f g h i j k l m n o",
+    0x70: "p q r s t u v w x y z braceleft bar braceright asciitilde bullet",
+    0x80: "Euro bullet quotesinglbase florin quotedblbase ellipsis dagger daggerdbl circumflex perthousand Scaron guilsinglleft OE bullet Zcaron bullet",
+    0x90: "bullet quoteleft quoteright quotedblleft quotedblright bullet endash emdash tilde trademark scaron guilsinglright oe bullet zcaron Ydieresis",
+    0xA0: "space exclamdown cent sterling currency yen brokenbar section dieresis copyright ordfeminine guillemotleft logicalnot hyphen registered macron",
+    0xB0: "degree plusminus twosuperior threesuperior acute mu paragraph periodcentered cedilla onesuperior ordmasculine guillemotright onequarter onehalf threequarters questiondown",
+    0xC0: "Agrave Aacute Acircumflex Atilde Adieresis Aring AE Ccedilla Egrave Eacute Ecircumflex Edieresis Igrave Iacute Icircumflex Idieresis",
+    0xD0: "Eth Ntilde Ograve Oacute Ocircumflex Otilde Odieresis multiply Oslash Ugrave Uacute Ucircumflex Udieresis Yacute Thorn germandbls",
+    0xE0: "agrave aacute acircumflex atilde adieresis aring ae ccedilla egrave eacute ecircumflex edieresis igrave iacute icircumflex idieresis",
+    0xF0: "eth ntilde ograve oacute ocircumflex otilde odieresis divide oslash ugrave uacute ucircumflex udieresis yacute thorn ydieresis",
+}
+
+MAC_ROMAN = {
+    0x20: "space exclam quotedbl numbersign dollar percent ampersand quotesingle parenleft parenright asterisk plus comma hyphen period slash",
+    0x30: "zero one two three four five six seven eight nine colon semicolon less equal greater question",
+    0x40: "at A B C D E F G H I J K L M N O",
+    0x50: "P Q R S T U V W X Y Z bracketleft backslash bracketright asciicircum underscore",
+    0x60: "grave a b c d e f g h i j k l m n o",
+    0x70: "p q r s t u v w x y z braceleft bar braceright asciitilde -",
+    0x80: "Adieresis Aring Ccedilla Eacute Ntilde Odieresis Udieresis aacute agrave acircumflex adieresis atilde aring ccedilla eacute egrave",
+    0x90: "ecircumflex edieresis iacute igrave icircumflex idieresis ntilde oacute ograve ocircumflex odieresis otilde uacute ugrave ucircumflex udieresis",
+    0xA0: "dagger degree cent sterling section bullet paragraph germandbls registered copyright trademark acute dieresis notequal AE Oslash",
+    0xB0: "infinity plusminus lessequal greaterequal yen mu partialdiff summation product pi integral ordfeminine ordmasculine Omega ae oslash",
+    0xC0: "questiondown exclamdown logicalnot radical florin approxequal Delta guillemotleft guillemotright ellipsis space Agrave Atilde Otilde OE oe",
+    0xD0: "endash emdash quotedblleft quotedblright quoteleft quoteright divide lozenge ydieresis Ydieresis fraction currency guilsinglleft guilsinglright fi fl",
+    0xE0: "daggerdbl periodcentered quotesinglbase quotedblbase perthousand Acircumflex Ecircumflex Aacute Edieresis Egrave Iacute Icircumflex Idieresis Igrave Oacute Ocircumflex",
+    0xF0: "- Ograve Uacute Ucircumflex Ugrave dotlessi circumflex tilde macron breve dotaccent ring cedilla hungarumlaut ogonek caron",
+}
+
+MAC_EXPERT = {
+    0x20: "space exclamsmall Hungarumlautsmall centoldstyle dollaroldstyle dollarsuperior ampersandsmall Acutesmall parenleftsuperior parenrightsuperior twodotenleader onedotenleader comma hyphen period fraction",
+    0x30: "zerooldstyle oneoldstyle twooldstyle threeoldstyle fouroldstyle fiveoldstyle sixoldstyle sevenoldstyle eightoldstyle nineoldstyle colon semicolon - threequartersemdash - questionsmall",
+    0x40: "- - - - Ethsmall - - onequarter onehalf threequarters oneeighth threeeighths fiveeighths seveneighths onethird twothirds",
+    0x50: "- - - - - - ff fi fl ffi ffl parenleftinferior - parenrightinferior Circumflexsmall hypheninferior",
+    0x60: "Gravesmall Asmall Bsmall Csmall Dsmall Esmall Fsmall Gsmall Hsmall Ismall Jsmall Ksmall Lsmall Msmall Nsmall Osmall",
+    0x70: "Psmall Qsmall Rsmall Ssmall Tsmall Usmall Vsmall Wsmall Xsmall Ysmall Zsmall colonmonetary onefitted rupiah Tildesmall -",
+    0x80: "- asuperior centsuperior - - - - Aacutesmall Agravesmall Acircumflexsmall Adieresissmall Atildesmall Aringsmall Ccedillasmall Eacutesmall Egravesmall",
+    0x90: "Ecircumflexsmall Edieresissmall Iacutesmall Igravesmall Icircumflexsmall Idieresissmall Ntildesmall Oacutesmall Ogravesmall Ocircumflexsmall Odieresissmall Otildesmall Uacutesmall Ugravesmall Ucircumflexsmall Udieresissmall",
+    0xA0: "- eightsuperior fourinferior threeinferior sixinferior eightinferior seveninferior Scaronsmall - centinferior twoinferior - Dieresissmall - Caronsmall osuperior",
+    0xB0: "fiveinferior - commainferior periodinferior Yacutesmall - dollarinferior - - Thornsmall - nineinferior zeroinferior Zcaronsmall AEsmall Oslashsmall",
+    0xC0: "questiondownsmall oneinferior Lslashsmall - - - - - - Cedillasmall - - - - - OEsmall",
+    0xD0: "figuredash hyphensuperior - - - - exclamdownsmall - Ydieresissmall - onesuperior twosuperior threesuperior foursuperior fivesuperior sixsuperior",
+    0xE0: "sevensuperior ninesuperior zerosuperior - esuperior rsuperior tsuperior - - isuperior ssuperior dsuperior - - - -",
+    0xF0: "- lsuperior Ogoneksmall Brevesmall Macronsmall bsuperior nsuperior msuperior commasuperior periodsuperior Dotaccentsmall Ringsmall - - - -",
+}
+
+PDF_DOC = {
+    0x10: "- - - - - - - - breve caron circumflex dotaccent hungarumlaut ogonek ring tilde",
+    0x20: "space exclam quotedbl numbersign dollar percent ampersand quotesingle parenleft parenright asterisk plus comma hyphen period slash",
+    0x30: "zero one two three four five six seven eight nine colon semicolon less equal greater question",
+    0x40: "at A B C D E F G H I J K L M N O",
+    0x50: "P Q R S T U V W X Y Z bracketleft backslash bracketright asciicircum underscore",
+    0x60: "grave a b c d e f g h i j k l m n o",
+    0x70: "p q r s t u v w x y z braceleft bar braceright asciitilde -",
+    0x80: "bullet dagger daggerdbl ellipsis emdash endash florin fraction guilsinglleft guilsinglright minus perthousand quotedblbase quotedblleft quotedblright quoteleft",
+    0x90: "quoteright quotesinglbase trademark fi fl Lslash OE Scaron Ydieresis Zcaron dotlessi lslash oe scaron zcaron -",
+    0xA0: "Euro exclamdown cent sterling currency yen brokenbar section dieresis copyright ordfeminine guillemotleft logicalnot - registered macron",
+    0xB0: "degree plusminus twosuperior threesuperior acute mu paragraph periodcentered cedilla onesuperior ordmasculine guillemotright onequarter onehalf threequarters questiondown",
+    0xC0: "Agrave Aacute Acircumflex Atilde Adieresis Aring AE Ccedilla Egrave Eacute Ecircumflex Edieresis Igrave Iacute Icircumflex Idieresis",
+    0xD0: "Eth Ntilde Ograve Oacute Ocircumflex Otilde Odieresis multiply Oslash Ugrave Uacute Ucircumflex Udieresis Yacute Thorn germandbls",
+    0xE0: "agrave aacute acircumflex atilde adieresis aring ae ccedilla egrave eacute ecircumflex edieresis igrave iacute icircumflex idieresis",
+    0xF0: "eth ntilde ograve oacute ocircumflex otilde odieresis divide oslash ugrave uacute ucircumflex udieresis yacute thorn ydieresis",
+}
+
+SYMBOL = {
+    0x20: "space exclam universal numbersign existential percent ampersand suchthat parenleft parenright asteriskmath plus comma minus period slash",
+    0x30: "zero one two three four five six seven eight nine colon semicolon less equal greater question",
+    0x40: "congruent Alpha Beta Chi Delta Epsilon Phi Gamma Eta Iota theta1 Kappa Lambda Mu Nu Omicron",
+    0x50: "Pi Theta Rho Sigma Tau Upsilon sigma1 Omega Xi Psi Zeta bracketleft therefore bracketright perpendicular underscore",
+    0x60: "radicalex alpha beta chi delta epsilon phi gamma eta iota phi1 kappa lambda mu nu omicron",
+    0x70: "pi theta rho sigma tau upsilon omega1 omega xi psi zeta braceleft bar braceright similar -",
+    0xA0: "Euro Upsilon1 minute lessequal fraction infinity florin club diamond heart spade arrowboth arrowleft arrowup arrowright arrowdown",
+    0xB0: "degree plusminus second greaterequal multiply proportional partialdiff bullet divide notequal equivalence approxequal ellipsis arrowvertex arrowhorizex carriagereturn",
+    0xC0: "aleph Ifraktur Rfraktur weierstrass circlemultiply circleplus emptyset intersection union propersuperset reflexsuperset notsubset propersubset reflexsubset element notelement",
+    0xD0: "angle gradient registerserif copyrightserif trademarkserif product radical dotmath logicalnot logicaland logicalor arrowdblboth arrowdblleft arrowdblup arrowdblright arrowdbldown",
+    0xE0: "lozenge angleleft registersans copyrightsans trademarksans summation parenlefttp parenleftex parenleftbt bracketlefttp bracketleftex bracketleftbt bracelefttp braceleftmid braceleftbt braceex",
+    0xF0: "- angleright integral integraltp integralex integralbt parenrighttp parenrightex parenrightbt bracketrighttp bracketrightex bracketrightbt bracerighttp bracerightmid bracerightbt -",
+}
+
+ZAPF_DINGBATS = {
+    0x20: "space a1 a2 a202 a3 a4 a5 a119 a118 a117 a11 a12 a13 a14 a15 a16",
+    0x30: "a105 a17 a18 a19 a20 a21 a22 a23 a24 a25 a26 a27 a28 a6 a7 a8",
+    0x40: "a9 a10 a29 a30 a31 a32 a33 a34 a35 a36 a37 a38 a39 a40 a41 a42",
+    0x50: "a43 a44 a45 a46 a47 a48 a49 a50 a51 a52 a53 a54 a55 a56 a57 a58",
+    0x60: "a59 a60 a61 a62 a63 a64 a65 a66 a67 a68 a69 a70 a71 a72 a73 a74",
+    0x70: "a203 a75 a204 a76 a77 a78 a79 a81 a82 a83 a84 a97 a98 a99 a100 -",
+    0x80: "a89 a90 a93 a94 a91 a92 a205 a85 a206 a86 a87 a88 a95 a96 - -",
+    0xA0: "- a101 a102 a103 a104 a106 a107 a108 a112 a111 a110 a109 a120 a121 a122 a123",
+    0xB0: "a124 a125 a126 a127 a128 a129 a130 a131 a132 a133 a134 a135 a136 a137 a138 a139",
+    0xC0: "a140 a141 a142 a143 a144 a145 a146 a147 a148 a149 a150 a151 a152 a153 a154 a155",
+    0xD0: "a156 a157 a158 a159 a160 a161 a163 a164 a196 a165 a192 a166 a167 a168 a169 a170",
+    0xE0: "a171 a172 a173 a162 a174 a175 a176 a177 a178 a179 a193 a180 a199 a181 a200 a182",
+    0xF0: "- a201 a183 a184 a197 a185 a194 a198 a186 a195 a187 a188 a189 a190 a191 -",
+}
+
+ENCODINGS = [
+    ("standard.rs", "STANDARD", "The Adobe Standard Encoding.", STANDARD),
+    ("win_ansi.rs", "WIN_ANSI", "The `WinAnsiEncoding`.", WIN_ANSI),
+    ("mac_roman.rs", "MAC_ROMAN", "The `MacRomanEncoding`.", MAC_ROMAN),
+    ("mac_expert.rs", "MAC_EXPERT", "The `MacExpertEncoding`.", MAC_EXPERT),
+    ("pdf_doc.rs", "PDF_DOC", "The `PDFDocEncoding`.", PDF_DOC),
+    ("symbol.rs", "SYMBOL", "The built-in encoding of the Symbol font.", SYMBOL),
+    (
+        "zapf_dingbats.rs",
+        "ZAPF_DINGBATS",
+        "The built-in encoding of the ZapfDingbats font.",
+        ZAPF_DINGBATS,
+    ),
+]
+
+
+def generate(name, doc, rows):
+    lines = [
+        "// THIS FILE WAS AUTO-GENERATED, DO NOT EDIT MANUALLY!",
+        "use phf::phf_map;",
+        "",
+        "/// %s" % doc,
+        "pub static %s: phf::Map<u8, &'static str> = phf_map! {" % name,
+    ]
+    for row, names in sorted(rows.items()):
+        names = names.split()
+        assert len(names) == 16, "row 0x%02X must have 16 entries" % row
+        for i, glyph_name in enumerate(names):
+            if glyph_name != "-":
+                lines.append('    %du8 => "%s",' % (row + i, glyph_name))
+    lines.append("};")
+    return "\n".join(lines) + "\n"
+
+
+def main():
+    out_dir = sys.argv[1]
+    for file_name, name, doc, rows in ENCODINGS:
+        with open(os.path.join(out_dir, file_name), "w") as f:
+            f.write(generate(name, doc, rows))
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,116 @@
+#!/usr/bin/env python3
+"""Generates `src/standard14/data.rs` from the AFM files of the standard 14 fonts.
+
+The AFM files are part of Adobe's Core14 font metrics. Usage:
+
+    python3 scripts/gen_standard14.py path/to/afm-dir > src/standard14/data.rs
+"""
+
+import os
+import sys
+
+FONTS = [
+    ("HELVETICA", "Helvetica"),
+    ("HELVETICA_BOLD", "Helvetica-Bold"),
+    ("HELVETICA_OBLIQUE", "Helvetica-Oblique"),
+    ("HELVETICA_BOLD_OBLIQUE", "Helvetica-BoldOblique"),
+    ("TIMES_ROMAN", "Times-Roman"),
+    ("TIMES_BOLD", "Times-Bold"),
+    ("TIMES_ITALIC", "Times-Italic"),
+    ("TIMES_BOLD_ITALIC", "Times-BoldItalic"),
+    ("COURIER", "Courier"),
+    ("COURIER_BOLD", "Courier-Bold"),
+    ("COURIER_OBLIQUE", "Courier-Oblique"),
+    ("COURIER_BOLD_OBLIQUE", "Courier-BoldOblique"),
+    ("SYMBOL", "Symbol"),
+    ("ZAPF_DINGBATS", "ZapfDingbats"),
+]
+
+
+def parse_afm(path):
+    header = {}
+    glyphs = {}
+    kern_pairs = {}
+    section = None
+    with open(path, encoding="latin-1") as f:
+        for line in f:
+            line = line.strip()
+            if not line or line.startswith("Comment"):
+                continue
+            key, _, value = line.partition(" ")
+            if key in ("StartCharMetrics", "StartKernPairs", "StartKernPairs0"):
+                section = key
+            elif key.startswith("End"):
+                section = None
+            elif section == "StartCharMetrics":
+                fields = dict(
+                    (f.split()[0], f.split()[1:]) for f in line.split(";") if f.strip()
+                )
+                bbox = fields.get("B")
+                glyphs[fields["N"][0]] = (
+                    int(float(fields["WX"][0])),
+                    tuple(int(v) for v in bbox) if bbox else None,
+                )
+            elif section is not None and key == "KPX":
+                left, right, x = value.split()
+                kern_pairs[(left, right)] = int(x)
+            elif section is None:
+                header[key] = value
+    return header, glyphs, kern_pairs
+
+
+def optional(header, key):
+    value = header.get(key)
+    return "Some(%d)" % int(value) if value is not None else "None"
+
+
+def rect(values):
+    return "Rect { x_min: %d, y_min: %d, x_max: %d, y_max: %d }" % tuple(values)
+
+
+def generate(static_name, path):
+    header, glyphs, kern_pairs = parse_afm(path)
+    bbox = [int(v) for v in header["FontBBox"].split()]
+    lines = [
+        "pub(crate) static %s: FontMetrics = FontMetrics {" % static_name,
+        '    font_name: "%s",' % header["FontName"],
+        "    bbox: %s," % rect(bbox),
+        "    italic_angle: %s," % repr(float(header.get("ItalicAngle", "0"))),
+        "    is_fixed_pitch: %s," % header.get("IsFixedPitch", "false"),
+        "    ascent: %s," % optional(header, "Ascender"),
+        "    descent: %s," % optional(header, "Descender"),
+        "    cap_height: %s," % optional(header, "CapHeight"),
+        "    x_height: %s," % optional(header, "XHeight"),
+        "    stem_v: %d," % int(header["StdVW"]),
+        "    stem_h: %d," % int(header["StdHW"]),
+        "    underline_position: %d," % int(header["UnderlinePosition"]),
+        "    underline_thickness: %d," % int(header["UnderlineThickness"]),
+        "    glyphs: &[",
+    ]
+    for name, (width, glyph_bbox) in sorted(glyphs.items()):
+        glyph_bbox = "Some(%s)" % rect(glyph_bbox) if glyph_bbox else "None"
+        lines.append(
+            '        GlyphMetrics { name: "%s", width: %d, bbox: %s },'
+            % (name, width, glyph_bbox)
+        )
+    lines.append("    ],")
+    lines.append("    kern_pairs: &[")
+    for (left, right), x in sorted(kern_pairs.items()):
+        lines.append('        ("%s", "%s", %d),' % (left, right, x))
+    lines.append("    ],")
+    lines.append("};")
+    return "\n".join(lines)
+
+
+def main():
+    afm_dir = sys.argv[1]
+    print("// THIS FILE WAS AUTO-GENERATED, DO NOT EDIT MANUALLY!")
+    print("use super::{FontMetrics, GlyphMetrics};")
+    print("use crate::Rect;")
+    for static_name, font_name in FONTS:
+        print()
+        print(generate(static_name, os.path.join(afm_dir, font_name + ".afm")))
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,433 @@
+//! An [Adobe Font Metrics](
+//! https://adobe-type-tools.github.io/font-tech-notes/pdfs/5004.AFM_Spec.pdf) parser.
+//!
+//! Glyphs are looked up by name, so the metrics can be used together
+//! with a [`type1::Table`](crate::type1::Table).
+
+use core::convert::TryFrom;
+use std::collections::HashMap;
+
+use crate::RectF;
+
+/// The metrics of a single character, from a `StartCharMetrics` section.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CharMetrics {
+    /// The character code in the default encoding, or `None` if unencoded.
+    pub code: Option<u8>,
+    /// The glyph name.
+    pub name: String,
+    /// The horizontal advance width.
+    pub width: f32,
+    /// The bounding box.
+    pub bbox: Option<RectF>,
+    /// The ligatures, as pairs of a successor and a ligature glyph name.
+    pub ligatures: Vec<(String, String)>,
+}
+
+/// A kerning pair, from a `KPX`, `KPY` or `KP` entry.
+#[derive(Clone, Debug, PartialEq)]
+pub struct KernPair {
+    /// The name of the first glyph.
+    pub left: String,
+    /// The name of the second glyph.
+    pub right: String,
+    /// The horizontal kerning.
+    pub x: f32,
+    /// The vertical kerning.
+    pub y: f32,
+}
+
+/// A part of a composite character, from a `PCC` entry.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CompositePart {
+    /// The glyph name.
+    pub name: String,
+    /// The horizontal displacement.
+    pub dx: f32,
+    /// The vertical displacement.
+    pub dy: f32,
+}
+
+/// A composite character, from a `CC` entry.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Composite {
+    /// The name of the composite glyph.
+    pub name: String,
+    /// The parts the glyph is built from.
+    pub parts: Vec<CompositePart>,
+}
+
+/// A parsed AFM file.
+#[allow(missing_docs)]
+#[derive(Clone, Default, Debug)]
+pub struct Afm {
+    pub font_name: Option<String>,
+    pub full_name: Option<String>,
+    pub family_name: Option<String>,
+    pub weight: Option<String>,
+    pub version: Option<String>,
+    pub notice: Option<String>,
+    pub encoding_scheme: Option<String>,
+    pub character_set: Option<String>,
+    pub font_bbox: Option<RectF>,
+    pub italic_angle: f32,
+    pub is_fixed_pitch: bool,
+    pub underline_position: Option<f32>,
+    pub underline_thickness: Option<f32>,
+    pub cap_height: Option<f32>,
+    pub x_height: Option<f32>,
+    pub ascender: Option<f32>,
+    pub descender: Option<f32>,
+    pub std_hw: Option<f32>,
+    pub std_vw: Option<f32>,
+    // Private, so they can't get out of sync with the indices below.
+    char_metrics: Vec<CharMetrics>,
+    kern_pairs: Vec<KernPair>,
+    composites: Vec<Composite>,
+    // Indices into the vectors above by glyph name.
+    char_metrics_index: HashMap<String, usize>,
+    composites_index: HashMap<String, usize>,
+    kern_pairs_index: HashMap<String, HashMap<String, usize>>,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Section {
+    Header,
+    CharMetrics,
+    KernPairs,
+    Composites,
+    Other,
+}
+
+impl Afm {
+    /// Parses an AFM file.
+    ///
+    /// Unknown keys and malformed lines are skipped.
+    pub fn parse(data: &[u8]) -> Option<Self> {
+        let text = String::from_utf8_lossy(data);
+        let mut lines = text.lines().map(str::trim);
+
+        let first = lines.find(|line| !line.is_empty())?;
+        if !first.starts_with("StartFontMetrics") {
+            return None;
+        }
+
+        let mut afm = Afm::default();
+        let mut section = Section::Header;
+
+        for line in lines {
+            let (key, value) = match line.split_once(char::is_whitespace) {
+                Some((key, value)) => (key, value.trim()),
+                None => (line, ""),
+            };
+
+            match key {
+                "" | "Comment" => continue,
+                "StartCharMetrics" => section = Section::CharMetrics,
+                "StartKernPairs" | "StartKernPairs0" => section = Section::KernPairs,
+                "StartComposites" => section = Section::Composites,
+                // The kerning data and track kerning sections only contain other sections.
+                "StartKernData" | "EndKernData" => section = Section::Header,
+                "StartTrackKern" | "StartKernPairs1" | "StartDirection" => section = Section::Other,
+                "EndCharMetrics" | "EndKernPairs" | "EndComposites" | "EndTrackKern"
+                | "EndDirection" => section = Section::Header,
+                "EndFontMetrics" => break,
+                _ => match section {
+                    Section::Header => afm.parse_header_entry(key, value),
+                    Section::CharMetrics => {
+                        if let Some(metrics) = parse_char_metrics(line) {
+                            afm.char_metrics.push(metrics);
+                        }
+                    }
+                    Section::KernPairs => {
+                        if let Some(pair) = parse_kern_pair(key, value) {
+                            afm.kern_pairs.push(pair);
+                        }
+                    }
+                    Section::Composites => {
+                        if let Some(composite) = parse_composite(line) {
+                            afm.composites.push(composite);
+                        }
+                    }
+                    Section::Other => {}
+                },
+            }
+        }
+
+        // Later entries take precedence, like in a dictionary.
+        for (i, metrics) in afm.char_metrics.iter().enumerate() {
+            afm.char_metrics_index.insert(metrics.name.clone(), i);
+        }
+
+        for (i, composite) in afm.composites.iter().enumerate() {
+            afm.composites_index.insert(composite.name.clone(), i);
+        }
+
+        for (i, pair) in afm.kern_pairs.iter().enumerate() {
+            afm.kern_pairs_index
+                .entry(pair.left.clone())
+                .or_default()
+                .insert(pair.right.clone(), i);
+        }
+
+        Some(afm)
+    }
+
+    fn parse_header_entry(&mut self, key: &str, value: &str) {
+        let string = || Some(value.to_string());
+        let number = || value.parse::<f32>().ok();
+
+        match key {
+            "FontName" => self.font_name = string(),
+            "FullName" => self.full_name = string(),
+            "FamilyName" => self.family_name = string(),
+            "Weight" => self.weight = string(),
+            "Version" => self.version = string(),
+            "Notice" => self.notice = string(),
+            "EncodingScheme" => self.encoding_scheme = string(),
+            "CharacterSet" => self.character_set = string(),
+            "FontBBox" => self.font_bbox = parse_rect(value),
+            "ItalicAngle" => self.italic_angle = number().unwrap_or(0.0),
+            "IsFixedPitch" => self.is_fixed_pitch = value == "true",
+            "UnderlinePosition" => self.underline_position = number(),
+            "UnderlineThickness" => self.underline_thickness = number(),
+            "CapHeight" => self.cap_height = number(),
+            "XHeight" => self.x_height = number(),
+            "Ascender" => self.ascender = number(),
+            "Descender" => self.descender = number(),
+            "StdHW" => self.std_hw = number(),
+            "StdVW" => self.std_vw = number(),
+            _ => {}
+        }
+    }
+
+    /// Returns the metrics of all glyphs, in file order.
+    pub fn char_metrics(&self) -> &[CharMetrics] {
+        &self.char_metrics
+    }
+
+    /// Returns all kerning pairs, in file order.
+    pub fn kern_pairs(&self) -> &[KernPair] {
+        &self.kern_pairs
+    }
+
+    /// Returns all composite characters, in file order.
+    pub fn composites(&self) -> &[Composite] {
+        &self.composites
+    }
+
+    /// Returns the metrics of a glyph.
+    pub fn glyph_metrics(&self, name: &str) -> Option<&CharMetrics> {
+        let index = *self.char_metrics_index.get(name)?;
+        self.char_metrics.get(index)
+    }
+
+    /// Returns the advance width of a glyph.
+    pub fn glyph_width(&self, name: &str) -> Option<f32> {
+        self.glyph_metrics(name).map(|metrics| metrics.width)
+    }
+
+    /// Returns the metrics of the glyph mapped to a code in the default encoding.
+    pub fn glyph_metrics_by_code(&self, code: u8) -> Option<&CharMetrics> {
+        self.char_metrics
+            .iter()
+            .find(|metrics| metrics.code == Some(code))
+    }
+
+    /// Returns the horizontal kerning between two glyphs.
+    pub fn kerning(&self, left: &str, right: &str) -> Option<f32> {
+        self.kern_pair(left, right).map(|pair| pair.x)
+    }
+
+    /// Returns the kerning pair of two glyphs.
+    pub fn kern_pair(&self, left: &str, right: &str) -> Option<&KernPair> {
+        let index = *self.kern_pairs_index.get(left)?.get(right)?;
+        self.kern_pairs.get(index)
+    }
+
+    /// Returns the ligature that replaces two glyphs.
+    pub fn ligature(&self, first: &str, second: &str) -> Option<&str> {
+        self.glyph_metrics(first)?
+            .ligatures
+            .iter()
+            .find(|(successor, _)| successor == second)
+            .map(|(_, ligature)| ligature.as_str())
+    }
+
+    /// Returns the composite character with a name.
+    pub fn composite(&self, name: &str) -> Option<&Composite> {
+        let index = *self.composites_index.get(name)?;
+        self.composites.get(index)
+    }
+}
+
+fn parse_rect(value: &str) -> Option<RectF> {
+    let mut numbers = value.split_whitespace().map(|n| n.parse::<f32>().ok());
+    Some(RectF {
+        x_min: numbers.next()??,
+        y_min: numbers.next()??,
+        x_max: numbers.next()??,
+        y_max: numbers.next()??,
+    })
+}
+
+// Parses a line like `C 65 ; WX 667 ; N A ; B 14 0 654 718 ; L f fi ;`.
+fn parse_char_metrics(line: &str) -> Option<CharMetrics> {
+    let mut code = None;
+    let mut name = None;
+    let mut width = None;
+    let mut bbox = None;
+    let mut ligatures = Vec::new();
+
+    for entry in line.split(';') {
+        let mut parts = entry.split_whitespace();
+        let key = match parts.next() {
+            Some(key) => key,
+            None => continue,
+        };
+
+        match key {
+            "C" => {
+                let value = parts.next()?.parse::<i32>().ok()?;
+                code = u8::try_from(value).ok();
+            }
+            "CH" => {
+                let value = parts.next()?;
+                let value = value.strip_prefix('<')?.strip_suffix('>')?;
+                code = u8::from_str_radix(value, 16).ok();
+            }
+            "WX" | "W0X" => width = parts.next()?.parse::<f32>().ok(),
+            "W" | "W0" => width = parts.next()?.parse::<f32>().ok(),
+            "N" => name = parts.next().map(str::to_string),
+            "B" => bbox = parse_rect(&parts.collect::<Vec<_>>().join(" ")),
+            "L" => {
+                let successor = parts.next()?.to_string();
+                let ligature = parts.next()?.to_string();
+                ligatures.push((successor, ligature));
+            }
+            _ => {}
+        }
+    }
+
+    Some(CharMetrics {
+        code,
+        name: name?,
+        width: width.unwrap_or(0.0),
+        bbox,
+        ligatures,
+    })
+}
+
+fn parse_kern_pair(key: &str, value: &str) -> Option<KernPair> {
+    let mut parts = value.split_whitespace();
+    let left = parts.next()?.to_string();
+    let right = parts.next()?.to_string();
+    let mut number = || parts.next()?.parse::<f32>().ok();
+
+    let (x, y) = match key {
+        "KPX" => (number()?, 0.0),
+        "KPY" => (0.0, number()?),
+        "KP" => (number()?, number()?),
+        _ => return None,
+    };
+
+    Some(KernPair { left, right, x, y })
+}
+
+// Parses a line like `CC Aacute 2 ; PCC A 0 0 ; PCC acute 194 218 ;`.
+fn parse_composite(line: &str) -> Option<Composite> {
+    let mut entries = line.split(';');
+
+    let mut header = entries.next()?.split_whitespace();
+    if header.next()? != "CC" {
+        return None;
+    }
+
+    let name = header.next()?.to_string();
+    let mut parts = Vec::new();
+    for entry in entries {
+        let mut fields = entry.split_whitespace();
+        if fields.next() != Some("PCC") {
+            continue;
+        }
+
+        parts.push(CompositePart {
+            name: fields.next()?.to_string(),
+            dx: fields.next()?.parse().ok()?,
+            dy: fields.next()?.parse().ok()?,
+        });
+    }
+
+    Some(Composite { name, parts })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const AFM: &str = "StartFontMetrics 4.1
+Comment A test font
+FontName Test-Regular
+FamilyName Test
+ItalicAngle -12.5
+IsFixedPitch false
+FontBBox -166 -225 1000 931
+CapHeight 718
+StdVW 88
+StartCharMetrics 4
+C 65 ; WX 667 ; N A ; B 14 0 654 718 ;
+C 102 ; WX 278 ; N f ; B 14 0 262 728 ; L i fi ; L l fl ;
+C -1 ; WX 278 ; N fi ; B 14 0 262 728 ;
+CH <C1> ; WX 667 ; N Aacute ; B 14 0 654 929 ;
+EndCharMetrics
+StartKernData
+StartKernPairs 3
+KPX A f -20
+KPY A Aacute 5
+KP f A 10 -5
+EndKernPairs
+EndKernData
+StartComposites 1
+CC Aacute 2 ; PCC A 0 0 ; PCC acute 194 211 ;
+EndComposites
+EndFontMetrics
+";
+
+    #[test]
+    fn parse_afm() {
+        let afm = Afm::parse(AFM.as_bytes()).unwrap();
+        assert_eq!(afm.font_name.as_deref(), Some("Test-Regular"));
+        assert_eq!(afm.italic_angle, -12.5);
+        assert_eq!(afm.cap_height, Some(718.0));
+        assert_eq!(afm.font_bbox.unwrap().y_max, 931.0);
+        assert_eq!(afm.char_metrics().len(), 4);
+        assert_eq!(afm.kern_pairs().len(), 3);
+        assert_eq!(afm.composites()[0].name, "Aacute");
+
+        let a = afm.glyph_metrics("A").unwrap();
+        assert_eq!(a.code, Some(65));
+        assert_eq!(a.bbox.unwrap().x_max, 654.0);
+        assert_eq!(afm.glyph_width("fi"), Some(278.0));
+        assert_eq!(afm.glyph_metrics("fi").unwrap().code, None);
+        assert_eq!(afm.glyph_metrics_by_code(0xC1).unwrap().name, "Aacute");
+
+        assert_eq!(afm.ligature("f", "i"), Some("fi"));
+        assert_eq!(afm.ligature("f", "f"), None);
+
+        assert_eq!(afm.kerning("A", "f"), Some(-20.0));
+        assert_eq!(afm.kern_pair("A", "Aacute").unwrap().y, 5.0);
+        assert_eq!(afm.kern_pair("f", "A").unwrap().y, -5.0);
+        assert_eq!(afm.kerning("f", "f"), None);
+
+        let composite = afm.composite("Aacute").unwrap();
+        assert_eq!(composite.parts.len(), 2);
+        assert_eq!(composite.parts[1].name, "acute");
+        assert_eq!(composite.parts[1].dx, 194.0);
+    }
+
+    #[test]
+    fn invalid_afm() {
+        assert!(Afm::parse(b"FontName Test").is_none());
+        assert!(Afm::parse(b"").is_none());
+    }
+}
@@ -0,0 +1,4875 @@
+// THIS FILE WAS AUTO-GENERATED, DO NOT EDIT MANUALLY!
+use phf::phf_map;
+
+pub(crate) static GLYPH_LIST: phf::Map<&'static str, &'static str> = phf_map! {
+    "A" => "\u{41}",
+    "AE" => "\u{C6}",
+    "AEacute" => "\u{1FC}",
+    "AEmacron" => "\u{1E2}",
+    "AEsmall" => "\u{F7E6}",
+    "Aacute" => "\u{C1}",
+    "Aacutesmall" => "\u{F7E1}",
+    "Abreve" => "\u{102}",
+    "Abreveacute" => "\u{1EAE}",
+    "Abrevecyrillic" => "\u{4D0}",
+    "Abrevedotbelow" => "\u{1EB6}",
+    "Abrevegrave" => "\u{1EB0}",
+    "Abrevehookabove" => "\u{1EB2}",
+    "Abrevetilde" => "\u{1EB4}",
+    "Acaron" => "\u{1CD}",
+    "Acircle" => "\u{24B6}",
+    "Acircumflex" => "\u{C2}",
+    "Acircumflexacute" => "\u{1EA4}",
+    "Acircumflexdotbelow" => "\u{1EAC}",
+    "Acircumflexgrave" => "\u{1EA6}",
+    "Acircumflexhookabove" => "\u{1EA8}",
+    "Acircumflexsmall" => "\u{F7E2}",
+    "Acircumflextilde" => "\u{1EAA}",
+    "Acute" => "\u{F6C9}",
+    "Acutesmall" => "\u{F7B4}",
+    "Acyrillic" => "\u{410}",
+    "Adblgrave" => "\u{200}",
+    "Adieresis" => "\u{C4}",
+    "Adieresiscyrillic" => "\u{4D2}",
+    "Adieresismacron" => "\u{1DE}",
+    "Adieresissmall" => "\u{F7E4}",
+    "Adotbelow" => "\u{1EA0}",
+    "Adotmacron" => "\u{1E0}",
+    "Agrave" => "\u{C0}",
+    "Agravesmall" => "\u{F7E0}",
+    "Ahookabove" => "\u{1EA2}",
+    "Aiecyrillic" => "\u{4D4}",
+    "Ainvertedbreve" => "\u{202}",
+    "Alpha" => "\u{391}",
+    "Alphatonos" => "\u{386}",
+    "Amacron" => "\u{100}",
+    "Amonospace" => "\u{FF21}",
+    "Aogonek" => "\u{104}",
+    "Aring" => "\u{C5}",
+    "Aringacute" => "\u{1FA}",
+    "Aringbelow" => "\u{1E00}",
+    "Aringsmall" => "\u{F7E5}",
+    "Asmall" => "\u{F761}",
+    "Atilde" => "\u{C3}",
+    "Atildesmall" => "\u{F7E3}",
+    "Aybarmenian" => "\u{531}",
+    "B" => "\u{42}",
+    "Bcircle" => "\u{24B7}",
+    "Bdotaccent" => "\u{1E02}",
+    "Bdotbelow" => "\u{1E04}",
+    "Becyrillic" => "\u{411}",
+    "Benarmenian" => "\u{532}",
+    "Beta" => "\u{392}",
+    "Bhook" => "\u{181}",
+    "Blinebelow" => "\u{1E06}",
+    "Bmonospace" => "\u{FF22}",
+    "Brevesmall" => "\u{F6F4}",
+    "Bsmall" => "\u{F762}",
+    "Btopbar" => "\u{182}",
+    "C" => "\u{43}",
+    "Caarmenian" => "\u{53E}",
+    "Cacute" => "\u{106}",
+    "Caron" => "\u{F6CA}",
+    "Caronsmall" => "\u{F6F5}",
+    "Ccaron" => "\u{10C}",
+    "Ccedilla" => "\u{C7}",
+    "Ccedillaacute" => "\u{1E08}",
+    "Ccedillasmall" => "\u{F7E7}",
+    "Ccircle" => "\u{24B8}",
+    "Ccircumflex" => "\u{108}",
+    "Cdot" => "\u{10A}",
+    "Cdotaccent" => "\u{10A}",
+    "Cedillasmall" => "\u{F7B8}",
+    "Chaarmenian" => "\u{549}",
+    "Cheabkhasiancyrillic" => "\u{4BC}",
+    "Checyrillic" => "\u{427}",
+    "Chedescenderabkhasiancyrillic" => "\u{4BE}",
+    "Chedescendercyrillic" => "\u{4B6}",
+    "Chedieresiscyrillic" => "\u{4F4}",
+    "Cheharmenian" => "\u{543}",
+    "Chekhakassiancyrillic" => "\u{4CB}",
+    "Cheverticalstrokecyrillic" => "\u{4B8}",
+    "Chi" => "\u{3A7}",
+    "Chook" => "\u{187}",
+    "Circumflexsmall" => "\u{F6F6}",
+    "Cmonospace" => "\u{FF23}",
+    "Coarmenian" => "\u{551}",
+    "Csmall" => "\u{F763}",
+    "D" => "\u{44}",
+    "DZ" => "\u{1F1}",
+    "DZcaron" => "\u{1C4}",
+    "Daarmenian" => "\u{534}",
+    "Dafrican" => "\u{189}",
+    "Dcaron" => "\u{10E}",
+    "Dcedilla" => "\u{1E10}",
+    "Dcircle" => "\u{24B9}",
+    "Dcircumflexbelow" => "\u{1E12}",
+    "Dcroat" => "\u{110}",
+    "Ddotaccent" => "\u{1E0A}",
+    "Ddotbelow" => "\u{1E0C}",
+    "Decyrillic" => "\u{414}",
+    "Deicoptic" => "\u{3EE}",
+    "Delta" => "\u{2206}",
+    "Deltagreek" => "\u{394}",
+    "Dhook" => "\u{18A}",
+    "Dieresis" => "\u{F6CB}",
+    "DieresisAcute" => "\u{F6CC}",
+    "DieresisGrave" => "\u{F6CD}",
+    "Dieresissmall" => "\u{F7A8}",
+    "Digammagreek" => "\u{3DC}",
+    "Djecyrillic" => "\u{402}",
+    "Dlinebelow" => "\u{1E0E}",
+    "Dmonospace" => "\u{FF24}",
+    "Dotaccentsmall" => "\u{F6F7}",
+    "Dslash" => "\u{110}",
+    "Dsmall" => "\u{F764}",
+    "Dtopbar" => "\u{18B}",
+    "Dz" => "\u{1F2}",
+    "Dzcaron" => "\u{1C5}",
+    "Dzeabkhasiancyrillic" => "\u{4E0}",
+    "Dzecyrillic" => "\u{405}",
+    "Dzhecyrillic" => "\u{40F}",
+    "E" => "\u{45}",
+    "Eacute" => "\u{C9}",
+    "Eacutesmall" => "\u{F7E9}",
+    "Ebreve" => "\u{114}",
+    "Ecaron" => "\u{11A}",
+    "Ecedillabreve" => "\u{1E1C}",
+    "Echarmenian" => "\u{535}",
+    "Ecircle" => "\u{24BA}",
+    "Ecircumflex" => "\u{CA}",
+    "Ecircumflexacute" => "\u{1EBE}",
+    "Ecircumflexbelow" => "\u{1E18}",
+    "Ecircumflexdotbelow" => "\u{1EC6}",
+    "Ecircumflexgrave" => "\u{1EC0}",
+    "Ecircumflexhookabove" => "\u{1EC2}",
+    "Ecircumflexsmall" => "\u{F7EA}",
+    "Ecircumflextilde" => "\u{1EC4}",
+    "Ecyrillic" => "\u{404}",
+    "Edblgrave" => "\u{204}",
+    "Edieresis" => "\u{CB}",
+    "Edieresissmall" => "\u{F7EB}",
+    "Edot" => "\u{116}",
+    "Edotaccent" => "\u{116}",
+    "Edotbelow" => "\u{1EB8}",
+    "Efcyrillic" => "\u{424}",
+    "Egrave" => "\u{C8}",
+    "Egravesmall" => "\u{F7E8}",
+    "Eharmenian" => "\u{537}",
+    "Ehookabove" => "\u{1EBA}",
+    "Eightroman" => "\u{2167}",
+    "Einvertedbreve" => "\u{206}",
+    "Eiotifiedcyrillic" => "\u{464}",
+    "Elcyrillic" => "\u{41B}",
+    "Elevenroman" => "\u{216A}",
+    "Emacron" => "\u{112}",
+    "Emacronacute" => "\u{1E16}",
+    "Emacrongrave" => "\u{1E14}",
+    "Emcyrillic" => "\u{41C}",
+    "Emonospace" => "\u{FF25}",
+    "Encyrillic" => "\u{41D}",
+    "Endescendercyrillic" => "\u{4A2}",
+    "Eng" => "\u{14A}",
+    "Enghecyrillic" => "\u{4A4}",
+    "Enhookcyrillic" => "\u{4C7}",
+    "Eogonek" => "\u{118}",
+    "Eopen" => "\u{190}",
+    "Epsilon" => "\u{395}",
+    "Epsilontonos" => "\u{388}",
+    "Ercyrillic" => "\u{420}",
+    "Ereversed" => "\u{18E}",
+    "Ereversedcyrillic" => "\u{42D}",
+    "Escyrillic" => "\u{421}",
+    "Esdescendercyrillic" => "\u{4AA}",
+    "Esh" => "\u{1A9}",
+    "Esmall" => "\u{F765}",
+    "Eta" => "\u{397}",
+    "Etarmenian" => "\u{538}",
+    "Etatonos" => "\u{389}",
+    "Eth" => "\u{D0}",
+    "Ethsmall" => "\u{F7F0}",
+    "Etilde" => "\u{1EBC}",
+    "Etildebelow" => "\u{1E1A}",
+    "Euro" => "\u{20AC}",
+    "Ezh" => "\u{1B7}",
+    "Ezhcaron" => "\u{1EE}",
+    "Ezhreversed" => "\u{1B8}",
+    "F" => "\u{46}",
+    "Fcircle" => "\u{24BB}",
+    "Fdotaccent" => "\u{1E1E}",
+    "Feharmenian" => "\u{556}",
+    "Feicoptic" => "\u{3E4}",
+    "Fhook" => "\u{191}",
+    "Fitacyrillic" => "\u{472}",
+    "Fiveroman" => "\u{2164}",
+    "Fmonospace" => "\u{FF26}",
+    "Fourroman" => "\u{2163}",
+    "Fsmall" => "\u{F766}",
+    "G" => "\u{47}",
+    "GBsquare" => "\u{3387}",
+    "Gacute" => "\u{1F4}",
+    "Gamma" => "\u{393}",
+    "Gammaafrican" => "\u{194}",
+    "Gangiacoptic" => "\u{3EA}",
+    "Gbreve" => "\u{11E}",
+    "Gcaron" => "\u{1E6}",
+    "Gcedilla" => "\u{122}",
+    "Gcircle" => "\u{24BC}",
+    "Gcircumflex" => "\u{11C}",
+    "Gcommaaccent" => "\u{122}",
+    "Gdot" => "\u{120}",
+    "Gdotaccent" => "\u{120}",
+    "Gecyrillic" => "\u{413}",
+    "Ghadarmenian" => "\u{542}",
+    "Ghemiddlehookcyrillic" => "\u{494}",
+    "Ghestrokecyrillic" => "\u{492}",
+    "Gheupturncyrillic" => "\u{490}",
+    "Ghook" => "\u{193}",
+    "Gimarmenian" => "\u{533}",
+    "Gjecyrillic" => "\u{403}",
+    "Gmacron" => "\u{1E20}",
+    "Gmonospace" => "\u{FF27}",
+    "Grave" => "\u{F6CE}",
+    "Gravesmall" => "\u{F760}",
+    "Gsmall" => "\u{F767}",
+    "Gsmallhook" => "\u{29B}",
+    "Gstroke" => "\u{1E4}",
+    "H" => "\u{48}",
+    "H18533" => "\u{25CF}",
+    "H18543" => "\u{25AA}",
+    "H18551" => "\u{25AB}",
+    "H22073" => "\u{25A1}",
+    "HPsquare" => "\u{33CB}",
+    "Haabkhasiancyrillic" => "\u{4A8}",
+    "Hadescendercyrillic" => "\u{4B2}",
+    "Hardsigncyrillic" => "\u{42A}",
+    "Hbar" => "\u{126}",
+    "Hbrevebelow" => "\u{1E2A}",
+    "Hcedilla" => "\u{1E28}",
+    "Hcircle" => "\u{24BD}",
+    "Hcircumflex" => "\u{124}",
+    "Hdieresis" => "\u{1E26}",
+    "Hdotaccent" => "\u{1E22}",
+    "Hdotbelow" => "\u{1E24}",
+    "Hmonospace" => "\u{FF28}",
+    "Hoarmenian" => "\u{540}",
+    "Horicoptic" => "\u{3E8}",
+    "Hsmall" => "\u{F768}",
+    "Hungarumlaut" => "\u{F6CF}",
+    "Hungarumlautsmall" => "\u{F6F8}",
+    "Hzsquare" => "\u{3390}",
+    "I" => "\u{49}",
+    "IAcyrillic" => "\u{42F}",
+    "IJ" => "\u{132}",
+    "IUcyrillic" => "\u{42E}",
+    "Iacute" => "\u{CD}",
+    "Iacutesmall" => "\u{F7ED}",
+    "Ibreve" => "\u{12C}",
+    "Icaron" => "\u{1CF}",
+    "Icircle" => "\u{24BE}",
+    "Icircumflex" => "\u{CE}",
+    "Icircumflexsmall" => "\u{F7EE}",
+    "Icyrillic" => "\u{406}",
+    "Idblgrave" => "\u{208}",
+    "Idieresis" => "\u{CF}",
+    "Idieresisacute" => "\u{1E2E}",
+    "Idieresiscyrillic" => "\u{4E4}",
+    "Idieresissmall" => "\u{F7EF}",
+    "Idot" => "\u{130}",
+    "Idotaccent" => "\u{130}",
+    "Idotbelow" => "\u{1ECA}",
+    "Iebrevecyrillic" => "\u{4D6}",
+    "Iecyrillic" => "\u{415}",
+    "Ifraktur" => "\u{2111}",
+    "Igrave" => "\u{CC}",
+    "Igravesmall" => "\u{F7EC}",
+    "Ihookabove" => "\u{1EC8}",
+    "Iicyrillic" => "\u{418}",
+    "Iinvertedbreve" => "\u{20A}",
+    "Iishortcyrillic" => "\u{419}",
+    "Imacron" => "\u{12A}",
+    "Imacroncyrillic" => "\u{4E2}",
+    "Imonospace" => "\u{FF29}",
+    "Iniarmenian" => "\u{53B}",
+    "Iocyrillic" => "\u{401}",
+    "Iogonek" => "\u{12E}",
+    "Iota" => "\u{399}",
+    "Iotaafrican" => "\u{196}",
+    "Iotadieresis" => "\u{3AA}",
+    "Iotatonos" => "\u{38A}",
+    "Ismall" => "\u{F769}",
+    "Istroke" => "\u{197}",
+    "Itilde" => "\u{128}",
+    "Itildebelow" => "\u{1E2C}",
+    "Izhitsacyrillic" => "\u{474}",
+    "Izhitsadblgravecyrillic" => "\u{476}",
+    "J" => "\u{4A}",
+    "Jaarmenian" => "\u{541}",
+    "Jcircle" => "\u{24BF}",
+    "Jcircumflex" => "\u{134}",
+    "Jecyrillic" => "\u{408}",
+    "Jheharmenian" => "\u{54B}",
+    "Jmonospace" => "\u{FF2A}",
+    "Jsmall" => "\u{F76A}",
+    "K" => "\u{4B}",
+    "KBsquare" => "\u{3385}",
+    "KKsquare" => "\u{33CD}",
+    "Kabashkircyrillic" => "\u{4A0}",
+    "Kacute" => "\u{1E30}",
+    "Kacyrillic" => "\u{41A}",
+    "Kadescendercyrillic" => "\u{49A}",
+    "Kahookcyrillic" => "\u{4C3}",
+    "Kappa" => "\u{39A}",
+    "Kastrokecyrillic" => "\u{49E}",
+    "Kaverticalstrokecyrillic" => "\u{49C}",
+    "Kcaron" => "\u{1E8}",
+    "Kcedilla" => "\u{136}",
+    "Kcircle" => "\u{24C0}",
+    "Kcommaaccent" => "\u{136}",
+    "Kdotbelow" => "\u{1E32}",
+    "Keharmenian" => "\u{554}",
+    "Kenarmenian" => "\u{53F}",
+    "Khacyrillic" => "\u{425}",
+    "Kheicoptic" => "\u{3E6}",
+    "Khook" => "\u{198}",
+    "Kjecyrillic" => "\u{40C}",
+    "Klinebelow" => "\u{1E34}",
+    "Kmonospace" => "\u{FF2B}",
+    "Koppacyrillic" => "\u{480}",
+    "Koppagreek" => "\u{3DE}",
+    "Ksicyrillic" => "\u{46E}",
+    "Ksmall" => "\u{F76B}",
+    "L" => "\u{4C}",
+    "LJ" => "\u{1C7}",
+    "LL" => "\u{F6BF}",
+    "Lacute" => "\u{139}",
+    "Lambda" => "\u{39B}",
+    "Lcaron" => "\u{13D}",
+    "Lcedilla" => "\u{13B}",
+    "Lcircle" => "\u{24C1}",
+    "Lcircumflexbelow" => "\u{1E3C}",
+    "Lcommaaccent" => "\u{13B}",
+    "Ldot" => "\u{13F}",
+    "Ldotaccent" => "\u{13F}",
+    "Ldotbelow" => "\u{1E36}",
+    "Ldotbelowmacron" => "\u{1E38}",
+    "Liwnarmenian" => "\u{53C}",
+    "Lj" => "\u{1C8}",
+    "Ljecyrillic" => "\u{409}",
+    "Llinebelow" => "\u{1E3A}",
+    "Lmonospace" => "\u{FF2C}",
+    "Lslash" => "\u{141}",
+    "Lslashsmall" => "\u{F6F9}",
+    "Lsmall" => "\u{F76C}",
+    "M" => "\u{4D}",
+    "MBsquare" => "\u{3386}",
+    "Macron" => "\u{F6D0}",
+    "Macronsmall" => "\u{F7AF}",
+    "Macute" => "\u{1E3E}",
+    "Mcircle" => "\u{24C2}",
+    "Mdotaccent" => "\u{1E40}",
+    "Mdotbelow" => "\u{1E42}",
+    "Menarmenian" => "\u{544}",
+    "Mmonospace" => "\u{FF2D}",
+    "Msmall" => "\u{F76D}",
+    "Mturned" => "\u{19C}",
+    "Mu" => "\u{39C}",
+    "N" => "\u{4E}",
+    "NJ" => "\u{1CA}",
+    "Nacute" => "\u{143}",
+    "Ncaron" => "\u{147}",
+    "Ncedilla" => "\u{145}",
+    "Ncircle" => "\u{24C3}",
+    "Ncircumflexbelow" => "\u{1E4A}",
+    "Ncommaaccent" => "\u{145}",
+    "Ndotaccent" => "\u{1E44}",
+    "Ndotbelow" => "\u{1E46}",
+    "Nhookleft" => "\u{19D}",
+    "Nineroman" => "\u{2168}",
+    "Nj" => "\u{1CB}",
+    "Njecyrillic" => "\u{40A}",
+    "Nlinebelow" => "\u{1E48}",
+    "Nmonospace" => "\u{FF2E}",
+    "Nowarmenian" => "\u{546}",
+    "Nsmall" => "\u{F76E}",
+    "Ntilde" => "\u{D1}",
+    "Ntildesmall" => "\u{F7F1}",
+    "Nu" => "\u{39D}",
+    "O" => "\u{4F}",
+    "OE" => "\u{152}",
+    "OEsmall" => "\u{F6FA}",
+    "Oacute" => "\u{D3}",
+    "Oacutesmall" => "\u{F7F3}",
+    "Obarredcyrillic" => "\u{4E8}",
+    "Obarreddieresiscyrillic" => "\u{4EA}",
+    "Obreve" => "\u{14E}",
+    "Ocaron" => "\u{1D1}",
+    "Ocenteredtilde" => "\u{19F}",
+    "Ocircle" => "\u{24C4}",
+    "Ocircumflex" => "\u{D4}",
+    "Ocircumflexacute" => "\u{1ED0}",
+    "Ocircumflexdotbelow" => "\u{1ED8}",
+    "Ocircumflexgrave" => "\u{1ED2}",
+    "Ocircumflexhookabove" => "\u{1ED4}",
+    "Ocircumflexsmall" => "\u{F7F4}",
+    "Ocircumflextilde" => "\u{1ED6}",
+    "Ocyrillic" => "\u{41E}",
+    "Odblacute" => "\u{150}",
+    "Odblgrave" => "\u{20C}",
+    "Odieresis" => "\u{D6}",
+    "Odieresiscyrillic" => "\u{4E6}",
+    "Odieresissmall" => "\u{F7F6}",
+    "Odotbelow" => "\u{1ECC}",
+    "Ogoneksmall" => "\u{F6FB}",
+    "Ograve" => "\u{D2}",
+    "Ogravesmall" => "\u{F7F2}",
+    "Oharmenian" => "\u{555}",
+    "Ohm" => "\u{2126}",
+    "Ohookabove" => "\u{1ECE}",
+    "Ohorn" => "\u{1A0}",
+    "Ohornacute" => "\u{1EDA}",
+    "Ohorndotbelow" => "\u{1EE2}",
+    "Ohorngrave" => "\u{1EDC}",
+    "Ohornhookabove" => "\u{1EDE}",
+    "Ohorntilde" => "\u{1EE0}",
+    "Ohungarumlaut" => "\u{150}",
+    "Oi" => "\u{1A2}",
+    "Oinvertedbreve" => "\u{20E}",
+    "Omacron" => "\u{14C}",
+    "Omacronacute" => "\u{1E52}",
+    "Omacrongrave" => "\u{1E50}",
+    "Omega" => "\u{2126}",
+    "Omegacyrillic" => "\u{460}",
+    "Omegagreek" => "\u{3A9}",
+    "Omegaroundcyrillic" => "\u{47A}",
+    "Omegatitlocyrillic" => "\u{47C}",
+    "Omegatonos" => "\u{38F}",
+    "Omicron" => "\u{39F}",
+    "Omicrontonos" => "\u{38C}",
+    "Omonospace" => "\u{FF2F}",
+    "Oneroman" => "\u{2160}",
+    "Oogonek" => "\u{1EA}",
+    "Oogonekmacron" => "\u{1EC}",
+    "Oopen" => "\u{186}",
+    "Oslash" => "\u{D8}",
+    "Oslashacute" => "\u{1FE}",
+    "Oslashsmall" => "\u{F7F8}",
+    "Osmall" => "\u{F76F}",
+    "Ostrokeacute" => "\u{1FE}",
+    "Otcyrillic" => "\u{47E}",
+    "Otilde" => "\u{D5}",
+    "Otildeacute" => "\u{1E4C}",
+    "Otildedieresis" => "\u{1E4E}",
+    "Otildesmall" => "\u{F7F5}",
+    "P" => "\u{50}",
+    "Pacute" => "\u{1E54}",
+    "Pcircle" => "\u{24C5}",
+    "Pdotaccent" => "\u{1E56}",
+    "Pecyrillic" => "\u{41F}",
+    "Peharmenian" => "\u{54A}",
+    "Pemiddlehookcyrillic" => "\u{4A6}",
+    "Phi" => "\u{3A6}",
+    "Phook" => "\u{1A4}",
+    "Pi" => "\u{3A0}",
+    "Piwrarmenian" => "\u{553}",
+    "Pmonospace" => "\u{FF30}",
+    "Psi" => "\u{3A8}",
+    "Psicyrillic" => "\u{470}",
+    "Psmall" => "\u{F770}",
+    "Q" => "\u{51}",
+    "Qcircle" => "\u{24C6}",
+    "Qmonospace" => "\u{FF31}",
+    "Qsmall" => "\u{F771}",
+    "R" => "\u{52}",
+    "Raarmenian" => "\u{54C}",
+    "Racute" => "\u{154}",
+    "Rcaron" => "\u{158}",
+    "Rcedilla" => "\u{156}",
+    "Rcircle" => "\u{24C7}",
+    "Rcommaaccent" => "\u{156}",
+    "Rdblgrave" => "\u{210}",
+    "Rdotaccent" => "\u{1E58}",
+    "Rdotbelow" => "\u{1E5A}",
+    "Rdotbelowmacron" => "\u{1E5C}",
+    "Reharmenian" => "\u{550}",
+    "Rfraktur" => "\u{211C}",
+    "Rho" => "\u{3A1}",
+    "Ringsmall" => "\u{F6FC}",
+    "Rinvertedbreve" => "\u{212}",
+    "Rlinebelow" => "\u{1E5E}",
+    "Rmonospace" => "\u{FF32}",
+    "Rsmall" => "\u{F772}",
+    "Rsmallinverted" => "\u{281}",
+    "Rsmallinvertedsuperior" => "\u{2B6}",
+    "S" => "\u{53}",
+    "SF010000" => "\u{250C}",
+    "SF020000" => "\u{2514}",
+    "SF030000" => "\u{2510}",
+    "SF040000" => "\u{2518}",
+    "SF050000" => "\u{253C}",
+    "SF060000" => "\u{252C}",
+    "SF070000" => "\u{2534}",
+    "SF080000" => "\u{251C}",
+    "SF090000" => "\u{2524}",
+    "SF100000" => "\u{2500}",
+    "SF110000" => "\u{2502}",
+    "SF190000" => "\u{2561}",
+    "SF200000" => "\u{2562}",
+    "SF210000" => "\u{2556}",
+    "SF220000" => "\u{2555}",
+    "SF230000" => "\u{2563}",
+    "SF240000" => "\u{2551}",
+    "SF250000" => "\u{2557}",
+    "SF260000" => "\u{255D}",
+    "SF270000" => "\u{255C}",
+    "SF280000" => "\u{255B}",
+    "SF360000" => "\u{255E}",
+    "SF370000" => "\u{255F}",
+    "SF380000" => "\u{255A}",
+    "SF390000" => "\u{2554}",
+    "SF400000" => "\u{2569}",
+    "SF410000" => "\u{2566}",
+    "SF420000" => "\u{2560}",
+    "SF430000" => "\u{2550}",
+    "SF440000" => "\u{256C}",
+    "SF450000" => "\u{2567}",
+    "SF460000" => "\u{2568}",
+    "SF470000" => "\u{2564}",
+    "SF480000" => "\u{2565}",
+    "SF490000" => "\u{2559}",
+    "SF500000" => "\u{2558}",
+    "SF510000" => "\u{2552}",
+    "SF520000" => "\u{2553}",
+    "SF530000" => "\u{256B}",
+    "SF540000" => "\u{256A}",
+    "Sacute" => "\u{15A}",
+    "Sacutedotaccent" => "\u{1E64}",
+    "Sampigreek" => "\u{3E0}",
+    "Scaron" => "\u{160}",
+    "Scarondotaccent" => "\u{1E66}",
+    "Scaronsmall" => "\u{F6FD}",
+    "Scedilla" => "\u{15E}",
+    "Schwa" => "\u{18F}",
+    "Schwacyrillic" => "\u{4D8}",
+    "Schwadieresiscyrillic" => "\u{4DA}",
+    "Scircle" => "\u{24C8}",
+    "Scircumflex" => "\u{15C}",
+    "Scommaaccent" => "\u{218}",
+    "Sdotaccent" => "\u{1E60}",
+    "Sdotbelow" => "\u{1E62}",
+    "Sdotbelowdotaccent" => "\u{1E68}",
+    "Seharmenian" => "\u{54D}",
+    "Sevenroman" => "\u{2166}",
+    "Shaarmenian" => "\u{547}",
+    "Shacyrillic" => "\u{428}",
+    "Shchacyrillic" => "\u{429}",
+    "Sheicoptic" => "\u{3E2}",
+    "Shhacyrillic" => "\u{4BA}",
+    "Shimacoptic" => "\u{3EC}",
+    "Sigma" => "\u{3A3}",
+    "Sixroman" => "\u{2165}",
+    "Smonospace" => "\u{FF33}",
+    "Softsigncyrillic" => "\u{42C}",
+    "Ssmall" => "\u{F773}",
+    "Stigmagreek" => "\u{3DA}",
+    "T" => "\u{54}",
+    "Tau" => "\u{3A4}",
+    "Tbar" => "\u{166}",
+    "Tcaron" => "\u{164}",
+    "Tcedilla" => "\u{162}",
+    "Tcircle" => "\u{24C9}",
+    "Tcircumflexbelow" => "\u{1E70}",
+    "Tcommaaccent" => "\u{162}",
+    "Tdotaccent" => "\u{1E6A}",
+    "Tdotbelow" => "\u{1E6C}",
+    "Tecyrillic" => "\u{422}",
+    "Tedescendercyrillic" => "\u{4AC}",
+    "Tenroman" => "\u{2169}",
+    "Tetsecyrillic" => "\u{4B4}",
+    "Theta" => "\u{398}",
+    "Thook" => "\u{1AC}",
+    "Thorn" => "\u{DE}",
+    "Thornsmall" => "\u{F7FE}",
+    "Threeroman" => "\u{2162}",
+    "Tildesmall" => "\u{F6FE}",
+    "Tiwnarmenian" => "\u{54F}",
+    "Tlinebelow" => "\u{1E6E}",
+    "Tmonospace" => "\u{FF34}",
+    "Toarmenian" => "\u{539}",
+    "Tonefive" => "\u{1BC}",
+    "Tonesix" => "\u{184}",
+    "Tonetwo" => "\u{1A7}",
+    "Tretroflexhook" => "\u{1AE}",
+    "Tsecyrillic" => "\u{426}",
+    "Tshecyrillic" => "\u{40B}",
+    "Tsmall" => "\u{F774}",
+    "Twelveroman" => "\u{216B}",
+    "Tworoman" => "\u{2161}",
+    "U" => "\u{55}",
+    "Uacute" => "\u{DA}",
+    "Uacutesmall" => "\u{F7FA}",
+    "Ubreve" => "\u{16C}",
+    "Ucaron" => "\u{1D3}",
+    "Ucircle" => "\u{24CA}",
+    "Ucircumflex" => "\u{DB}",
+    "Ucircumflexbelow" => "\u{1E76}",
+    "Ucircumflexsmall" => "\u{F7FB}",
+    "Ucyrillic" => "\u{423}",
+    "Udblacute" => "\u{170}",
+    "Udblgrave" => "\u{214}",
+    "Udieresis" => "\u{DC}",
+    "Udieresisacute" => "\u{1D7}",
+    "Udieresisbelow" => "\u{1E72}",
+    "Udieresiscaron" => "\u{1D9}",
+    "Udieresiscyrillic" => "\u{4F0}",
+    "Udieresisgrave" => "\u{1DB}",
+    "Udieresismacron" => "\u{1D5}",
+    "Udieresissmall" => "\u{F7FC}",
+    "Udotbelow" => "\u{1EE4}",
+    "Ugrave" => "\u{D9}",
+    "Ugravesmall" => "\u{F7F9}",
+    "Uhookabove" => "\u{1EE6}",
+    "Uhorn" => "\u{1AF}",
+    "Uhornacute" => "\u{1EE8}",
+    "Uhorndotbelow" => "\u{1EF0}",
+    "Uhorngrave" => "\u{1EEA}",
+    "Uhornhookabove" => "\u{1EEC}",
+    "Uhorntilde" => "\u{1EEE}",
+    "Uhungarumlaut" => "\u{170}",
+    "Uhungarumlautcyrillic" => "\u{4F2}",
+    "Uinvertedbreve" => "\u{216}",
+    "Ukcyrillic" => "\u{478}",
+    "Umacron" => "\u{16A}",
+    "Umacroncyrillic" => "\u{4EE}",
+    "Umacrondieresis" => "\u{1E7A}",
+    "Umonospace" => "\u{FF35}",
+    "Uogonek" => "\u{172}",
+    "Upsilon" => "\u{3A5}",
+    "Upsilon1" => "\u{3D2}",
+    "Upsilonacutehooksymbolgreek" => "\u{3D3}",
+    "Upsilonafrican" => "\u{1B1}",
+    "Upsilondieresis" => "\u{3AB}",
+    "Upsilondieresishooksymbolgreek" => "\u{3D4}",
+    "Upsilonhooksymbol" => "\u{3D2}",
+    "Upsilontonos" => "\u{38E}",
+    "Uring" => "\u{16E}",
+    "Ushortcyrillic" => "\u{40E}",
+    "Usmall" => "\u{F775}",
+    "Ustraightcyrillic" => "\u{4AE}",
+    "Ustraightstrokecyrillic" => "\u{4B0}",
+    "Utilde" => "\u{168}",
+    "Utildeacute" => "\u{1E78}",
+    "Utildebelow" => "\u{1E74}",
+    "V" => "\u{56}",
+    "Vcircle" => "\u{24CB}",
+    "Vdotbelow" => "\u{1E7E}",
+    "Vecyrillic" => "\u{412}",
+    "Vewarmenian" => "\u{54E}",
+    "Vhook" => "\u{1B2}",
+    "Vmonospace" => "\u{FF36}",
+    "Voarmenian" => "\u{548}",
+    "Vsmall" => "\u{F776}",
+    "Vtilde" => "\u{1E7C}",
+    "W" => "\u{57}",
+    "Wacute" => "\u{1E82}",
+    "Wcircle" => "\u{24CC}",
+    "Wcircumflex" => "\u{174}",
+    "Wdieresis" => "\u{1E84}",
+    "Wdotaccent" => "\u{1E86}",
+    "Wdotbelow" => "\u{1E88}",
+    "Wgrave" => "\u{1E80}",
+    "Wmonospace" => "\u{FF37}",
+    "Wsmall" => "\u{F777}",
+    "X" => "\u{58}",
+    "Xcircle" => "\u{24CD}",
+    "Xdieresis" => "\u{1E8C}",
+    "Xdotaccent" => "\u{1E8A}",
+    "Xeharmenian" => "\u{53D}",
+    "Xi" => "\u{39E}",
+    "Xmonospace" => "\u{FF38}",
+    "Xsmall" => "\u{F778}",
+    "Y" => "\u{59}",
+    "Yacute" => "\u{DD}",
+    "Yacutesmall" => "\u{F7FD}",
+    "Yatcyrillic" => "\u{462}",
+    "Ycircle" => "\u{24CE}",
+    "Ycircumflex" => "\u{176}",
+    "Ydieresis" => "\u{178}",
+    "Ydieresissmall" => "\u{F7FF}",
+    "Ydotaccent" => "\u{1E8E}",
+    "Ydotbelow" => "\u{1EF4}",
+    "Yericyrillic" => "\u{42B}",
+    "Yerudieresiscyrillic" => "\u{4F8}",
+    "Ygrave" => "\u{1EF2}",
+    "Yhook" => "\u{1B3}",
+    "Yhookabove" => "\u{1EF6}",
+    "Yiarmenian" => "\u{545}",
+    "Yicyrillic" => "\u{407}",
+    "Yiwnarmenian" => "\u{552}",
+    "Ymonospace" => "\u{FF39}",
+    "Ysmall" => "\u{F779}",
+    "Ytilde" => "\u{1EF8}",
+    "Yusbigcyrillic" => "\u{46A}",
+    "Yusbigiotifiedcyrillic" => "\u{46C}",
+    "Yuslittlecyrillic" => "\u{466}",
+    "Yuslittleiotifiedcyrillic" => "\u{468}",
+    "Z" => "\u{5A}",
+    "Zaarmenian" => "\u{536}",
+    "Zacute" => "\u{179}",
+    "Zcaron" => "\u{17D}",
+    "Zcaronsmall" => "\u{F6FF}",
+    "Zcircle" => "\u{24CF}",
+    "Zcircumflex" => "\u{1E90}",
+    "Zdot" => "\u{17B}",
+    "Zdotaccent" => "\u{17B}",
+    "Zdotbelow" => "\u{1E92}",
+    "Zecyrillic" => "\u{417}",
+    "Zedescendercyrillic" => "\u{498}",
+    "Zedieresiscyrillic" => "\u{4DE}",
+    "Zeta" => "\u{396}",
+    "Zhearmenian" => "\u{53A}",
+    "Zhebrevecyrillic" => "\u{4C1}",
+    "Zhecyrillic" => "\u{416}",
+    "Zhedescendercyrillic" => "\u{496}",
+    "Zhedieresiscyrillic" => "\u{4DC}",
+    "Zlinebelow" => "\u{1E94}",
+    "Zmonospace" => "\u{FF3A}",
+    "Zsmall" => "\u{F77A}",
+    "Zstroke" => "\u{1B5}",
+    "a" => "\u{61}",
+    "aabengali" => "\u{986}",
+    "aacute" => "\u{E1}",
+    "aadeva" => "\u{906}",
+    "aagujarati" => "\u{A86}",
+    "aagurmukhi" => "\u{A06}",
+    "aamatragurmukhi" => "\u{A3E}",
+    "aarusquare" => "\u{3303}",
+    "aavowelsignbengali" => "\u{9BE}",
+    "aavowelsigndeva" => "\u{93E}",
+    "aavowelsigngujarati" => "\u{ABE}",
+    "abbreviationmarkarmenian" => "\u{55F}",
+    "abbreviationsigndeva" => "\u{970}",
+    "abengali" => "\u{985}",
+    "abopomofo" => "\u{311A}",
+    "abreve" => "\u{103}",
+    "abreveacute" => "\u{1EAF}",
+    "abrevecyrillic" => "\u{4D1}",
+    "abrevedotbelow" => "\u{1EB7}",
+    "abrevegrave" => "\u{1EB1}",
+    "abrevehookabove" => "\u{1EB3}",
+    "abrevetilde" => "\u{1EB5}",
+    "acaron" => "\u{1CE}",
+    "acircle" => "\u{24D0}",
+    "acircumflex" => "\u{E2}",
+    "acircumflexacute" => "\u{1EA5}",
+    "acircumflexdotbelow" => "\u{1EAD}",
+    "acircumflexgrave" => "\u{1EA7}",
+    "acircumflexhookabove" => "\u{1EA9}",
+    "acircumflextilde" => "\u{1EAB}",
+    "acute" => "\u{B4}",
+    "acutebelowcmb" => "\u{317}",
+    "acutecmb" => "\u{301}",
+    "acutecomb" => "\u{301}",
+    "acutedeva" => "\u{954}",
+    "acutelowmod" => "\u{2CF}",
+    "acutetonecmb" => "\u{341}",
+    "acyrillic" => "\u{430}",
+    "adblgrave" => "\u{201}",
+    "addakgurmukhi" => "\u{A71}",
+    "adeva" => "\u{905}",
+    "adieresis" => "\u{E4}",
+    "adieresiscyrillic" => "\u{4D3}",
+    "adieresismacron" => "\u{1DF}",
+    "adotbelow" => "\u{1EA1}",
+    "adotmacron" => "\u{1E1}",
+    "ae" => "\u{E6}",
+    "aeacute" => "\u{1FD}",
+    "aekorean" => "\u{3150}",
+    "aemacron" => "\u{1E3}",
+    "afii00208" => "\u{2015}",
+    "afii08941" => "\u{20A4}",
+    "afii10017" => "\u{410}",
+    "afii10018" => "\u{411}",
+    "afii10019" => "\u{412}",
+    "afii10020" => "\u{413}",
+    "afii10021" => "\u{414}",
+    "afii10022" => "\u{415}",
+    "afii10023" => "\u{401}",
+    "afii10024" => "\u{416}",
+    "afii10025" => "\u{417}",
+    "afii10026" => "\u{418}",
+    "afii10027" => "\u{419}",
+    "afii10028" => "\u{41A}",
+    "afii10029" => "\u{41B}",
+    "afii10030" => "\u{41C}",
+    "afii10031" => "\u{41D}",
+    "afii10032" => "\u{41E}",
+    "afii10033" => "\u{41F}",
+    "afii10034" => "\u{420}",
+    "afii10035" => "\u{421}",
+    "afii10036" => "\u{422}",
+    "afii10037" => "\u{423}",
+    "afii10038" => "\u{424}",
+    "afii10039" => "\u{425}",
+    "afii10040" => "\u{426}",
+    "afii10041" => "\u{427}",
+    "afii10042" => "\u{428}",
+    "afii10043" => "\u{429}",
+    "afii10044" => "\u{42A}",
+    "afii10045" => "\u{42B}",
+    "afii10046" => "\u{42C}",
+    "afii10047" => "\u{42D}",
+    "afii10048" => "\u{42E}",
+    "afii10049" => "\u{42F}",
+    "afii10050" => "\u{490}",
+    "afii10051" => "\u{402}",
+    "afii10052" => "\u{403}",
+    "afii10053" => "\u{404}",
+    "afii10054" => "\u{405}",
+    "afii10055" => "\u{406}",
+    "afii10056" => "\u{407}",
+    "afii10057" => "\u{408}",
+    "afii10058" => "\u{409}",
+    "afii10059" => "\u{40A}",
+    "afii10060" => "\u{40B}",
+    "afii10061" => "\u{40C}",
+    "afii10062" => "\u{40E}",
+    "afii10063" => "\u{F6C4}",
+    "afii10064" => "\u{F6C5}",
+    "afii10065" => "\u{430}",
+    "afii10066" => "\u{431}",
+    "afii10067" => "\u{432}",
+    "afii10068" => "\u{433}",
+    "afii10069" => "\u{434}",
+    "afii10070" => "\u{435}",
+    "afii10071" => "\u{451}",
+    "afii10072" => "\u{436}",
+    "afii10073" => "\u{437}",
+    "afii10074" => "\u{438}",
+    "afii10075" => "\u{439}",
+    "afii10076" => "\u{43A}",
+    "afii10077" => "\u{43B}",
+    "afii10078" => "\u{43C}",
+    "afii10079" => "\u{43D}",
+    "afii10080" => "\u{43E}",
+    "afii10081" => "\u{43F}",
+    "afii10082" => "\u{440}",
+    "afii10083" => "\u{441}",
+    "afii10084" => "\u{442}",
+    "afii10085" => "\u{443}",
+    "afii10086" => "\u{444}",
+    "afii10087" => "\u{445}",
+    "afii10088" => "\u{446}",
+    "afii10089" => "\u{447}",
+    "afii10090" => "\u{448}",
+    "afii10091" => "\u{449}",
+    "afii10092" => "\u{44A}",
+    "afii10093" => "\u{44B}",
+    "afii10094" => "\u{44C}",
+    "afii10095" => "\u{44D}",
+    "afii10096" => "\u{44E}",
+    "afii10097" => "\u{44F}",
+    "afii10098" => "\u{491}",
+    "afii10099" => "\u{452}",
+    "afii10100" => "\u{453}",
+    "afii10101" => "\u{454}",
+    "afii10102" => "\u{455}",
+    "afii10103" => "\u{456}",
+    "afii10104" => "\u{457}",
+    "afii10105" => "\u{458}",
+    "afii10106" => "\u{459}",
+    "afii10107" => "\u{45A}",
+    "afii10108" => "\u{45B}",
+    "afii10109" => "\u{45C}",
+    "afii10110" => "\u{45E}",
+    "afii10145" => "\u{40F}",
+    "afii10146" => "\u{462}",
+    "afii10147" => "\u{472}",
+    "afii10148" => "\u{474}",
+    "afii10192" => "\u{F6C6}",
+    "afii10193" => "\u{45F}",
+    "afii10194" => "\u{463}",
+    "afii10195" => "\u{473}",
+    "afii10196" => "\u{475}",
+    "afii10831" => "\u{F6C7}",
+    "afii10832" => "\u{F6C8}",
+    "afii10846" => "\u{4D9}",
+    "afii299" => "\u{200E}",
+    "afii300" => "\u{200F}",
+    "afii301" => "\u{200D}",
+    "afii57381" => "\u{66A}",
+    "afii57388" => "\u{60C}",
+    "afii57392" => "\u{660}",
+    "afii57393" => "\u{661}",
+    "afii57394" => "\u{662}",
+    "afii57395" => "\u{663}",
+    "afii57396" => "\u{664}",
+    "afii57397" => "\u{665}",
+    "afii57398" => "\u{666}",
+    "afii57399" => "\u{667}",
+    "afii57400" => "\u{668}",
+    "afii57401" => "\u{669}",
+    "afii57403" => "\u{61B}",
+    "afii57407" => "\u{61F}",
+    "afii57409" => "\u{621}",
+    "afii57410" => "\u{622}",
+    "afii57411" => "\u{623}",
+    "afii57412" => "\u{624}",
+    "afii57413" => "\u{625}",
+    "afii57414" => "\u{626}",
+    "afii57415" => "\u{627}",
+    "afii57416" => "\u{628}",
+    "afii57417" => "\u{629}",
+    "afii57418" => "\u{62A}",
+    "afii57419" => "\u{62B}",
+    "afii57420" => "\u{62C}",
+    "afii57421" => "\u{62D}",
+    "afii57422" => "\u{62E}",
+    "afii57423" => "\u{62F}",
+    "afii57424" => "\u{630}",
+    "afii57425" => "\u{631}",
+    "afii57426" => "\u{632}",
+    "afii57427" => "\u{633}",
+    "afii57428" => "\u{634}",
+    "afii57429" => "\u{635}",
+    "afii57430" => "\u{636}",
+    "afii57431" => "\u{637}",
+    "afii57432" => "\u{638}",
+    "afii57433" => "\u{639}",
+    "afii57434" => "\u{63A}",
+    "afii57440" => "\u{640}",
+    "afii57441" => "\u{641}",
+    "afii57442" => "\u{642}",
+    "afii57443" => "\u{643}",
+    "afii57444" => "\u{644}",
+    "afii57445" => "\u{645}",
+    "afii57446" => "\u{646}",
+    "afii57448" => "\u{648}",
+    "afii57449" => "\u{649}",
+    "afii57450" => "\u{64A}",
+    "afii57451" => "\u{64B}",
+    "afii57452" => "\u{64C}",
+    "afii57453" => "\u{64D}",
+    "afii57454" => "\u{64E}",
+    "afii57455" => "\u{64F}",
+    "afii57456" => "\u{650}",
+    "afii57457" => "\u{651}",
+    "afii57458" => "\u{652}",
+    "afii57470" => "\u{647}",
+    "afii57505" => "\u{6A4}",
+    "afii57506" => "\u{67E}",
+    "afii57507" => "\u{686}",
+    "afii57508" => "\u{698}",
+    "afii57509" => "\u{6AF}",
+    "afii57511" => "\u{679}",
+    "afii57512" => "\u{688}",
+    "afii57513" => "\u{691}",
+    "afii57514" => "\u{6BA}",
+    "afii57519" => "\u{6D2}",
+    "afii57534" => "\u{6D5}",
+    "afii57636" => "\u{20AA}",
+    "afii57645" => "\u{5BE}",
+    "afii57658" => "\u{5C3}",
+    "afii57664" => "\u{5D0}",
+    "afii57665" => "\u{5D1}",
+    "afii57666" => "\u{5D2}",
+    "afii57667" => "\u{5D3}",
+    "afii57668" => "\u{5D4}",
+    "afii57669" => "\u{5D5}",
+    "afii57670" => "\u{5D6}",
+    "afii57671" => "\u{5D7}",
+    "afii57672" => "\u{5D8}",
+    "afii57673" => "\u{5D9}",
+    "afii57674" => "\u{5DA}",
+    "afii57675" => "\u{5DB}",
+    "afii57676" => "\u{5DC}",
+    "afii57677" => "\u{5DD}",
+    "afii57678" => "\u{5DE}",
+    "afii57679" => "\u{5DF}",
+    "afii57680" => "\u{5E0}",
+    "afii57681" => "\u{5E1}",
+    "afii57682" => "\u{5E2}",
+    "afii57683" => "\u{5E3}",
+    "afii57684" => "\u{5E4}",
+    "afii57685" => "\u{5E5}",
+    "afii57686" => "\u{5E6}",
+    "afii57687" => "\u{5E7}",
+    "afii57688" => "\u{5E8}",
+    "afii57689" => "\u{5E9}",
+    "afii57690" => "\u{5EA}",
+    "afii57694" => "\u{FB2A}",
+    "afii57695" => "\u{FB2B}",
+    "afii57700" => "\u{FB4B}",
+    "afii57705" => "\u{FB1F}",
+    "afii57716" => "\u{5F0}",
+    "afii57717" => "\u{5F1}",
+    "afii57718" => "\u{5F2}",
+    "afii57723" => "\u{FB35}",
+    "afii57793" => "\u{5B4}",
+    "afii57794" => "\u{5B5}",
+    "afii57795" => "\u{5B6}",
+    "afii57796" => "\u{5BB}",
+    "afii57797" => "\u{5B8}",
+    "afii57798" => "\u{5B7}",
+    "afii57799" => "\u{5B0}",
+    "afii57800" => "\u{5B2}",
+    "afii57801" => "\u{5B1}",
+    "afii57802" => "\u{5B3}",
+    "afii57803" => "\u{5C2}",
+    "afii57804" => "\u{5C1}",
+    "afii57806" => "\u{5B9}",
+    "afii57807" => "\u{5BC}",
+    "afii57839" => "\u{5BD}",
+    "afii57841" => "\u{5BF}",
+    "afii57842" => "\u{5C0}",
+    "afii57929" => "\u{2BC}",
+    "afii61248" => "\u{2105}",
+    "afii61289" => "\u{2113}",
+    "afii61352" => "\u{2116}",
+    "afii61573" => "\u{202C}",
+    "afii61574" => "\u{202D}",
+    "afii61575" => "\u{202E}",
+    "afii61664" => "\u{200C}",
+    "afii63167" => "\u{66D}",
+    "afii64937" => "\u{2BD}",
+    "agrave" => "\u{E0}",
+    "agujarati" => "\u{A85}",
+    "agurmukhi" => "\u{A05}",
+    "ahiragana" => "\u{3042}",
+    "ahookabove" => "\u{1EA3}",
+    "aibengali" => "\u{990}",
+    "aibopomofo" => "\u{311E}",
+    "aideva" => "\u{910}",
+    "aiecyrillic" => "\u{4D5}",
+    "aigujarati" => "\u{A90}",
+    "aigurmukhi" => "\u{A10}",
+    "aimatragurmukhi" => "\u{A48}",
+    "ainarabic" => "\u{639}",
+    "ainfinalarabic" => "\u{FECA}",
+    "aininitialarabic" => "\u{FECB}",
+    "ainmedialarabic" => "\u{FECC}",
+    "ainvertedbreve" => "\u{203}",
+    "aivowelsignbengali" => "\u{9C8}",
+    "aivowelsigndeva" => "\u{948}",
+    "aivowelsigngujarati" => "\u{AC8}",
+    "akatakana" => "\u{30A2}",
+    "akatakanahalfwidth" => "\u{FF71}",
+    "akorean" => "\u{314F}",
+    "alef" => "\u{5D0}",
+    "alefarabic" => "\u{627}",
+    "alefdageshhebrew" => "\u{FB30}",
+    "aleffinalarabic" => "\u{FE8E}",
+    "alefhamzaabovearabic" => "\u{623}",
+    "alefhamzaabovefinalarabic" => "\u{FE84}",
+    "alefhamzabelowarabic" => "\u{625}",
+    "alefhamzabelowfinalarabic" => "\u{FE88}",
+    "alefhebrew" => "\u{5D0}",
+    "aleflamedhebrew" => "\u{FB4F}",
+    "alefmaddaabovearabic" => "\u{622}",
+    "alefmaddaabovefinalarabic" => "\u{FE82}",
+    "alefmaksuraarabic" => "\u{649}",
+    "alefmaksurafinalarabic" => "\u{FEF0}",
+    "alefmaksurainitialarabic" => "\u{FEF3}",
+    "alefmaksuramedialarabic" => "\u{FEF4}",
+    "alefpatahhebrew" => "\u{FB2E}",
+    "alefqamatshebrew" => "\u{FB2F}",
+    "aleph" => "\u{2135}",
+    "allequal" => "\u{224C}",
+    "alpha" => "\u{3B1}",
+    "alphatonos" => "\u{3AC}",
+    "amacron" => "\u{101}",
+    "amonospace" => "\u{FF41}",
+    "ampersand" => "\u{26}",
+    "ampersandmonospace" => "\u{FF06}",
+    "ampersandsmall" => "\u{F726}",
+    "amsquare" => "\u{33C2}",
+    "anbopomofo" => "\u{3122}",
+    "angbopomofo" => "\u{3124}",
+    "angkhankhuthai" => "\u{E5A}",
+    "angle" => "\u{2220}",
+    "anglebracketleft" => "\u{3008}",
+    "anglebracketleftvertical" => "\u{FE3F}",
+    "anglebracketright" => "\u{3009}",
+    "anglebracketrightvertical" => "\u{FE40}",
+    "angleleft" => "\u{2329}",
+    "angleright" => "\u{232A}",
+    "angstrom" => "\u{212B}",
+    "anoteleia" => "\u{387}",
+    "anudattadeva" => "\u{952}",
+    "anusvarabengali" => "\u{982}",
+    "anusvaradeva" => "\u{902}",
+    "anusvaragujarati" => "\u{A82}",
+    "aogonek" => "\u{105}",
+    "apaatosquare" => "\u{3300}",
+    "aparen" => "\u{249C}",
+    "apostrophearmenian" => "\u{55A}",
+    "apostrophemod" => "\u{2BC}",
+    "apple" => "\u{F8FF}",
+    "approaches" => "\u{2250}",
+    "approxequal" => "\u{2248}",
+    "approxequalorimage" => "\u{2252}",
+    "approximatelyequal" => "\u{2245}",
+    "araeaekorean" => "\u{318E}",
+    "araeakorean" => "\u{318D}",
+    "arc" => "\u{2312}",
+    "arighthalfring" => "\u{1E9A}",
+    "aring" => "\u{E5}",
+    "aringacute" => "\u{1FB}",
+    "aringbelow" => "\u{1E01}",
+    "arrowboth" => "\u{2194}",
+    "arrowdashdown" => "\u{21E3}",
+    "arrowdashleft" => "\u{21E0}",
+    "arrowdashright" => "\u{21E2}",
+    "arrowdashup" => "\u{21E1}",
+    "arrowdblboth" => "\u{21D4}",
+    "arrowdbldown" => "\u{21D3}",
+    "arrowdblleft" => "\u{21D0}",
+    "arrowdblright" => "\u{21D2}",
+    "arrowdblup" => "\u{21D1}",
+    "arrowdown" => "\u{2193}",
+    "arrowdownleft" => "\u{2199}",
+    "arrowdownright" => "\u{2198}",
+    "arrowdownwhite" => "\u{21E9}",
+    "arrowheaddownmod" => "\u{2C5}",
+    "arrowheadleftmod" => "\u{2C2}",
+    "arrowheadrightmod" => "\u{2C3}",
+    "arrowheadupmod" => "\u{2C4}",
+    "arrowhorizex" => "\u{F8E7}",
+    "arrowleft" => "\u{2190}",
+    "arrowleftdbl" => "\u{21D0}",
+    "arrowleftdblstroke" => "\u{21CD}",
+    "arrowleftoverright" => "\u{21C6}",
+    "arrowleftwhite" => "\u{21E6}",
+    "arrowright" => "\u{2192}",
+    "arrowrightdblstroke" => "\u{21CF}",
+    "arrowrightheavy" => "\u{279E}",
+    "arrowrightoverleft" => "\u{21C4}",
+    "arrowrightwhite" => "\u{21E8}",
+    "arrowtableft" => "\u{21E4}",
+    "arrowtabright" => "\u{21E5}",
+    "arrowup" => "\u{2191}",
+    "arrowupdn" => "\u{2195}",
+    "arrowupdnbse" => "\u{21A8}",
+    "arrowupdownbase" => "\u{21A8}",
+    "arrowupleft" => "\u{2196}",
+    "arrowupleftofdown" => "\u{21C5}",
+    "arrowupright" => "\u{2197}",
+    "arrowupwhite" => "\u{21E7}",
+    "arrowvertex" => "\u{F8E6}",
+    "asciicircum" => "\u{5E}",
+    "asciicircummonospace" => "\u{FF3E}",
+    "asciitilde" => "\u{7E}",
+    "asciitildemonospace" => "\u{FF5E}",
+    "ascript" => "\u{251}",
+    "ascriptturned" => "\u{252}",
+    "asmallhiragana" => "\u{3041}",
+    "asmallkatakana" => "\u{30A1}",
+    "asmallkatakanahalfwidth" => "\u{FF67}",
+    "asterisk" => "\u{2A}",
+    "asteriskaltonearabic" => "\u{66D}",
+    "asteriskarabic" => "\u{66D}",
+    "asteriskmath" => "\u{2217}",
+    "asteriskmonospace" => "\u{FF0A}",
+    "asterisksmall" => "\u{FE61}",
+    "asterism" => "\u{2042}",
+    "asuperior" => "\u{F6E9}",
+    "asymptoticallyequal" => "\u{2243}",
+    "at" => "\u{40}",
+    "atilde" => "\u{E3}",
+    "atmonospace" => "\u{FF20}",
+    "atsmall" => "\u{FE6B}",
+    "aturned" => "\u{250}",
+    "aubengali" => "\u{994}",
+    "aubopomofo" => "\u{3120}",
+    "audeva" => "\u{914}",
+    "augujarati" => "\u{A94}",
+    "augurmukhi" => "\u{A14}",
+    "aulengthmarkbengali" => "\u{9D7}",
+    "aumatragurmukhi" => "\u{A4C}",
+    "auvowelsignbengali" => "\u{9CC}",
+    "auvowelsigndeva" => "\u{94C}",
+    "auvowelsigngujarati" => "\u{ACC}",
+    "avagrahadeva" => "\u{93D}",
+    "aybarmenian" => "\u{561}",
+    "ayin" => "\u{5E2}",
+    "ayinaltonehebrew" => "\u{FB20}",
+    "ayinhebrew" => "\u{5E2}",
+    "b" => "\u{62}",
+    "babengali" => "\u{9AC}",
+    "backslash" => "\u{5C}",
+    "backslashmonospace" => "\u{FF3C}",
+    "badeva" => "\u{92C}",
+    "bagujarati" => "\u{AAC}",
+    "bagurmukhi" => "\u{A2C}",
+    "bahiragana" => "\u{3070}",
+    "bahtthai" => "\u{E3F}",
+    "bakatakana" => "\u{30D0}",
+    "bar" => "\u{7C}",
+    "barmonospace" => "\u{FF5C}",
+    "bbopomofo" => "\u{3105}",
+    "bcircle" => "\u{24D1}",
+    "bdotaccent" => "\u{1E03}",
+    "bdotbelow" => "\u{1E05}",
+    "beamedsixteenthnotes" => "\u{266C}",
+    "because" => "\u{2235}",
+    "becyrillic" => "\u{431}",
+    "beharabic" => "\u{628}",
+    "behfinalarabic" => "\u{FE90}",
+    "behinitialarabic" => "\u{FE91}",
+    "behiragana" => "\u{3079}",
+    "behmedialarabic" => "\u{FE92}",
+    "behmeeminitialarabic" => "\u{FC9F}",
+    "behmeemisolatedarabic" => "\u{FC08}",
+    "behnoonfinalarabic" => "\u{FC6D}",
+    "bekatakana" => "\u{30D9}",
+    "benarmenian" => "\u{562}",
+    "bet" => "\u{5D1}",
+    "beta" => "\u{3B2}",
+    "betasymbolgreek" => "\u{3D0}",
+    "betdagesh" => "\u{FB31}",
+    "betdageshhebrew" => "\u{FB31}",
+    "bethebrew" => "\u{5D1}",
+    "betrafehebrew" => "\u{FB4C}",
+    "bhabengali" => "\u{9AD}",
+    "bhadeva" => "\u{92D}",
+    "bhagujarati" => "\u{AAD}",
+    "bhagurmukhi" => "\u{A2D}",
+    "bhook" => "\u{253}",
+    "bihiragana" => "\u{3073}",
+    "bikatakana" => "\u{30D3}",
+    "bilabialclick" => "\u{298}",
+    "bindigurmukhi" => "\u{A02}",
+    "birusquare" => "\u{3331}",
+    "blackcircle" => "\u{25CF}",
+    "blackdiamond" => "\u{25C6}",
+    "blackdownpointingtriangle" => "\u{25BC}",
+    "blackleftpointingpointer" => "\u{25C4}",
+    "blackleftpointingtriangle" => "\u{25C0}",
+    "blacklenticularbracketleft" => "\u{3010}",
+    "blacklenticularbracketleftvertical" => "\u{FE3B}",
+    "blacklenticularbracketright" => "\u{3011}",
+    "blacklenticularbracketrightvertical" => "\u{FE3C}",
+    "blacklowerlefttriangle" => "\u{25E3}",
+    "blacklowerrighttriangle" => "\u{25E2}",
+    "blackrectangle" => "\u{25AC}",
+    "blackrightpointingpointer" => "\u{25BA}",
+    "blackrightpointingtriangle" => "\u{25B6}",
+    "blacksmallsquare" => "\u{25AA}",
+    "blacksmilingface" => "\u{263B}",
+    "blacksquare" => "\u{25A0}",
+    "blackstar" => "\u{2605}",
+    "blackupperlefttriangle" => "\u{25E4}",
+    "blackupperrighttriangle" => "\u{25E5}",
+    "blackuppointingsmalltriangle" => "\u{25B4}",
+    "blackuppointingtriangle" => "\u{25B2}",
+    "blank" => "\u{2423}",
+    "blinebelow" => "\u{1E07}",
+    "block" => "\u{2588}",
+    "bmonospace" => "\u{FF42}",
+    "bobaimaithai" => "\u{E1A}",
+    "bohiragana" => "\u{307C}",
+    "bokatakana" => "\u{30DC}",
+    "bparen" => "\u{249D}",
+    "bqsquare" => "\u{33C3}",
+    "braceex" => "\u{F8F4}",
+    "braceleft" => "\u{7B}",
+    "braceleftbt" => "\u{F8F3}",
+    "braceleftmid" => "\u{F8F2}",
+    "braceleftmonospace" => "\u{FF5B}",
+    "braceleftsmall" => "\u{FE5B}",
+    "bracelefttp" => "\u{F8F1}",
+    "braceleftvertical" => "\u{FE37}",
+    "braceright" => "\u{7D}",
+    "bracerightbt" => "\u{F8FE}",
+    "bracerightmid" => "\u{F8FD}",
+    "bracerightmonospace" => "\u{FF5D}",
+    "bracerightsmall" => "\u{FE5C}",
+    "bracerighttp" => "\u{F8FC}",
+    "bracerightvertical" => "\u{FE38}",
+    "bracketleft" => "\u{5B}",
+    "bracketleftbt" => "\u{F8F0}",
+    "bracketleftex" => "\u{F8EF}",
+    "bracketleftmonospace" => "\u{FF3B}",
+    "bracketlefttp" => "\u{F8EE}",
+    "bracketright" => "\u{5D}",
+    "bracketrightbt" => "\u{F8FB}",
+    "bracketrightex" => "\u{F8FA}",
+    "bracketrightmonospace" => "\u{FF3D}",
+    "bracketrighttp" => "\u{F8F9}",
+    "breve" => "\u{2D8}",
+    "brevebelowcmb" => "\u{32E}",
+    "brevecmb" => "\u{306}",
+    "breveinvertedbelowcmb" => "\u{32F}",
+    "breveinvertedcmb" => "\u{311}",
+    "breveinverteddoublecmb" => "\u{361}",
+    "bridgebelowcmb" => "\u{32A}",
+    "bridgeinvertedbelowcmb" => "\u{33A}",
+    "brokenbar" => "\u{A6}",
+    "bstroke" => "\u{180}",
+    "bsuperior" => "\u{F6EA}",
+    "btopbar" => "\u{183}",
+    "buhiragana" => "\u{3076}",
+    "bukatakana" => "\u{30D6}",
+    "bullet" => "\u{2022}",
+    "bulletinverse" => "\u{25D8}",
+    "bulletoperator" => "\u{2219}",
+    "bullseye" => "\u{25CE}",
+    "c" => "\u{63}",
+    "caarmenian" => "\u{56E}",
+    "cabengali" => "\u{99A}",
+    "cacute" => "\u{107}",
+    "cadeva" => "\u{91A}",
+    "cagujarati" => "\u{A9A}",
+    "cagurmukhi" => "\u{A1A}",
+    "calsquare" => "\u{3388}",
+    "candrabindubengali" => "\u{981}",
+    "candrabinducmb" => "\u{310}",
+    "candrabindudeva" => "\u{901}",
+    "candrabindugujarati" => "\u{A81}",
+    "capslock" => "\u{21EA}",
+    "careof" => "\u{2105}",
+    "caron" => "\u{2C7}",
+    "caronbelowcmb" => "\u{32C}",
+    "caroncmb" => "\u{30C}",
+    "carriagereturn" => "\u{21B5}",
+    "cbopomofo" => "\u{3118}",
+    "ccaron" => "\u{10D}",
+    "ccedilla" => "\u{E7}",
+    "ccedillaacute" => "\u{1E09}",
+    "ccircle" => "\u{24D2}",
+    "ccircumflex" => "\u{109}",
+    "ccurl" => "\u{255}",
+    "cdot" => "\u{10B}",
+    "cdotaccent" => "\u{10B}",
+    "cdsquare" => "\u{33C5}",
+    "cedilla" => "\u{B8}",
+    "cedillacmb" => "\u{327}",
+    "cent" => "\u{A2}",
+    "centigrade" => "\u{2103}",
+    "centinferior" => "\u{F6DF}",
+    "centmonospace" => "\u{FFE0}",
+    "centoldstyle" => "\u{F7A2}",
+    "centsuperior" => "\u{F6E0}",
+    "chaarmenian" => "\u{579}",
+    "chabengali" => "\u{99B}",
+    "chadeva" => "\u{91B}",
+    "chagujarati" => "\u{A9B}",
+    "chagurmukhi" => "\u{A1B}",
+    "chbopomofo" => "\u{3114}",
+    "cheabkhasiancyrillic" => "\u{4BD}",
+    "checkmark" => "\u{2713}",
+    "checyrillic" => "\u{447}",
+    "chedescenderabkhasiancyrillic" => "\u{4BF}",
+    "chedescendercyrillic" => "\u{4B7}",
+    "chedieresiscyrillic" => "\u{4F5}",
+    "cheharmenian" => "\u{573}",
+    "chekhakassiancyrillic" => "\u{4CC}",
+    "cheverticalstrokecyrillic" => "\u{4B9}",
+    "chi" => "\u{3C7}",
+    "chieuchacirclekorean" => "\u{3277}",
+    "chieuchaparenkorean" => "\u{3217}",
+    "chieuchcirclekorean" => "\u{3269}",
+    "chieuchkorean" => "\u{314A}",
+    "chieuchparenkorean" => "\u{3209}",
+    "chochangthai" => "\u{E0A}",
+    "chochanthai" => "\u{E08}",
+    "chochingthai" => "\u{E09}",
+    "chochoethai" => "\u{E0C}",
+    "chook" => "\u{188}",
+    "cieucacirclekorean" => "\u{3276}",
+    "cieucaparenkorean" => "\u{3216}",
+    "cieuccirclekorean" => "\u{3268}",
+    "cieuckorean" => "\u{3148}",
+    "cieucparenkorean" => "\u{3208}",
+    "cieucuparenkorean" => "\u{321C}",
+    "circle" => "\u{25CB}",
+    "circlemultiply" => "\u{2297}",
+    "circleot" => "\u{2299}",
+    "circleplus" => "\u{2295}",
+    "circlepostalmark" => "\u{3036}",
+    "circlewithlefthalfblack" => "\u{25D0}",
+    "circlewithrighthalfblack" => "\u{25D1}",
+    "circumflex" => "\u{2C6}",
+    "circumflexbelowcmb" => "\u{32D}",
+    "circumflexcmb" => "\u{302}",
+    "clear" => "\u{2327}",
+    "clickalveolar" => "\u{1C2}",
+    "clickdental" => "\u{1C0}",
+    "clicklateral" => "\u{1C1}",
+    "clickretroflex" => "\u{1C3}",
+    "club" => "\u{2663}",
+    "clubsuitblack" => "\u{2663}",
+    "clubsuitwhite" => "\u{2667}",
+    "cmcubedsquare" => "\u{33A4}",
+    "cmonospace" => "\u{FF43}",
+    "cmsquaredsquare" => "\u{33A0}",
+    "coarmenian" => "\u{581}",
+    "colon" => "\u{3A}",
+    "colonmonetary" => "\u{20A1}",
+    "colonmonospace" => "\u{FF1A}",
+    "colonsign" => "\u{20A1}",
+    "colonsmall" => "\u{FE55}",
+    "colontriangularhalfmod" => "\u{2D1}",
+    "colontriangularmod" => "\u{2D0}",
+    "comma" => "\u{2C}",
+    "commaabovecmb" => "\u{313}",
+    "commaaboverightcmb" => "\u{315}",
+    "commaaccent" => "\u{F6C3}",
+    "commaarabic" => "\u{60C}",
+    "commaarmenian" => "\u{55D}",
+    "commainferior" => "\u{F6E1}",
+    "commamonospace" => "\u{FF0C}",
+    "commareversedabovecmb" => "\u{314}",
+    "commareversedmod" => "\u{2BD}",
+    "commasmall" => "\u{FE50}",
+    "commasuperior" => "\u{F6E2}",
+    "commaturnedabovecmb" => "\u{312}",
+    "commaturnedmod" => "\u{2BB}",
+    "compass" => "\u{263C}",
+    "congruent" => "\u{2245}",
+    "contourintegral" => "\u{222E}",
+    "control" => "\u{2303}",
+    "controlACK" => "\u{6}",
+    "controlBEL" => "\u{7}",
+    "controlBS" => "\u{8}",
+    "controlCAN" => "\u{18}",
+    "controlCR" => "\u{D}",
+    "controlDC1" => "\u{11}",
+    "controlDC2" => "\u{12}",
+    "controlDC3" => "\u{13}",
+    "controlDC4" => "\u{14}",
+    "controlDEL" => "\u{7F}",
+    "controlDLE" => "\u{10}",
+    "controlEM" => "\u{19}",
+    "controlENQ" => "\u{5}",
+    "controlEOT" => "\u{4}",
+    "controlESC" => "\u{1B}",
+    "controlETB" => "\u{17}",
+    "controlETX" => "\u{3}",
+    "controlFF" => "\u{C}",
+    "controlFS" => "\u{1C}",
+    "controlGS" => "\u{1D}",
+    "controlHT" => "\u{9}",
+    "controlLF" => "\u{A}",
+    "controlNAK" => "\u{15}",
+    "controlRS" => "\u{1E}",
+    "controlSI" => "\u{F}",
+    "controlSO" => "\u{E}",
+    "controlSOT" => "\u{2}",
+    "controlSTX" => "\u{1}",
+    "controlSUB" => "\u{1A}",
+    "controlSYN" => "\u{16}",
+    "controlUS" => "\u{1F}",
+    "controlVT" => "\u{B}",
+    "copyright" => "\u{A9}",
+    "copyrightsans" => "\u{F8E9}",
+    "copyrightserif" => "\u{F6D9}",
+    "cornerbracketleft" => "\u{300C}",
+    "cornerbracketlefthalfwidth" => "\u{FF62}",
+    "cornerbracketleftvertical" => "\u{FE41}",
+    "cornerbracketright" => "\u{300D}",
+    "cornerbracketrighthalfwidth" => "\u{FF63}",
+    "cornerbracketrightvertical" => "\u{FE42}",
+    "corporationsquare" => "\u{337F}",
+    "cosquare" => "\u{33C7}",
+    "coverkgsquare" => "\u{33C6}",
+    "cparen" => "\u{249E}",
+    "cruzeiro" => "\u{20A2}",
+    "cstretched" => "\u{297}",
+    "curlyand" => "\u{22CF}",
+    "curlyor" => "\u{22CE}",
+    "currency" => "\u{A4}",
+    "cyrBreve" => "\u{F6D1}",
+    "cyrFlex" => "\u{F6D2}",
+    "cyrbreve" => "\u{F6D4}",
+    "cyrflex" => "\u{F6D5}",
+    "d" => "\u{64}",
+    "daarmenian" => "\u{564}",
+    "dabengali" => "\u{9A6}",
+    "dadarabic" => "\u{636}",
+    "dadeva" => "\u{926}",
+    "dadfinalarabic" => "\u{FEBE}",
+    "dadinitialarabic" => "\u{FEBF}",
+    "dadmedialarabic" => "\u{FEC0}",
+    "dagesh" => "\u{5BC}",
+    "dageshhebrew" => "\u{5BC}",
+    "dagger" => "\u{2020}",
+    "daggerdbl" => "\u{2021}",
+    "dagujarati" => "\u{AA6}",
+    "dagurmukhi" => "\u{A26}",
+    "dahiragana" => "\u{3060}",
+    "dakatakana" => "\u{30C0}",
+    "dalarabic" => "\u{62F}",
+    "dalet" => "\u{5D3}",
+    "daletdagesh" => "\u{FB33}",
+    "daletdageshhebrew" => "\u{FB33}",
+    "dalethatafpatah" => "\u{5D3}\u{5B2}",
+    "dalethatafpatahhebrew" => "\u{5D3}\u{5B2}",
+    "dalethatafsegol" => "\u{5D3}\u{5B1}",
+    "dalethatafsegolhebrew" => "\u{5D3}\u{5B1}",
+    "dalethebrew" => "\u{5D3}",
+    "dalethiriq" => "\u{5D3}\u{5B4}",
+    "dalethiriqhebrew" => "\u{5D3}\u{5B4}",
+    "daletholam" => "\u{5D3}\u{5B9}",
+    "daletholamhebrew" => "\u{5D3}\u{5B9}",
+    "daletpatah" => "\u{5D3}\u{5B7}",
+    "daletpatahhebrew" => "\u{5D3}\u{5B7}",
+    "daletqamats" => "\u{5D3}\u{5B8}",
+    "daletqamatshebrew" => "\u{5D3}\u{5B8}",
+    "daletqubuts" => "\u{5D3}\u{5BB}",
+    "daletqubutshebrew" => "\u{5D3}\u{5BB}",
+    "daletsegol" => "\u{5D3}\u{5B6}",
+    "daletsegolhebrew" => "\u{5D3}\u{5B6}",
+    "daletsheva" => "\u{5D3}\u{5B0}",
+    "daletshevahebrew" => "\u{5D3}\u{5B0}",
+    "dalettsere" => "\u{5D3}\u{5B5}",
+    "dalettserehebrew" => "\u{5D3}\u{5B5}",
+    "dalfinalarabic" => "\u{FEAA}",
+    "dammaarabic" => "\u{64F}",
+    "dammalowarabic" => "\u{64F}",
+    "dammatanaltonearabic" => "\u{64C}",
+    "dammatanarabic" => "\u{64C}",
+    "danda" => "\u{964}",
+    "dargahebrew" => "\u{5A7}",
+    "dargalefthebrew" => "\u{5A7}",
+    "dasiapneumatacyrilliccmb" => "\u{485}",
+    "dblGrave" => "\u{F6D3}",
+    "dblanglebracketleft" => "\u{300A}",
+    "dblanglebracketleftvertical" => "\u{FE3D}",
+    "dblanglebracketright" => "\u{300B}",
+    "dblanglebracketrightvertical" => "\u{FE3E}",
+    "dblarchinvertedbelowcmb" => "\u{32B}",
+    "dblarrowleft" => "\u{21D4}",
+    "dblarrowright" => "\u{21D2}",
+    "dbldanda" => "\u{965}",
+    "dblgrave" => "\u{F6D6}",
+    "dblgravecmb" => "\u{30F}",
+    "dblintegral" => "\u{222C}",
+    "dbllowline" => "\u{2017}",
+    "dbllowlinecmb" => "\u{333}",
+    "dbloverlinecmb" => "\u{33F}",
+    "dblprimemod" => "\u{2BA}",
+    "dblverticalbar" => "\u{2016}",
+    "dblverticallineabovecmb" => "\u{30E}",
+    "dbopomofo" => "\u{3109}",
+    "dbsquare" => "\u{33C8}",
+    "dcaron" => "\u{10F}",
+    "dcedilla" => "\u{1E11}",
+    "dcircle" => "\u{24D3}",
+    "dcircumflexbelow" => "\u{1E13}",
+    "dcroat" => "\u{111}",
+    "ddabengali" => "\u{9A1}",
+    "ddadeva" => "\u{921}",
+    "ddagujarati" => "\u{AA1}",
+    "ddagurmukhi" => "\u{A21}",
+    "ddalarabic" => "\u{688}",
+    "ddalfinalarabic" => "\u{FB89}",
+    "dddhadeva" => "\u{95C}",
+    "ddhabengali" => "\u{9A2}",
+    "ddhadeva" => "\u{922}",
+    "ddhagujarati" => "\u{AA2}",
+    "ddhagurmukhi" => "\u{A22}",
+    "ddotaccent" => "\u{1E0B}",
+    "ddotbelow" => "\u{1E0D}",
+    "decimalseparatorarabic" => "\u{66B}",
+    "decimalseparatorpersian" => "\u{66B}",
+    "decyrillic" => "\u{434}",
+    "degree" => "\u{B0}",
+    "dehihebrew" => "\u{5AD}",
+    "dehiragana" => "\u{3067}",
+    "deicoptic" => "\u{3EF}",
+    "dekatakana" => "\u{30C7}",
+    "deleteleft" => "\u{232B}",
+    "deleteright" => "\u{2326}",
+    "delta" => "\u{3B4}",
+    "deltaturned" => "\u{18D}",
+    "denominatorminusonenumeratorbengali" => "\u{9F8}",
+    "dezh" => "\u{2A4}",
+    "dhabengali" => "\u{9A7}",
+    "dhadeva" => "\u{927}",
+    "dhagujarati" => "\u{AA7}",
+    "dhagurmukhi" => "\u{A27}",
+    "dhook" => "\u{257}",
+    "dialytikatonos" => "\u{385}",
+    "dialytikatonoscmb" => "\u{344}",
+    "diamond" => "\u{2666}",
+    "diamondsuitwhite" => "\u{2662}",
+    "dieresis" => "\u{A8}",
+    "dieresisacute" => "\u{F6D7}",
+    "dieresisbelowcmb" => "\u{324}",
+    "dieresiscmb" => "\u{308}",
+    "dieresisgrave" => "\u{F6D8}",
+    "dieresistonos" => "\u{385}",
+    "dihiragana" => "\u{3062}",
+    "dikatakana" => "\u{30C2}",
+    "dittomark" => "\u{3003}",
+    "divide" => "\u{F7}",
+    "divides" => "\u{2223}",
+    "divisionslash" => "\u{2215}",
+    "djecyrillic" => "\u{452}",
+    "dkshade" => "\u{2593}",
+    "dlinebelow" => "\u{1E0F}",
+    "dlsquare" => "\u{3397}",
+    "dmacron" => "\u{111}",
+    "dmonospace" => "\u{FF44}",
+    "dnblock" => "\u{2584}",
+    "dochadathai" => "\u{E0E}",
+    "dodekthai" => "\u{E14}",
+    "dohiragana" => "\u{3069}",
+    "dokatakana" => "\u{30C9}",
+    "dollar" => "\u{24}",
+    "dollarinferior" => "\u{F6E3}",
+    "dollarmonospace" => "\u{FF04}",
+    "dollaroldstyle" => "\u{F724}",
+    "dollarsmall" => "\u{FE69}",
+    "dollarsuperior" => "\u{F6E4}",
+    "dong" => "\u{20AB}",
+    "dorusquare" => "\u{3326}",
+    "dotaccent" => "\u{2D9}",
+    "dotaccentcmb" => "\u{307}",
+    "dotbelowcmb" => "\u{323}",
+    "dotbelowcomb" => "\u{323}",
+    "dotkatakana" => "\u{30FB}",
+    "dotlessi" => "\u{131}",
+    "dotlessj" => "\u{F6BE}",
+    "dotlessjstrokehook" => "\u{284}",
+    "dotmath" => "\u{22C5}",
+    "dottedcircle" => "\u{25CC}",
+    "doubleyodpatah" => "\u{FB1F}",
+    "doubleyodpatahhebrew" => "\u{FB1F}",
+    "downtackbelowcmb" => "\u{31E}",
+    "downtackmod" => "\u{2D5}",
+    "dparen" => "\u{249F}",
+    "dsuperior" => "\u{F6EB}",
+    "dtail" => "\u{256}",
+    "dtopbar" => "\u{18C}",
+    "duhiragana" => "\u{3065}",
+    "dukatakana" => "\u{30C5}",
+    "dz" => "\u{1F3}",
+    "dzaltone" => "\u{2A3}",
+    "dzcaron" => "\u{1C6}",
+    "dzcurl" => "\u{2A5}",
+    "dzeabkhasiancyrillic" => "\u{4E1}",
+    "dzecyrillic" => "\u{455}",
+    "dzhecyrillic" => "\u{45F}",
+    "e" => "\u{65}",
+    "eacute" => "\u{E9}",
+    "earth" => "\u{2641}",
+    "ebengali" => "\u{98F}",
+    "ebopomofo" => "\u{311C}",
+    "ebreve" => "\u{115}",
+    "ecandradeva" => "\u{90D}",
+    "ecandragujarati" => "\u{A8D}",
+    "ecandravowelsigndeva" => "\u{945}",
+    "ecandravowelsigngujarati" => "\u{AC5}",
+    "ecaron" => "\u{11B}",
+    "ecedillabreve" => "\u{1E1D}",
+    "echarmenian" => "\u{565}",
+    "echyiwnarmenian" => "\u{587}",
+    "ecircle" => "\u{24D4}",
+    "ecircumflex" => "\u{EA}",
+    "ecircumflexacute" => "\u{1EBF}",
+    "ecircumflexbelow" => "\u{1E19}",
+    "ecircumflexdotbelow" => "\u{1EC7}",
+    "ecircumflexgrave" => "\u{1EC1}",
+    "ecircumflexhookabove" => "\u{1EC3}",
+    "ecircumflextilde" => "\u{1EC5}",
+    "ecyrillic" => "\u{454}",
+    "edblgrave" => "\u{205}",
+    "edeva" => "\u{90F}",
+    "edieresis" => "\u{EB}",
+    "edot" => "\u{117}",
+    "edotaccent" => "\u{117}",
+    "edotbelow" => "\u{1EB9}",
+    "eegurmukhi" => "\u{A0F}",
+    "eematragurmukhi" => "\u{A47}",
+    "efcyrillic" => "\u{444}",
+    "egrave" => "\u{E8}",
+    "egujarati" => "\u{A8F}",
+    "eharmenian" => "\u{567}",
+    "ehbopomofo" => "\u{311D}",
+    "ehiragana" => "\u{3048}",
+    "ehookabove" => "\u{1EBB}",
+    "eibopomofo" => "\u{311F}",
+    "eight" => "\u{38}",
+    "eightarabic" => "\u{668}",
+    "eightbengali" => "\u{9EE}",
+    "eightcircle" => "\u{2467}",
+    "eightcircleinversesansserif" => "\u{2791}",
+    "eightdeva" => "\u{96E}",
+    "eighteencircle" => "\u{2471}",
+    "eighteenparen" => "\u{2485}",
+    "eighteenperiod" => "\u{2499}",
+    "eightgujarati" => "\u{AEE}",
+    "eightgurmukhi" => "\u{A6E}",
+    "eighthackarabic" => "\u{668}",
+    "eighthangzhou" => "\u{3028}",
+    "eighthnotebeamed" => "\u{266B}",
+    "eightideographicparen" => "\u{3227}",
+    "eightinferior" => "\u{2088}",
+    "eightmonospace" => "\u{FF18}",
+    "eightoldstyle" => "\u{F738}",
+    "eightparen" => "\u{247B}",
+    "eightperiod" => "\u{248F}",
+    "eightpersian" => "\u{6F8}",
+    "eightroman" => "\u{2177}",
+    "eightsuperior" => "\u{2078}",
+    "eightthai" => "\u{E58}",
+    "einvertedbreve" => "\u{207}",
+    "eiotifiedcyrillic" => "\u{465}",
+    "ekatakana" => "\u{30A8}",
+    "ekatakanahalfwidth" => "\u{FF74}",
+    "ekonkargurmukhi" => "\u{A74}",
+    "ekorean" => "\u{3154}",
+    "elcyrillic" => "\u{43B}",
+    "element" => "\u{2208}",
+    "elevencircle" => "\u{246A}",
+    "elevenparen" => "\u{247E}",
+    "elevenperiod" => "\u{2492}",
+    "elevenroman" => "\u{217A}",
+    "ellipsis" => "\u{2026}",
+    "ellipsisvertical" => "\u{22EE}",
+    "emacron" => "\u{113}",
+    "emacronacute" => "\u{1E17}",
+    "emacrongrave" => "\u{1E15}",
+    "emcyrillic" => "\u{43C}",
+    "emdash" => "\u{2014}",
+    "emdashvertical" => "\u{FE31}",
+    "emonospace" => "\u{FF45}",
+    "emphasismarkarmenian" => "\u{55B}",
+    "emptyset" => "\u{2205}",
+    "enbopomofo" => "\u{3123}",
+    "encyrillic" => "\u{43D}",
+    "endash" => "\u{2013}",
+    "endashvertical" => "\u{FE32}",
+    "endescendercyrillic" => "\u{4A3}",
+    "eng" => "\u{14B}",
+    "engbopomofo" => "\u{3125}",
+    "enghecyrillic" => "\u{4A5}",
+    "enhookcyrillic" => "\u{4C8}",
+    "enspace" => "\u{2002}",
+    "eogonek" => "\u{119}",
+    "eokorean" => "\u{3153}",
+    "eopen" => "\u{25B}",
+    "eopenclosed" => "\u{29A}",
+    "eopenreversed" => "\u{25C}",
+    "eopenreversedclosed" => "\u{25E}",
+    "eopenreversedhook" => "\u{25D}",
+    "eparen" => "\u{24A0}",
+    "epsilon" => "\u{3B5}",
+    "epsilontonos" => "\u{3AD}",
+    "equal" => "\u{3D}",
+    "equalmonospace" => "\u{FF1D}",
+    "equalsmall" => "\u{FE66}",
+    "equalsuperior" => "\u{207C}",
+    "equivalence" => "\u{2261}",
+    "erbopomofo" => "\u{3126}",
+    "ercyrillic" => "\u{440}",
+    "ereversed" => "\u{258}",
+    "ereversedcyrillic" => "\u{44D}",
+    "escyrillic" => "\u{441}",
+    "esdescendercyrillic" => "\u{4AB}",
+    "esh" => "\u{283}",
+    "eshcurl" => "\u{286}",
+    "eshortdeva" => "\u{90E}",
+    "eshortvowelsigndeva" => "\u{946}",
+    "eshreversedloop" => "\u{1AA}",
+    "eshsquatreversed" => "\u{285}",
+    "esmallhiragana" => "\u{3047}",
+    "esmallkatakana" => "\u{30A7}",
+    "esmallkatakanahalfwidth" => "\u{FF6A}",
+    "estimated" => "\u{212E}",
+    "esuperior" => "\u{F6EC}",
+    "eta" => "\u{3B7}",
+    "etarmenian" => "\u{568}",
+    "etatonos" => "\u{3AE}",
+    "eth" => "\u{F0}",
+    "etilde" => "\u{1EBD}",
+    "etildebelow" => "\u{1E1B}",
+    "etnahtafoukhhebrew" => "\u{591}",
+    "etnahtafoukhlefthebrew" => "\u{591}",
+    "etnahtahebrew" => "\u{591}",
+    "etnahtalefthebrew" => "\u{591}",
+    "eturned" => "\u{1DD}",
+    "eukorean" => "\u{3161}",
+    "euro" => "\u{20AC}",
+    "evowelsignbengali" => "\u{9C7}",
+    "evowelsigndeva" => "\u{947}",
+    "evowelsigngujarati" => "\u{AC7}",
+    "exclam" => "\u{21}",
+    "exclamarmenian" => "\u{55C}",
+    "exclamdbl" => "\u{203C}",
+    "exclamdown" => "\u{A1}",
+    "exclamdownsmall" => "\u{F7A1}",
+    "exclammonospace" => "\u{FF01}",
+    "exclamsmall" => "\u{F721}",
+    "existential" => "\u{2203}",
+    "ezh" => "\u{292}",
+    "ezhcaron" => "\u{1EF}",
+    "ezhcurl" => "\u{293}",
+    "ezhreversed" => "\u{1B9}",
+    "ezhtail" => "\u{1BA}",
+    "f" => "\u{66}",
+    "fadeva" => "\u{95E}",
+    "fagurmukhi" => "\u{A5E}",
+    "fahrenheit" => "\u{2109}",
+    "fathaarabic" => "\u{64E}",
+    "fathalowarabic" => "\u{64E}",
+    "fathatanarabic" => "\u{64B}",
+    "fbopomofo" => "\u{3108}",
+    "fcircle" => "\u{24D5}",
+    "fdotaccent" => "\u{1E1F}",
+    "feharabic" => "\u{641}",
+    "feharmenian" => "\u{586}",
+    "fehfinalarabic" => "\u{FED2}",
+    "fehinitialarabic" => "\u{FED3}",
+    "fehmedialarabic" => "\u{FED4}",
+    "feicoptic" => "\u{3E5}",
+    "female" => "\u{2640}",
+    "ff" => "\u{FB00}",
+    "ffi" => "\u{FB03}",
+    "ffl" => "\u{FB04}",
+    "fi" => "\u{FB01}",
+    "fifteencircle" => "\u{246E}",
+    "fifteenparen" => "\u{2482}",
+    "fifteenperiod" => "\u{2496}",
+    "figuredash" => "\u{2012}",
+    "filledbox" => "\u{25A0}",
+    "filledrect" => "\u{25AC}",
+    "finalkaf" => "\u{5DA}",
+    "finalkafdagesh" => "\u{FB3A}",
+    "finalkafdageshhebrew" => "\u{FB3A}",
+    "finalkafhebrew" => "\u{5DA}",
+    "finalkafqamats" => "\u{5DA}\u{5B8}",
+    "finalkafqamatshebrew" => "\u{5DA}\u{5B8}",
+    "finalkafsheva" => "\u{5DA}\u{5B0}",
+    "finalkafshevahebrew" => "\u{5DA}\u{5B0}",
+    "finalmem" => "\u{5DD}",
+    "finalmemhebrew" => "\u{5DD}",
+    "finalnun" => "\u{5DF}",
+    "finalnunhebrew" => "\u{5DF}",
+    "finalpe" => "\u{5E3}",
+    "finalpehebrew" => "\u{5E3}",
+    "finaltsadi" => "\u{5E5}",
+    "finaltsadihebrew" => "\u{5E5}",
+    "firsttonechinese" => "\u{2C9}",
+    "fisheye" => "\u{25C9}",
+    "fitacyrillic" => "\u{473}",
+    "five" => "\u{35}",
+    "fivearabic" => "\u{665}",
+    "fivebengali" => "\u{9EB}",
+    "fivecircle" => "\u{2464}",
+    "fivecircleinversesansserif" => "\u{278E}",
+    "fivedeva" => "\u{96B}",
+    "fiveeighths" => "\u{215D}",
+    "fivegujarati" => "\u{AEB}",
+    "fivegurmukhi" => "\u{A6B}",
+    "fivehackarabic" => "\u{665}",
+    "fivehangzhou" => "\u{3025}",
+    "fiveideographicparen" => "\u{3224}",
+    "fiveinferior" => "\u{2085}",
+    "fivemonospace" => "\u{FF15}",
+    "fiveoldstyle" => "\u{F735}",
+    "fiveparen" => "\u{2478}",
+    "fiveperiod" => "\u{248C}",
+    "fivepersian" => "\u{6F5}",
+    "fiveroman" => "\u{2174}",
+    "fivesuperior" => "\u{2075}",
+    "fivethai" => "\u{E55}",
+    "fl" => "\u{FB02}",
+    "florin" => "\u{192}",
+    "fmonospace" => "\u{FF46}",
+    "fmsquare" => "\u{3399}",
+    "fofanthai" => "\u{E1F}",
+    "fofathai" => "\u{E1D}",
+    "fongmanthai" => "\u{E4F}",
+    "forall" => "\u{2200}",
+    "four" => "\u{34}",
+    "fourarabic" => "\u{664}",
+    "fourbengali" => "\u{9EA}",
+    "fourcircle" => "\u{2463}",
+    "fourcircleinversesansserif" => "\u{278D}",
+    "fourdeva" => "\u{96A}",
+    "fourgujarati" => "\u{AEA}",
+    "fourgurmukhi" => "\u{A6A}",
+    "fourhackarabic" => "\u{664}",
+    "fourhangzhou" => "\u{3024}",
+    "fourideographicparen" => "\u{3223}",
+    "fourinferior" => "\u{2084}",
+    "fourmonospace" => "\u{FF14}",
+    "fournumeratorbengali" => "\u{9F7}",
+    "fouroldstyle" => "\u{F734}",
+    "fourparen" => "\u{2477}",
+    "fourperiod" => "\u{248B}",
+    "fourpersian" => "\u{6F4}",
+    "fourroman" => "\u{2173}",
+    "foursuperior" => "\u{2074}",
+    "fourteencircle" => "\u{246D}",
+    "fourteenparen" => "\u{2481}",
+    "fourteenperiod" => "\u{2495}",
+    "fourthai" => "\u{E54}",
+    "fourthtonechinese" => "\u{2CB}",
+    "fparen" => "\u{24A1}",
+    "fraction" => "\u{2044}",
+    "franc" => "\u{20A3}",
+    "g" => "\u{67}",
+    "gabengali" => "\u{997}",
+    "gacute" => "\u{1F5}",
+    "gadeva" => "\u{917}",
+    "gafarabic" => "\u{6AF}",
+    "gaffinalarabic" => "\u{FB93}",
+    "gafinitialarabic" => "\u{FB94}",
+    "gafmedialarabic" => "\u{FB95}",
+    "gagujarati" => "\u{A97}",
+    "gagurmukhi" => "\u{A17}",
+    "gahiragana" => "\u{304C}",
+    "gakatakana" => "\u{30AC}",
+    "gamma" => "\u{3B3}",
+    "gammalatinsmall" => "\u{263}",
+    "gammasuperior" => "\u{2E0}",
+    "gangiacoptic" => "\u{3EB}",
+    "gbopomofo" => "\u{310D}",
+    "gbreve" => "\u{11F}",
+    "gcaron" => "\u{1E7}",
+    "gcedilla" => "\u{123}",
+    "gcircle" => "\u{24D6}",
+    "gcircumflex" => "\u{11D}",
+    "gcommaaccent" => "\u{123}",
+    "gdot" => "\u{121}",
+    "gdotaccent" => "\u{121}",
+    "gecyrillic" => "\u{433}",
+    "gehiragana" => "\u{3052}",
+    "gekatakana" => "\u{30B2}",
+    "geometricallyequal" => "\u{2251}",
+    "gereshaccenthebrew" => "\u{59C}",
+    "gereshhebrew" => "\u{5F3}",
+    "gereshmuqdamhebrew" => "\u{59D}",
+    "germandbls" => "\u{DF}",
+    "gershayimaccenthebrew" => "\u{59E}",
+    "gershayimhebrew" => "\u{5F4}",
+    "getamark" => "\u{3013}",
+    "ghabengali" => "\u{998}",
+    "ghadarmenian" => "\u{572}",
+    "ghadeva" => "\u{918}",
+    "ghagujarati" => "\u{A98}",
+    "ghagurmukhi" => "\u{A18}",
+    "ghainarabic" => "\u{63A}",
+    "ghainfinalarabic" => "\u{FECE}",
+    "ghaininitialarabic" => "\u{FECF}",
+    "ghainmedialarabic" => "\u{FED0}",
+    "ghemiddlehookcyrillic" => "\u{495}",
+    "ghestrokecyrillic" => "\u{493}",
+    "gheupturncyrillic" => "\u{491}",
+    "ghhadeva" => "\u{95A}",
+    "ghhagurmukhi" => "\u{A5A}",
+    "ghook" => "\u{260}",
+    "ghzsquare" => "\u{3393}",
+    "gihiragana" => "\u{304E}",
+    "gikatakana" => "\u{30AE}",
+    "gimarmenian" => "\u{563}",
+    "gimel" => "\u{5D2}",
+    "gimeldagesh" => "\u{FB32}",
+    "gimeldageshhebrew" => "\u{FB32}",
+    "gimelhebrew" => "\u{5D2}",
+    "gjecyrillic" => "\u{453}",
+    "glottalinvertedstroke" => "\u{1BE}",
+    "glottalstop" => "\u{294}",
+    "glottalstopinverted" => "\u{296}",
+    "glottalstopmod" => "\u{2C0}",
+    "glottalstopreversed" => "\u{295}",
+    "glottalstopreversedmod" => "\u{2C1}",
+    "glottalstopreversedsuperior" => "\u{2E4}",
+    "glottalstopstroke" => "\u{2A1}",
+    "glottalstopstrokereversed" => "\u{2A2}",
+    "gmacron" => "\u{1E21}",
+    "gmonospace" => "\u{FF47}",
+    "gohiragana" => "\u{3054}",
+    "gokatakana" => "\u{30B4}",
+    "gparen" => "\u{24A2}",
+    "gpasquare" => "\u{33AC}",
+    "gradient" => "\u{2207}",
+    "grave" => "\u{60}",
+    "gravebelowcmb" => "\u{316}",
+    "gravecmb" => "\u{300}",
+    "gravecomb" => "\u{300}",
+    "gravedeva" => "\u{953}",
+    "gravelowmod" => "\u{2CE}",
+    "gravemonospace" => "\u{FF40}",
+    "gravetonecmb" => "\u{340}",
+    "greater" => "\u{3E}",
+    "greaterequal" => "\u{2265}",
+    "greaterequalorless" => "\u{22DB}",
+    "greatermonospace" => "\u{FF1E}",
+    "greaterorequivalent" => "\u{2273}",
+    "greaterorless" => "\u{2277}",
+    "greateroverequal" => "\u{2267}",
+    "greatersmall" => "\u{FE65}",
+    "gscript" => "\u{261}",
+    "gstroke" => "\u{1E5}",
+    "guhiragana" => "\u{3050}",
+    "guillemotleft" => "\u{AB}",
+    "guillemotright" => "\u{BB}",
+    "guilsinglleft" => "\u{2039}",
+    "guilsinglright" => "\u{203A}",
+    "gukatakana" => "\u{30B0}",
+    "guramusquare" => "\u{3318}",
+    "gysquare" => "\u{33C9}",
+    "h" => "\u{68}",
+    "haabkhasiancyrillic" => "\u{4A9}",
+    "haaltonearabic" => "\u{6C1}",
+    "habengali" => "\u{9B9}",
+    "hadescendercyrillic" => "\u{4B3}",
+    "hadeva" => "\u{939}",
+    "hagujarati" => "\u{AB9}",
+    "hagurmukhi" => "\u{A39}",
+    "haharabic" => "\u{62D}",
+    "hahfinalarabic" => "\u{FEA2}",
+    "hahinitialarabic" => "\u{FEA3}",
+    "hahiragana" => "\u{306F}",
+    "hahmedialarabic" => "\u{FEA4}",
+    "haitusquare" => "\u{332A}",
+    "hakatakana" => "\u{30CF}",
+    "hakatakanahalfwidth" => "\u{FF8A}",
+    "halantgurmukhi" => "\u{A4D}",
+    "hamzaarabic" => "\u{621}",
+    "hamzadammaarabic" => "\u{621}\u{64F}",
+    "hamzadammatanarabic" => "\u{621}\u{64C}",
+    "hamzafathaarabic" => "\u{621}\u{64E}",
+    "hamzafathatanarabic" => "\u{621}\u{64B}",
+    "hamzalowarabic" => "\u{621}",
+    "hamzalowkasraarabic" => "\u{621}\u{650}",
+    "hamzalowkasratanarabic" => "\u{621}\u{64D}",
+    "hamzasukunarabic" => "\u{621}\u{652}",
+    "hangulfiller" => "\u{3164}",
+    "hardsigncyrillic" => "\u{44A}",
+    "harpoonleftbarbup" => "\u{21BC}",
+    "harpoonrightbarbup" => "\u{21C0}",
+    "hasquare" => "\u{33CA}",
+    "hatafpatah" => "\u{5B2}",
+    "hatafpatah16" => "\u{5B2}",
+    "hatafpatah23" => "\u{5B2}",
+    "hatafpatah2f" => "\u{5B2}",
+    "hatafpatahhebrew" => "\u{5B2}",
+    "hatafpatahnarrowhebrew" => "\u{5B2}",
+    "hatafpatahquarterhebrew" => "\u{5B2}",
+    "hatafpatahwidehebrew" => "\u{5B2}",
+    "hatafqamats" => "\u{5B3}",
+    "hatafqamats1b" => "\u{5B3}",
+    "hatafqamats28" => "\u{5B3}",
+    "hatafqamats34" => "\u{5B3}",
+    "hatafqamatshebrew" => "\u{5B3}",
+    "hatafqamatsnarrowhebrew" => "\u{5B3}",
+    "hatafqamatsquarterhebrew" => "\u{5B3}",
+    "hatafqamatswidehebrew" => "\u{5B3}",
+    "hatafsegol" => "\u{5B1}",
+    "hatafsegol17" => "\u{5B1}",
+    "hatafsegol24" => "\u{5B1}",
+    "hatafsegol30" => "\u{5B1}",
+    "hatafsegolhebrew" => "\u{5B1}",
+    "hatafsegolnarrowhebrew" => "\u{5B1}",
+    "hatafsegolquarterhebrew" => "\u{5B1}",
+    "hatafsegolwidehebrew" => "\u{5B1}",
+    "hbar" => "\u{127}",
+    "hbopomofo" => "\u{310F}",
+    "hbrevebelow" => "\u{1E2B}",
+    "hcedilla" => "\u{1E29}",
+    "hcircle" => "\u{24D7}",
+    "hcircumflex" => "\u{125}",
+    "hdieresis" => "\u{1E27}",
+    "hdotaccent" => "\u{1E23}",
+    "hdotbelow" => "\u{1E25}",
+    "he" => "\u{5D4}",
+    "heart" => "\u{2665}",
+    "heartsuitblack" => "\u{2665}",
+    "heartsuitwhite" => "\u{2661}",
+    "hedagesh" => "\u{FB34}",
+    "hedageshhebrew" => "\u{FB34}",
+    "hehaltonearabic" => "\u{6C1}",
+    "heharabic" => "\u{647}",
+    "hehebrew" => "\u{5D4}",
+    "hehfinalaltonearabic" => "\u{FBA7}",
+    "hehfinalalttwoarabic" => "\u{FEEA}",
+    "hehfinalarabic" => "\u{FEEA}",
+    "hehhamzaabovefinalarabic" => "\u{FBA5}",
+    "hehhamzaaboveisolatedarabic" => "\u{FBA4}",
+    "hehinitialaltonearabic" => "\u{FBA8}",
+    "hehinitialarabic" => "\u{FEEB}",
+    "hehiragana" => "\u{3078}",
+    "hehmedialaltonearabic" => "\u{FBA9}",
+    "hehmedialarabic" => "\u{FEEC}",
+    "heiseierasquare" => "\u{337B}",
+    "hekatakana" => "\u{30D8}",
+    "hekatakanahalfwidth" => "\u{FF8D}",
+    "hekutaarusquare" => "\u{3336}",
+    "henghook" => "\u{267}",
+    "herutusquare" => "\u{3339}",
+    "het" => "\u{5D7}",
+    "hethebrew" => "\u{5D7}",
+    "hhook" => "\u{266}",
+    "hhooksuperior" => "\u{2B1}",
+    "hieuhacirclekorean" => "\u{327B}",
+    "hieuhaparenkorean" => "\u{321B}",
+    "hieuhcirclekorean" => "\u{326D}",
+    "hieuhkorean" => "\u{314E}",
+    "hieuhparenkorean" => "\u{320D}",
+    "hihiragana" => "\u{3072}",
+    "hikatakana" => "\u{30D2}",
+    "hikatakanahalfwidth" => "\u{FF8B}",
+    "hiriq" => "\u{5B4}",
+    "hiriq14" => "\u{5B4}",
+    "hiriq21" => "\u{5B4}",
+    "hiriq2d" => "\u{5B4}",
+    "hiriqhebrew" => "\u{5B4}",
+    "hiriqnarrowhebrew" => "\u{5B4}",
+    "hiriqquarterhebrew" => "\u{5B4}",
+    "hiriqwidehebrew" => "\u{5B4}",
+    "hlinebelow" => "\u{1E96}",
+    "hmonospace" => "\u{FF48}",
+    "hoarmenian" => "\u{570}",
+    "hohipthai" => "\u{E2B}",
+    "hohiragana" => "\u{307B}",
+    "hokatakana" => "\u{30DB}",
+    "hokatakanahalfwidth" => "\u{FF8E}",
+    "holam" => "\u{5B9}",
+    "holam19" => "\u{5B9}",
+    "holam26" => "\u{5B9}",
+    "holam32" => "\u{5B9}",
+    "holamhebrew" => "\u{5B9}",
+    "holamnarrowhebrew" => "\u{5B9}",
+    "holamquarterhebrew" => "\u{5B9}",
+    "holamwidehebrew" => "\u{5B9}",
+    "honokhukthai" => "\u{E2E}",
+    "hookabovecomb" => "\u{309}",
+    "hookcmb" => "\u{309}",
+    "hookpalatalizedbelowcmb" => "\u{321}",
+    "hookretroflexbelowcmb" => "\u{322}",
+    "hoonsquare" => "\u{3342}",
+    "horicoptic" => "\u{3E9}",
+    "horizontalbar" => "\u{2015}",
+    "horncmb" => "\u{31B}",
+    "hotsprings" => "\u{2668}",
+    "house" => "\u{2302}",
+    "hparen" => "\u{24A3}",
+    "hsuperior" => "\u{2B0}",
+    "hturned" => "\u{265}",
+    "huhiragana" => "\u{3075}",
+    "huiitosquare" => "\u{3333}",
+    "hukatakana" => "\u{30D5}",
+    "hukatakanahalfwidth" => "\u{FF8C}",
+    "hungarumlaut" => "\u{2DD}",
+    "hungarumlautcmb" => "\u{30B}",
+    "hv" => "\u{195}",
+    "hyphen" => "\u{2D}",
+    "hypheninferior" => "\u{F6E5}",
+    "hyphenmonospace" => "\u{FF0D}",
+    "hyphensmall" => "\u{FE63}",
+    "hyphensuperior" => "\u{F6E6}",
+    "hyphentwo" => "\u{2010}",
+    "i" => "\u{69}",
+    "iacute" => "\u{ED}",
+    "iacyrillic" => "\u{44F}",
+    "ibengali" => "\u{987}",
+    "ibopomofo" => "\u{3127}",
+    "ibreve" => "\u{12D}",
+    "icaron" => "\u{1D0}",
+    "icircle" => "\u{24D8}",
+    "icircumflex" => "\u{EE}",
+    "icyrillic" => "\u{456}",
+    "idblgrave" => "\u{209}",
+    "ideographearthcircle" => "\u{328F}",
+    "ideographfirecircle" => "\u{328B}",
+    "ideographicallianceparen" => "\u{323F}",
+    "ideographiccallparen" => "\u{323A}",
+    "ideographiccentrecircle" => "\u{32A5}",
+    "ideographicclose" => "\u{3006}",
+    "ideographiccomma" => "\u{3001}",
+    "ideographiccommaleft" => "\u{FF64}",
+    "ideographiccongratulationparen" => "\u{3237}",
+    "ideographiccorrectcircle" => "\u{32A3}",
+    "ideographicearthparen" => "\u{322F}",
+    "ideographicenterpriseparen" => "\u{323D}",
+    "ideographicexcellentcircle" => "\u{329D}",
+    "ideographicfestivalparen" => "\u{3240}",
+    "ideographicfinancialcircle" => "\u{3296}",
+    "ideographicfinancialparen" => "\u{3236}",
+    "ideographicfireparen" => "\u{322B}",
+    "ideographichaveparen" => "\u{3232}",
+    "ideographichighcircle" => "\u{32A4}",
+    "ideographiciterationmark" => "\u{3005}",
+    "ideographiclaborcircle" => "\u{3298}",
+    "ideographiclaborparen" => "\u{3238}",
+    "ideographicleftcircle" => "\u{32A7}",
+    "ideographiclowcircle" => "\u{32A6}",
+    "ideographicmedicinecircle" => "\u{32A9}",
+    "ideographicmetalparen" => "\u{322E}",
+    "ideographicmoonparen" => "\u{322A}",
+    "ideographicnameparen" => "\u{3234}",
+    "ideographicperiod" => "\u{3002}",
+    "ideographicprintcircle" => "\u{329E}",
+    "ideographicreachparen" => "\u{3243}",
+    "ideographicrepresentparen" => "\u{3239}",
+    "ideographicresourceparen" => "\u{323E}",
+    "ideographicrightcircle" => "\u{32A8}",
+    "ideographicsecretcircle" => "\u{3299}",
+    "ideographicselfparen" => "\u{3242}",
+    "ideographicsocietyparen" => "\u{3233}",
+    "ideographicspace" => "\u{3000}",
+    "ideographicspecialparen" => "\u{3235}",
+    "ideographicstockparen" => "\u{3231}",
+    "ideographicstudyparen" => "\u{323B}",
+    "ideographicsunparen" => "\u{3230}",
+    "ideographicsuperviseparen" => "\u{323C}",
+    "ideographicwaterparen" => "\u{322C}",
+    "ideographicwoodparen" => "\u{322D}",
+    "ideographiczero" => "\u{3007}",
+    "ideographmetalcircle" => "\u{328E}",
+    "ideographmooncircle" => "\u{328A}",
+    "ideographnamecircle" => "\u{3294}",
+    "ideographsuncircle" => "\u{3290}",
+    "ideographwatercircle" => "\u{328C}",
+    "ideographwoodcircle" => "\u{328D}",
+    "ideva" => "\u{907}",
+    "idieresis" => "\u{EF}",
+    "idieresisacute" => "\u{1E2F}",
+    "idieresiscyrillic" => "\u{4E5}",
+    "idotbelow" => "\u{1ECB}",
+    "iebrevecyrillic" => "\u{4D7}",
+    "iecyrillic" => "\u{435}",
+    "ieungacirclekorean" => "\u{3275}",
+    "ieungaparenkorean" => "\u{3215}",
+    "ieungcirclekorean" => "\u{3267}",
+    "ieungkorean" => "\u{3147}",
+    "ieungparenkorean" => "\u{3207}",
+    "igrave" => "\u{EC}",
+    "igujarati" => "\u{A87}",
+    "igurmukhi" => "\u{A07}",
+    "ihiragana" => "\u{3044}",
+    "ihookabove" => "\u{1EC9}",
+    "iibengali" => "\u{988}",
+    "iicyrillic" => "\u{438}",
+    "iideva" => "\u{908}",
+    "iigujarati" => "\u{A88}",
+    "iigurmukhi" => "\u{A08}",
+    "iimatragurmukhi" => "\u{A40}",
+    "iinvertedbreve" => "\u{20B}",
+    "iishortcyrillic" => "\u{439}",
+    "iivowelsignbengali" => "\u{9C0}",
+    "iivowelsigndeva" => "\u{940}",
+    "iivowelsigngujarati" => "\u{AC0}",
+    "ij" => "\u{133}",
+    "ikatakana" => "\u{30A4}",
+    "ikatakanahalfwidth" => "\u{FF72}",
+    "ikorean" => "\u{3163}",
+    "ilde" => "\u{2DC}",
+    "iluyhebrew" => "\u{5AC}",
+    "imacron" => "\u{12B}",
+    "imacroncyrillic" => "\u{4E3}",
+    "imageorapproximatelyequal" => "\u{2253}",
+    "imatragurmukhi" => "\u{A3F}",
+    "imonospace" => "\u{FF49}",
+    "increment" => "\u{2206}",
+    "infinity" => "\u{221E}",
+    "iniarmenian" => "\u{56B}",
+    "integral" => "\u{222B}",
+    "integralbottom" => "\u{2321}",
+    "integralbt" => "\u{2321}",
+    "integralex" => "\u{F8F5}",
+    "integraltop" => "\u{2320}",
+    "integraltp" => "\u{2320}",
+    "intersection" => "\u{2229}",
+    "intisquare" => "\u{3305}",
+    "invbullet" => "\u{25D8}",
+    "invcircle" => "\u{25D9}",
+    "invsmileface" => "\u{263B}",
+    "iocyrillic" => "\u{451}",
+    "iogonek" => "\u{12F}",
+    "iota" => "\u{3B9}",
+    "iotadieresis" => "\u{3CA}",
+    "iotadieresistonos" => "\u{390}",
+    "iotalatin" => "\u{269}",
+    "iotatonos" => "\u{3AF}",
+    "iparen" => "\u{24A4}",
+    "irigurmukhi" => "\u{A72}",
+    "ismallhiragana" => "\u{3043}",
+    "ismallkatakana" => "\u{30A3}",
+    "ismallkatakanahalfwidth" => "\u{FF68}",
+    "issharbengali" => "\u{9FA}",
+    "istroke" => "\u{268}",
+    "isuperior" => "\u{F6ED}",
+    "iterationhiragana" => "\u{309D}",
+    "iterationkatakana" => "\u{30FD}",
+    "itilde" => "\u{129}",
+    "itildebelow" => "\u{1E2D}",
+    "iubopomofo" => "\u{3129}",
+    "iucyrillic" => "\u{44E}",
+    "ivowelsignbengali" => "\u{9BF}",
+    "ivowelsigndeva" => "\u{93F}",
+    "ivowelsigngujarati" => "\u{ABF}",
+    "izhitsacyrillic" => "\u{475}",
+    "izhitsadblgravecyrillic" => "\u{477}",
+    "j" => "\u{6A}",
+    "jaarmenian" => "\u{571}",
+    "jabengali" => "\u{99C}",
+    "jadeva" => "\u{91C}",
+    "jagujarati" => "\u{A9C}",
+    "jagurmukhi" => "\u{A1C}",
+    "jbopomofo" => "\u{3110}",
+    "jcaron" => "\u{1F0}",
+    "jcircle" => "\u{24D9}",
+    "jcircumflex" => "\u{135}",
+    "jcrossedtail" => "\u{29D}",
+    "jdotlessstroke" => "\u{25F}",
+    "jecyrillic" => "\u{458}",
+    "jeemarabic" => "\u{62C}",
+    "jeemfinalarabic" => "\u{FE9E}",
+    "jeeminitialarabic" => "\u{FE9F}",
+    "jeemmedialarabic" => "\u{FEA0}",
+    "jeharabic" => "\u{698}",
+    "jehfinalarabic" => "\u{FB8B}",
+    "jhabengali" => "\u{99D}",
+    "jhadeva" => "\u{91D}",
+    "jhagujarati" => "\u{A9D}",
+    "jhagurmukhi" => "\u{A1D}",
+    "jheharmenian" => "\u{57B}",
+    "jis" => "\u{3004}",
+    "jmonospace" => "\u{FF4A}",
+    "jparen" => "\u{24A5}",
+    "jsuperior" => "\u{2B2}",
+    "k" => "\u{6B}",
+    "kabashkircyrillic" => "\u{4A1}",
+    "kabengali" => "\u{995}",
+    "kacute" => "\u{1E31}",
+    "kacyrillic" => "\u{43A}",
+    "kadescendercyrillic" => "\u{49B}",
+    "kadeva" => "\u{915}",
+    "kaf" => "\u{5DB}",
+    "kafarabic" => "\u{643}",
+    "kafdagesh" => "\u{FB3B}",
+    "kafdageshhebrew" => "\u{FB3B}",
+    "kaffinalarabic" => "\u{FEDA}",
+    "kafhebrew" => "\u{5DB}",
+    "kafinitialarabic" => "\u{FEDB}",
+    "kafmedialarabic" => "\u{FEDC}",
+    "kafrafehebrew" => "\u{FB4D}",
+    "kagujarati" => "\u{A95}",
+    "kagurmukhi" => "\u{A15}",
+    "kahiragana" => "\u{304B}",
+    "kahookcyrillic" => "\u{4C4}",
+    "kakatakana" => "\u{30AB}",
+    "kakatakanahalfwidth" => "\u{FF76}",
+    "kappa" => "\u{3BA}",
+    "kappasymbolgreek" => "\u{3F0}",
+    "kapyeounmieumkorean" => "\u{3171}",
+    "kapyeounphieuphkorean" => "\u{3184}",
+    "kapyeounpieupkorean" => "\u{3178}",
+    "kapyeounssangpieupkorean" => "\u{3179}",
+    "karoriisquare" => "\u{330D}",
+    "kashidaautoarabic" => "\u{640}",
+    "kashidaautonosidebearingarabic" => "\u{640}",
+    "kasmallkatakana" => "\u{30F5}",
+    "kasquare" => "\u{3384}",
+    "kasraarabic" => "\u{650}",
+    "kasratanarabic" => "\u{64D}",
+    "kastrokecyrillic" => "\u{49F}",
+    "katahiraprolongmarkhalfwidth" => "\u{FF70}",
+    "kaverticalstrokecyrillic" => "\u{49D}",
+    "kbopomofo" => "\u{310E}",
+    "kcalsquare" => "\u{3389}",
+    "kcaron" => "\u{1E9}",
+    "kcedilla" => "\u{137}",
+    "kcircle" => "\u{24DA}",
+    "kcommaaccent" => "\u{137}",
+    "kdotbelow" => "\u{1E33}",
+    "keharmenian" => "\u{584}",
+    "kehiragana" => "\u{3051}",
+    "kekatakana" => "\u{30B1}",
+    "kekatakanahalfwidth" => "\u{FF79}",
+    "kenarmenian" => "\u{56F}",
+    "kesmallkatakana" => "\u{30F6}",
+    "kgreenlandic" => "\u{138}",
+    "khabengali" => "\u{996}",
+    "khacyrillic" => "\u{445}",
+    "khadeva" => "\u{916}",
+    "khagujarati" => "\u{A96}",
+    "khagurmukhi" => "\u{A16}",
+    "khaharabic" => "\u{62E}",
+    "khahfinalarabic" => "\u{FEA6}",
+    "khahinitialarabic" => "\u{FEA7}",
+    "khahmedialarabic" => "\u{FEA8}",
+    "kheicoptic" => "\u{3E7}",
+    "khhadeva" => "\u{959}",
+    "khhagurmukhi" => "\u{A59}",
+    "khieukhacirclekorean" => "\u{3278}",
+    "khieukhaparenkorean" => "\u{3218}",
+    "khieukhcirclekorean" => "\u{326A}",
+    "khieukhkorean" => "\u{314B}",
+    "khieukhparenkorean" => "\u{320A}",
+    "khokhaithai" => "\u{E02}",
+    "khokhonthai" => "\u{E05}",
+    "khokhuatthai" => "\u{E03}",
+    "khokhwaithai" => "\u{E04}",
+    "khomutthai" => "\u{E5B}",
+    "khook" => "\u{199}",
+    "khorakhangthai" => "\u{E06}",
+    "khzsquare" => "\u{3391}",
+    "kihiragana" => "\u{304D}",
+    "kikatakana" => "\u{30AD}",
+    "kikatakanahalfwidth" => "\u{FF77}",
+    "kiroguramusquare" => "\u{3315}",
+    "kiromeetorusquare" => "\u{3316}",
+    "kirosquare" => "\u{3314}",
+    "kiyeokacirclekorean" => "\u{326E}",
+    "kiyeokaparenkorean" => "\u{320E}",
+    "kiyeokcirclekorean" => "\u{3260}",
+    "kiyeokkorean" => "\u{3131}",
+    "kiyeokparenkorean" => "\u{3200}",
+    "kiyeoksioskorean" => "\u{3133}",
+    "kjecyrillic" => "\u{45C}",
+    "klinebelow" => "\u{1E35}",
+    "klsquare" => "\u{3398}",
+    "kmcubedsquare" => "\u{33A6}",
+    "kmonospace" => "\u{FF4B}",
+    "kmsquaredsquare" => "\u{33A2}",
+    "kohiragana" => "\u{3053}",
+    "kohmsquare" => "\u{33C0}",
+    "kokaithai" => "\u{E01}",
+    "kokatakana" => "\u{30B3}",
+    "kokatakanahalfwidth" => "\u{FF7A}",
+    "kooposquare" => "\u{331E}",
+    "koppacyrillic" => "\u{481}",
+    "koreanstandardsymbol" => "\u{327F}",
+    "koroniscmb" => "\u{343}",
+    "kparen" => "\u{24A6}",
+    "kpasquare" => "\u{33AA}",
+    "ksicyrillic" => "\u{46F}",
+    "ktsquare" => "\u{33CF}",
+    "kturned" => "\u{29E}",
+    "kuhiragana" => "\u{304F}",
+    "kukatakana" => "\u{30AF}",
+    "kukatakanahalfwidth" => "\u{FF78}",
+    "kvsquare" => "\u{33B8}",
+    "kwsquare" => "\u{33BE}",
+    "l" => "\u{6C}",
+    "labengali" => "\u{9B2}",
+    "lacute" => "\u{13A}",
+    "ladeva" => "\u{932}",
+    "lagujarati" => "\u{AB2}",
+    "lagurmukhi" => "\u{A32}",
+    "lakkhangyaothai" => "\u{E45}",
+    "lamaleffinalarabic" => "\u{FEFC}",
+    "lamalefhamzaabovefinalarabic" => "\u{FEF8}",
+    "lamalefhamzaaboveisolatedarabic" => "\u{FEF7}",
+    "lamalefhamzabelowfinalarabic" => "\u{FEFA}",
+    "lamalefhamzabelowisolatedarabic" => "\u{FEF9}",
+    "lamalefisolatedarabic" => "\u{FEFB}",
+    "lamalefmaddaabovefinalarabic" => "\u{FEF6}",
+    "lamalefmaddaaboveisolatedarabic" => "\u{FEF5}",
+    "lamarabic" => "\u{644}",
+    "lambda" => "\u{3BB}",
+    "lambdastroke" => "\u{19B}",
+    "lamed" => "\u{5DC}",
+    "lameddagesh" => "\u{FB3C}",
+    "lameddageshhebrew" => "\u{FB3C}",
+    "lamedhebrew" => "\u{5DC}",
+    "lamedholam" => "\u{5DC}\u{5B9}",
+    "lamedholamdagesh" => "\u{5DC}\u{5B9}\u{5BC}",
+    "lamedholamdageshhebrew" => "\u{5DC}\u{5B9}\u{5BC}",
+    "lamedholamhebrew" => "\u{5DC}\u{5B9}",
+    "lamfinalarabic" => "\u{FEDE}",
+    "lamhahinitialarabic" => "\u{FCCA}",
+    "laminitialarabic" => "\u{FEDF}",
+    "lamjeeminitialarabic" => "\u{FCC9}",
+    "lamkhahinitialarabic" => "\u{FCCB}",
+    "lamlamhehisolatedarabic" => "\u{FDF2}",
+    "lammedialarabic" => "\u{FEE0}",
+    "lammeemhahinitialarabic" => "\u{FD88}",
+    "lammeeminitialarabic" => "\u{FCCC}",
+    "lammeemjeeminitialarabic" => "\u{FEDF}\u{FEE4}\u{FEA0}",
+    "lammeemkhahinitialarabic" => "\u{FEDF}\u{FEE4}\u{FEA8}",
+    "largecircle" => "\u{25EF}",
+    "lbar" => "\u{19A}",
+    "lbelt" => "\u{26C}",
+    "lbopomofo" => "\u{310C}",
+    "lcaron" => "\u{13E}",
+    "lcedilla" => "\u{13C}",
+    "lcircle" => "\u{24DB}",
+    "lcircumflexbelow" => "\u{1E3D}",
+    "lcommaaccent" => "\u{13C}",
+    "ldot" => "\u{140}",
+    "ldotaccent" => "\u{140}",
+    "ldotbelow" => "\u{1E37}",
+    "ldotbelowmacron" => "\u{1E39}",
+    "leftangleabovecmb" => "\u{31A}",
+    "lefttackbelowcmb" => "\u{318}",
+    "less" => "\u{3C}",
+    "lessequal" => "\u{2264}",
+    "lessequalorgreater" => "\u{22DA}",
+    "lessmonospace" => "\u{FF1C}",
+    "lessorequivalent" => "\u{2272}",
+    "lessorgreater" => "\u{2276}",
+    "lessoverequal" => "\u{2266}",
+    "lesssmall" => "\u{FE64}",
+    "lezh" => "\u{26E}",
+    "lfblock" => "\u{258C}",
+    "lhookretroflex" => "\u{26D}",
+    "lira" => "\u{20A4}",
+    "liwnarmenian" => "\u{56C}",
+    "lj" => "\u{1C9}",
+    "ljecyrillic" => "\u{459}",
+    "ll" => "\u{F6C0}",
+    "lladeva" => "\u{933}",
+    "llagujarati" => "\u{AB3}",
+    "llinebelow" => "\u{1E3B}",
+    "llladeva" => "\u{934}",
+    "llvocalicbengali" => "\u{9E1}",
+    "llvocalicdeva" => "\u{961}",
+    "llvocalicvowelsignbengali" => "\u{9E3}",
+    "llvocalicvowelsigndeva" => "\u{963}",
+    "lmiddletilde" => "\u{26B}",
+    "lmonospace" => "\u{FF4C}",
+    "lmsquare" => "\u{33D0}",
+    "lochulathai" => "\u{E2C}",
+    "logicaland" => "\u{2227}",
+    "logicalnot" => "\u{AC}",
+    "logicalnotreversed" => "\u{2310}",
+    "logicalor" => "\u{2228}",
+    "lolingthai" => "\u{E25}",
+    "longs" => "\u{17F}",
+    "lowlinecenterline" => "\u{FE4E}",
+    "lowlinecmb" => "\u{332}",
+    "lowlinedashed" => "\u{FE4D}",
+    "lozenge" => "\u{25CA}",
+    "lparen" => "\u{24A7}",
+    "lslash" => "\u{142}",
+    "lsquare" => "\u{2113}",
+    "lsuperior" => "\u{F6EE}",
+    "ltshade" => "\u{2591}",
+    "luthai" => "\u{E26}",
+    "lvocalicbengali" => "\u{98C}",
+    "lvocalicdeva" => "\u{90C}",
+    "lvocalicvowelsignbengali" => "\u{9E2}",
+    "lvocalicvowelsigndeva" => "\u{962}",
+    "lxsquare" => "\u{33D3}",
+    "m" => "\u{6D}",
+    "mabengali" => "\u{9AE}",
+    "macron" => "\u{AF}",
+    "macronbelowcmb" => "\u{331}",
+    "macroncmb" => "\u{304}",
+    "macronlowmod" => "\u{2CD}",
+    "macronmonospace" => "\u{FFE3}",
+    "macute" => "\u{1E3F}",
+    "madeva" => "\u{92E}",
+    "magujarati" => "\u{AAE}",
+    "magurmukhi" => "\u{A2E}",
+    "mahapakhhebrew" => "\u{5A4}",
+    "mahapakhlefthebrew" => "\u{5A4}",
+    "mahiragana" => "\u{307E}",
+    "maichattawalowleftthai" => "\u{F895}",
+    "maichattawalowrightthai" => "\u{F894}",
+    "maichattawathai" => "\u{E4B}",
+    "maichattawaupperleftthai" => "\u{F893}",
+    "maieklowleftthai" => "\u{F88C}",
+    "maieklowrightthai" => "\u{F88B}",
+    "maiekthai" => "\u{E48}",
+    "maiekupperleftthai" => "\u{F88A}",
+    "maihanakatleftthai" => "\u{F884}",
+    "maihanakatthai" => "\u{E31}",
+    "maitaikhuleftthai" => "\u{F889}",
+    "maitaikhuthai" => "\u{E47}",
+    "maitholowleftthai" => "\u{F88F}",
+    "maitholowrightthai" => "\u{F88E}",
+    "maithothai" => "\u{E49}",
+    "maithoupperleftthai" => "\u{F88D}",
+    "maitrilowleftthai" => "\u{F892}",
+    "maitrilowrightthai" => "\u{F891}",
+    "maitrithai" => "\u{E4A}",
+    "maitriupperleftthai" => "\u{F890}",
+    "maiyamokthai" => "\u{E46}",
+    "makatakana" => "\u{30DE}",
+    "makatakanahalfwidth" => "\u{FF8F}",
+    "male" => "\u{2642}",
+    "mansyonsquare" => "\u{3347}",
+    "maqafhebrew" => "\u{5BE}",
+    "mars" => "\u{2642}",
+    "masoracirclehebrew" => "\u{5AF}",
+    "masquare" => "\u{3383}",
+    "mbopomofo" => "\u{3107}",
+    "mbsquare" => "\u{33D4}",
+    "mcircle" => "\u{24DC}",
+    "mcubedsquare" => "\u{33A5}",
+    "mdotaccent" => "\u{1E41}",
+    "mdotbelow" => "\u{1E43}",
+    "meemarabic" => "\u{645}",
+    "meemfinalarabic" => "\u{FEE2}",
+    "meeminitialarabic" => "\u{FEE3}",
+    "meemmedialarabic" => "\u{FEE4}",
+    "meemmeeminitialarabic" => "\u{FCD1}",
+    "meemmeemisolatedarabic" => "\u{FC48}",
+    "meetorusquare" => "\u{334D}",
+    "mehiragana" => "\u{3081}",
+    "meizierasquare" => "\u{337E}",
+    "mekatakana" => "\u{30E1}",
+    "mekatakanahalfwidth" => "\u{FF92}",
+    "mem" => "\u{5DE}",
+    "memdagesh" => "\u{FB3E}",
+    "memdageshhebrew" => "\u{FB3E}",
+    "memhebrew" => "\u{5DE}",
+    "menarmenian" => "\u{574}",
+    "merkhahebrew" => "\u{5A5}",
+    "merkhakefulahebrew" => "\u{5A6}",
+    "merkhakefulalefthebrew" => "\u{5A6}",
+    "merkhalefthebrew" => "\u{5A5}",
+    "mhook" => "\u{271}",
+    "mhzsquare" => "\u{3392}",
+    "middledotkatakanahalfwidth" => "\u{FF65}",
+    "middot" => "\u{B7}",
+    "mieumacirclekorean" => "\u{3272}",
+    "mieumaparenkorean" => "\u{3212}",
+    "mieumcirclekorean" => "\u{3264}",
+    "mieumkorean" => "\u{3141}",
+    "mieumpansioskorean" => "\u{3170}",
+    "mieumparenkorean" => "\u{3204}",
+    "mieumpieupkorean" => "\u{316E}",
+    "mieumsioskorean" => "\u{316F}",
+    "mihiragana" => "\u{307F}",
+    "mikatakana" => "\u{30DF}",
+    "mikatakanahalfwidth" => "\u{FF90}",
+    "minus" => "\u{2212}",
+    "minusbelowcmb" => "\u{320}",
+    "minuscircle" => "\u{2296}",
+    "minusmod" => "\u{2D7}",
+    "minusplus" => "\u{2213}",
+    "minute" => "\u{2032}",
+    "miribaarusquare" => "\u{334A}",
+    "mirisquare" => "\u{3349}",
+    "mlonglegturned" => "\u{270}",
+    "mlsquare" => "\u{3396}",
+    "mmcubedsquare" => "\u{33A3}",
+    "mmonospace" => "\u{FF4D}",
+    "mmsquaredsquare" => "\u{339F}",
+    "mohiragana" => "\u{3082}",
+    "mohmsquare" => "\u{33C1}",
+    "mokatakana" => "\u{30E2}",
+    "mokatakanahalfwidth" => "\u{FF93}",
+    "molsquare" => "\u{33D6}",
+    "momathai" => "\u{E21}",
+    "moverssquare" => "\u{33A7}",
+    "moverssquaredsquare" => "\u{33A8}",
+    "mparen" => "\u{24A8}",
+    "mpasquare" => "\u{33AB}",
+    "mssquare" => "\u{33B3}",
+    "msuperior" => "\u{F6EF}",
+    "mturned" => "\u{26F}",
+    "mu" => "\u{B5}",
+    "mu1" => "\u{B5}",
+    "muasquare" => "\u{3382}",
+    "muchgreater" => "\u{226B}",
+    "muchless" => "\u{226A}",
+    "mufsquare" => "\u{338C}",
+    "mugreek" => "\u{3BC}",
+    "mugsquare" => "\u{338D}",
+    "muhiragana" => "\u{3080}",
+    "mukatakana" => "\u{30E0}",
+    "mukatakanahalfwidth" => "\u{FF91}",
+    "mulsquare" => "\u{3395}",
+    "multiply" => "\u{D7}",
+    "mumsquare" => "\u{339B}",
+    "munahhebrew" => "\u{5A3}",
+    "munahlefthebrew" => "\u{5A3}",
+    "musicalnote" => "\u{266A}",
+    "musicalnotedbl" => "\u{266B}",
+    "musicflatsign" => "\u{266D}",
+    "musicsharpsign" => "\u{266F}",
+    "mussquare" => "\u{33B2}",
+    "muvsquare" => "\u{33B6}",
+    "muwsquare" => "\u{33BC}",
+    "mvmegasquare" => "\u{33B9}",
+    "mvsquare" => "\u{33B7}",
+    "mwmegasquare" => "\u{33BF}",
+    "mwsquare" => "\u{33BD}",
+    "n" => "\u{6E}",
+    "nabengali" => "\u{9A8}",
+    "nabla" => "\u{2207}",
+    "nacute" => "\u{144}",
+    "nadeva" => "\u{928}",
+    "nagujarati" => "\u{AA8}",
+    "nagurmukhi" => "\u{A28}",
+    "nahiragana" => "\u{306A}",
+    "nakatakana" => "\u{30CA}",
+    "nakatakanahalfwidth" => "\u{FF85}",
+    "napostrophe" => "\u{149}",
+    "nasquare" => "\u{3381}",
+    "nbopomofo" => "\u{310B}",
+    "nbspace" => "\u{A0}",
+    "ncaron" => "\u{148}",
+    "ncedilla" => "\u{146}",
+    "ncircle" => "\u{24DD}",
+    "ncircumflexbelow" => "\u{1E4B}",
+    "ncommaaccent" => "\u{146}",
+    "ndotaccent" => "\u{1E45}",
+    "ndotbelow" => "\u{1E47}",
+    "nehiragana" => "\u{306D}",
+    "nekatakana" => "\u{30CD}",
+    "nekatakanahalfwidth" => "\u{FF88}",
+    "newsheqelsign" => "\u{20AA}",
+    "nfsquare" => "\u{338B}",
+    "ngabengali" => "\u{999}",
+    "ngadeva" => "\u{919}",
+    "ngagujarati" => "\u{A99}",
+    "ngagurmukhi" => "\u{A19}",
+    "ngonguthai" => "\u{E07}",
+    "nhiragana" => "\u{3093}",
+    "nhookleft" => "\u{272}",
+    "nhookretroflex" => "\u{273}",
+    "nieunacirclekorean" => "\u{326F}",
+    "nieunaparenkorean" => "\u{320F}",
+    "nieuncieuckorean" => "\u{3135}",
+    "nieuncirclekorean" => "\u{3261}",
+    "nieunhieuhkorean" => "\u{3136}",
+    "nieunkorean" => "\u{3134}",
+    "nieunpansioskorean" => "\u{3168}",
+    "nieunparenkorean" => "\u{3201}",
+    "nieunsioskorean" => "\u{3167}",
+    "nieuntikeutkorean" => "\u{3166}",
+    "nihiragana" => "\u{306B}",
+    "nikatakana" => "\u{30CB}",
+    "nikatakanahalfwidth" => "\u{FF86}",
+    "nikhahitleftthai" => "\u{F899}",
+    "nikhahitthai" => "\u{E4D}",
+    "nine" => "\u{39}",
+    "ninearabic" => "\u{669}",
+    "ninebengali" => "\u{9EF}",
+    "ninecircle" => "\u{2468}",
+    "ninecircleinversesansserif" => "\u{2792}",
+    "ninedeva" => "\u{96F}",
+    "ninegujarati" => "\u{AEF}",
+    "ninegurmukhi" => "\u{A6F}",
+    "ninehackarabic" => "\u{669}",
+    "ninehangzhou" => "\u{3029}",
+    "nineideographicparen" => "\u{3228}",
+    "nineinferior" => "\u{2089}",
+    "ninemonospace" => "\u{FF19}",
+    "nineoldstyle" => "\u{F739}",
+    "nineparen" => "\u{247C}",
+    "nineperiod" => "\u{2490}",
+    "ninepersian" => "\u{6F9}",
+    "nineroman" => "\u{2178}",
+    "ninesuperior" => "\u{2079}",
+    "nineteencircle" => "\u{2472}",
+    "nineteenparen" => "\u{2486}",
+    "nineteenperiod" => "\u{249A}",
+    "ninethai" => "\u{E59}",
+    "nj" => "\u{1CC}",
+    "njecyrillic" => "\u{45A}",
+    "nkatakana" => "\u{30F3}",
+    "nkatakanahalfwidth" => "\u{FF9D}",
+    "nlegrightlong" => "\u{19E}",
+    "nlinebelow" => "\u{1E49}",
+    "nmonospace" => "\u{FF4E}",
+    "nmsquare" => "\u{339A}",
+    "nnabengali" => "\u{9A3}",
+    "nnadeva" => "\u{923}",
+    "nnagujarati" => "\u{AA3}",
+    "nnagurmukhi" => "\u{A23}",
+    "nnnadeva" => "\u{929}",
+    "nohiragana" => "\u{306E}",
+    "nokatakana" => "\u{30CE}",
+    "nokatakanahalfwidth" => "\u{FF89}",
+    "nonbreakingspace" => "\u{A0}",
+    "nonenthai" => "\u{E13}",
+    "nonuthai" => "\u{E19}",
+    "noonarabic" => "\u{646}",
+    "noonfinalarabic" => "\u{FEE6}",
+    "noonghunnaarabic" => "\u{6BA}",
+    "noonghunnafinalarabic" => "\u{FB9F}",
+    "noonhehinitialarabic" => "\u{FEE7}\u{FEEC}",
+    "nooninitialarabic" => "\u{FEE7}",
+    "noonjeeminitialarabic" => "\u{FCD2}",
+    "noonjeemisolatedarabic" => "\u{FC4B}",
+    "noonmedialarabic" => "\u{FEE8}",
+    "noonmeeminitialarabic" => "\u{FCD5}",
+    "noonmeemisolatedarabic" => "\u{FC4E}",
+    "noonnoonfinalarabic" => "\u{FC8D}",
+    "notcontains" => "\u{220C}",
+    "notelement" => "\u{2209}",
+    "notelementof" => "\u{2209}",
+    "notequal" => "\u{2260}",
+    "notgreater" => "\u{226F}",
+    "notgreaternorequal" => "\u{2271}",
+    "notgreaternorless" => "\u{2279}",
+    "notidentical" => "\u{2262}",
+    "notless" => "\u{226E}",
+    "notlessnorequal" => "\u{2270}",
+    "notparallel" => "\u{2226}",
+    "notprecedes" => "\u{2280}",
+    "notsubset" => "\u{2284}",
+    "notsucceeds" => "\u{2281}",
+    "notsuperset" => "\u{2285}",
+    "nowarmenian" => "\u{576}",
+    "nparen" => "\u{24A9}",
+    "nssquare" => "\u{33B1}",
+    "nsuperior" => "\u{207F}",
+    "ntilde" => "\u{F1}",
+    "nu" => "\u{3BD}",
+    "nuhiragana" => "\u{306C}",
+    "nukatakana" => "\u{30CC}",
+    "nukatakanahalfwidth" => "\u{FF87}",
+    "nuktabengali" => "\u{9BC}",
+    "nuktadeva" => "\u{93C}",
+    "nuktagujarati" => "\u{ABC}",
+    "nuktagurmukhi" => "\u{A3C}",
+    "numbersign" => "\u{23}",
+    "numbersignmonospace" => "\u{FF03}",
+    "numbersignsmall" => "\u{FE5F}",
+    "numeralsigngreek" => "\u{374}",
+    "numeralsignlowergreek" => "\u{375}",
+    "numero" => "\u{2116}",
+    "nun" => "\u{5E0}",
+    "nundagesh" => "\u{FB40}",
+    "nundageshhebrew" => "\u{FB40}",
+    "nunhebrew" => "\u{5E0}",
+    "nvsquare" => "\u{33B5}",
+    "nwsquare" => "\u{33BB}",
+    "nyabengali" => "\u{99E}",
+    "nyadeva" => "\u{91E}",
+    "nyagujarati" => "\u{A9E}",
+    "nyagurmukhi" => "\u{A1E}",
+    "o" => "\u{6F}",
+    "oacute" => "\u{F3}",
+    "oangthai" => "\u{E2D}",
+    "obarred" => "\u{275}",
+    "obarredcyrillic" => "\u{4E9}",
+    "obarreddieresiscyrillic" => "\u{4EB}",
+    "obengali" => "\u{993}",
+    "obopomofo" => "\u{311B}",
+    "obreve" => "\u{14F}",
+    "ocandradeva" => "\u{911}",
+    "ocandragujarati" => "\u{A91}",
+    "ocandravowelsigndeva" => "\u{949}",
+    "ocandravowelsigngujarati" => "\u{AC9}",
+    "ocaron" => "\u{1D2}",
+    "ocircle" => "\u{24DE}",
+    "ocircumflex" => "\u{F4}",
+    "ocircumflexacute" => "\u{1ED1}",
+    "ocircumflexdotbelow" => "\u{1ED9}",
+    "ocircumflexgrave" => "\u{1ED3}",
+    "ocircumflexhookabove" => "\u{1ED5}",
+    "ocircumflextilde" => "\u{1ED7}",
+    "ocyrillic" => "\u{43E}",
+    "odblacute" => "\u{151}",
+    "odblgrave" => "\u{20D}",
+    "odeva" => "\u{913}",
+    "odieresis" => "\u{F6}",
+    "odieresiscyrillic" => "\u{4E7}",
+    "odotbelow" => "\u{1ECD}",
+    "oe" => "\u{153}",
+    "oekorean" => "\u{315A}",
+    "ogonek" => "\u{2DB}",
+    "ogonekcmb" => "\u{328}",
+    "ograve" => "\u{F2}",
+    "ogujarati" => "\u{A93}",
+    "oharmenian" => "\u{585}",
+    "ohiragana" => "\u{304A}",
+    "ohookabove" => "\u{1ECF}",
+    "ohorn" => "\u{1A1}",
+    "ohornacute" => "\u{1EDB}",
+    "ohorndotbelow" => "\u{1EE3}",
+    "ohorngrave" => "\u{1EDD}",
+    "ohornhookabove" => "\u{1EDF}",
+    "ohorntilde" => "\u{1EE1}",
+    "ohungarumlaut" => "\u{151}",
+    "oi" => "\u{1A3}",
+    "oinvertedbreve" => "\u{20F}",
+    "okatakana" => "\u{30AA}",
+    "okatakanahalfwidth" => "\u{FF75}",
+    "okorean" => "\u{3157}",
+    "olehebrew" => "\u{5AB}",
+    "omacron" => "\u{14D}",
+    "omacronacute" => "\u{1E53}",
+    "omacrongrave" => "\u{1E51}",
+    "omdeva" => "\u{950}",
+    "omega" => "\u{3C9}",
+    "omega1" => "\u{3D6}",
+    "omegacyrillic" => "\u{461}",
+    "omegalatinclosed" => "\u{277}",
+    "omegaroundcyrillic" => "\u{47B}",
+    "omegatitlocyrillic" => "\u{47D}",
+    "omegatonos" => "\u{3CE}",
+    "omgujarati" => "\u{AD0}",
+    "omicron" => "\u{3BF}",
+    "omicrontonos" => "\u{3CC}",
+    "omonospace" => "\u{FF4F}",
+    "one" => "\u{31}",
+    "onearabic" => "\u{661}",
+    "onebengali" => "\u{9E7}",
+    "onecircle" => "\u{2460}",
+    "onecircleinversesansserif" => "\u{278A}",
+    "onedeva" => "\u{967}",
+    "onedotenleader" => "\u{2024}",
+    "oneeighth" => "\u{215B}",
+    "onefitted" => "\u{F6DC}",
+    "onegujarati" => "\u{AE7}",
+    "onegurmukhi" => "\u{A67}",
+    "onehackarabic" => "\u{661}",
+    "onehalf" => "\u{BD}",
+    "onehangzhou" => "\u{3021}",
+    "oneideographicparen" => "\u{3220}",
+    "oneinferior" => "\u{2081}",
+    "onemonospace" => "\u{FF11}",
+    "onenumeratorbengali" => "\u{9F4}",
+    "oneoldstyle" => "\u{F731}",
+    "oneparen" => "\u{2474}",
+    "oneperiod" => "\u{2488}",
+    "onepersian" => "\u{6F1}",
+    "onequarter" => "\u{BC}",
+    "oneroman" => "\u{2170}",
+    "onesuperior" => "\u{B9}",
+    "onethai" => "\u{E51}",
+    "onethird" => "\u{2153}",
+    "oogonek" => "\u{1EB}",
+    "oogonekmacron" => "\u{1ED}",
+    "oogurmukhi" => "\u{A13}",
+    "oomatragurmukhi" => "\u{A4B}",
+    "oopen" => "\u{254}",
+    "oparen" => "\u{24AA}",
+    "openbullet" => "\u{25E6}",
+    "option" => "\u{2325}",
+    "ordfeminine" => "\u{AA}",
+    "ordmasculine" => "\u{BA}",
+    "orthogonal" => "\u{221F}",
+    "oshortdeva" => "\u{912}",
+    "oshortvowelsigndeva" => "\u{94A}",
+    "oslash" => "\u{F8}",
+    "oslashacute" => "\u{1FF}",
+    "osmallhiragana" => "\u{3049}",
+    "osmallkatakana" => "\u{30A9}",
+    "osmallkatakanahalfwidth" => "\u{FF6B}",
+    "ostrokeacute" => "\u{1FF}",
+    "osuperior" => "\u{F6F0}",
+    "otcyrillic" => "\u{47F}",
+    "otilde" => "\u{F5}",
+    "otildeacute" => "\u{1E4D}",
+    "otildedieresis" => "\u{1E4F}",
+    "oubopomofo" => "\u{3121}",
+    "overline" => "\u{203E}",
+    "overlinecenterline" => "\u{FE4A}",
+    "overlinecmb" => "\u{305}",
+    "overlinedashed" => "\u{FE49}",
+    "overlinedblwavy" => "\u{FE4C}",
+    "overlinewavy" => "\u{FE4B}",
+    "overscore" => "\u{AF}",
+    "ovowelsignbengali" => "\u{9CB}",
+    "ovowelsigndeva" => "\u{94B}",
+    "ovowelsigngujarati" => "\u{ACB}",
+    "p" => "\u{70}",
+    "paampssquare" => "\u{3380}",
+    "paasentosquare" => "\u{332B}",
+    "pabengali" => "\u{9AA}",
+    "pacute" => "\u{1E55}",
+    "padeva" => "\u{92A}",
+    "pagedown" => "\u{21DF}",
+    "pageup" => "\u{21DE}",
+    "pagujarati" => "\u{AAA}",
+    "pagurmukhi" => "\u{A2A}",
+    "pahiragana" => "\u{3071}",
+    "paiyannoithai" => "\u{E2F}",
+    "pakatakana" => "\u{30D1}",
+    "palatalizationcyrilliccmb" => "\u{484}",
+    "palochkacyrillic" => "\u{4C0}",
+    "pansioskorean" => "\u{317F}",
+    "paragraph" => "\u{B6}",
+    "parallel" => "\u{2225}",
+    "parenleft" => "\u{28}",
+    "parenleftaltonearabic" => "\u{FD3E}",
+    "parenleftbt" => "\u{F8ED}",
+    "parenleftex" => "\u{F8EC}",
+    "parenleftinferior" => "\u{208D}",
+    "parenleftmonospace" => "\u{FF08}",
+    "parenleftsmall" => "\u{FE59}",
+    "parenleftsuperior" => "\u{207D}",
+    "parenlefttp" => "\u{F8EB}",
+    "parenleftvertical" => "\u{FE35}",
+    "parenright" => "\u{29}",
+    "parenrightaltonearabic" => "\u{FD3F}",
+    "parenrightbt" => "\u{F8F8}",
+    "parenrightex" => "\u{F8F7}",
+    "parenrightinferior" => "\u{208E}",
+    "parenrightmonospace" => "\u{FF09}",
+    "parenrightsmall" => "\u{FE5A}",
+    "parenrightsuperior" => "\u{207E}",
+    "parenrighttp" => "\u{F8F6}",
+    "parenrightvertical" => "\u{FE36}",
+    "partialdiff" => "\u{2202}",
+    "paseqhebrew" => "\u{5C0}",
+    "pashtahebrew" => "\u{599}",
+    "pasquare" => "\u{33A9}",
+    "patah" => "\u{5B7}",
+    "patah11" => "\u{5B7}",
+    "patah1d" => "\u{5B7}",
+    "patah2a" => "\u{5B7}",
+    "patahhebrew" => "\u{5B7}",
+    "patahnarrowhebrew" => "\u{5B7}",
+    "patahquarterhebrew" => "\u{5B7}",
+    "patahwidehebrew" => "\u{5B7}",
+    "pazerhebrew" => "\u{5A1}",
+    "pbopomofo" => "\u{3106}",
+    "pcircle" => "\u{24DF}",
+    "pdotaccent" => "\u{1E57}",
+    "pe" => "\u{5E4}",
+    "pecyrillic" => "\u{43F}",
+    "pedagesh" => "\u{FB44}",
+    "pedageshhebrew" => "\u{FB44}",
+    "peezisquare" => "\u{333B}",
+    "pefinaldageshhebrew" => "\u{FB43}",
+    "peharabic" => "\u{67E}",
+    "peharmenian" => "\u{57A}",
+    "pehebrew" => "\u{5E4}",
+    "pehfinalarabic" => "\u{FB57}",
+    "pehinitialarabic" => "\u{FB58}",
+    "pehiragana" => "\u{307A}",
+    "pehmedialarabic" => "\u{FB59}",
+    "pekatakana" => "\u{30DA}",
+    "pemiddlehookcyrillic" => "\u{4A7}",
+    "perafehebrew" => "\u{FB4E}",
+    "percent" => "\u{25}",
+    "percentarabic" => "\u{66A}",
+    "percentmonospace" => "\u{FF05}",
+    "percentsmall" => "\u{FE6A}",
+    "period" => "\u{2E}",
+    "periodarmenian" => "\u{589}",
+    "periodcentered" => "\u{B7}",
+    "periodhalfwidth" => "\u{FF61}",
+    "periodinferior" => "\u{F6E7}",
+    "periodmonospace" => "\u{FF0E}",
+    "periodsmall" => "\u{FE52}",
+    "periodsuperior" => "\u{F6E8}",
+    "perispomenigreekcmb" => "\u{342}",
+    "perpendicular" => "\u{22A5}",
+    "perthousand" => "\u{2030}",
+    "peseta" => "\u{20A7}",
+    "pfsquare" => "\u{338A}",
+    "phabengali" => "\u{9AB}",
+    "phadeva" => "\u{92B}",
+    "phagujarati" => "\u{AAB}",
+    "phagurmukhi" => "\u{A2B}",
+    "phi" => "\u{3C6}",
+    "phi1" => "\u{3D5}",
+    "phieuphacirclekorean" => "\u{327A}",
+    "phieuphaparenkorean" => "\u{321A}",
+    "phieuphcirclekorean" => "\u{326C}",
+    "phieuphkorean" => "\u{314D}",
+    "phieuphparenkorean" => "\u{320C}",
+    "philatin" => "\u{278}",
+    "phinthuthai" => "\u{E3A}",
+    "phisymbolgreek" => "\u{3D5}",
+    "phook" => "\u{1A5}",
+    "phophanthai" => "\u{E1E}",
+    "phophungthai" => "\u{E1C}",
+    "phosamphaothai" => "\u{E20}",
+    "pi" => "\u{3C0}",
+    "pieupacirclekorean" => "\u{3273}",
+    "pieupaparenkorean" => "\u{3213}",
+    "pieupcieuckorean" => "\u{3176}",
+    "pieupcirclekorean" => "\u{3265}",
+    "pieupkiyeokkorean" => "\u{3172}",
+    "pieupkorean" => "\u{3142}",
+    "pieupparenkorean" => "\u{3205}",
+    "pieupsioskiyeokkorean" => "\u{3174}",
+    "pieupsioskorean" => "\u{3144}",
+    "pieupsiostikeutkorean" => "\u{3175}",
+    "pieupthieuthkorean" => "\u{3177}",
+    "pieuptikeutkorean" => "\u{3173}",
+    "pihiragana" => "\u{3074}",
+    "pikatakana" => "\u{30D4}",
+    "pisymbolgreek" => "\u{3D6}",
+    "piwrarmenian" => "\u{583}",
+    "plus" => "\u{2B}",
+    "plusbelowcmb" => "\u{31F}",
+    "pluscircle" => "\u{2295}",
+    "plusminus" => "\u{B1}",
+    "plusmod" => "\u{2D6}",
+    "plusmonospace" => "\u{FF0B}",
+    "plussmall" => "\u{FE62}",
+    "plussuperior" => "\u{207A}",
+    "pmonospace" => "\u{FF50}",
+    "pmsquare" => "\u{33D8}",
+    "pohiragana" => "\u{307D}",
+    "pointingindexdownwhite" => "\u{261F}",
+    "pointingindexleftwhite" => "\u{261C}",
+    "pointingindexrightwhite" => "\u{261E}",
+    "pointingindexupwhite" => "\u{261D}",
+    "pokatakana" => "\u{30DD}",
+    "poplathai" => "\u{E1B}",
+    "postalmark" => "\u{3012}",
+    "postalmarkface" => "\u{3020}",
+    "pparen" => "\u{24AB}",
+    "precedes" => "\u{227A}",
+    "prescription" => "\u{211E}",
+    "primemod" => "\u{2B9}",
+    "primereversed" => "\u{2035}",
+    "product" => "\u{220F}",
+    "projective" => "\u{2305}",
+    "prolongedkana" => "\u{30FC}",
+    "propellor" => "\u{2318}",
+    "propersubset" => "\u{2282}",
+    "propersuperset" => "\u{2283}",
+    "proportion" => "\u{2237}",
+    "proportional" => "\u{221D}",
+    "psi" => "\u{3C8}",
+    "psicyrillic" => "\u{471}",
+    "psilipneumatacyrilliccmb" => "\u{486}",
+    "pssquare" => "\u{33B0}",
+    "puhiragana" => "\u{3077}",
+    "pukatakana" => "\u{30D7}",
+    "pvsquare" => "\u{33B4}",
+    "pwsquare" => "\u{33BA}",
+    "q" => "\u{71}",
+    "qadeva" => "\u{958}",
+    "qadmahebrew" => "\u{5A8}",
+    "qafarabic" => "\u{642}",
+    "qaffinalarabic" => "\u{FED6}",
+    "qafinitialarabic" => "\u{FED7}",
+    "qafmedialarabic" => "\u{FED8}",
+    "qamats" => "\u{5B8}",
+    "qamats10" => "\u{5B8}",
+    "qamats1a" => "\u{5B8}",
+    "qamats1c" => "\u{5B8}",
+    "qamats27" => "\u{5B8}",
+    "qamats29" => "\u{5B8}",
+    "qamats33" => "\u{5B8}",
+    "qamatsde" => "\u{5B8}",
+    "qamatshebrew" => "\u{5B8}",
+    "qamatsnarrowhebrew" => "\u{5B8}",
+    "qamatsqatanhebrew" => "\u{5B8}",
+    "qamatsqatannarrowhebrew" => "\u{5B8}",
+    "qamatsqatanquarterhebrew" => "\u{5B8}",
+    "qamatsqatanwidehebrew" => "\u{5B8}",
+    "qamatsquarterhebrew" => "\u{5B8}",
+    "qamatswidehebrew" => "\u{5B8}",
+    "qarneyparahebrew" => "\u{59F}",
+    "qbopomofo" => "\u{3111}",
+    "qcircle" => "\u{24E0}",
+    "qhook" => "\u{2A0}",
+    "qmonospace" => "\u{FF51}",
+    "qof" => "\u{5E7}",
+    "qofdagesh" => "\u{FB47}",
+    "qofdageshhebrew" => "\u{FB47}",
+    "qofhatafpatah" => "\u{5E7}\u{5B2}",
+    "qofhatafpatahhebrew" => "\u{5E7}\u{5B2}",
+    "qofhatafsegol" => "\u{5E7}\u{5B1}",
+    "qofhatafsegolhebrew" => "\u{5E7}\u{5B1}",
+    "qofhebrew" => "\u{5E7}",
+    "qofhiriq" => "\u{5E7}\u{5B4}",
+    "qofhiriqhebrew" => "\u{5E7}\u{5B4}",
+    "qofholam" => "\u{5E7}\u{5B9}",
+    "qofholamhebrew" => "\u{5E7}\u{5B9}",
+    "qofpatah" => "\u{5E7}\u{5B7}",
+    "qofpatahhebrew" => "\u{5E7}\u{5B7}",
+    "qofqamats" => "\u{5E7}\u{5B8}",
+    "qofqamatshebrew" => "\u{5E7}\u{5B8}",
+    "qofqubuts" => "\u{5E7}\u{5BB}",
+    "qofqubutshebrew" => "\u{5E7}\u{5BB}",
+    "qofsegol" => "\u{5E7}\u{5B6}",
+    "qofsegolhebrew" => "\u{5E7}\u{5B6}",
+    "qofsheva" => "\u{5E7}\u{5B0}",
+    "qofshevahebrew" => "\u{5E7}\u{5B0}",
+    "qoftsere" => "\u{5E7}\u{5B5}",
+    "qoftserehebrew" => "\u{5E7}\u{5B5}",
+    "qparen" => "\u{24AC}",
+    "quarternote" => "\u{2669}",
+    "qubuts" => "\u{5BB}",
+    "qubuts18" => "\u{5BB}",
+    "qubuts25" => "\u{5BB}",
+    "qubuts31" => "\u{5BB}",
+    "qubutshebrew" => "\u{5BB}",
+    "qubutsnarrowhebrew" => "\u{5BB}",
+    "qubutsquarterhebrew" => "\u{5BB}",
+    "qubutswidehebrew" => "\u{5BB}",
+    "question" => "\u{3F}",
+    "questionarabic" => "\u{61F}",
+    "questionarmenian" => "\u{55E}",
+    "questiondown" => "\u{BF}",
+    "questiondownsmall" => "\u{F7BF}",
+    "questiongreek" => "\u{37E}",
+    "questionmonospace" => "\u{FF1F}",
+    "questionsmall" => "\u{F73F}",
+    "quotedbl" => "\u{22}",
+    "quotedblbase" => "\u{201E}",
+    "quotedblleft" => "\u{201C}",
+    "quotedblmonospace" => "\u{FF02}",
+    "quotedblprime" => "\u{301E}",
+    "quotedblprimereversed" => "\u{301D}",
+    "quotedblright" => "\u{201D}",
+    "quoteleft" => "\u{2018}",
+    "quoteleftreversed" => "\u{201B}",
+    "quotereversed" => "\u{201B}",
+    "quoteright" => "\u{2019}",
+    "quoterightn" => "\u{149}",
+    "quotesinglbase" => "\u{201A}",
+    "quotesingle" => "\u{27}",
+    "quotesinglemonospace" => "\u{FF07}",
+    "r" => "\u{72}",
+    "raarmenian" => "\u{57C}",
+    "rabengali" => "\u{9B0}",
+    "racute" => "\u{155}",
+    "radeva" => "\u{930}",
+    "radical" => "\u{221A}",
+    "radicalex" => "\u{F8E5}",
+    "radoverssquare" => "\u{33AE}",
+    "radoverssquaredsquare" => "\u{33AF}",
+    "radsquare" => "\u{33AD}",
+    "rafe" => "\u{5BF}",
+    "rafehebrew" => "\u{5BF}",
+    "ragujarati" => "\u{AB0}",
+    "ragurmukhi" => "\u{A30}",
+    "rahiragana" => "\u{3089}",
+    "rakatakana" => "\u{30E9}",
+    "rakatakanahalfwidth" => "\u{FF97}",
+    "ralowerdiagonalbengali" => "\u{9F1}",
+    "ramiddlediagonalbengali" => "\u{9F0}",
+    "ramshorn" => "\u{264}",
+    "ratio" => "\u{2236}",
+    "rbopomofo" => "\u{3116}",
+    "rcaron" => "\u{159}",
+    "rcedilla" => "\u{157}",
+    "rcircle" => "\u{24E1}",
+    "rcommaaccent" => "\u{157}",
+    "rdblgrave" => "\u{211}",
+    "rdotaccent" => "\u{1E59}",
+    "rdotbelow" => "\u{1E5B}",
+    "rdotbelowmacron" => "\u{1E5D}",
+    "referencemark" => "\u{203B}",
+    "reflexsubset" => "\u{2286}",
+    "reflexsuperset" => "\u{2287}",
+    "registered" => "\u{AE}",
+    "registersans" => "\u{F8E8}",
+    "registerserif" => "\u{F6DA}",
+    "reharabic" => "\u{631}",
+    "reharmenian" => "\u{580}",
+    "rehfinalarabic" => "\u{FEAE}",
+    "rehiragana" => "\u{308C}",
+    "rehyehaleflamarabic" => "\u{631}\u{FEF3}\u{FE8E}\u{644}",
+    "rekatakana" => "\u{30EC}",
+    "rekatakanahalfwidth" => "\u{FF9A}",
+    "resh" => "\u{5E8}",
+    "reshdageshhebrew" => "\u{FB48}",
+    "reshhatafpatah" => "\u{5E8}\u{5B2}",
+    "reshhatafpatahhebrew" => "\u{5E8}\u{5B2}",
+    "reshhatafsegol" => "\u{5E8}\u{5B1}",
+    "reshhatafsegolhebrew" => "\u{5E8}\u{5B1}",
+    "reshhebrew" => "\u{5E8}",
+    "reshhiriq" => "\u{5E8}\u{5B4}",
+    "reshhiriqhebrew" => "\u{5E8}\u{5B4}",
+    "reshholam" => "\u{5E8}\u{5B9}",
+    "reshholamhebrew" => "\u{5E8}\u{5B9}",
+    "reshpatah" => "\u{5E8}\u{5B7}",
+    "reshpatahhebrew" => "\u{5E8}\u{5B7}",
+    "reshqamats" => "\u{5E8}\u{5B8}",
+    "reshqamatshebrew" => "\u{5E8}\u{5B8}",
+    "reshqubuts" => "\u{5E8}\u{5BB}",
+    "reshqubutshebrew" => "\u{5E8}\u{5BB}",
+    "reshsegol" => "\u{5E8}\u{5B6}",
+    "reshsegolhebrew" => "\u{5E8}\u{5B6}",
+    "reshsheva" => "\u{5E8}\u{5B0}",
+    "reshshevahebrew" => "\u{5E8}\u{5B0}",
+    "reshtsere" => "\u{5E8}\u{5B5}",
+    "reshtserehebrew" => "\u{5E8}\u{5B5}",
+    "reversedtilde" => "\u{223D}",
+    "reviahebrew" => "\u{597}",
+    "reviamugrashhebrew" => "\u{597}",
+    "revlogicalnot" => "\u{2310}",
+    "rfishhook" => "\u{27E}",
+    "rfishhookreversed" => "\u{27F}",
+    "rhabengali" => "\u{9DD}",
+    "rhadeva" => "\u{95D}",
+    "rho" => "\u{3C1}",
+    "rhook" => "\u{27D}",
+    "rhookturned" => "\u{27B}",
+    "rhookturnedsuperior" => "\u{2B5}",
+    "rhosymbolgreek" => "\u{3F1}",
+    "rhotichookmod" => "\u{2DE}",
+    "rieulacirclekorean" => "\u{3271}",
+    "rieulaparenkorean" => "\u{3211}",
+    "rieulcirclekorean" => "\u{3263}",
+    "rieulhieuhkorean" => "\u{3140}",
+    "rieulkiyeokkorean" => "\u{313A}",
+    "rieulkiyeoksioskorean" => "\u{3169}",
+    "rieulkorean" => "\u{3139}",
+    "rieulmieumkorean" => "\u{313B}",
+    "rieulpansioskorean" => "\u{316C}",
+    "rieulparenkorean" => "\u{3203}",
+    "rieulphieuphkorean" => "\u{313F}",
+    "rieulpieupkorean" => "\u{313C}",
+    "rieulpieupsioskorean" => "\u{316B}",
+    "rieulsioskorean" => "\u{313D}",
+    "rieulthieuthkorean" => "\u{313E}",
+    "rieultikeutkorean" => "\u{316A}",
+    "rieulyeorinhieuhkorean" => "\u{316D}",
+    "rightangle" => "\u{221F}",
+    "righttackbelowcmb" => "\u{319}",
+    "righttriangle" => "\u{22BF}",
+    "rihiragana" => "\u{308A}",
+    "rikatakana" => "\u{30EA}",
+    "rikatakanahalfwidth" => "\u{FF98}",
+    "ring" => "\u{2DA}",
+    "ringbelowcmb" => "\u{325}",
+    "ringcmb" => "\u{30A}",
+    "ringhalfleft" => "\u{2BF}",
+    "ringhalfleftarmenian" => "\u{559}",
+    "ringhalfleftbelowcmb" => "\u{31C}",
+    "ringhalfleftcentered" => "\u{2D3}",
+    "ringhalfright" => "\u{2BE}",
+    "ringhalfrightbelowcmb" => "\u{339}",
+    "ringhalfrightcentered" => "\u{2D2}",
+    "rinvertedbreve" => "\u{213}",
+    "rittorusquare" => "\u{3351}",
+    "rlinebelow" => "\u{1E5F}",
+    "rlongleg" => "\u{27C}",
+    "rlonglegturned" => "\u{27A}",
+    "rmonospace" => "\u{FF52}",
+    "rohiragana" => "\u{308D}",
+    "rokatakana" => "\u{30ED}",
+    "rokatakanahalfwidth" => "\u{FF9B}",
+    "roruathai" => "\u{E23}",
+    "rparen" => "\u{24AD}",
+    "rrabengali" => "\u{9DC}",
+    "rradeva" => "\u{931}",
+    "rragurmukhi" => "\u{A5C}",
+    "rreharabic" => "\u{691}",
+    "rrehfinalarabic" => "\u{FB8D}",
+    "rrvocalicbengali" => "\u{9E0}",
+    "rrvocalicdeva" => "\u{960}",
+    "rrvocalicgujarati" => "\u{AE0}",
+    "rrvocalicvowelsignbengali" => "\u{9C4}",
+    "rrvocalicvowelsigndeva" => "\u{944}",
+    "rrvocalicvowelsigngujarati" => "\u{AC4}",
+    "rsuperior" => "\u{F6F1}",
+    "rtblock" => "\u{2590}",
+    "rturned" => "\u{279}",
+    "rturnedsuperior" => "\u{2B4}",
+    "ruhiragana" => "\u{308B}",
+    "rukatakana" => "\u{30EB}",
+    "rukatakanahalfwidth" => "\u{FF99}",
+    "rupeemarkbengali" => "\u{9F2}",
+    "rupeesignbengali" => "\u{9F3}",
+    "rupiah" => "\u{F6DD}",
+    "ruthai" => "\u{E24}",
+    "rvocalicbengali" => "\u{98B}",
+    "rvocalicdeva" => "\u{90B}",
+    "rvocalicgujarati" => "\u{A8B}",
+    "rvocalicvowelsignbengali" => "\u{9C3}",
+    "rvocalicvowelsigndeva" => "\u{943}",
+    "rvocalicvowelsigngujarati" => "\u{AC3}",
+    "s" => "\u{73}",
+    "sabengali" => "\u{9B8}",
+    "sacute" => "\u{15B}",
+    "sacutedotaccent" => "\u{1E65}",
+    "sadarabic" => "\u{635}",
+    "sadeva" => "\u{938}",
+    "sadfinalarabic" => "\u{FEBA}",
+    "sadinitialarabic" => "\u{FEBB}",
+    "sadmedialarabic" => "\u{FEBC}",
+    "sagujarati" => "\u{AB8}",
+    "sagurmukhi" => "\u{A38}",
+    "sahiragana" => "\u{3055}",
+    "sakatakana" => "\u{30B5}",
+    "sakatakanahalfwidth" => "\u{FF7B}",
+    "sallallahoualayhewasallamarabic" => "\u{FDFA}",
+    "samekh" => "\u{5E1}",
+    "samekhdagesh" => "\u{FB41}",
+    "samekhdageshhebrew" => "\u{FB41}",
+    "samekhhebrew" => "\u{5E1}",
+    "saraaathai" => "\u{E32}",
+    "saraaethai" => "\u{E41}",
+    "saraaimaimalaithai" => "\u{E44}",
+    "saraaimaimuanthai" => "\u{E43}",
+    "saraamthai" => "\u{E33}",
+    "saraathai" => "\u{E30}",
+    "saraethai" => "\u{E40}",
+    "saraiileftthai" => "\u{F886}",
+    "saraiithai" => "\u{E35}",
+    "saraileftthai" => "\u{F885}",
+    "saraithai" => "\u{E34}",
+    "saraothai" => "\u{E42}",
+    "saraueeleftthai" => "\u{F888}",
+    "saraueethai" => "\u{E37}",
+    "saraueleftthai" => "\u{F887}",
+    "sarauethai" => "\u{E36}",
+    "sarauthai" => "\u{E38}",
+    "sarauuthai" => "\u{E39}",
+    "sbopomofo" => "\u{3119}",
+    "scaron" => "\u{161}",
+    "scarondotaccent" => "\u{1E67}",
+    "scedilla" => "\u{15F}",
+    "schwa" => "\u{259}",
+    "schwacyrillic" => "\u{4D9}",
+    "schwadieresiscyrillic" => "\u{4DB}",
+    "schwahook" => "\u{25A}",
+    "scircle" => "\u{24E2}",
+    "scircumflex" => "\u{15D}",
+    "scommaaccent" => "\u{219}",
+    "sdotaccent" => "\u{1E61}",
+    "sdotbelow" => "\u{1E63}",
+    "sdotbelowdotaccent" => "\u{1E69}",
+    "seagullbelowcmb" => "\u{33C}",
+    "second" => "\u{2033}",
+    "secondtonechinese" => "\u{2CA}",
+    "section" => "\u{A7}",
+    "seenarabic" => "\u{633}",
+    "seenfinalarabic" => "\u{FEB2}",
+    "seeninitialarabic" => "\u{FEB3}",
+    "seenmedialarabic" => "\u{FEB4}",
+    "segol" => "\u{5B6}",
+    "segol13" => "\u{5B6}",
+    "segol1f" => "\u{5B6}",
+    "segol2c" => "\u{5B6}",
+    "segolhebrew" => "\u{5B6}",
+    "segolnarrowhebrew" => "\u{5B6}",
+    "segolquarterhebrew" => "\u{5B6}",
+    "segoltahebrew" => "\u{592}",
+    "segolwidehebrew" => "\u{5B6}",
+    "seharmenian" => "\u{57D}",
+    "sehiragana" => "\u{305B}",
+    "sekatakana" => "\u{30BB}",
+    "sekatakanahalfwidth" => "\u{FF7E}",
+    "semicolon" => "\u{3B}",
+    "semicolonarabic" => "\u{61B}",
+    "semicolonmonospace" => "\u{FF1B}",
+    "semicolonsmall" => "\u{FE54}",
+    "semivoicedmarkkana" => "\u{309C}",
+    "semivoicedmarkkanahalfwidth" => "\u{FF9F}",
+    "sentisquare" => "\u{3322}",
+    "sentosquare" => "\u{3323}",
+    "seven" => "\u{37}",
+    "sevenarabic" => "\u{667}",
+    "sevenbengali" => "\u{9ED}",
+    "sevencircle" => "\u{2466}",
+    "sevencircleinversesansserif" => "\u{2790}",
+    "sevendeva" => "\u{96D}",
+    "seveneighths" => "\u{215E}",
+    "sevengujarati" => "\u{AED}",
+    "sevengurmukhi" => "\u{A6D}",
+    "sevenhackarabic" => "\u{667}",
+    "sevenhangzhou" => "\u{3027}",
+    "sevenideographicparen" => "\u{3226}",
+    "seveninferior" => "\u{2087}",
+    "sevenmonospace" => "\u{FF17}",
+    "sevenoldstyle" => "\u{F737}",
+    "sevenparen" => "\u{247A}",
+    "sevenperiod" => "\u{248E}",
+    "sevenpersian" => "\u{6F7}",
+    "sevenroman" => "\u{2176}",
+    "sevensuperior" => "\u{2077}",
+    "seventeencircle" => "\u{2470}",
+    "seventeenparen" => "\u{2484}",
+    "seventeenperiod" => "\u{2498}",
+    "seventhai" => "\u{E57}",
+    "sfthyphen" => "\u{AD}",
+    "shaarmenian" => "\u{577}",
+    "shabengali" => "\u{9B6}",
+    "shacyrillic" => "\u{448}",
+    "shaddaarabic" => "\u{651}",
+    "shaddadammaarabic" => "\u{FC61}",
+    "shaddadammatanarabic" => "\u{FC5E}",
+    "shaddafathaarabic" => "\u{FC60}",
+    "shaddafathatanarabic" => "\u{651}\u{64B}",
+    "shaddakasraarabic" => "\u{FC62}",
+    "shaddakasratanarabic" => "\u{FC5F}",
+    "shade" => "\u{2592}",
+    "shadedark" => "\u{2593}",
+    "shadelight" => "\u{2591}",
+    "shademedium" => "\u{2592}",
+    "shadeva" => "\u{936}",
+    "shagujarati" => "\u{AB6}",
+    "shagurmukhi" => "\u{A36}",
+    "shalshelethebrew" => "\u{593}",
+    "shbopomofo" => "\u{3115}",
+    "shchacyrillic" => "\u{449}",
+    "sheenarabic" => "\u{634}",
+    "sheenfinalarabic" => "\u{FEB6}",
+    "sheeninitialarabic" => "\u{FEB7}",
+    "sheenmedialarabic" => "\u{FEB8}",
+    "sheicoptic" => "\u{3E3}",
+    "sheqel" => "\u{20AA}",
+    "sheqelhebrew" => "\u{20AA}",
+    "sheva" => "\u{5B0}",
+    "sheva115" => "\u{5B0}",
+    "sheva15" => "\u{5B0}",
+    "sheva22" => "\u{5B0}",
+    "sheva2e" => "\u{5B0}",
+    "shevahebrew" => "\u{5B0}",
+    "shevanarrowhebrew" => "\u{5B0}",
+    "shevaquarterhebrew" => "\u{5B0}",
+    "shevawidehebrew" => "\u{5B0}",
+    "shhacyrillic" => "\u{4BB}",
+    "shimacoptic" => "\u{3ED}",
+    "shin" => "\u{5E9}",
+    "shindagesh" => "\u{FB49}",
+    "shindageshhebrew" => "\u{FB49}",
+    "shindageshshindot" => "\u{FB2C}",
+    "shindageshshindothebrew" => "\u{FB2C}",
+    "shindageshsindot" => "\u{FB2D}",
+    "shindageshsindothebrew" => "\u{FB2D}",
+    "shindothebrew" => "\u{5C1}",
+    "shinhebrew" => "\u{5E9}",
+    "shinshindot" => "\u{FB2A}",
+    "shinshindothebrew" => "\u{FB2A}",
+    "shinsindot" => "\u{FB2B}",
+    "shinsindothebrew" => "\u{FB2B}",
+    "shook" => "\u{282}",
+    "sigma" => "\u{3C3}",
+    "sigma1" => "\u{3C2}",
+    "sigmafinal" => "\u{3C2}",
+    "sigmalunatesymbolgreek" => "\u{3F2}",
+    "sihiragana" => "\u{3057}",
+    "sikatakana" => "\u{30B7}",
+    "sikatakanahalfwidth" => "\u{FF7C}",
+    "siluqhebrew" => "\u{5BD}",
+    "siluqlefthebrew" => "\u{5BD}",
+    "similar" => "\u{223C}",
+    "sindothebrew" => "\u{5C2}",
+    "siosacirclekorean" => "\u{3274}",
+    "siosaparenkorean" => "\u{3214}",
+    "sioscieuckorean" => "\u{317E}",
+    "sioscirclekorean" => "\u{3266}",
+    "sioskiyeokkorean" => "\u{317A}",
+    "sioskorean" => "\u{3145}",
+    "siosnieunkorean" => "\u{317B}",
+    "siosparenkorean" => "\u{3206}",
+    "siospieupkorean" => "\u{317D}",
+    "siostikeutkorean" => "\u{317C}",
+    "six" => "\u{36}",
+    "sixarabic" => "\u{666}",
+    "sixbengali" => "\u{9EC}",
+    "sixcircle" => "\u{2465}",
+    "sixcircleinversesansserif" => "\u{278F}",
+    "sixdeva" => "\u{96C}",
+    "sixgujarati" => "\u{AEC}",
+    "sixgurmukhi" => "\u{A6C}",
+    "sixhackarabic" => "\u{666}",
+    "sixhangzhou" => "\u{3026}",
+    "sixideographicparen" => "\u{3225}",
+    "sixinferior" => "\u{2086}",
+    "sixmonospace" => "\u{FF16}",
+    "sixoldstyle" => "\u{F736}",
+    "sixparen" => "\u{2479}",
+    "sixperiod" => "\u{248D}",
+    "sixpersian" => "\u{6F6}",
+    "sixroman" => "\u{2175}",
+    "sixsuperior" => "\u{2076}",
+    "sixteencircle" => "\u{246F}",
+    "sixteencurrencydenominatorbengali" => "\u{9F9}",
+    "sixteenparen" => "\u{2483}",
+    "sixteenperiod" => "\u{2497}",
+    "sixthai" => "\u{E56}",
+    "slash" => "\u{2F}",
+    "slashmonospace" => "\u{FF0F}",
+    "slong" => "\u{17F}",
+    "slongdotaccent" => "\u{1E9B}",
+    "smileface" => "\u{263A}",
+    "smonospace" => "\u{FF53}",
+    "sofpasuqhebrew" => "\u{5C3}",
+    "softhyphen" => "\u{AD}",
+    "softsigncyrillic" => "\u{44C}",
+    "sohiragana" => "\u{305D}",
+    "sokatakana" => "\u{30BD}",
+    "sokatakanahalfwidth" => "\u{FF7F}",
+    "soliduslongoverlaycmb" => "\u{338}",
+    "solidusshortoverlaycmb" => "\u{337}",
+    "sorusithai" => "\u{E29}",
+    "sosalathai" => "\u{E28}",
+    "sosothai" => "\u{E0B}",
+    "sosuathai" => "\u{E2A}",
+    "space" => "\u{20}",
+    "spacehackarabic" => "\u{20}",
+    "spade" => "\u{2660}",
+    "spadesuitblack" => "\u{2660}",
+    "spadesuitwhite" => "\u{2664}",
+    "sparen" => "\u{24AE}",
+    "squarebelowcmb" => "\u{33B}",
+    "squarecc" => "\u{33C4}",
+    "squarecm" => "\u{339D}",
+    "squarediagonalcrosshatchfill" => "\u{25A9}",
+    "squarehorizontalfill" => "\u{25A4}",
+    "squarekg" => "\u{338F}",
+    "squarekm" => "\u{339E}",
+    "squarekmcapital" => "\u{33CE}",
+    "squareln" => "\u{33D1}",
+    "squarelog" => "\u{33D2}",
+    "squaremg" => "\u{338E}",
+    "squaremil" => "\u{33D5}",
+    "squaremm" => "\u{339C}",
+    "squaremsquared" => "\u{33A1}",
+    "squareorthogonalcrosshatchfill" => "\u{25A6}",
+    "squareupperlefttolowerrightfill" => "\u{25A7}",
+    "squareupperrighttolowerleftfill" => "\u{25A8}",
+    "squareverticalfill" => "\u{25A5}",
+    "squarewhitewithsmallblack" => "\u{25A3}",
+    "srsquare" => "\u{33DB}",
+    "ssabengali" => "\u{9B7}",
+    "ssadeva" => "\u{937}",
+    "ssagujarati" => "\u{AB7}",
+    "ssangcieuckorean" => "\u{3149}",
+    "ssanghieuhkorean" => "\u{3185}",
+    "ssangieungkorean" => "\u{3180}",
+    "ssangkiyeokkorean" => "\u{3132}",
+    "ssangnieunkorean" => "\u{3165}",
+    "ssangpieupkorean" => "\u{3143}",
+    "ssangsioskorean" => "\u{3146}",
+    "ssangtikeutkorean" => "\u{3138}",
+    "ssuperior" => "\u{F6F2}",
+    "sterling" => "\u{A3}",
+    "sterlingmonospace" => "\u{FFE1}",
+    "strokelongoverlaycmb" => "\u{336}",
+    "strokeshortoverlaycmb" => "\u{335}",
+    "subset" => "\u{2282}",
+    "subsetnotequal" => "\u{228A}",
+    "subsetorequal" => "\u{2286}",
+    "succeeds" => "\u{227B}",
+    "suchthat" => "\u{220B}",
+    "suhiragana" => "\u{3059}",
+    "sukatakana" => "\u{30B9}",
+    "sukatakanahalfwidth" => "\u{FF7D}",
+    "sukunarabic" => "\u{652}",
+    "summation" => "\u{2211}",
+    "sun" => "\u{263C}",
+    "superset" => "\u{2283}",
+    "supersetnotequal" => "\u{228B}",
+    "supersetorequal" => "\u{2287}",
+    "svsquare" => "\u{33DC}",
+    "syouwaerasquare" => "\u{337C}",
+    "t" => "\u{74}",
+    "tabengali" => "\u{9A4}",
+    "tackdown" => "\u{22A4}",
+    "tackleft" => "\u{22A3}",
+    "tadeva" => "\u{924}",
+    "tagujarati" => "\u{AA4}",
+    "tagurmukhi" => "\u{A24}",
+    "taharabic" => "\u{637}",
+    "tahfinalarabic" => "\u{FEC2}",
+    "tahinitialarabic" => "\u{FEC3}",
+    "tahiragana" => "\u{305F}",
+    "tahmedialarabic" => "\u{FEC4}",
+    "taisyouerasquare" => "\u{337D}",
+    "takatakana" => "\u{30BF}",
+    "takatakanahalfwidth" => "\u{FF80}",
+    "tatweelarabic" => "\u{640}",
+    "tau" => "\u{3C4}",
+    "tav" => "\u{5EA}",
+    "tavdages" => "\u{FB4A}",
+    "tavdagesh" => "\u{FB4A}",
+    "tavdageshhebrew" => "\u{FB4A}",
+    "tavhebrew" => "\u{5EA}",
+    "tbar" => "\u{167}",
+    "tbopomofo" => "\u{310A}",
+    "tcaron" => "\u{165}",
+    "tccurl" => "\u{2A8}",
+    "tcedilla" => "\u{163}",
+    "tcheharabic" => "\u{686}",
+    "tchehfinalarabic" => "\u{FB7B}",
+    "tchehinitialarabic" => "\u{FB7C}",
+    "tchehmedialarabic" => "\u{FB7D}",
+    "tchehmeeminitialarabic" => "\u{FB7C}\u{FEE4}",
+    "tcircle" => "\u{24E3}",
+    "tcircumflexbelow" => "\u{1E71}",
+    "tcommaaccent" => "\u{163}",
+    "tdieresis" => "\u{1E97}",
+    "tdotaccent" => "\u{1E6B}",
+    "tdotbelow" => "\u{1E6D}",
+    "tecyrillic" => "\u{442}",
+    "tedescendercyrillic" => "\u{4AD}",
+    "teharabic" => "\u{62A}",
+    "tehfinalarabic" => "\u{FE96}",
+    "tehhahinitialarabic" => "\u{FCA2}",
+    "tehhahisolatedarabic" => "\u{FC0C}",
+    "tehinitialarabic" => "\u{FE97}",
+    "tehiragana" => "\u{3066}",
+    "tehjeeminitialarabic" => "\u{FCA1}",
+    "tehjeemisolatedarabic" => "\u{FC0B}",
+    "tehmarbutaarabic" => "\u{629}",
+    "tehmarbutafinalarabic" => "\u{FE94}",
+    "tehmedialarabic" => "\u{FE98}",
+    "tehmeeminitialarabic" => "\u{FCA4}",
+    "tehmeemisolatedarabic" => "\u{FC0E}",
+    "tehnoonfinalarabic" => "\u{FC73}",
+    "tekatakana" => "\u{30C6}",
+    "tekatakanahalfwidth" => "\u{FF83}",
+    "telephone" => "\u{2121}",
+    "telephoneblack" => "\u{260E}",
+    "telishagedolahebrew" => "\u{5A0}",
+    "telishaqetanahebrew" => "\u{5A9}",
+    "tencircle" => "\u{2469}",
+    "tenideographicparen" => "\u{3229}",
+    "tenparen" => "\u{247D}",
+    "tenperiod" => "\u{2491}",
+    "tenroman" => "\u{2179}",
+    "tesh" => "\u{2A7}",
+    "tet" => "\u{5D8}",
+    "tetdagesh" => "\u{FB38}",
+    "tetdageshhebrew" => "\u{FB38}",
+    "tethebrew" => "\u{5D8}",
+    "tetsecyrillic" => "\u{4B5}",
+    "tevirhebrew" => "\u{59B}",
+    "tevirlefthebrew" => "\u{59B}",
+    "thabengali" => "\u{9A5}",
+    "thadeva" => "\u{925}",
+    "thagujarati" => "\u{AA5}",
+    "thagurmukhi" => "\u{A25}",
+    "thalarabic" => "\u{630}",
+    "thalfinalarabic" => "\u{FEAC}",
+    "thanthakhatlowleftthai" => "\u{F898}",
+    "thanthakhatlowrightthai" => "\u{F897}",
+    "thanthakhatthai" => "\u{E4C}",
+    "thanthakhatupperleftthai" => "\u{F896}",
+    "theharabic" => "\u{62B}",
+    "thehfinalarabic" => "\u{FE9A}",
+    "thehinitialarabic" => "\u{FE9B}",
+    "thehmedialarabic" => "\u{FE9C}",
+    "thereexists" => "\u{2203}",
+    "therefore" => "\u{2234}",
+    "theta" => "\u{3B8}",
+    "theta1" => "\u{3D1}",
+    "thetasymbolgreek" => "\u{3D1}",
+    "thieuthacirclekorean" => "\u{3279}",
+    "thieuthaparenkorean" => "\u{3219}",
+    "thieuthcirclekorean" => "\u{326B}",
+    "thieuthkorean" => "\u{314C}",
+    "thieuthparenkorean" => "\u{320B}",
+    "thirteencircle" => "\u{246C}",
+    "thirteenparen" => "\u{2480}",
+    "thirteenperiod" => "\u{2494}",
+    "thonangmonthothai" => "\u{E11}",
+    "thook" => "\u{1AD}",
+    "thophuthaothai" => "\u{E12}",
+    "thorn" => "\u{FE}",
+    "thothahanthai" => "\u{E17}",
+    "thothanthai" => "\u{E10}",
+    "thothongthai" => "\u{E18}",
+    "thothungthai" => "\u{E16}",
+    "thousandcyrillic" => "\u{482}",
+    "thousandsseparatorarabic" => "\u{66C}",
+    "thousandsseparatorpersian" => "\u{66C}",
+    "three" => "\u{33}",
+    "threearabic" => "\u{663}",
+    "threebengali" => "\u{9E9}",
+    "threecircle" => "\u{2462}",
+    "threecircleinversesansserif" => "\u{278C}",
+    "threedeva" => "\u{969}",
+    "threeeighths" => "\u{215C}",
+    "threegujarati" => "\u{AE9}",
+    "threegurmukhi" => "\u{A69}",
+    "threehackarabic" => "\u{663}",
+    "threehangzhou" => "\u{3023}",
+    "threeideographicparen" => "\u{3222}",
+    "threeinferior" => "\u{2083}",
+    "threemonospace" => "\u{FF13}",
+    "threenumeratorbengali" => "\u{9F6}",
+    "threeoldstyle" => "\u{F733}",
+    "threeparen" => "\u{2476}",
+    "threeperiod" => "\u{248A}",
+    "threepersian" => "\u{6F3}",
+    "threequarters" => "\u{BE}",
+    "threequartersemdash" => "\u{F6DE}",
+    "threeroman" => "\u{2172}",
+    "threesuperior" => "\u{B3}",
+    "threethai" => "\u{E53}",
+    "thzsquare" => "\u{3394}",
+    "tihiragana" => "\u{3061}",
+    "tikatakana" => "\u{30C1}",
+    "tikatakanahalfwidth" => "\u{FF81}",
+    "tikeutacirclekorean" => "\u{3270}",
+    "tikeutaparenkorean" => "\u{3210}",
+    "tikeutcirclekorean" => "\u{3262}",
+    "tikeutkorean" => "\u{3137}",
+    "tikeutparenkorean" => "\u{3202}",
+    "tilde" => "\u{2DC}",
+    "tildebelowcmb" => "\u{330}",
+    "tildecmb" => "\u{303}",
+    "tildecomb" => "\u{303}",
+    "tildedoublecmb" => "\u{360}",
+    "tildeoperator" => "\u{223C}",
+    "tildeoverlaycmb" => "\u{334}",
+    "tildeverticalcmb" => "\u{33E}",
+    "timescircle" => "\u{2297}",
+    "tipehahebrew" => "\u{596}",
+    "tipehalefthebrew" => "\u{596}",
+    "tippigurmukhi" => "\u{A70}",
+    "titlocyrilliccmb" => "\u{483}",
+    "tiwnarmenian" => "\u{57F}",
+    "tlinebelow" => "\u{1E6F}",
+    "tmonospace" => "\u{FF54}",
+    "toarmenian" => "\u{569}",
+    "tohiragana" => "\u{3068}",
+    "tokatakana" => "\u{30C8}",
+    "tokatakanahalfwidth" => "\u{FF84}",
+    "tonebarextrahighmod" => "\u{2E5}",
+    "tonebarextralowmod" => "\u{2E9}",
+    "tonebarhighmod" => "\u{2E6}",
+    "tonebarlowmod" => "\u{2E8}",
+    "tonebarmidmod" => "\u{2E7}",
+    "tonefive" => "\u{1BD}",
+    "tonesix" => "\u{185}",
+    "tonetwo" => "\u{1A8}",
+    "tonos" => "\u{384}",
+    "tonsquare" => "\u{3327}",
+    "topatakthai" => "\u{E0F}",
+    "tortoiseshellbracketleft" => "\u{3014}",
+    "tortoiseshellbracketleftsmall" => "\u{FE5D}",
+    "tortoiseshellbracketleftvertical" => "\u{FE39}",
+    "tortoiseshellbracketright" => "\u{3015}",
+    "tortoiseshellbracketrightsmall" => "\u{FE5E}",
+    "tortoiseshellbracketrightvertical" => "\u{FE3A}",
+    "totaothai" => "\u{E15}",
+    "tpalatalhook" => "\u{1AB}",
+    "tparen" => "\u{24AF}",
+    "trademark" => "\u{2122}",
+    "trademarksans" => "\u{F8EA}",
+    "trademarkserif" => "\u{F6DB}",
+    "tretroflexhook" => "\u{288}",
+    "triagdn" => "\u{25BC}",
+    "triaglf" => "\u{25C4}",
+    "triagrt" => "\u{25BA}",
+    "triagup" => "\u{25B2}",
+    "ts" => "\u{2A6}",
+    "tsadi" => "\u{5E6}",
+    "tsadidagesh" => "\u{FB46}",
+    "tsadidageshhebrew" => "\u{FB46}",
+    "tsadihebrew" => "\u{5E6}",
+    "tsecyrillic" => "\u{446}",
+    "tsere" => "\u{5B5}",
+    "tsere12" => "\u{5B5}",
+    "tsere1e" => "\u{5B5}",
+    "tsere2b" => "\u{5B5}",
+    "tserehebrew" => "\u{5B5}",
+    "tserenarrowhebrew" => "\u{5B5}",
+    "tserequarterhebrew" => "\u{5B5}",
+    "tserewidehebrew" => "\u{5B5}",
+    "tshecyrillic" => "\u{45B}",
+    "tsuperior" => "\u{F6F3}",
+    "ttabengali" => "\u{99F}",
+    "ttadeva" => "\u{91F}",
+    "ttagujarati" => "\u{A9F}",
+    "ttagurmukhi" => "\u{A1F}",
+    "tteharabic" => "\u{679}",
+    "ttehfinalarabic" => "\u{FB67}",
+    "ttehinitialarabic" => "\u{FB68}",
+    "ttehmedialarabic" => "\u{FB69}",
+    "tthabengali" => "\u{9A0}",
+    "tthadeva" => "\u{920}",
+    "tthagujarati" => "\u{AA0}",
+    "tthagurmukhi" => "\u{A20}",
+    "tturned" => "\u{287}",
+    "tuhiragana" => "\u{3064}",
+    "tukatakana" => "\u{30C4}",
+    "tukatakanahalfwidth" => "\u{FF82}",
+    "tusmallhiragana" => "\u{3063}",
+    "tusmallkatakana" => "\u{30C3}",
+    "tusmallkatakanahalfwidth" => "\u{FF6F}",
+    "twelvecircle" => "\u{246B}",
+    "twelveparen" => "\u{247F}",
+    "twelveperiod" => "\u{2493}",
+    "twelveroman" => "\u{217B}",
+    "twentycircle" => "\u{2473}",
+    "twentyhangzhou" => "\u{5344}",
+    "twentyparen" => "\u{2487}",
+    "twentyperiod" => "\u{249B}",
+    "two" => "\u{32}",
+    "twoarabic" => "\u{662}",
+    "twobengali" => "\u{9E8}",
+    "twocircle" => "\u{2461}",
+    "twocircleinversesansserif" => "\u{278B}",
+    "twodeva" => "\u{968}",
+    "twodotenleader" => "\u{2025}",
+    "twodotleader" => "\u{2025}",
+    "twodotleadervertical" => "\u{FE30}",
+    "twogujarati" => "\u{AE8}",
+    "twogurmukhi" => "\u{A68}",
+    "twohackarabic" => "\u{662}",
+    "twohangzhou" => "\u{3022}",
+    "twoideographicparen" => "\u{3221}",
+    "twoinferior" => "\u{2082}",
+    "twomonospace" => "\u{FF12}",
+    "twonumeratorbengali" => "\u{9F5}",
+    "twooldstyle" => "\u{F732}",
+    "twoparen" => "\u{2475}",
+    "twoperiod" => "\u{2489}",
+    "twopersian" => "\u{6F2}",
+    "tworoman" => "\u{2171}",
+    "twostroke" => "\u{1BB}",
+    "twosuperior" => "\u{B2}",
+    "twothai" => "\u{E52}",
+    "twothirds" => "\u{2154}",
+    "u" => "\u{75}",
+    "uacute" => "\u{FA}",
+    "ubar" => "\u{289}",
+    "ubengali" => "\u{989}",
+    "ubopomofo" => "\u{3128}",
+    "ubreve" => "\u{16D}",
+    "ucaron" => "\u{1D4}",
+    "ucircle" => "\u{24E4}",
+    "ucircumflex" => "\u{FB}",
+    "ucircumflexbelow" => "\u{1E77}",
+    "ucyrillic" => "\u{443}",
+    "udattadeva" => "\u{951}",
+    "udblacute" => "\u{171}",
+    "udblgrave" => "\u{215}",
+    "udeva" => "\u{909}",
+    "udieresis" => "\u{FC}",
+    "udieresisacute" => "\u{1D8}",
+    "udieresisbelow" => "\u{1E73}",
+    "udieresiscaron" => "\u{1DA}",
+    "udieresiscyrillic" => "\u{4F1}",
+    "udieresisgrave" => "\u{1DC}",
+    "udieresismacron" => "\u{1D6}",
+    "udotbelow" => "\u{1EE5}",
+    "ugrave" => "\u{F9}",
+    "ugujarati" => "\u{A89}",
+    "ugurmukhi" => "\u{A09}",
+    "uhiragana" => "\u{3046}",
+    "uhookabove" => "\u{1EE7}",
+    "uhorn" => "\u{1B0}",
+    "uhornacute" => "\u{1EE9}",
+    "uhorndotbelow" => "\u{1EF1}",
+    "uhorngrave" => "\u{1EEB}",
+    "uhornhookabove" => "\u{1EED}",
+    "uhorntilde" => "\u{1EEF}",
+    "uhungarumlaut" => "\u{171}",
+    "uhungarumlautcyrillic" => "\u{4F3}",
+    "uinvertedbreve" => "\u{217}",
+    "ukatakana" => "\u{30A6}",
+    "ukatakanahalfwidth" => "\u{FF73}",
+    "ukcyrillic" => "\u{479}",
+    "ukorean" => "\u{315C}",
+    "umacron" => "\u{16B}",
+    "umacroncyrillic" => "\u{4EF}",
+    "umacrondieresis" => "\u{1E7B}",
+    "umatragurmukhi" => "\u{A41}",
+    "umonospace" => "\u{FF55}",
+    "underscore" => "\u{5F}",
+    "underscoredbl" => "\u{2017}",
+    "underscoremonospace" => "\u{FF3F}",
+    "underscorevertical" => "\u{FE33}",
+    "underscorewavy" => "\u{FE4F}",
+    "union" => "\u{222A}",
+    "universal" => "\u{2200}",
+    "uogonek" => "\u{173}",
+    "uparen" => "\u{24B0}",
+    "upblock" => "\u{2580}",
+    "upperdothebrew" => "\u{5C4}",
+    "upsilon" => "\u{3C5}",
+    "upsilondieresis" => "\u{3CB}",
+    "upsilondieresistonos" => "\u{3B0}",
+    "upsilonlatin" => "\u{28A}",
+    "upsilontonos" => "\u{3CD}",
+    "uptackbelowcmb" => "\u{31D}",
+    "uptackmod" => "\u{2D4}",
+    "uragurmukhi" => "\u{A73}",
+    "uring" => "\u{16F}",
+    "ushortcyrillic" => "\u{45E}",
+    "usmallhiragana" => "\u{3045}",
+    "usmallkatakana" => "\u{30A5}",
+    "usmallkatakanahalfwidth" => "\u{FF69}",
+    "ustraightcyrillic" => "\u{4AF}",
+    "ustraightstrokecyrillic" => "\u{4B1}",
+    "utilde" => "\u{169}",
+    "utildeacute" => "\u{1E79}",
+    "utildebelow" => "\u{1E75}",
+    "uubengali" => "\u{98A}",
+    "uudeva" => "\u{90A}",
+    "uugujarati" => "\u{A8A}",
+    "uugurmukhi" => "\u{A0A}",
+    "uumatragurmukhi" => "\u{A42}",
+    "uuvowelsignbengali" => "\u{9C2}",
+    "uuvowelsigndeva" => "\u{942}",
+    "uuvowelsigngujarati" => "\u{AC2}",
+    "uvowelsignbengali" => "\u{9C1}",
+    "uvowelsigndeva" => "\u{941}",
+    "uvowelsigngujarati" => "\u{AC1}",
+    "v" => "\u{76}",
+    "vadeva" => "\u{935}",
+    "vagujarati" => "\u{AB5}",
+    "vagurmukhi" => "\u{A35}",
+    "vakatakana" => "\u{30F7}",
+    "vav" => "\u{5D5}",
+    "vavdagesh" => "\u{FB35}",
+    "vavdagesh65" => "\u{FB35}",
+    "vavdageshhebrew" => "\u{FB35}",
+    "vavhebrew" => "\u{5D5}",
+    "vavholam" => "\u{FB4B}",
+    "vavholamhebrew" => "\u{FB4B}",
+    "vavvavhebrew" => "\u{5F0}",
+    "vavyodhebrew" => "\u{5F1}",
+    "vcircle" => "\u{24E5}",
+    "vdotbelow" => "\u{1E7F}",
+    "vecyrillic" => "\u{432}",
+    "veharabic" => "\u{6A4}",
+    "vehfinalarabic" => "\u{FB6B}",
+    "vehinitialarabic" => "\u{FB6C}",
+    "vehmedialarabic" => "\u{FB6D}",
+    "vekatakana" => "\u{30F9}",
+    "venus" => "\u{2640}",
+    "verticalbar" => "\u{7C}",
+    "verticallineabovecmb" => "\u{30D}",
+    "verticallinebelowcmb" => "\u{329}",
+    "verticallinelowmod" => "\u{2CC}",
+    "verticallinemod" => "\u{2C8}",
+    "vewarmenian" => "\u{57E}",
+    "vhook" => "\u{28B}",
+    "vikatakana" => "\u{30F8}",
+    "viramabengali" => "\u{9CD}",
+    "viramadeva" => "\u{94D}",
+    "viramagujarati" => "\u{ACD}",
+    "visargabengali" => "\u{983}",
+    "visargadeva" => "\u{903}",
+    "visargagujarati" => "\u{A83}",
+    "vmonospace" => "\u{FF56}",
+    "voarmenian" => "\u{578}",
+    "voicediterationhiragana" => "\u{309E}",
+    "voicediterationkatakana" => "\u{30FE}",
+    "voicedmarkkana" => "\u{309B}",
+    "voicedmarkkanahalfwidth" => "\u{FF9E}",
+    "vokatakana" => "\u{30FA}",
+    "vparen" => "\u{24B1}",
+    "vtilde" => "\u{1E7D}",
+    "vturned" => "\u{28C}",
+    "vuhiragana" => "\u{3094}",
+    "vukatakana" => "\u{30F4}",
+    "w" => "\u{77}",
+    "wacute" => "\u{1E83}",
+    "waekorean" => "\u{3159}",
+    "wahiragana" => "\u{308F}",
+    "wakatakana" => "\u{30EF}",
+    "wakatakanahalfwidth" => "\u{FF9C}",
+    "wakorean" => "\u{3158}",
+    "wasmallhiragana" => "\u{308E}",
+    "wasmallkatakana" => "\u{30EE}",
+    "wattosquare" => "\u{3357}",
+    "wavedash" => "\u{301C}",
+    "wavyunderscorevertical" => "\u{FE34}",
+    "wawarabic" => "\u{648}",
+    "wawfinalarabic" => "\u{FEEE}",
+    "wawhamzaabovearabic" => "\u{624}",
+    "wawhamzaabovefinalarabic" => "\u{FE86}",
+    "wbsquare" => "\u{33DD}",
+    "wcircle" => "\u{24E6}",
+    "wcircumflex" => "\u{175}",
+    "wdieresis" => "\u{1E85}",
+    "wdotaccent" => "\u{1E87}",
+    "wdotbelow" => "\u{1E89}",
+    "wehiragana" => "\u{3091}",
+    "weierstrass" => "\u{2118}",
+    "wekatakana" => "\u{30F1}",
+    "wekorean" => "\u{315E}",
+    "weokorean" => "\u{315D}",
+    "wgrave" => "\u{1E81}",
+    "whitebullet" => "\u{25E6}",
+    "whitecircle" => "\u{25CB}",
+    "whitecircleinverse" => "\u{25D9}",
+    "whitecornerbracketleft" => "\u{300E}",
+    "whitecornerbracketleftvertical" => "\u{FE43}",
+    "whitecornerbracketright" => "\u{300F}",
+    "whitecornerbracketrightvertical" => "\u{FE44}",
+    "whitediamond" => "\u{25C7}",
+    "whitediamondcontainingblacksmalldiamond" => "\u{25C8}",
+    "whitedownpointingsmalltriangle" => "\u{25BF}",
+    "whitedownpointingtriangle" => "\u{25BD}",
+    "whiteleftpointingsmalltriangle" => "\u{25C3}",
+    "whiteleftpointingtriangle" => "\u{25C1}",
+    "whitelenticularbracketleft" => "\u{3016}",
+    "whitelenticularbracketright" => "\u{3017}",
+    "whiterightpointingsmalltriangle" => "\u{25B9}",
+    "whiterightpointingtriangle" => "\u{25B7}",
+    "whitesmallsquare" => "\u{25AB}",
+    "whitesmilingface" => "\u{263A}",
+    "whitesquare" => "\u{25A1}",
+    "whitestar" => "\u{2606}",
+    "whitetelephone" => "\u{260F}",
+    "whitetortoiseshellbracketleft" => "\u{3018}",
+    "whitetortoiseshellbracketright" => "\u{3019}",
+    "whiteuppointingsmalltriangle" => "\u{25B5}",
+    "whiteuppointingtriangle" => "\u{25B3}",
+    "wihiragana" => "\u{3090}",
+    "wikatakana" => "\u{30F0}",
+    "wikorean" => "\u{315F}",
+    "wmonospace" => "\u{FF57}",
+    "wohiragana" => "\u{3092}",
+    "wokatakana" => "\u{30F2}",
+    "wokatakanahalfwidth" => "\u{FF66}",
+    "won" => "\u{20A9}",
+    "wonmonospace" => "\u{FFE6}",
+    "wowaenthai" => "\u{E27}",
+    "wparen" => "\u{24B2}",
+    "wring" => "\u{1E98}",
+    "wsuperior" => "\u{2B7}",
+    "wturned" => "\u{28D}",
+    "wynn" => "\u{1BF}",
+    "x" => "\u{78}",
+    "xabovecmb" => "\u{33D}",
+    "xbopomofo" => "\u{3112}",
+    "xcircle" => "\u{24E7}",
+    "xdieresis" => "\u{1E8D}",
+    "xdotaccent" => "\u{1E8B}",
+    "xeharmenian" => "\u{56D}",
+    "xi" => "\u{3BE}",
+    "xmonospace" => "\u{FF58}",
+    "xparen" => "\u{24B3}",
+    "xsuperior" => "\u{2E3}",
+    "y" => "\u{79}",
+    "yaadosquare" => "\u{334E}",
+    "yabengali" => "\u{9AF}",
+    "yacute" => "\u{FD}",
+    "yadeva" => "\u{92F}",
+    "yaekorean" => "\u{3152}",
+    "yagujarati" => "\u{AAF}",
+    "yagurmukhi" => "\u{A2F}",
+    "yahiragana" => "\u{3084}",
+    "yakatakana" => "\u{30E4}",
+    "yakatakanahalfwidth" => "\u{FF94}",
+    "yakorean" => "\u{3151}",
+    "yamakkanthai" => "\u{E4E}",
+    "yasmallhiragana" => "\u{3083}",
+    "yasmallkatakana" => "\u{30E3}",
+    "yasmallkatakanahalfwidth" => "\u{FF6C}",
+    "yatcyrillic" => "\u{463}",
+    "ycircle" => "\u{24E8}",
+    "ycircumflex" => "\u{177}",
+    "ydieresis" => "\u{FF}",
+    "ydotaccent" => "\u{1E8F}",
+    "ydotbelow" => "\u{1EF5}",
+    "yeharabic" => "\u{64A}",
+    "yehbarreearabic" => "\u{6D2}",
+    "yehbarreefinalarabic" => "\u{FBAF}",
+    "yehfinalarabic" => "\u{FEF2}",
+    "yehhamzaabovearabic" => "\u{626}",
+    "yehhamzaabovefinalarabic" => "\u{FE8A}",
+    "yehhamzaaboveinitialarabic" => "\u{FE8B}",
+    "yehhamzaabovemedialarabic" => "\u{FE8C}",
+    "yehinitialarabic" => "\u{FEF3}",
+    "yehmedialarabic" => "\u{FEF4}",
+    "yehmeeminitialarabic" => "\u{FCDD}",
+    "yehmeemisolatedarabic" => "\u{FC58}",
+    "yehnoonfinalarabic" => "\u{FC94}",
+    "yehthreedotsbelowarabic" => "\u{6D1}",
+    "yekorean" => "\u{3156}",
+    "yen" => "\u{A5}",
+    "yenmonospace" => "\u{FFE5}",
+    "yeokorean" => "\u{3155}",
+    "yeorinhieuhkorean" => "\u{3186}",
+    "yerahbenyomohebrew" => "\u{5AA}",
+    "yerahbenyomolefthebrew" => "\u{5AA}",
+    "yericyrillic" => "\u{44B}",
+    "yerudieresiscyrillic" => "\u{4F9}",
+    "yesieungkorean" => "\u{3181}",
+    "yesieungpansioskorean" => "\u{3183}",
+    "yesieungsioskorean" => "\u{3182}",
+    "yetivhebrew" => "\u{59A}",
+    "ygrave" => "\u{1EF3}",
+    "yhook" => "\u{1B4}",
+    "yhookabove" => "\u{1EF7}",
+    "yiarmenian" => "\u{575}",
+    "yicyrillic" => "\u{457}",
+    "yikorean" => "\u{3162}",
+    "yinyang" => "\u{262F}",
+    "yiwnarmenian" => "\u{582}",
+    "ymonospace" => "\u{FF59}",
+    "yod" => "\u{5D9}",
+    "yoddagesh" => "\u{FB39}",
+    "yoddageshhebrew" => "\u{FB39}",
+    "yodhebrew" => "\u{5D9}",
+    "yodyodhebrew" => "\u{5F2}",
+    "yodyodpatahhebrew" => "\u{FB1F}",
+    "yohiragana" => "\u{3088}",
+    "yoikorean" => "\u{3189}",
+    "yokatakana" => "\u{30E8}",
+    "yokatakanahalfwidth" => "\u{FF96}",
+    "yokorean" => "\u{315B}",
+    "yosmallhiragana" => "\u{3087}",
+    "yosmallkatakana" => "\u{30E7}",
+    "yosmallkatakanahalfwidth" => "\u{FF6E}",
+    "yotgreek" => "\u{3F3}",
+    "yoyaekorean" => "\u{3188}",
+    "yoyakorean" => "\u{3187}",
+    "yoyakthai" => "\u{E22}",
+    "yoyingthai" => "\u{E0D}",
+    "yparen" => "\u{24B4}",
+    "ypogegrammeni" => "\u{37A}",
+    "ypogegrammenigreekcmb" => "\u{345}",
+    "yr" => "\u{1A6}",
+    "yring" => "\u{1E99}",
+    "ysuperior" => "\u{2B8}",
+    "ytilde" => "\u{1EF9}",
+    "yturned" => "\u{28E}",
+    "yuhiragana" => "\u{3086}",
+    "yuikorean" => "\u{318C}",
+    "yukatakana" => "\u{30E6}",
+    "yukatakanahalfwidth" => "\u{FF95}",
+    "yukorean" => "\u{3160}",
+    "yusbigcyrillic" => "\u{46B}",
+    "yusbigiotifiedcyrillic" => "\u{46D}",
+    "yuslittlecyrillic" => "\u{467}",
+    "yuslittleiotifiedcyrillic" => "\u{469}",
+    "yusmallhiragana" => "\u{3085}",
+    "yusmallkatakana" => "\u{30E5}",
+    "yusmallkatakanahalfwidth" => "\u{FF6D}",
+    "yuyekorean" => "\u{318B}",
+    "yuyeokorean" => "\u{318A}",
+    "yyabengali" => "\u{9DF}",
+    "yyadeva" => "\u{95F}",
+    "z" => "\u{7A}",
+    "zaarmenian" => "\u{566}",
+    "zacute" => "\u{17A}",
+    "zadeva" => "\u{95B}",
+    "zagurmukhi" => "\u{A5B}",
+    "zaharabic" => "\u{638}",
+    "zahfinalarabic" => "\u{FEC6}",
+    "zahinitialarabic" => "\u{FEC7}",
+    "zahiragana" => "\u{3056}",
+    "zahmedialarabic" => "\u{FEC8}",
+    "zainarabic" => "\u{632}",
+    "zainfinalarabic" => "\u{FEB0}",
+    "zakatakana" => "\u{30B6}",
+    "zaqefgadolhebrew" => "\u{595}",
+    "zaqefqatanhebrew" => "\u{594}",
+    "zarqahebrew" => "\u{598}",
+    "zayin" => "\u{5D6}",
+    "zayindagesh" => "\u{FB36}",
+    "zayindageshhebrew" => "\u{FB36}",
+    "zayinhebrew" => "\u{5D6}",
+    "zbopomofo" => "\u{3117}",
+    "zcaron" => "\u{17E}",
+    "zcircle" => "\u{24E9}",
+    "zcircumflex" => "\u{1E91}",
+    "zcurl" => "\u{291}",
+    "zdot" => "\u{17C}",
+    "zdotaccent" => "\u{17C}",
+    "zdotbelow" => "\u{1E93}",
+    "zecyrillic" => "\u{437}",
+    "zedescendercyrillic" => "\u{499}",
+    "zedieresiscyrillic" => "\u{4DF}",
+    "zehiragana" => "\u{305C}",
+    "zekatakana" => "\u{30BC}",
+    "zero" => "\u{30}",
+    "zeroarabic" => "\u{660}",
+    "zerobengali" => "\u{9E6}",
+    "zerodeva" => "\u{966}",
+    "zerogujarati" => "\u{AE6}",
+    "zerogurmukhi" => "\u{A66}",
+    "zerohackarabic" => "\u{660}",
+    "zeroinferior" => "\u{2080}",
+    "zeromonospace" => "\u{FF10}",
+    "zerooldstyle" => "\u{F730}",
+    "zeropersian" => "\u{6F0}",
+    "zerosuperior" => "\u{2070}",
+    "zerothai" => "\u{E50}",
+    "zerowidthjoiner" => "\u{FEFF}",
+    "zerowidthnonjoiner" => "\u{200C}",
+    "zerowidthspace" => "\u{200B}",
+    "zeta" => "\u{3B6}",
+    "zhbopomofo" => "\u{3113}",
+    "zhearmenian" => "\u{56A}",
+    "zhebrevecyrillic" => "\u{4C2}",
+    "zhecyrillic" => "\u{436}",
+    "zhedescendercyrillic" => "\u{497}",
+    "zhedieresiscyrillic" => "\u{4DD}",
+    "zihiragana" => "\u{3058}",
+    "zikatakana" => "\u{30B8}",
+    "zinorhebrew" => "\u{5AE}",
+    "zlinebelow" => "\u{1E95}",
+    "zmonospace" => "\u{FF5A}",
+    "zohiragana" => "\u{305E}",
+    "zokatakana" => "\u{30BE}",
+    "zparen" => "\u{24B5}",
+    "zretroflexhook" => "\u{290}",
+    "zstroke" => "\u{1B6}",
+    "zuhiragana" => "\u{305A}",
+    "zukatakana" => "\u{30BA}",
+};
+
+pub(crate) static PREFERRED_NAMES: &[(char, &str)] = &[
+    ('\u{20}', "space"),
+    ('\u{21}', "exclam"),
+    ('\u{22}', "quotedbl"),
+    ('\u{23}', "numbersign"),
+    ('\u{24}', "dollar"),
+    ('\u{25}', "percent"),
+    ('\u{26}', "ampersand"),
+    ('\u{27}', "quotesingle"),
+    ('\u{28}', "parenleft"),
+    ('\u{29}', "parenright"),
+    ('\u{2A}', "asterisk"),
+    ('\u{2B}', "plus"),
+    ('\u{2C}', "comma"),
+    ('\u{2D}', "hyphen"),
+    ('\u{2E}', "period"),
+    ('\u{2F}', "slash"),
+    ('\u{30}', "zero"),
+    ('\u{31}', "one"),
+    ('\u{32}', "two"),
+    ('\u{33}', "three"),
+    ('\u{34}', "four"),
+    ('\u{35}', "five"),
+    ('\u{36}', "six"),
+    ('\u{37}', "seven"),
+    ('\u{38}', "eight"),
+    ('\u{39}', "nine"),
+    ('\u{3A}', "colon"),
+    ('\u{3B}', "semicolon"),
+    ('\u{3C}', "less"),
+    ('\u{3D}', "equal"),
+    ('\u{3E}', "greater"),
+    ('\u{3F}', "question"),
+    ('\u{40}', "at"),
+    ('\u{41}', "A"),
+    ('\u{42}', "B"),
+    ('\u{43}', "C"),
+    ('\u{44}', "D"),
+    ('\u{45}', "E"),
+    ('\u{46}', "F"),
+    ('\u{47}', "G"),
+    ('\u{48}', "H"),
+    ('\u{49}', "I"),
+    ('\u{4A}', "J"),
+    ('\u{4B}', "K"),
+    ('\u{4C}', "L"),
+    ('\u{4D}', "M"),
+    ('\u{4E}', "N"),
+    ('\u{4F}', "O"),
+    ('\u{50}', "P"),
+    ('\u{51}', "Q"),
+    ('\u{52}', "R"),
+    ('\u{53}', "S"),
+    ('\u{54}', "T"),
+    ('\u{55}', "U"),
+    ('\u{56}', "V"),
+    ('\u{57}', "W"),
+    ('\u{58}', "X"),
+    ('\u{59}', "Y"),
+    ('\u{5A}', "Z"),
+    ('\u{5B}', "bracketleft"),
+    ('\u{5C}', "backslash"),
+    ('\u{5D}', "bracketright"),
+    ('\u{5E}', "asciicircum"),
+    ('\u{5F}', "underscore"),
+    ('\u{60}', "grave"),
+    ('\u{61}', "a"),
+    ('\u{62}', "b"),
+    ('\u{63}', "c"),
+    ('\u{64}', "d"),
+    ('\u{65}', "e"),
+    ('\u{66}', "f"),
+    ('\u{67}', "g"),
+    ('\u{68}', "h"),
+    ('\u{69}', "i"),
+    ('\u{6A}', "j"),
+    ('\u{6B}', "k"),
+    ('\u{6C}', "l"),
+    ('\u{6D}', "m"),
+    ('\u{6E}', "n"),
+    ('\u{6F}', "o"),
+    ('\u{70}', "p"),
+    ('\u{71}', "q"),
+    ('\u{72}', "r"),
+    ('\u{73}', "s"),
+    ('\u{74}', "t"),
+    ('\u{75}', "u"),
+    ('\u{76}', "v"),
+    ('\u{77}', "w"),
+    ('\u{78}', "x"),
+    ('\u{79}', "y"),
+    ('\u{7A}', "z"),
+    ('\u{7B}', "braceleft"),
+    ('\u{7C}', "bar"),
+    ('\u{7D}', "braceright"),
+    ('\u{7E}', "asciitilde"),
+    ('\u{A1}', "exclamdown"),
+    ('\u{A2}', "cent"),
+    ('\u{A3}', "sterling"),
+    ('\u{A4}', "currency"),
+    ('\u{A5}', "yen"),
+    ('\u{A6}', "brokenbar"),
+    ('\u{A7}', "section"),
+    ('\u{A8}', "dieresis"),
+    ('\u{A9}', "copyright"),
+    ('\u{AA}', "ordfeminine"),
+    ('\u{AB}', "guillemotleft"),
+    ('\u{AC}', "logicalnot"),
+    ('\u{AE}', "registered"),
+    ('\u{AF}', "macron"),
+    ('\u{B0}', "degree"),
+    ('\u{B1}', "plusminus"),
+    ('\u{B4}', "acute"),
+    ('\u{B5}', "mu"),
+    ('\u{B6}', "paragraph"),
+    ('\u{B7}', "periodcentered"),
+    ('\u{B8}', "cedilla"),
+    ('\u{BA}', "ordmasculine"),
+    ('\u{BB}', "guillemotright"),
+    ('\u{BC}', "onequarter"),
+    ('\u{BD}', "onehalf"),
+    ('\u{BE}', "threequarters"),
+    ('\u{BF}', "questiondown"),
+    ('\u{C0}', "Agrave"),
+    ('\u{C1}', "Aacute"),
+    ('\u{C2}', "Acircumflex"),
+    ('\u{C3}', "Atilde"),
+    ('\u{C4}', "Adieresis"),
+    ('\u{C5}', "Aring"),
+    ('\u{C6}', "AE"),
+    ('\u{C7}', "Ccedilla"),
+    ('\u{C8}', "Egrave"),
+    ('\u{C9}', "Eacute"),
+    ('\u{CA}', "Ecircumflex"),
+    ('\u{CB}', "Edieresis"),
+    ('\u{CC}', "Igrave"),
+    ('\u{CD}', "Iacute"),
+    ('\u{CE}', "Icircumflex"),
+    ('\u{CF}', "Idieresis"),
+    ('\u{D0}', "Eth"),
+    ('\u{D1}', "Ntilde"),
+    ('\u{D2}', "Ograve"),
+    ('\u{D3}', "Oacute"),
+    ('\u{D4}', "Ocircumflex"),
+    ('\u{D5}', "Otilde"),
+    ('\u{D6}', "Odieresis"),
+    ('\u{D7}', "multiply"),
+    ('\u{D8}', "Oslash"),
+    ('\u{D9}', "Ugrave"),
+    ('\u{DA}', "Uacute"),
+    ('\u{DB}', "Ucircumflex"),
+    ('\u{DC}', "Udieresis"),
+    ('\u{DD}', "Yacute"),
+    ('\u{DE}', "Thorn"),
+    ('\u{DF}', "germandbls"),
+    ('\u{E0}', "agrave"),
+    ('\u{E1}', "aacute"),
+    ('\u{E2}', "acircumflex"),
+    ('\u{E3}', "atilde"),
+    ('\u{E4}', "adieresis"),
+    ('\u{E5}', "aring"),
+    ('\u{E6}', "ae"),
+    ('\u{E7}', "ccedilla"),
+    ('\u{E8}', "egrave"),
+    ('\u{E9}', "eacute"),
+    ('\u{EA}', "ecircumflex"),
+    ('\u{EB}', "edieresis"),
+    ('\u{EC}', "igrave"),
+    ('\u{ED}', "iacute"),
+    ('\u{EE}', "icircumflex"),
+    ('\u{EF}', "idieresis"),
+    ('\u{F0}', "eth"),
+    ('\u{F1}', "ntilde"),
+    ('\u{F2}', "ograve"),
+    ('\u{F3}', "oacute"),
+    ('\u{F4}', "ocircumflex"),
+    ('\u{F5}', "otilde"),
+    ('\u{F6}', "odieresis"),
+    ('\u{F7}', "divide"),
+    ('\u{F8}', "oslash"),
+    ('\u{F9}', "ugrave"),
+    ('\u{FA}', "uacute"),
+    ('\u{FB}', "ucircumflex"),
+    ('\u{FC}', "udieresis"),
+    ('\u{FD}', "yacute"),
+    ('\u{FE}', "thorn"),
+    ('\u{FF}', "ydieresis"),
+    ('\u{100}', "Amacron"),
+    ('\u{101}', "amacron"),
+    ('\u{102}', "Abreve"),
+    ('\u{103}', "abreve"),
+    ('\u{104}', "Aogonek"),
+    ('\u{105}', "aogonek"),
+    ('\u{106}', "Cacute"),
+    ('\u{107}', "cacute"),
+    ('\u{108}', "Ccircumflex"),
+    ('\u{109}', "ccircumflex"),
+    ('\u{10A}', "Cdotaccent"),
+    ('\u{10B}', "cdotaccent"),
+    ('\u{10C}', "Ccaron"),
+    ('\u{10D}', "ccaron"),
+    ('\u{10E}', "Dcaron"),
+    ('\u{10F}', "dcaron"),
+    ('\u{110}', "Dcroat"),
+    ('\u{111}', "dcroat"),
+    ('\u{112}', "Emacron"),
+    ('\u{113}', "emacron"),
+    ('\u{114}', "Ebreve"),
+    ('\u{115}', "ebreve"),
+    ('\u{116}', "Edotaccent"),
+    ('\u{117}', "edotaccent"),
+    ('\u{118}', "Eogonek"),
+    ('\u{119}', "eogonek"),
+    ('\u{11A}', "Ecaron"),
+    ('\u{11B}', "ecaron"),
+    ('\u{11C}', "Gcircumflex"),
+    ('\u{11D}', "gcircumflex"),
+    ('\u{11E}', "Gbreve"),
+    ('\u{11F}', "gbreve"),
+    ('\u{120}', "Gdotaccent"),
+    ('\u{121}', "gdotaccent"),
+    ('\u{124}', "Hcircumflex"),
+    ('\u{125}', "hcircumflex"),
+    ('\u{126}', "Hbar"),
+    ('\u{127}', "hbar"),
+    ('\u{128}', "Itilde"),
+    ('\u{129}', "itilde"),
+    ('\u{12A}', "Imacron"),
+    ('\u{12B}', "imacron"),
+    ('\u{12C}', "Ibreve"),
+    ('\u{12D}', "ibreve"),
+    ('\u{12E}', "Iogonek"),
+    ('\u{12F}', "iogonek"),
+    ('\u{130}', "Idotaccent"),
+    ('\u{131}', "dotlessi"),
+    ('\u{132}', "IJ"),
+    ('\u{133}', "ij"),
+    ('\u{134}', "Jcircumflex"),
+    ('\u{135}', "jcircumflex"),
+    ('\u{138}', "kgreenlandic"),
+    ('\u{139}', "Lacute"),
+    ('\u{13A}', "lacute"),
+    ('\u{13D}', "Lcaron"),
+    ('\u{13E}', "lcaron"),
+    ('\u{13F}', "Ldot"),
+    ('\u{140}', "ldot"),
+    ('\u{141}', "Lslash"),
+    ('\u{142}', "lslash"),
+    ('\u{143}', "Nacute"),
+    ('\u{144}', "nacute"),
+    ('\u{147}', "Ncaron"),
+    ('\u{148}', "ncaron"),
+    ('\u{149}', "napostrophe"),
+    ('\u{14A}', "Eng"),
+    ('\u{14B}', "eng"),
+    ('\u{14C}', "Omacron"),
+    ('\u{14D}', "omacron"),
+    ('\u{14E}', "Obreve"),
+    ('\u{14F}', "obreve"),
+    ('\u{150}', "Ohungarumlaut"),
+    ('\u{151}', "ohungarumlaut"),
+    ('\u{152}', "OE"),
+    ('\u{153}', "oe"),
+    ('\u{154}', "Racute"),
+    ('\u{155}', "racute"),
+    ('\u{158}', "Rcaron"),
+    ('\u{159}', "rcaron"),
+    ('\u{15A}', "Sacute"),
+    ('\u{15B}', "sacute"),
+    ('\u{15C}', "Scircumflex"),
+    ('\u{15D}', "scircumflex"),
+    ('\u{15E}', "Scedilla"),
+    ('\u{15F}', "scedilla"),
+    ('\u{160}', "Scaron"),
+    ('\u{161}', "scaron"),
+    ('\u{164}', "Tcaron"),
+    ('\u{165}', "tcaron"),
+    ('\u{166}', "Tbar"),
+    ('\u{167}', "tbar"),
+    ('\u{168}', "Utilde"),
+    ('\u{169}', "utilde"),
+    ('\u{16A}', "Umacron"),
+    ('\u{16B}', "umacron"),
+    ('\u{16C}', "Ubreve"),
+    ('\u{16D}', "ubreve"),
+    ('\u{16E}', "Uring"),
+    ('\u{16F}', "uring"),
+    ('\u{170}', "Uhungarumlaut"),
+    ('\u{171}', "uhungarumlaut"),
+    ('\u{172}', "Uogonek"),
+    ('\u{173}', "uogonek"),
+    ('\u{174}', "Wcircumflex"),
+    ('\u{175}', "wcircumflex"),
+    ('\u{176}', "Ycircumflex"),
+    ('\u{177}', "ycircumflex"),
+    ('\u{178}', "Ydieresis"),
+    ('\u{179}', "Zacute"),
+    ('\u{17A}', "zacute"),
+    ('\u{17B}', "Zdotaccent"),
+    ('\u{17C}', "zdotaccent"),
+    ('\u{17D}', "Zcaron"),
+    ('\u{17E}', "zcaron"),
+    ('\u{17F}', "longs"),
+    ('\u{192}', "florin"),
+    ('\u{1A0}', "Ohorn"),
+    ('\u{1A1}', "ohorn"),
+    ('\u{1AF}', "Uhorn"),
+    ('\u{1B0}', "uhorn"),
+    ('\u{1E6}', "Gcaron"),
+    ('\u{1E7}', "gcaron"),
+    ('\u{1FA}', "Aringacute"),
+    ('\u{1FB}', "aringacute"),
+    ('\u{1FC}', "AEacute"),
+    ('\u{1FD}', "aeacute"),
+    ('\u{1FE}', "Oslashacute"),
+    ('\u{1FF}', "oslashacute"),
+    ('\u{2C6}', "circumflex"),
+    ('\u{2C7}', "caron"),
+    ('\u{2D8}', "breve"),
+    ('\u{2D9}', "dotaccent"),
+    ('\u{2DA}', "ring"),
+    ('\u{2DB}', "ogonek"),
+    ('\u{2DC}', "tilde"),
+    ('\u{2DD}', "hungarumlaut"),
+    ('\u{300}', "gravecomb"),
+    ('\u{301}', "acutecomb"),
+    ('\u{303}', "tildecomb"),
+    ('\u{309}', "hookabovecomb"),
+    ('\u{323}', "dotbelowcomb"),
+    ('\u{384}', "tonos"),
+    ('\u{385}', "dieresistonos"),
+    ('\u{386}', "Alphatonos"),
+    ('\u{387}', "anoteleia"),
+    ('\u{388}', "Epsilontonos"),
+    ('\u{389}', "Etatonos"),
+    ('\u{38A}', "Iotatonos"),
+    ('\u{38C}', "Omicrontonos"),
+    ('\u{38E}', "Upsilontonos"),
+    ('\u{38F}', "Omegatonos"),
+    ('\u{390}', "iotadieresistonos"),
+    ('\u{391}', "Alpha"),
+    ('\u{392}', "Beta"),
+    ('\u{393}', "Gamma"),
+    ('\u{395}', "Epsilon"),
+    ('\u{396}', "Zeta"),
+    ('\u{397}', "Eta"),
+    ('\u{398}', "Theta"),
+    ('\u{399}', "Iota"),
+    ('\u{39A}', "Kappa"),
+    ('\u{39B}', "Lambda"),
+    ('\u{39C}', "Mu"),
+    ('\u{39D}', "Nu"),
+    ('\u{39E}', "Xi"),
+    ('\u{39F}', "Omicron"),
+    ('\u{3A0}', "Pi"),
+    ('\u{3A1}', "Rho"),
+    ('\u{3A3}', "Sigma"),
+    ('\u{3A4}', "Tau"),
+    ('\u{3A5}', "Upsilon"),
+    ('\u{3A6}', "Phi"),
+    ('\u{3A7}', "Chi"),
+    ('\u{3A8}', "Psi"),
+    ('\u{3AA}', "Iotadieresis"),
+    ('\u{3AB}', "Upsilondieresis"),
+    ('\u{3AC}', "alphatonos"),
+    ('\u{3AD}', "epsilontonos"),
+    ('\u{3AE}', "etatonos"),
+    ('\u{3AF}', "iotatonos"),
+    ('\u{3B0}', "upsilondieresistonos"),
+    ('\u{3B1}', "alpha"),
+    ('\u{3B2}', "beta"),
+    ('\u{3B3}', "gamma"),
+    ('\u{3B4}', "delta"),
+    ('\u{3B5}', "epsilon"),
+    ('\u{3B6}', "zeta"),
+    ('\u{3B7}', "eta"),
+    ('\u{3B8}', "theta"),
+    ('\u{3B9}', "iota"),
+    ('\u{3BA}', "kappa"),
+    ('\u{3BB}', "lambda"),
+    ('\u{3BD}', "nu"),
+    ('\u{3BE}', "xi"),
+    ('\u{3BF}', "omicron"),
+    ('\u{3C0}', "pi"),
+    ('\u{3C1}', "rho"),
+    ('\u{3C2}', "sigma1"),
+    ('\u{3C3}', "sigma"),
+    ('\u{3C4}', "tau"),
+    ('\u{3C5}', "upsilon"),
+    ('\u{3C6}', "phi"),
+    ('\u{3C7}', "chi"),
+    ('\u{3C8}', "psi"),
+    ('\u{3C9}', "omega"),
+    ('\u{3CA}', "iotadieresis"),
+    ('\u{3CB}', "upsilondieresis"),
+    ('\u{3CC}', "omicrontonos"),
+    ('\u{3CD}', "upsilontonos"),
+    ('\u{3CE}', "omegatonos"),
+    ('\u{3D1}', "theta1"),
+    ('\u{3D2}', "Upsilon1"),
+    ('\u{3D5}', "phi1"),
+    ('\u{3D6}', "omega1"),
+    ('\u{1E80}', "Wgrave"),
+    ('\u{1E81}', "wgrave"),
+    ('\u{1E82}', "Wacute"),
+    ('\u{1E83}', "wacute"),
+    ('\u{1E84}', "Wdieresis"),
+    ('\u{1E85}', "wdieresis"),
+    ('\u{1EF2}', "Ygrave"),
+    ('\u{1EF3}', "ygrave"),
+    ('\u{2012}', "figuredash"),
+    ('\u{2013}', "endash"),
+    ('\u{2014}', "emdash"),
+    ('\u{2017}', "underscoredbl"),
+    ('\u{2018}', "quoteleft"),
+    ('\u{2019}', "quoteright"),
+    ('\u{201A}', "quotesinglbase"),
+    ('\u{201B}', "quotereversed"),
+    ('\u{201C}', "quotedblleft"),
+    ('\u{201D}', "quotedblright"),
+    ('\u{201E}', "quotedblbase"),
+    ('\u{2020}', "dagger"),
+    ('\u{2021}', "daggerdbl"),
+    ('\u{2022}', "bullet"),
+    ('\u{2024}', "onedotenleader"),
+    ('\u{2025}', "twodotenleader"),
+    ('\u{2026}', "ellipsis"),
+    ('\u{2030}', "perthousand"),
+    ('\u{2032}', "minute"),
+    ('\u{2033}', "second"),
+    ('\u{2039}', "guilsinglleft"),
+    ('\u{203A}', "guilsinglright"),
+    ('\u{203C}', "exclamdbl"),
+    ('\u{2044}', "fraction"),
+    ('\u{20A1}', "colonmonetary"),
+    ('\u{20A3}', "franc"),
+    ('\u{20A4}', "lira"),
+    ('\u{20A7}', "peseta"),
+    ('\u{20AB}', "dong"),
+    ('\u{20AC}', "Euro"),
+    ('\u{2111}', "Ifraktur"),
+    ('\u{2118}', "weierstrass"),
+    ('\u{211C}', "Rfraktur"),
+    ('\u{211E}', "prescription"),
+    ('\u{2122}', "trademark"),
+    ('\u{2126}', "Omega"),
+    ('\u{212E}', "estimated"),
+    ('\u{2135}', "aleph"),
+    ('\u{2153}', "onethird"),
+    ('\u{2154}', "twothirds"),
+    ('\u{215B}', "oneeighth"),
+    ('\u{215C}', "threeeighths"),
+    ('\u{215D}', "fiveeighths"),
+    ('\u{215E}', "seveneighths"),
+    ('\u{2190}', "arrowleft"),
+    ('\u{2191}', "arrowup"),
+    ('\u{2192}', "arrowright"),
+    ('\u{2193}', "arrowdown"),
+    ('\u{2194}', "arrowboth"),
+    ('\u{2195}', "arrowupdn"),
+    ('\u{21A8}', "arrowupdnbse"),
+    ('\u{21B5}', "carriagereturn"),
+    ('\u{21D0}', "arrowdblleft"),
+    ('\u{21D1}', "arrowdblup"),
+    ('\u{21D2}', "arrowdblright"),
+    ('\u{21D3}', "arrowdbldown"),
+    ('\u{21D4}', "arrowdblboth"),
+    ('\u{2200}', "universal"),
+    ('\u{2202}', "partialdiff"),
+    ('\u{2203}', "existential"),
+    ('\u{2205}', "emptyset"),
+    ('\u{2206}', "Delta"),
+    ('\u{2207}', "gradient"),
+    ('\u{2208}', "element"),
+    ('\u{2209}', "notelement"),
+    ('\u{220B}', "suchthat"),
+    ('\u{220F}', "product"),
+    ('\u{2211}', "summation"),
+    ('\u{2212}', "minus"),
+    ('\u{2217}', "asteriskmath"),
+    ('\u{221A}', "radical"),
+    ('\u{221D}', "proportional"),
+    ('\u{221E}', "infinity"),
+    ('\u{221F}', "orthogonal"),
+    ('\u{2220}', "angle"),
+    ('\u{2227}', "logicaland"),
+    ('\u{2228}', "logicalor"),
+    ('\u{2229}', "intersection"),
+    ('\u{222A}', "union"),
+    ('\u{222B}', "integral"),
+    ('\u{2234}', "therefore"),
+    ('\u{223C}', "similar"),
+    ('\u{2245}', "congruent"),
+    ('\u{2248}', "approxequal"),
+    ('\u{2260}', "notequal"),
+    ('\u{2261}', "equivalence"),
+    ('\u{2264}', "lessequal"),
+    ('\u{2265}', "greaterequal"),
+    ('\u{2282}', "propersubset"),
+    ('\u{2283}', "propersuperset"),
+    ('\u{2284}', "notsubset"),
+    ('\u{2286}', "reflexsubset"),
+    ('\u{2287}', "reflexsuperset"),
+    ('\u{2295}', "circleplus"),
+    ('\u{2297}', "circlemultiply"),
+    ('\u{22A5}', "perpendicular"),
+    ('\u{22C5}', "dotmath"),
+    ('\u{2302}', "house"),
+    ('\u{2310}', "revlogicalnot"),
+    ('\u{2320}', "integraltp"),
+    ('\u{2321}', "integralbt"),
+    ('\u{2329}', "angleleft"),
+    ('\u{232A}', "angleright"),
+    ('\u{2500}', "SF100000"),
+    ('\u{2502}', "SF110000"),
+    ('\u{250C}', "SF010000"),
+    ('\u{2510}', "SF030000"),
+    ('\u{2514}', "SF020000"),
+    ('\u{2518}', "SF040000"),
+    ('\u{251C}', "SF080000"),
+    ('\u{2524}', "SF090000"),
+    ('\u{252C}', "SF060000"),
+    ('\u{2534}', "SF070000"),
+    ('\u{253C}', "SF050000"),
+    ('\u{2550}', "SF430000"),
+    ('\u{2551}', "SF240000"),
+    ('\u{2552}', "SF510000"),
+    ('\u{2553}', "SF520000"),
+    ('\u{2554}', "SF390000"),
+    ('\u{2555}', "SF220000"),
+    ('\u{2556}', "SF210000"),
+    ('\u{2557}', "SF250000"),
+    ('\u{2558}', "SF500000"),
+    ('\u{2559}', "SF490000"),
+    ('\u{255A}', "SF380000"),
+    ('\u{255B}', "SF280000"),
+    ('\u{255C}', "SF270000"),
+    ('\u{255D}', "SF260000"),
+    ('\u{255E}', "SF360000"),
+    ('\u{255F}', "SF370000"),
+    ('\u{2560}', "SF420000"),
+    ('\u{2561}', "SF190000"),
+    ('\u{2562}', "SF200000"),
+    ('\u{2563}', "SF230000"),
+    ('\u{2564}', "SF470000"),
+    ('\u{2565}', "SF480000"),
+    ('\u{2566}', "SF410000"),
+    ('\u{2567}', "SF450000"),
+    ('\u{2568}', "SF460000"),
+    ('\u{2569}', "SF400000"),
+    ('\u{256A}', "SF540000"),
+    ('\u{256B}', "SF530000"),
+    ('\u{256C}', "SF440000"),
+    ('\u{2580}', "upblock"),
+    ('\u{2584}', "dnblock"),
+    ('\u{2588}', "block"),
+    ('\u{258C}', "lfblock"),
+    ('\u{2590}', "rtblock"),
+    ('\u{2591}', "ltshade"),
+    ('\u{2592}', "shade"),
+    ('\u{2593}', "dkshade"),
+    ('\u{25A0}', "filledbox"),
+    ('\u{25A1}', "H22073"),
+    ('\u{25AA}', "H18543"),
+    ('\u{25AB}', "H18551"),
+    ('\u{25AC}', "filledrect"),
+    ('\u{25B2}', "triagup"),
+    ('\u{25BA}', "triagrt"),
+    ('\u{25BC}', "triagdn"),
+    ('\u{25C4}', "triaglf"),
+    ('\u{25CA}', "lozenge"),
+    ('\u{25CB}', "circle"),
+    ('\u{25CF}', "H18533"),
+    ('\u{25D8}', "invbullet"),
+    ('\u{25D9}', "invcircle"),
+    ('\u{25E6}', "openbullet"),
+    ('\u{263A}', "smileface"),
+    ('\u{263B}', "invsmileface"),
+    ('\u{263C}', "sun"),
+    ('\u{2640}', "female"),
+    ('\u{2642}', "male"),
+    ('\u{2660}', "spade"),
+    ('\u{2663}', "club"),
+    ('\u{2665}', "heart"),
+    ('\u{2666}', "diamond"),
+    ('\u{266A}', "musicalnote"),
+    ('\u{266B}', "musicalnotedbl"),
+];
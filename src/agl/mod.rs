@@ -0,0 +1,225 @@
+//! Glyph name to Unicode mappings according to the Adobe Glyph List.
+//!
+//! The functions in this module work with glyph names from both
+//! [`cff::Table`](crate::cff::Table) and [`type1::Table`](crate::type1::Table)
+//! and implement the algorithm from the
+//! [AGL specification](https://github.com/adobe-type-tools/agl-specification).
+//!
+//! The name table is generated by `scripts/gen_agl.py` from `glyphlist.txt`
+//! and `aglfn.txt`.
+
+#[rustfmt::skip]
+mod glyph_list;
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use glyph_list::{GLYPH_LIST, PREFERRED_NAMES};
+
+/// Maps a glyph name to a Unicode string.
+///
+/// The suffix after the first period is ignored and ligature components
+/// separated by underscores are mapped individually, so `f_f_i` and `a.sc`
+/// are resolved as well. Components that can't be mapped are skipped.
+///
+/// Returns `None` if no component could be mapped.
+pub fn name_to_unicode(name: &str) -> Option<String> {
+    let mut text = String::new();
+    for component in base_name(name).split('_') {
+        map_component(component, &mut text);
+    }
+
+    if text.is_empty() {
+        None
+    } else {
+        Some(text)
+    }
+}
+
+/// Maps a glyph name to a single character.
+///
+/// Returns `None` if the name maps to no or to multiple characters.
+pub fn name_to_char(name: &str) -> Option<char> {
+    let name = base_name(name);
+    // Fast path for the common case that avoids allocating.
+    if !name.contains('_') {
+        if let Some(text) = GLYPH_LIST.get(name) {
+            return single_char(text);
+        }
+    }
+
+    single_char(&name_to_unicode(name)?)
+}
+
+/// Returns the preferred glyph name for a character.
+///
+/// Uses the name from the Adobe Glyph List for New Fonts if there is one,
+/// and `uniXXXX` or `uXXXXX` otherwise.
+pub fn char_to_name(c: char) -> Cow<'static, str> {
+    if let Ok(index) = PREFERRED_NAMES.binary_search_by(|(code, _)| code.cmp(&c)) {
+        return Cow::Borrowed(PREFERRED_NAMES[index].1);
+    }
+
+    let code = u32::from(c);
+    if code <= 0xFFFF {
+        Cow::Owned(format!("uni{:04X}", code))
+    } else {
+        Cow::Owned(format!("u{:X}", code))
+    }
+}
+
+/// Builds a map from characters to the glyphs that draw them.
+///
+/// `glyphs` should yield the preferred glyphs first, like the ones that are
+/// reachable through the font's encoding. Glyphs without a suffix always
+/// take precedence over ones with a suffix, so `a` wins over `a.sc`.
+pub(crate) fn char_map<'a, G, I>(glyphs: I) -> HashMap<char, G>
+where
+    I: IntoIterator<Item = (G, &'a str)>,
+{
+    let mut map: HashMap<char, (G, bool)> = HashMap::new();
+    for (glyph, name) in glyphs {
+        let c = match name_to_char(name) {
+            Some(c) => c,
+            None => continue,
+        };
+
+        let has_suffix = name.contains('.');
+        match map.get(&c) {
+            Some((_, true)) if !has_suffix => {}
+            Some(_) => continue,
+            None => {}
+        }
+
+        map.insert(c, (glyph, has_suffix));
+    }
+
+    map.into_iter().map(|(c, (glyph, _))| (c, glyph)).collect()
+}
+
+fn base_name(name: &str) -> &str {
+    match name.find('.') {
+        Some(index) => &name[..index],
+        None => name,
+    }
+}
+
+fn single_char(text: &str) -> Option<char> {
+    let mut chars = text.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Some(c),
+        _ => None,
+    }
+}
+
+fn map_component(component: &str, text: &mut String) {
+    if let Some(s) = GLYPH_LIST.get(component) {
+        text.push_str(s);
+        return;
+    }
+
+    if let Some(digits) = component.strip_prefix("uni") {
+        if !digits.is_empty() && digits.len() % 4 == 0 {
+            let chars: Option<Vec<char>> = (0..digits.len())
+                .step_by(4)
+                .map(|i| parse_code(&digits[i..i + 4]))
+                .collect();
+
+            if let Some(chars) = chars {
+                text.extend(chars);
+                return;
+            }
+        }
+    }
+
+    if let Some(digits) = component.strip_prefix('u') {
+        if (4..=6).contains(&digits.len()) {
+            if let Some(c) = parse_code(digits) {
+                text.push(c);
+            }
+        }
+    }
+}
+
+// Parses uppercase hexadecimal digits. `char::from_u32` rejects surrogates
+// and values above U+10FFFF.
+fn parse_code(digits: &str) -> Option<char> {
+    if !digits
+        .bytes()
+        .all(|b| b.is_ascii_digit() || (b'A'..=b'F').contains(&b))
+    {
+        return None;
+    }
+
+    char::from_u32(u32::from_str_radix(digits, 16).ok()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names_to_unicode() {
+        assert_eq!(name_to_unicode("uni0041").as_deref(), Some("A"));
+        assert_eq!(name_to_unicode("uni00410042").as_deref(), Some("AB"));
+        assert_eq!(name_to_unicode("u1F600").as_deref(), Some("\u{1F600}"));
+        assert_eq!(name_to_unicode("Aacute").as_deref(), Some("\u{C1}"));
+        assert_eq!(name_to_unicode("f_f_i").as_deref(), Some("ffi"));
+        assert_eq!(
+            name_to_unicode("dalethatafpatah").as_deref(),
+            Some("\u{5D3}\u{5B2}")
+        );
+        assert_eq!(name_to_unicode("a.sc").as_deref(), Some("a"));
+        assert_eq!(
+            name_to_unicode("Lcommaaccent_uni20AC0308_u1040C.alternate").as_deref(),
+            Some("\u{13B}\u{20AC}\u{308}\u{1040C}")
+        );
+
+        assert_eq!(name_to_unicode("uni0041.sc"), name_to_unicode("A"));
+        assert_eq!(name_to_unicode("uni004"), None);
+        assert_eq!(name_to_unicode("uni00e9"), None);
+        assert_eq!(name_to_unicode("uniD800"), None);
+        assert_eq!(name_to_unicode("u110000"), None);
+        assert_eq!(name_to_unicode(".notdef"), None);
+        assert_eq!(name_to_unicode("foo"), None);
+    }
+
+    #[test]
+    fn names_to_char() {
+        assert_eq!(name_to_char("quoteright"), Some('\u{2019}'));
+        assert_eq!(name_to_char("uni2019.alt"), Some('\u{2019}'));
+        assert_eq!(name_to_char("zerooldstyle"), Some('\u{F730}'));
+        assert_eq!(name_to_char("afii10017"), Some('\u{410}'));
+        assert_eq!(name_to_char("f_i"), None);
+        assert_eq!(name_to_char("dalethatafpatah"), None);
+        assert_eq!(name_to_char("notaglyph"), None);
+    }
+
+    #[test]
+    fn char_map_precedence() {
+        let map = char_map(vec![
+            (1, "a.sc"),
+            (2, "b"),
+            (3, "a"),
+            (4, "b"),
+            (5, "a.alt"),
+            (6, "f_i"),
+        ]);
+        assert_eq!(map.get(&'a'), Some(&3));
+        assert_eq!(map.get(&'b'), Some(&2));
+        assert_eq!(map.len(), 2);
+    }
+
+    #[test]
+    fn chars_to_names() {
+        assert_eq!(char_to_name('A'), "A");
+        assert_eq!(char_to_name('\u{C1}'), "Aacute");
+        assert_eq!(char_to_name('\u{2206}'), "Delta");
+        assert_eq!(char_to_name('\u{0394}'), "uni0394");
+        assert_eq!(char_to_name('\u{1F600}'), "u1F600");
+
+        for c in ['a', '\u{E9}', '\u{141}', '\u{3C9}', '\u{20AC}', '\u{1F600}'] {
+            assert_eq!(name_to_char(&char_to_name(c)), Some(c));
+        }
+    }
+}
@@ -0,0 +1,345 @@
+//! Assembly of Type 1 and Type 2 charstrings.
+//!
+//! The text has the form printed by the [`Disassembler`](crate::disasm::Disassembler):
+//! numbers are operands, words are operator names and `%` starts a comment
+//! that extends to the end of the line.
+//!
+//! ```text
+//! -250 0 50 hstemhm  % width nominalWidthX - 250
+//! 20 80 hintmask 0b11000000
+//! 100 0 rmoveto
+//! 50 hlineto
+//! endchar
+//! ```
+//!
+//! The mask of `hintmask` and `cntrmask` is the next token, written as bits with
+//! a `0b` prefix and padded with zeros to whole bytes. Without such a token, the mask is empty.
+//! Operators without a name can be written as `reserved<N>` and `reserved12.<N>`.
+//!
+//! The disassembler output only assembles back to the same charstring
+//! when subroutines are not inlined.
+
+use core::convert::TryFrom;
+use core::fmt;
+
+use crate::cff::cff::operator;
+use crate::disasm::CharStringKind;
+use crate::type1::operator::{sb_operator, tb_operator};
+
+const ESCAPE: u8 = 12;
+
+const TYPE1_OPERATORS: &[(&str, &[u8])] = &[
+    ("hstem", &[sb_operator::HORIZONTAL_STEM]),
+    ("vstem", &[sb_operator::VERTICAL_STEM]),
+    ("vmoveto", &[sb_operator::VERTICAL_MOVE_TO]),
+    ("rlineto", &[sb_operator::LINE_TO]),
+    ("hlineto", &[sb_operator::HORIZONTAL_LINE_TO]),
+    ("vlineto", &[sb_operator::VERTICAL_LINE_TO]),
+    ("rrcurveto", &[sb_operator::CURVE_TO]),
+    ("closepath", &[sb_operator::CLOSE_PATH]),
+    ("callsubr", &[sb_operator::CALL_SUBR]),
+    ("return", &[sb_operator::RETURN]),
+    ("hsbw", &[sb_operator::HSBW]),
+    ("endchar", &[sb_operator::ENDCHAR]),
+    ("rmoveto", &[sb_operator::MOVE_TO]),
+    ("hmoveto", &[sb_operator::HORIZONTAL_MOVE_TO]),
+    ("vhcurveto", &[sb_operator::VH_CURVE_TO]),
+    ("hvcurveto", &[sb_operator::HV_CURVE_TO]),
+    ("dotsection", &[ESCAPE, tb_operator::DOTSECTION]),
+    ("vstem3", &[ESCAPE, tb_operator::VSTEM3]),
+    ("hstem3", &[ESCAPE, tb_operator::HSTEM3]),
+    ("seac", &[ESCAPE, tb_operator::SEAC]),
+    ("sbw", &[ESCAPE, tb_operator::SBW]),
+    ("div", &[ESCAPE, tb_operator::DIV]),
+    ("callothersubr", &[ESCAPE, tb_operator::CALL_OTHER_SUBR]),
+    ("pop", &[ESCAPE, tb_operator::POP]),
+    ("setcurrentpoint", &[ESCAPE, tb_operator::SET_CURRENT_POINT]),
+];
+
+const TYPE2_OPERATORS: &[(&str, &[u8])] = &[
+    ("hstem", &[operator::HORIZONTAL_STEM]),
+    ("vstem", &[operator::VERTICAL_STEM]),
+    ("vmoveto", &[operator::VERTICAL_MOVE_TO]),
+    ("rlineto", &[operator::LINE_TO]),
+    ("hlineto", &[operator::HORIZONTAL_LINE_TO]),
+    ("vlineto", &[operator::VERTICAL_LINE_TO]),
+    ("rrcurveto", &[operator::CURVE_TO]),
+    ("callsubr", &[operator::CALL_LOCAL_SUBROUTINE]),
+    ("return", &[operator::RETURN]),
+    ("endchar", &[operator::ENDCHAR]),
+    ("hstemhm", &[operator::HORIZONTAL_STEM_HINT_MASK]),
+    ("hintmask", &[operator::HINT_MASK]),
+    ("cntrmask", &[operator::COUNTER_MASK]),
+    ("rmoveto", &[operator::MOVE_TO]),
+    ("hmoveto", &[operator::HORIZONTAL_MOVE_TO]),
+    ("vstemhm", &[operator::VERTICAL_STEM_HINT_MASK]),
+    ("rcurveline", &[operator::CURVE_LINE]),
+    ("rlinecurve", &[operator::LINE_CURVE]),
+    ("vvcurveto", &[operator::VV_CURVE_TO]),
+    ("hhcurveto", &[operator::HH_CURVE_TO]),
+    ("callgsubr", &[operator::CALL_GLOBAL_SUBROUTINE]),
+    ("vhcurveto", &[operator::VH_CURVE_TO]),
+    ("hvcurveto", &[operator::HV_CURVE_TO]),
+    ("dotsection", &[ESCAPE, operator::DOTSECTION]),
+    ("and", &[ESCAPE, operator::AND]),
+    ("or", &[ESCAPE, operator::OR]),
+    ("not", &[ESCAPE, operator::NOT]),
+    ("abs", &[ESCAPE, operator::ABS]),
+    ("add", &[ESCAPE, operator::ADD]),
+    ("sub", &[ESCAPE, operator::SUB]),
+    ("div", &[ESCAPE, operator::DIV]),
+    ("neg", &[ESCAPE, operator::NEG]),
+    ("eq", &[ESCAPE, operator::EQ]),
+    ("drop", &[ESCAPE, operator::DROP]),
+    ("put", &[ESCAPE, operator::PUT]),
+    ("get", &[ESCAPE, operator::GET]),
+    ("ifelse", &[ESCAPE, operator::IF_ELSE]),
+    ("random", &[ESCAPE, operator::RANDOM]),
+    ("mul", &[ESCAPE, operator::MUL]),
+    ("sqrt", &[ESCAPE, operator::SQRT]),
+    ("dup", &[ESCAPE, operator::DUP]),
+    ("exch", &[ESCAPE, operator::EXCH]),
+    ("index", &[ESCAPE, operator::INDEX]),
+    ("roll", &[ESCAPE, operator::ROLL]),
+    ("hflex", &[ESCAPE, operator::HFLEX]),
+    ("flex", &[ESCAPE, operator::FLEX]),
+    ("hflex1", &[ESCAPE, operator::HFLEX1]),
+    ("flex1", &[ESCAPE, operator::FLEX1]),
+];
+
+/// An error that occurred while assembling a charstring.
+///
+/// Holds the line number, starting at 1.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum AsmError {
+    /// A word is not an operator of the charstring format.
+    UnknownOperator(usize),
+    /// A number can't be represented in the charstring format.
+    InvalidNumber(usize),
+    /// The mask of `hintmask` or `cntrmask` is not made of bits.
+    InvalidMask(usize),
+}
+
+impl fmt::Display for AsmError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AsmError::UnknownOperator(line) => write!(f, "unknown operator on line {}", line),
+            AsmError::InvalidNumber(line) => write!(f, "invalid number on line {}", line),
+            AsmError::InvalidMask(line) => write!(f, "invalid mask on line {}", line),
+        }
+    }
+}
+
+impl std::error::Error for AsmError {}
+
+/// Assembles a charstring from its textual form.
+///
+/// Integers use the shortest encoding. In Type 2 charstrings, other numbers
+/// are written as 16.16 fixed numbers, while Type 1 charstrings only support integers.
+pub fn assemble(text: &str, kind: CharStringKind) -> Result<Vec<u8>, AsmError> {
+    let operators = match kind {
+        CharStringKind::Type1 => TYPE1_OPERATORS,
+        CharStringKind::Type2 => TYPE2_OPERATORS,
+    };
+
+    let mut data = Vec::new();
+    for (index, line) in text.lines().enumerate() {
+        let line_number = index + 1;
+        let line = line.split('%').next().unwrap_or_default();
+        let mut tokens = line.split_whitespace().peekable();
+        while let Some(token) = tokens.next() {
+            if token.starts_with(|c: char| c.is_ascii_digit() || matches!(c, '-' | '+' | '.')) {
+                if !write_number(token, kind, &mut data) {
+                    return Err(AsmError::InvalidNumber(line_number));
+                }
+                continue;
+            }
+
+            match operators.iter().find(|(name, _)| *name == token) {
+                Some((_, op)) => data.extend_from_slice(op),
+                None => match parse_reserved(token, kind) {
+                    Some(op) => data.extend_from_slice(&op),
+                    None => return Err(AsmError::UnknownOperator(line_number)),
+                },
+            }
+
+            if kind == CharStringKind::Type2 && matches!(token, "hintmask" | "cntrmask") {
+                if let Some(mask) = tokens.peek().and_then(|t| t.strip_prefix("0b")) {
+                    if !write_mask(mask, &mut data) {
+                        return Err(AsmError::InvalidMask(line_number));
+                    }
+                    tokens.next();
+                }
+            }
+        }
+    }
+
+    Ok(data)
+}
+
+// Writes a number operand and returns whether it can be represented.
+fn write_number(token: &str, kind: CharStringKind, out: &mut Vec<u8>) -> bool {
+    if let Ok(n) = token.parse::<i32>() {
+        match n {
+            -107..=107 => out.push((n + 139) as u8),
+            108..=1131 => {
+                let n = n - 108;
+                out.extend_from_slice(&[(n >> 8) as u8 + 247, n as u8]);
+            }
+            -1131..=-108 => {
+                let n = -n - 108;
+                out.extend_from_slice(&[(n >> 8) as u8 + 251, n as u8]);
+            }
+            _ => match kind {
+                CharStringKind::Type1 => {
+                    out.push(255);
+                    out.extend_from_slice(&n.to_be_bytes());
+                }
+                CharStringKind::Type2 => match i16::try_from(n) {
+                    Ok(n) => {
+                        out.push(operator::SHORT_INT);
+                        out.extend_from_slice(&n.to_be_bytes());
+                    }
+                    Err(_) => return false,
+                },
+            },
+        }
+
+        return true;
+    }
+
+    match (token.parse::<f32>(), kind) {
+        (Ok(n), CharStringKind::Type2) if (-32768.0..32768.0).contains(&n) => {
+            out.push(operator::FIXED_16_16);
+            out.extend_from_slice(&((n * 65536.0).round() as i32).to_be_bytes());
+            true
+        }
+        _ => false,
+    }
+}
+
+// Writes the bits of a hint mask, padded to whole bytes.
+fn write_mask(token: &str, out: &mut Vec<u8>) -> bool {
+    if !token.bytes().all(|b| b == b'0' || b == b'1') {
+        return false;
+    }
+
+    for chunk in token.as_bytes().chunks(8) {
+        let byte = chunk
+            .iter()
+            .enumerate()
+            .fold(0, |byte, (i, b)| byte | ((b - b'0') << (7 - i)));
+        out.push(byte);
+    }
+
+    true
+}
+
+// Parses the names the disassembler gives to operators without a name.
+fn parse_reserved(token: &str, kind: CharStringKind) -> Option<Vec<u8>> {
+    let number = token.strip_prefix("reserved")?;
+    match number.strip_prefix("12.") {
+        Some(number) => Some(vec![ESCAPE, number.parse().ok()?]),
+        None => {
+            let op = number.parse().ok()?;
+            // Numbers and the escape byte are not operators.
+            let is_short_int = kind == CharStringKind::Type2 && op == operator::SHORT_INT;
+            if op >= 32 || op == ESCAPE || is_short_int {
+                return None;
+            }
+
+            Some(vec![op])
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{assemble, AsmError};
+    use crate::disasm::{CharStringKind, Disassembler};
+
+    #[test]
+    fn numbers() {
+        let data = assemble(
+            "0 107 -107 108 1131 -1131 32767 -32768 1.5 -0.25",
+            CharStringKind::Type2,
+        )
+        .unwrap();
+        assert_eq!(
+            data,
+            [
+                0x8B, 0xF6, 0x20, 0xF7, 0x00, 0xFA, 0xFF, 0xFE, 0xFF, 0x1C, 0x7F, 0xFF, 0x1C, 0x80,
+                0x00, 0xFF, 0x00, 0x01, 0x80, 0x00, 0xFF, 0xFF, 0xFF, 0xC0, 0x00,
+            ]
+        );
+
+        let data = assemble("1131 100000 -100000", CharStringKind::Type1).unwrap();
+        assert_eq!(
+            data,
+            [0xFA, 0xFF, 0xFF, 0x00, 0x01, 0x86, 0xA0, 0xFF, 0xFF, 0xFE, 0x79, 0x60]
+        );
+
+        let error = assemble("0 0 rmoveto\n0.5 hlineto", CharStringKind::Type1);
+        assert_eq!(error, Err(AsmError::InvalidNumber(2)));
+        let error = assemble("40000 hlineto", CharStringKind::Type2);
+        assert_eq!(error, Err(AsmError::InvalidNumber(1)));
+    }
+
+    #[test]
+    fn operators() {
+        let data = assemble("100 0 rmoveto 50 hlineto endchar", CharStringKind::Type2).unwrap();
+        assert_eq!(data, [0xEF, 0x8B, 0x15, 0xBD, 0x06, 0x0E]);
+
+        let data = assemble(
+            "10 20 hstemhm hintmask 0b1\n30 40 50 60 cntrmask 0b111\nreserved2 reserved12.38",
+            CharStringKind::Type2,
+        )
+        .unwrap();
+        assert_eq!(
+            data,
+            [0x95, 0x9F, 0x12, 0x13, 0x80, 0xA9, 0xB3, 0xBD, 0xC7, 0x14, 0xE0, 0x02, 0x0C, 0x26]
+        );
+
+        // `closepath` and `hsbw` only exist in Type 1 charstrings.
+        let data = assemble("0 500 hsbw closepath", CharStringKind::Type1).unwrap();
+        assert_eq!(data, [0x8B, 0xF8, 0x88, 0x0D, 0x09]);
+        let error = assemble("% comment\nclosepath", CharStringKind::Type2);
+        assert_eq!(error, Err(AsmError::UnknownOperator(2)));
+        let error = assemble("hintmask 0b102", CharStringKind::Type2);
+        assert_eq!(error, Err(AsmError::InvalidMask(1)));
+
+        // Without the prefix, the next token is not a mask.
+        let data = assemble("hintmask 10 hmoveto", CharStringKind::Type2).unwrap();
+        assert_eq!(data, [0x13, 0x95, 0x16]);
+        let error = assemble("reserved28", CharStringKind::Type2);
+        assert_eq!(error, Err(AsmError::UnknownOperator(1)));
+    }
+
+    #[test]
+    fn round_trip() {
+        let text = "-250 0 50 hstemhm  % width nominalWidthX - 250\n\
+                    20 80 hintmask 0b11000000\n\
+                    20 0 rmoveto\n\
+                    10 20.5 30 40 5 hvcurveto\n\
+                    10 10 10 10 10 0 10 -10 10 -10 5 flex1\n\
+                    3 4 add\n\
+                    2 div\n\
+                    drop\n\
+                    endchar\n";
+        let data = assemble(text, CharStringKind::Type2).unwrap();
+        let disassembled = Disassembler::new().char_string(&data, CharStringKind::Type2);
+        assert_eq!(disassembled, text);
+
+        let text = "50 500 hsbw  % width 500\n\
+                    0 1 callothersubr  % start flex\n\
+                    50 200 100 3 0 callothersubr  % end flex\n\
+                    pop\n\
+                    pop\n\
+                    setcurrentpoint\n\
+                    closepath\n\
+                    endchar\n";
+        let data = assemble(text, CharStringKind::Type1).unwrap();
+        let disassembled = Disassembler::new().char_string(&data, CharStringKind::Type1);
+        assert_eq!(disassembled, text);
+    }
+}
@@ -0,0 +1,466 @@
+//! A minimal builder for CFF fonts.
+
+use super::cff::{private_dict_operator, top_dict_operator};
+use super::charset::write_charset;
+use super::dict::{write_fixed_int, write_int, write_number, write_operator};
+use super::encoding::write_encoding;
+use super::from_type1::{write_font_info, Strings};
+use super::index::write_index;
+use super::StringId;
+use crate::disasm::CharStringKind;
+use crate::type1::FontInfo;
+use crate::{Matrix, RectF};
+
+/// Builds minimal CFF fonts from charstrings.
+///
+/// Meant for tests that need fonts with specific charstrings, which can be
+/// written with [`assemble`](crate::asm::assemble). The charstrings are stored
+/// as they are, so fonts can also be malformed on purpose.
+///
+/// Without any mappings, the fonts use the Standard Encoding.
+#[derive(Clone, Debug)]
+pub struct FontBuilder {
+    name: String,
+    glyphs: Vec<(String, Vec<u8>)>,
+    global_subroutines: Vec<Vec<u8>>,
+    local_subroutines: Vec<Vec<u8>>,
+    default_width: f32,
+    nominal_width: f32,
+    matrix: Matrix,
+    font_info: FontInfo,
+    font_bbox: Option<RectF>,
+    encoding: Vec<(u8, String)>,
+    private_entries: Vec<(u16, Vec<f32>)>,
+    kind: CharStringKind,
+    cid: bool,
+    font_dict_matrix: Option<Matrix>,
+}
+
+impl FontBuilder {
+    /// Creates a builder for a font without glyphs.
+    pub fn new(name: &str) -> Self {
+        FontBuilder {
+            name: name.to_string(),
+            glyphs: Vec::new(),
+            global_subroutines: Vec::new(),
+            local_subroutines: Vec::new(),
+            default_width: 0.0,
+            nominal_width: 0.0,
+            matrix: Matrix::default(),
+            font_info: FontInfo::default(),
+            font_bbox: None,
+            encoding: Vec::new(),
+            private_entries: Vec::new(),
+            kind: CharStringKind::Type2,
+            cid: false,
+            font_dict_matrix: None,
+        }
+    }
+
+    /// Adds a glyph.
+    ///
+    /// The first glyph should be `.notdef`. In CID fonts, the name is ignored
+    /// and the CID of each glyph is its glyph ID.
+    pub fn glyph(mut self, name: &str, char_string: &[u8]) -> Self {
+        self.glyphs.push((name.to_string(), char_string.to_vec()));
+        self
+    }
+
+    /// Adds a global subroutine.
+    ///
+    /// Subroutines are numbered in the order they are added. With fewer than 1240
+    /// subroutines, a charstring calls subroutine `n` with the operand `n - 107`.
+    pub fn global_subroutine(mut self, char_string: &[u8]) -> Self {
+        self.global_subroutines.push(char_string.to_vec());
+        self
+    }
+
+    /// Adds a local subroutine.
+    ///
+    /// Numbered the same way as the global subroutines.
+    pub fn local_subroutine(mut self, char_string: &[u8]) -> Self {
+        self.local_subroutines.push(char_string.to_vec());
+        self
+    }
+
+    /// Sets the width of glyphs whose charstring doesn't store a width.
+    pub fn default_width(mut self, width: f32) -> Self {
+        self.default_width = width;
+        self
+    }
+
+    /// Sets the width that is added to the widths stored in charstrings.
+    pub fn nominal_width(mut self, width: f32) -> Self {
+        self.nominal_width = width;
+        self
+    }
+
+    /// Sets the font matrix.
+    pub fn matrix(mut self, matrix: Matrix) -> Self {
+        self.matrix = matrix;
+        self
+    }
+
+    /// Sets the strings and values of the Top DICT that a Type 1 font
+    /// stores in its `FontInfo` dictionary.
+    pub fn font_info(mut self, info: FontInfo) -> Self {
+        self.font_info = info;
+        self
+    }
+
+    /// Sets the font bounding box.
+    pub fn font_bbox(mut self, bbox: RectF) -> Self {
+        self.font_bbox = Some(bbox);
+        self
+    }
+
+    /// Maps a code to a glyph.
+    ///
+    /// Ignored in CID fonts, which have no encoding.
+    pub fn encoding(mut self, code: u8, name: &str) -> Self {
+        self.encoding.push((code, name.to_string()));
+        self
+    }
+
+    /// Adds an entry to the Private DICT.
+    ///
+    /// Two-byte operators are written as 1200 plus their second byte, so `BlueScale`
+    /// is 1209. The operands are written as they are, so arrays like `BlueValues`
+    /// have to be delta-encoded.
+    pub fn private_entry(mut self, operator: u16, operands: &[f32]) -> Self {
+        self.private_entries.push((operator, operands.to_vec()));
+        self
+    }
+
+    /// Sets the format of the charstrings and subroutines.
+    ///
+    /// Type 1 charstrings are marked with `CharstringType 1` in the Top DICT.
+    pub fn char_string_kind(mut self, kind: CharStringKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Sets the matrix of the Font DICT of a CID font.
+    ///
+    /// Ignored for other fonts.
+    pub fn font_dict_matrix(mut self, matrix: Matrix) -> Self {
+        self.font_dict_matrix = Some(matrix);
+        self
+    }
+
+    /// Sets whether to build a CID font with the `Adobe-Identity-0` ROS.
+    ///
+    /// All glyphs then use a single Font DICT.
+    pub fn cid(mut self, cid: bool) -> Self {
+        self.cid = cid;
+        self
+    }
+
+    /// Builds the font.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the encoding needs more than 255 supplements, which are used
+    /// for the codes of all glyphs after the first unencoded one.
+    pub fn build(&self) -> Vec<u8> {
+        let mut strings = Strings::default();
+        let mut top_dict = Vec::new();
+        if self.cid {
+            // The ROS has to be the first entry of a CID font.
+            write_int(i32::from(strings.sid("Adobe").0), &mut top_dict);
+            write_int(i32::from(strings.sid("Identity").0), &mut top_dict);
+            write_int(0, &mut top_dict);
+            write_operator(top_dict_operator::ROS, &mut top_dict);
+        }
+
+        write_font_info(&self.font_info, &mut strings, &mut top_dict);
+
+        if self.kind == CharStringKind::Type1 {
+            write_int(1, &mut top_dict);
+            write_operator(top_dict_operator::CHAR_STRING_TYPE, &mut top_dict);
+        }
+
+        if let Some(bbox) = self.font_bbox {
+            for n in [bbox.x_min, bbox.y_min, bbox.x_max, bbox.y_max] {
+                write_number(n, &mut top_dict);
+            }
+            write_operator(top_dict_operator::FONT_BBOX, &mut top_dict);
+        }
+
+        if self.matrix != Matrix::default() {
+            write_matrix(&self.matrix, &mut top_dict);
+        }
+
+        let sids: Vec<StringId> = self
+            .glyphs
+            .iter()
+            .enumerate()
+            .map(|(glyph_id, (name, _))| {
+                if self.cid {
+                    StringId(glyph_id as u16)
+                } else {
+                    strings.sid(name)
+                }
+            })
+            .collect();
+        let char_strings: Vec<&[u8]> = self.glyphs.iter().map(|(_, data)| &data[..]).collect();
+
+        let encoding = if self.encoding.is_empty() || self.cid {
+            None
+        } else {
+            let mut codes = vec![Vec::new(); self.glyphs.len()];
+            for (code, name) in &self.encoding {
+                if let Some(glyph_id) = self.glyphs.iter().position(|(n, _)| n == name) {
+                    codes[glyph_id].push(*code);
+                }
+            }
+
+            Some(write_encoding(&codes, &sids).expect("too many encoding supplements"))
+        };
+
+        let mut private_dict = Vec::new();
+        for (operator, operands) in &self.private_entries {
+            for n in operands {
+                write_number(*n, &mut private_dict);
+            }
+            write_operator(*operator, &mut private_dict);
+        }
+
+        let widths = [
+            (self.default_width, private_dict_operator::DEFAULT_WIDTH),
+            (self.nominal_width, private_dict_operator::NOMINAL_WIDTH),
+        ];
+        for (width, operator) in widths {
+            if width != 0.0 {
+                write_number(width, &mut private_dict);
+                write_operator(operator, &mut private_dict);
+            }
+        }
+
+        if !self.local_subroutines.is_empty() {
+            // The subroutines directly follow the Private DICT.
+            write_fixed_int(private_dict.len() as i32 + 6, &mut private_dict);
+            write_operator(
+                private_dict_operator::LOCAL_SUBROUTINES_OFFSET,
+                &mut private_dict,
+            );
+        }
+
+        // The offsets are written in the five byte form,
+        // so the Top DICT size doesn't depend on them.
+        let mut offsets_len = if self.cid { 26 } else { 23 };
+        if encoding.is_some() {
+            offsets_len += 6;
+        }
+        let mut data = vec![1, 0, 4, 4];
+        write_index(&[self.name.as_bytes()], &mut data);
+        let mut top_dict_index = Vec::new();
+        write_index(
+            &[vec![0; top_dict.len() + offsets_len]],
+            &mut top_dict_index,
+        );
+        let base = data.len() + top_dict_index.len();
+
+        let mut tail = Vec::new();
+        write_index(&strings.strings, &mut tail);
+        write_index(&self.global_subroutines, &mut tail);
+
+        write_fixed_int((base + tail.len()) as i32, &mut top_dict);
+        write_operator(top_dict_operator::CHARSET_OFFSET, &mut top_dict);
+        tail.extend_from_slice(&write_charset(&sids));
+
+        if let Some(encoding) = encoding {
+            write_fixed_int((base + tail.len()) as i32, &mut top_dict);
+            write_operator(top_dict_operator::ENCODING_OFFSET, &mut top_dict);
+            tail.extend_from_slice(&encoding);
+        }
+
+        write_fixed_int((base + tail.len()) as i32, &mut top_dict);
+        write_operator(top_dict_operator::CHAR_STRINGS_OFFSET, &mut top_dict);
+        write_index(&char_strings, &mut tail);
+
+        let private_dict_offset = base + tail.len();
+        tail.extend_from_slice(&private_dict);
+        if !self.local_subroutines.is_empty() {
+            write_index(&self.local_subroutines, &mut tail);
+        }
+
+        if self.cid {
+            let mut font_dict = Vec::new();
+            if let Some(ref matrix) = self.font_dict_matrix {
+                write_matrix(matrix, &mut font_dict);
+            }
+            write_fixed_int(private_dict.len() as i32, &mut font_dict);
+            write_fixed_int(private_dict_offset as i32, &mut font_dict);
+            write_operator(
+                top_dict_operator::PRIVATE_DICT_SIZE_AND_OFFSET,
+                &mut font_dict,
+            );
+
+            write_fixed_int((base + tail.len()) as i32, &mut top_dict);
+            write_operator(top_dict_operator::FD_ARRAY, &mut top_dict);
+            write_index(&[font_dict], &mut tail);
+
+            // FDSelect in format 0.
+            write_fixed_int((base + tail.len()) as i32, &mut top_dict);
+            write_operator(top_dict_operator::FD_SELECT, &mut top_dict);
+            tail.push(0);
+            tail.resize(tail.len() + self.glyphs.len(), 0);
+        } else {
+            write_fixed_int(private_dict.len() as i32, &mut top_dict);
+            write_fixed_int(private_dict_offset as i32, &mut top_dict);
+            write_operator(
+                top_dict_operator::PRIVATE_DICT_SIZE_AND_OFFSET,
+                &mut top_dict,
+            );
+        }
+
+        write_index(&[top_dict], &mut data);
+        data.extend_from_slice(&tail);
+        data
+    }
+}
+
+fn write_matrix(matrix: &Matrix, dict: &mut Vec<u8>) {
+    let Matrix {
+        sx,
+        ky,
+        kx,
+        sy,
+        tx,
+        ty,
+    } = *matrix;
+    for n in [sx, ky, kx, sy, tx, ty] {
+        write_number(n, dict);
+    }
+    write_operator(top_dict_operator::FONT_MATRIX, dict);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FontBuilder;
+    use crate::asm::assemble;
+    use crate::disasm::CharStringKind;
+    use crate::outline::{PathRecorder, PathSegment};
+    use crate::{cff, GlyphId, Matrix};
+
+    fn type2(text: &str) -> Vec<u8> {
+        assemble(text, CharStringKind::Type2).unwrap()
+    }
+
+    #[test]
+    fn subroutines() {
+        let data = FontBuilder::new("Test")
+            .glyph(".notdef", &type2("endchar"))
+            .glyph(
+                "A",
+                &type2("100 0 rmoveto -107 callsubr -107 callgsubr endchar"),
+            )
+            .glyph("B", &type2("10 0 0 rmoveto endchar"))
+            .global_subroutine(&type2("0 50 rlineto return"))
+            .local_subroutine(&type2("50 hlineto return"))
+            .default_width(500.0)
+            .nominal_width(600.0)
+            .matrix(Matrix {
+                sx: 0.002,
+                sy: 0.002,
+                ..Matrix::default()
+            })
+            .build();
+
+        let table = cff::Table::parse(&data).unwrap();
+        assert_eq!(table.name(), Some("Test"));
+        assert!(!table.is_cid());
+        assert_eq!(table.number_of_glyphs(), 3);
+        assert_eq!(table.glyph_name(GlyphId(1)), Some("A"));
+        assert_eq!(table.glyph_index(b'B'), Some(GlyphId(2)));
+        assert_eq!(table.glyph_width(GlyphId(1)), Some(500));
+        assert_eq!(table.glyph_width(GlyphId(2)), Some(610));
+        assert_eq!(table.matrix().sx, 0.002);
+
+        let mut recorder = PathRecorder::new();
+        table.outline(GlyphId(1), &mut recorder).unwrap();
+        assert_eq!(
+            recorder.segments(),
+            [
+                PathSegment::MoveTo(100.0, 0.0),
+                PathSegment::LineTo(150.0, 0.0),
+                PathSegment::LineTo(150.0, 50.0),
+                PathSegment::Close,
+            ]
+        );
+    }
+
+    #[test]
+    fn cid() {
+        let data = FontBuilder::new("Test")
+            .glyph("", &type2("endchar"))
+            .glyph("", &type2("0 0 rmoveto -107 callsubr endchar"))
+            .local_subroutine(&type2("50 50 rlineto return"))
+            .cid(true)
+            .build();
+
+        let table = cff::Table::parse(&data).unwrap();
+        assert!(table.is_cid());
+        let ros = table.ros().unwrap();
+        assert_eq!(
+            (ros.registry, ros.ordering, ros.supplement),
+            ("Adobe", "Identity", 0)
+        );
+        assert_eq!(table.glyph_index_by_cid(1), Some(GlyphId(1)));
+
+        let mut recorder = PathRecorder::new();
+        table.outline(GlyphId(1), &mut recorder).unwrap();
+        assert_eq!(recorder.segments()[1], PathSegment::LineTo(50.0, 50.0));
+    }
+
+    #[test]
+    fn cid_font_dict_matrix() {
+        let data = FontBuilder::new("Test")
+            .glyph("", &type2("endchar"))
+            .glyph("", &type2("0 0 rmoveto 1000 500 rlineto endchar"))
+            .matrix(Matrix::identity())
+            .font_dict_matrix(Matrix::default())
+            .cid(true)
+            .build();
+
+        let table = cff::Table::parse(&data).unwrap();
+        assert_eq!(table.matrix(), Matrix::identity());
+        assert_eq!(table.glyph_matrix(GlyphId(1)), Matrix::default());
+
+        // The outline is in the units of the Font DICT.
+        let mut recorder = PathRecorder::new();
+        let bbox = table.outline(GlyphId(1), &mut recorder).unwrap();
+        assert_eq!((bbox.x_max, bbox.y_max), (1000, 500));
+        assert_eq!(recorder.segments()[1], PathSegment::LineTo(1000.0, 500.0));
+
+        let (x, y) = table.glyph_matrix(GlyphId(1)).apply(1000.0, 500.0);
+        assert!((x - 1.0).abs() < 1e-6 && (y - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn type1_char_strings() {
+        let type1 = |text| assemble(text, CharStringKind::Type1).unwrap();
+        let data = FontBuilder::new("Test")
+            .glyph(".notdef", &type1("0 250 hsbw endchar"))
+            .glyph(
+                "A",
+                &type1("20 300 hsbw 0 100 rmoveto 100 hlineto closepath endchar"),
+            )
+            .char_string_kind(CharStringKind::Type1)
+            .build();
+
+        let table = cff::Table::parse(&data).unwrap();
+        assert_eq!(table.glyph_width(GlyphId(1)), Some(300));
+
+        let mut recorder = PathRecorder::new();
+        table.outline(GlyphId(1), &mut recorder).unwrap();
+        assert_eq!(
+            recorder.segments()[..2],
+            [
+                PathSegment::MoveTo(20.0, 100.0),
+                PathSegment::LineTo(120.0, 100.0),
+            ]
+        );
+    }
+}
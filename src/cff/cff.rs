@@ -13,26 +13,52 @@ use core::ops::Range;
 use super::charset::{parse_charset, Charset};
 use super::charstring::CharStringParser;
 use super::dict::DictionaryParser;
-use super::encoding::{parse_encoding, Encoding, STANDARD_ENCODING};
-use super::index::{parse_index, skip_index, Index};
+use super::encoding::{parse_encoding, Encoding, EncodingTable, STANDARD_ENCODING};
+use super::index::{parse_index, Index};
 use super::parser::{LazyArray16, NumFrom, Stream, TryNumFrom};
 use super::std_names::STANDARD_NAMES;
-use super::{calc_subroutine_bias, conv_subroutine_index, CFFError, IsEven, StringId};
+use super::{calc_subroutine_bias, conv_subroutine_index, f32_abs, CFFError, IsEven, StringId};
 use crate::argstack::ArgumentsStack;
+use crate::type1;
+use crate::type1::charstring::CharStringResolver;
 use crate::{Builder, DummyOutline, GlyphId, Matrix, OutlineBuilder, Rect, RectF};
 
+#[cfg(test)]
+use crate::EncodingKind;
+
 // Limits according to the Adobe Technical Note #5176, chapter 4 DICT Data.
 const MAX_OPERANDS_LEN: usize = 48;
 
 // Limits according to the Adobe Technical Note #5177 Appendix B.
 const STACK_LIMIT: u8 = 10;
 const MAX_ARGUMENTS_STACK_LEN: usize = 48;
+const TRANSIENT_ARRAY_LEN: usize = 32;
 
-const TWO_BYTE_OPERATOR_MARK: u8 = 12;
+pub(super) const TWO_BYTE_OPERATOR_MARK: u8 = 12;
 
 /// Enumerates some operators defined in the Adobe Technical Note #5177.
-mod operator {
+pub(crate) mod operator {
     pub const DOTSECTION: u8 = 0;
+    pub const AND: u8 = 3;
+    pub const OR: u8 = 4;
+    pub const NOT: u8 = 5;
+    pub const ABS: u8 = 9;
+    pub const ADD: u8 = 10;
+    pub const SUB: u8 = 11;
+    pub const DIV: u8 = 12;
+    pub const NEG: u8 = 14;
+    pub const EQ: u8 = 15;
+    pub const DROP: u8 = 18;
+    pub const PUT: u8 = 20;
+    pub const GET: u8 = 21;
+    pub const IF_ELSE: u8 = 22;
+    pub const RANDOM: u8 = 23;
+    pub const MUL: u8 = 24;
+    pub const SQRT: u8 = 26;
+    pub const DUP: u8 = 27;
+    pub const EXCH: u8 = 28;
+    pub const INDEX: u8 = 29;
+    pub const ROLL: u8 = 30;
     pub const HORIZONTAL_STEM: u8 = 1;
     pub const VERTICAL_STEM: u8 = 3;
     pub const VERTICAL_MOVE_TO: u8 = 4;
@@ -66,11 +92,24 @@ mod operator {
 
 /// Enumerates some operators defined in the Adobe Technical Note #5176,
 /// Table 9 Top DICT Operator Entries
-mod top_dict_operator {
+pub(super) mod top_dict_operator {
+    pub const VERSION: u16 = 0;
+    pub const NOTICE: u16 = 1;
+    pub const FULL_NAME: u16 = 2;
+    pub const FAMILY_NAME: u16 = 3;
+    pub const WEIGHT: u16 = 4;
+    pub const FONT_BBOX: u16 = 5;
+    pub const UNIQUE_ID: u16 = 13;
     pub const CHARSET_OFFSET: u16 = 15;
     pub const ENCODING_OFFSET: u16 = 16;
     pub const CHAR_STRINGS_OFFSET: u16 = 17;
     pub const PRIVATE_DICT_SIZE_AND_OFFSET: u16 = 18;
+    pub const COPYRIGHT: u16 = 1200;
+    pub const IS_FIXED_PITCH: u16 = 1201;
+    pub const ITALIC_ANGLE: u16 = 1202;
+    pub const UNDERLINE_POSITION: u16 = 1203;
+    pub const UNDERLINE_THICKNESS: u16 = 1204;
+    pub const CHAR_STRING_TYPE: u16 = 1206;
     pub const FONT_MATRIX: u16 = 1207;
     pub const ROS: u16 = 1230;
     pub const FD_ARRAY: u16 = 1236;
@@ -79,10 +118,23 @@ mod top_dict_operator {
 
 /// Enumerates some operators defined in the Adobe Technical Note #5176,
 /// Table 23 Private DICT Operators
-mod private_dict_operator {
+pub(super) mod private_dict_operator {
+    pub const BLUE_VALUES: u16 = 6;
+    pub const OTHER_BLUES: u16 = 7;
+    pub const FAMILY_BLUES: u16 = 8;
+    pub const FAMILY_OTHER_BLUES: u16 = 9;
+    pub const STD_HW: u16 = 10;
+    pub const STD_VW: u16 = 11;
     pub const LOCAL_SUBROUTINES_OFFSET: u16 = 19;
     pub const DEFAULT_WIDTH: u16 = 20;
     pub const NOMINAL_WIDTH: u16 = 21;
+    pub const BLUE_SCALE: u16 = 1209;
+    pub const BLUE_SHIFT: u16 = 1210;
+    pub const BLUE_FUZZ: u16 = 1211;
+    pub const STEM_SNAP_H: u16 = 1212;
+    pub const STEM_SNAP_V: u16 = 1213;
+    pub const FORCE_BOLD: u16 = 1214;
+    pub const LANGUAGE_GROUP: u16 = 1217;
 }
 
 /// Enumerates Charset IDs defined in the Adobe Technical Note #5176, Table 22
@@ -106,18 +158,20 @@ pub(crate) enum FontKind<'a> {
 
 #[derive(Clone, Copy, Default, Debug)]
 pub(crate) struct SIDMetadata<'a> {
-    local_subrs: Index<'a>,
+    pub(super) local_subrs: Index<'a>,
+    // The Private DICT data. Empty if the font has no Private DICT.
+    pub(super) private_dict: &'a [u8],
     /// Can be zero.
     default_width: f32,
     /// Can be zero.
     nominal_width: f32,
-    encoding: Encoding<'a>,
+    pub(super) encoding: EncodingTable<'a>,
 }
 
 #[derive(Clone, Copy, Default, Debug)]
 pub(crate) struct CIDMetadata<'a> {
-    fd_array: Index<'a>,
-    fd_select: FDSelect<'a>,
+    pub(super) fd_array: Index<'a>,
+    pub(super) fd_select: FDSelect<'a>,
 }
 
 #[derive(Default)]
@@ -126,20 +180,17 @@ struct TopDict {
     encoding_offset: Option<usize>,
     char_strings_offset: usize,
     private_dict_range: Option<Range<usize>>,
-    matrix: Matrix,
+    char_string_type: Option<u8>,
+    matrix: Option<Matrix>,
     has_ros: bool,
+    ros: Option<(StringId, StringId, u16)>,
     fd_array_offset: Option<usize>,
     fd_select_offset: Option<usize>,
 }
 
-fn parse_top_dict(s: &mut Stream) -> Option<TopDict> {
+fn parse_top_dict(data: &[u8]) -> Option<TopDict> {
     let mut top_dict = TopDict::default();
 
-    let index = parse_index::<u16>(s)?;
-
-    // The Top DICT INDEX should have only one dictionary.
-    let data = index.get(0)?;
-
     let mut operands_buffer = [0.0; MAX_OPERANDS_LEN];
     let mut dict_parser = DictionaryParser::new(data, &mut operands_buffer);
     while let Some(operator) = dict_parser.parse_next() {
@@ -156,22 +207,26 @@ fn parse_top_dict(s: &mut Stream) -> Option<TopDict> {
             top_dict_operator::PRIVATE_DICT_SIZE_AND_OFFSET => {
                 top_dict.private_dict_range = dict_parser.parse_range();
             }
+            top_dict_operator::CHAR_STRING_TYPE => {
+                top_dict.char_string_type = dict_parser.parse_number().map(|n| n as u8);
+            }
             top_dict_operator::FONT_MATRIX => {
                 dict_parser.parse_operands()?;
-                let operands = dict_parser.operands();
-                if operands.len() == 6 {
-                    top_dict.matrix = Matrix {
-                        sx: operands[0] as f32,
-                        ky: operands[1] as f32,
-                        kx: operands[2] as f32,
-                        sy: operands[3] as f32,
-                        tx: operands[4] as f32,
-                        ty: operands[5] as f32,
-                    };
-                }
+                top_dict.matrix = parse_font_matrix(dict_parser.operands());
             }
             top_dict_operator::ROS => {
                 top_dict.has_ros = true;
+                top_dict.ros =
+                    dict_parser
+                        .parse_operands()
+                        .and_then(|_| match *dict_parser.operands() {
+                            [registry, ordering, supplement] => Some((
+                                StringId(u16::try_from(registry as i32).ok()?),
+                                StringId(u16::try_from(ordering as i32).ok()?),
+                                u16::try_from(supplement as i32).ok()?,
+                            )),
+                            _ => None,
+                        });
             }
             top_dict_operator::FD_ARRAY => {
                 top_dict.fd_array_offset = dict_parser.parse_offset();
@@ -186,10 +241,44 @@ fn parse_top_dict(s: &mut Stream) -> Option<TopDict> {
     Some(top_dict)
 }
 
+// Returns the operands of the first DICT entry with an operator.
+fn find_dict_operands(data: &[u8], operator: u16) -> Option<Vec<f64>> {
+    let mut operands_buffer = [0.0; MAX_OPERANDS_LEN];
+    let mut dict_parser = DictionaryParser::new(data, &mut operands_buffer);
+    while let Some(op) = dict_parser.parse_next() {
+        if op.get() == operator {
+            dict_parser.parse_operands()?;
+            return Some(dict_parser.operands().to_vec());
+        }
+    }
+
+    None
+}
+
+fn parse_font_matrix(operands: &[f64]) -> Option<Matrix> {
+    if operands.len() == 6 {
+        Some(Matrix {
+            sx: operands[0] as f32,
+            ky: operands[1] as f32,
+            kx: operands[2] as f32,
+            sy: operands[3] as f32,
+            tx: operands[4] as f32,
+            ty: operands[5] as f32,
+        })
+    } else {
+        None
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn parse_first_top_dict(data: &[u8]) -> Option<TopDict> {
+        let index = parse_index::<u16>(&mut Stream::new(data))?;
+        parse_top_dict(index.get(0)?)
+    }
+
     #[test]
     fn private_dict_size_overflow() {
         let data = &[
@@ -202,7 +291,7 @@ mod tests {
             0x12, // operator: 18 (private)
         ];
 
-        let top_dict = parse_top_dict(&mut Stream::new(data)).unwrap();
+        let top_dict = parse_first_top_dict(data).unwrap();
         assert_eq!(top_dict.private_dict_range, Some(2147483647..4294967294));
     }
 
@@ -218,7 +307,7 @@ mod tests {
             0x11, // operator: 17 (char_string)
         ];
 
-        assert!(parse_top_dict(&mut Stream::new(data)).is_none());
+        assert!(parse_first_top_dict(data).is_none());
     }
 
     #[test]
@@ -233,7 +322,7 @@ mod tests {
             0x11, // operator: 17 (char_string)
         ];
 
-        assert!(parse_top_dict(&mut Stream::new(data)).is_none());
+        assert!(parse_first_top_dict(data).is_none());
     }
 
     #[test]
@@ -249,19 +338,438 @@ mod tests {
             0x12, // operator: 18 (private)
         ];
 
-        let top_dict = parse_top_dict(&mut Stream::new(data)).unwrap();
+        let top_dict = parse_first_top_dict(data).unwrap();
         assert!(top_dict.private_dict_range.is_none());
     }
+
+    #[test]
+    fn font_dict_with_matrix() {
+        let data = &[
+            0x1E, 0x0A, 0x00, 0x04, 0x88, 0x28, 0x12, 0x5F, // 0.00048828125
+            0x8B, // 0
+            0x8B, // 0
+            0x1E, 0x0A, 0x00, 0x04, 0x88, 0x28, 0x12, 0x5F, // 0.00048828125
+            0x8B, // 0
+            0x8B, // 0
+            0x0C, 0x07, // operator: 1207 (font matrix)
+            0x8C, // length: 1
+            0x8D, // offset: 2
+            0x12, // operator: 18 (private)
+        ];
+
+        let font_dict = parse_font_dict(data);
+        assert_eq!(font_dict.private_dict_range, Some(2..3));
+
+        let matrix = font_dict.matrix.unwrap();
+        assert_eq!(matrix.sx, 1.0 / 2048.0);
+        assert_eq!(matrix.sy, 1.0 / 2048.0);
+
+        let combined = matrix.multiply(&Matrix {
+            sx: 2.0,
+            ky: 0.0,
+            kx: 0.0,
+            sy: 2.0,
+            tx: 0.0,
+            ty: 0.0,
+        });
+        assert_eq!(combined.sx, 1.0 / 1024.0);
+        assert_eq!(combined.sy, 1.0 / 1024.0);
+    }
+
+    #[test]
+    fn font_set_with_deleted_font() {
+        let data = &[
+            0x01, 0x00, 0x04, 0x01, // header
+            // Name INDEX
+            0x00, 0x02, // count: 2
+            0x01, // offset size: 1
+            0x01, 0x03, 0x05, // offsets
+            0x00, 0x41, // deleted font
+            0x42, 0x31, // B1
+            // Top DICT INDEX
+            0x00, 0x02, // count: 2
+            0x01, // offset size: 1
+            0x01, 0x03, 0x05, // offsets
+            0xA7, 0x11, // charstrings offset: 28
+            0xA7, 0x11, // charstrings offset: 28
+            // String INDEX
+            0x00, 0x00, // count: 0
+            // Global Subrs INDEX
+            0x00, 0x00, // count: 0
+            // CharStrings INDEX
+            0x00, 0x01, // count: 1
+            0x01, // offset size: 1
+            0x01, 0x02, // offsets
+            0x0E, // endchar
+        ];
+
+        let set = FontSet::parse(data).unwrap();
+        assert_eq!(set.len(), 2);
+        assert!(set.is_deleted(0));
+        assert_eq!(set.name(0), None);
+        assert_eq!(set.name(1), Some("B1"));
+        assert_eq!(set.names().collect::<Vec<_>>(), vec![(1, "B1")]);
+        assert!(set.font(0).is_none());
+        assert_eq!(set.font_by_name("B1").unwrap().number_of_glyphs(), 1);
+        assert!(Table::parse(data).is_some());
+    }
+
+    fn single_glyph_font(char_string: &[u8]) -> Vec<u8> {
+        let mut data = vec![
+            0x01, 0x00, 0x04, 0x01, // header
+            // Name INDEX
+            0x00, 0x01, // count: 1
+            0x01, // offset size: 1
+            0x01, 0x02, // offsets
+            0x41, // A
+            // Top DICT INDEX
+            0x00, 0x01, // count: 1
+            0x01, // offset size: 1
+            0x01, 0x03, // offsets
+            0xA0, 0x11, // charstrings offset: 21
+            // String INDEX
+            0x00, 0x00, // count: 0
+            // Global Subrs INDEX
+            0x00, 0x00, // count: 0
+            // CharStrings INDEX
+            0x00, 0x01, // count: 1
+            0x01, // offset size: 1
+            0x01, // offsets
+        ];
+        data.push(char_string.len() as u8 + 1);
+        data.extend_from_slice(char_string);
+        data
+    }
+
+    #[test]
+    fn arithmetic_and_storage_operators() {
+        let data = single_glyph_font(&[
+            0x90, 0x8B, 0x0C, 0x14, // 5 0 put
+            0x8B, 0x0C, 0x15, // 0 get
+            0x0C, 0x1B, // dup
+            0x0C, 0x0A, // add
+            0x8E, 0x8F, 0x8C, 0x8D, 0x0C, 0x16, // 3 4 1 2 ifelse
+            0x15, // rmoveto
+            0x8C, 0x8D, 0xA9, 0x8E, 0x8C, 0x0C, 0x1E, // 1 2 30 3 1 roll
+            0x0C, 0x12, 0x0C, 0x12, // drop drop
+            0x8C, 0x8C, 0x0C, 0x0F, // 1 1 eq
+            0x8B, 0x0C, 0x05, // 0 not
+            0x0C, 0x03, // and
+            0x8B, 0x0C, 0x04, // 0 or
+            0x0C, 0x0A, // add
+            0x06, // hlineto
+            0x9B, 0x8B, 0x0C, 0x1D, // 16 0 index
+            0x0C, 0x0B, // sub
+            0x94, 0x0C, 0x1A, // 9 sqrt
+            0x0C, 0x0A, // add
+            0x0C, 0x0E, // neg
+            0x0C, 0x09, // abs
+            0x07, // vlineto
+            0x0E, // endchar
+        ]);
+
+        let table = Table::parse(&data).unwrap();
+        let rect = table.outline(GlyphId(0), &mut DummyOutline).unwrap();
+        assert_eq!(
+            rect,
+            Rect {
+                x_min: 10,
+                y_min: 3,
+                x_max: 41,
+                y_max: 6
+            }
+        );
+    }
+
+    #[test]
+    fn division_by_zero() {
+        let data = single_glyph_font(&[
+            0x95, 0x8B, 0x0C, 0x0C, // 10 0 div
+            0x9F, 0x15, // 20 rmoveto
+            0xA9, 0x06, // 30 hlineto
+            0x0E, // endchar
+        ]);
+
+        let table = Table::parse(&data).unwrap();
+        let rect = table.outline(GlyphId(0), &mut DummyOutline).unwrap();
+        assert_eq!(
+            rect,
+            Rect {
+                x_min: 0,
+                y_min: 20,
+                x_max: 30,
+                y_max: 20
+            }
+        );
+    }
+
+    #[test]
+    fn invalid_transient_array_index() {
+        let data = single_glyph_font(&[
+            0x90, 0xCB, 0x0C, 0x14, // 5 64 put
+            0x0E, // endchar
+        ]);
+
+        let table = Table::parse(&data).unwrap();
+        assert_eq!(
+            table.outline(GlyphId(0), &mut DummyOutline),
+            Err(CFFError::InvalidTransientArrayIndex)
+        );
+    }
+
+    #[test]
+    fn type1_char_strings() {
+        let char_string = [
+            0x8B, 0xF8, 0x88, 0x0D, // 0 500 hsbw
+            0x8B, 0x0A, // 0 callsubr
+            0xA9, 0x06, // 30 hlineto
+            0x09, // closepath
+            0x0E, // endchar
+        ];
+        let subr = [
+            0x95, 0x9F, 0x15, // 10 20 rmoveto
+            0x0B, // return
+        ];
+
+        let char_strings_offset = 35u8;
+        let private_dict_offset = char_strings_offset + 5 + char_string.len() as u8;
+
+        let mut data = vec![
+            0x01,
+            0x00,
+            0x04,
+            0x01, // header
+            // Name INDEX
+            0x00,
+            0x01, // count: 1
+            0x01, // offset size: 1
+            0x01,
+            0x02, // offsets
+            0x41, // A
+            // Top DICT INDEX
+            0x00,
+            0x01, // count: 1
+            0x01, // offset size: 1
+            0x01,
+            0x11, // offsets
+            0x8C,
+            0x0C,
+            0x06, // charstring type: 1
+            0x1D,
+            0x00,
+            0x00,
+            0x00,
+            char_strings_offset,
+            0x11, // charstrings offset
+            0x8D,
+            0x1D,
+            0x00,
+            0x00,
+            0x00,
+            private_dict_offset,
+            0x12, // private: 2
+            // String INDEX
+            0x00,
+            0x00, // count: 0
+            // Global Subrs INDEX
+            0x00,
+            0x00, // count: 0
+            // CharStrings INDEX
+            0x00,
+            0x01, // count: 1
+            0x01, // offset size: 1
+            0x01, // offsets
+        ];
+        assert_eq!(data.len() + 1, usize::from(char_strings_offset) + 5);
+        data.push(char_string.len() as u8 + 1);
+        data.extend_from_slice(&char_string);
+        data.extend_from_slice(&[
+            0x8D, 0x13, // subrs offset: 2
+            // Subrs INDEX
+            0x00, 0x01, // count: 1
+            0x01, // offset size: 1
+            0x01, // offsets
+        ]);
+        data.push(subr.len() as u8 + 1);
+        data.extend_from_slice(&subr);
+
+        let table = Table::parse(&data).unwrap();
+        let rect = table.outline(GlyphId(0), &mut DummyOutline).unwrap();
+        assert_eq!(
+            rect,
+            Rect {
+                x_min: 10,
+                y_min: 20,
+                x_max: 40,
+                y_max: 20
+            }
+        );
+        assert_eq!(table.glyph_width(GlyphId(0)), Some(500));
+    }
+
+    #[test]
+    fn custom_encoding_with_supplements() {
+        let data = &[
+            0x01, 0x00, 0x04, 0x01, // header
+            // Name INDEX
+            0x00, 0x01, // count: 1
+            0x01, // offset size: 1
+            0x01, 0x02, // offsets
+            0x41, // A
+            // Top DICT INDEX
+            0x00, 0x01, // count: 1
+            0x01, // offset size: 1
+            0x01, 0x13, // offsets
+            0x1D, 0x00, 0x00, 0x00, 0x25, 0x0F, // charset offset: 37
+            0x1D, 0x00, 0x00, 0x00, 0x2A, 0x10, // encoding offset: 42
+            0x1D, 0x00, 0x00, 0x00, 0x32, 0x11, // charstrings offset: 50
+            // String INDEX
+            0x00, 0x00, // count: 0
+            // Global Subrs INDEX
+            0x00, 0x00, // count: 0
+            // Charset
+            0x00, // format: 0
+            0x00, 0x22, // A
+            0x00, 0x23, // B
+            // Encoding
+            0x80, // format: 0 with supplements
+            0x02, // count: 2
+            0x41, 0x42, // codes
+            0x01, // supplements count: 1
+            0x61, 0x00, 0x22, // a -> A
+            // CharStrings INDEX
+            0x00, 0x03, // count: 3
+            0x01, // offset size: 1
+            0x01, 0x02, 0x03, 0x04, // offsets
+            0x0E, 0x0E, 0x0E, // endchar
+        ];
+
+        let table = Table::parse(data).unwrap();
+        let encoding = table.encoding().unwrap();
+        assert_eq!(encoding.kind(), EncodingKind::Custom);
+        assert!(encoding.has_supplements());
+        assert_eq!(encoding.glyph_index(0x41), Some(GlyphId(1)));
+        assert_eq!(encoding.glyph_index(0x42), Some(GlyphId(2)));
+        assert_eq!(encoding.glyph_index(0x61), Some(GlyphId(1)));
+        assert_eq!(encoding.glyph_index(0x20), None);
+        assert_eq!(
+            encoding.codes(GlyphId(1)).collect::<Vec<_>>(),
+            vec![0x41, 0x61]
+        );
+        assert_eq!(encoding.iter().count(), 3);
+
+        let char_map = crate::cff::CharMap::new(&table);
+        assert_eq!(char_map.glyph_for_char('A'), Some(GlyphId(1)));
+        assert_eq!(char_map.glyph_for_char('B'), Some(GlyphId(2)));
+        assert_eq!(char_map.glyph_for_char('a'), None);
+    }
+
+    #[test]
+    fn cid_font_with_ros() {
+        let data = &[
+            0x01, 0x00, 0x04, 0x01, // header
+            // Name INDEX
+            0x00, 0x01, // count: 1
+            0x01, // offset size: 1
+            0x01, 0x02, // offsets
+            0x41, // A
+            // Top DICT INDEX
+            0x00, 0x01, // count: 1
+            0x01, // offset size: 1
+            0x01, 0x24, // offsets
+            0x1C, 0x01, 0x87, 0x1C, 0x01, 0x88, 0x8B, 0x0C, 0x1E, // ROS: Adobe Japan1 0
+            0x1D, 0x00, 0x00, 0x00, 0x45, 0x0F, // charset offset: 69
+            0x1D, 0x00, 0x00, 0x00, 0x66, 0x11, // charstrings offset: 102
+            0x1D, 0x00, 0x00, 0x00, 0x4B, 0x0C, 0x24, // FDArray offset: 75
+            0x1D, 0x00, 0x00, 0x00, 0x48, 0x0C, 0x25, // FDSelect offset: 72
+            // String INDEX
+            0x00, 0x02, // count: 2
+            0x01, // offset size: 1
+            0x01, 0x06, 0x0C, // offsets
+            0x41, 0x64, 0x6F, 0x62, 0x65, // Adobe
+            0x4A, 0x61, 0x70, 0x61, 0x6E, 0x31, // Japan1
+            // Global Subrs INDEX
+            0x00, 0x00, // count: 0
+            // Charset
+            0x00, // format: 0
+            0x00, 0x22, // CID 34
+            // FDSelect
+            0x00, // format: 0
+            0x00, 0x00, // FD indices
+            // FDArray
+            0x00, 0x01, // count: 1
+            0x01, // offset size: 1
+            0x01, 0x17, // offsets
+            0x1E, 0x0A, 0x00, 0x04, 0x88, 0x28, 0x12, 0x5F, // 0.00048828125
+            0x8B, // 0
+            0x8B, // 0
+            0x1E, 0x0A, 0x00, 0x04, 0x88, 0x28, 0x12, 0x5F, // 0.00048828125
+            0x8B, // 0
+            0x8B, // 0
+            0x0C, 0x07, // operator: 1207 (font matrix)
+            // CharStrings INDEX
+            0x00, 0x02, // count: 2
+            0x01, // offset size: 1
+            0x01, 0x02, 0x0D, // offsets
+            0x0E, // endchar
+            0x8B, 0x8B, 0x15, // 0 0 rmoveto
+            0x1C, 0x08, 0x00, 0x1C, 0x08, 0x00, 0x05, // 2048 2048 rlineto
+            0x0E, // endchar
+        ];
+
+        let table = Table::parse(data).unwrap();
+        assert!(table.is_cid());
+        assert_eq!(
+            table.ros(),
+            Some(Ros {
+                registry: "Adobe",
+                ordering: "Japan1",
+                supplement: 0
+            })
+        );
+        assert_eq!(table.glyph_cid(GlyphId(1)), Some(34));
+        assert_eq!(table.matrix().sx, 0.001);
+        // The Top DICT has no FontMatrix, so the Font DICT one is used as is.
+        assert_eq!(table.glyph_matrix(GlyphId(1)).sx, 1.0 / 2048.0);
+
+        // The outline is in the units of the Font DICT.
+        let bbox = table.outline(GlyphId(1), &mut DummyOutline).unwrap();
+        assert_eq!(
+            bbox,
+            Rect {
+                x_min: 0,
+                y_min: 0,
+                x_max: 2048,
+                y_max: 2048
+            }
+        );
+    }
+
+    #[test]
+    fn random_is_deterministic() {
+        let data = single_glyph_font(&[
+            0x0C, 0x17, // random
+            0x0C, 0x17, // random
+            0x15, // rmoveto
+            0x8C, 0x06, // 1 hlineto
+            0x0E, // endchar
+        ]);
+
+        let table = Table::parse(&data).unwrap();
+        let rect = table.outline(GlyphId(0), &mut DummyOutline);
+        assert!(rect.is_ok());
+        assert_eq!(rect, table.outline(GlyphId(0), &mut DummyOutline));
+    }
 }
 
 #[derive(Default, Debug)]
-struct PrivateDict {
-    local_subroutines_offset: Option<usize>,
+pub(super) struct PrivateDict {
+    pub(super) local_subroutines_offset: Option<usize>,
     default_width: Option<f32>,
     nominal_width: Option<f32>,
 }
 
-fn parse_private_dict(data: &[u8]) -> PrivateDict {
+pub(super) fn parse_private_dict(data: &[u8]) -> PrivateDict {
     let mut dict = PrivateDict::default();
     let mut operands_buffer = [0.0; MAX_OPERANDS_LEN];
     let mut dict_parser = DictionaryParser::new(data, &mut operands_buffer);
@@ -278,16 +786,39 @@ fn parse_private_dict(data: &[u8]) -> PrivateDict {
     dict
 }
 
-fn parse_font_dict(data: &[u8]) -> Option<Range<usize>> {
+/// A Font DICT from the FDArray of a CID font.
+#[derive(Default, Debug)]
+pub(super) struct FontDict {
+    pub(super) private_dict_range: Option<Range<usize>>,
+    matrix: Option<Matrix>,
+}
+
+pub(super) fn parse_font_dict(data: &[u8]) -> FontDict {
+    let mut dict = FontDict::default();
     let mut operands_buffer = [0.0; MAX_OPERANDS_LEN];
     let mut dict_parser = DictionaryParser::new(data, &mut operands_buffer);
     while let Some(operator) = dict_parser.parse_next() {
-        if operator.get() == top_dict_operator::PRIVATE_DICT_SIZE_AND_OFFSET {
-            return dict_parser.parse_range();
+        match operator.get() {
+            top_dict_operator::PRIVATE_DICT_SIZE_AND_OFFSET => {
+                dict.private_dict_range = dict_parser.parse_range();
+            }
+            top_dict_operator::FONT_MATRIX => {
+                dict.matrix = dict_parser
+                    .parse_operands()
+                    .and_then(|_| parse_font_matrix(dict_parser.operands()));
+            }
+            _ => {}
         }
     }
 
-    None
+    dict
+}
+
+/// Returns the Font DICT used by a glyph in a CID font.
+fn parse_cid_font_dict(glyph_id: GlyphId, cid: &CIDMetadata) -> Option<FontDict> {
+    let font_dict_index = cid.fd_select.font_dict_index(glyph_id)?;
+    let font_dict_data = cid.fd_array.get(u32::from(font_dict_index))?;
+    Some(parse_font_dict(font_dict_data))
 }
 
 /// In CID fonts, to get local subroutines we have to:
@@ -301,9 +832,7 @@ fn parse_cid_local_subrs<'a>(
     glyph_id: GlyphId,
     cid: &CIDMetadata,
 ) -> Option<Index<'a>> {
-    let font_dict_index = cid.fd_select.font_dict_index(glyph_id)?;
-    let font_dict_data = cid.fd_array.get(u32::from(font_dict_index))?;
-    let private_dict_range = parse_font_dict(font_dict_data)?;
+    let private_dict_range = parse_cid_font_dict(glyph_id, cid)?.private_dict_range?;
     let private_dict_data = data.get(private_dict_range.clone())?;
     let private_dict = parse_private_dict(private_dict_data);
     let subroutines_offset = private_dict.local_subroutines_offset?;
@@ -316,14 +845,48 @@ fn parse_cid_local_subrs<'a>(
     parse_index::<u16>(&mut s)
 }
 
+/// A hint of a Type 2 charstring.
+#[derive(Clone, PartialEq, Debug)]
+pub(crate) enum Hint {
+    /// A stem, with an absolute position.
+    Stem {
+        horizontal: bool,
+        position: f32,
+        width: f32,
+    },
+    /// A `hintmask`, with one bit per stem in the order they were defined.
+    Mask(Vec<u8>),
+}
+
 struct CharStringParserContext<'a> {
     metadata: &'a Table<'a>,
     width: Option<f32>,
     stems_len: u32,
+    // The hints and the number of path segments before them, if requested.
+    hints: Option<Vec<(usize, Hint)>>,
     has_endchar: bool,
     has_seac: bool,
     glyph_id: GlyphId, // Required to parse local subroutine in CID fonts.
     local_subrs: Option<Index<'a>>,
+    transient_array: [f32; TRANSIENT_ARRAY_LEN],
+    random_state: u32,
+}
+
+impl CharStringParserContext<'_> {
+    /// Returns a pseudo-random number in the (0, 1] range.
+    ///
+    /// The generator is seeded with the glyph ID,
+    /// so outlining the same glyph always produces the same result.
+    fn next_random(&mut self) -> f32 {
+        // xorshift32
+        let mut x = self.random_state;
+        x ^= x << 13;
+        x ^= x >> 17;
+        x ^= x << 5;
+        self.random_state = x;
+
+        ((x >> 8) as f32 + 1.0) / (1 << 24) as f32
+    }
 }
 
 fn parse_char_string(
@@ -332,6 +895,7 @@ fn parse_char_string(
     glyph_id: GlyphId,
     width_only: bool,
     builder: &mut dyn OutlineBuilder,
+    hints: Option<&mut Vec<(usize, Hint)>>,
 ) -> Result<(Rect, Option<f32>), CFFError> {
     let local_subrs = match metadata.kind {
         FontKind::SID(ref sid) => Some(sid.local_subrs),
@@ -342,15 +906,20 @@ fn parse_char_string(
         metadata,
         width: None,
         stems_len: 0,
+        hints: hints.as_ref().map(|_| Vec::new()),
         has_endchar: false,
         has_seac: false,
         glyph_id,
         local_subrs,
+        transient_array: [0.0; TRANSIENT_ARRAY_LEN],
+        // xorshift must not be seeded with zero.
+        random_state: u32::from(glyph_id.0) | 0x1000_0000,
     };
 
     let mut inner_builder = Builder {
         builder,
         bbox: RectF::new(),
+        segments: 0,
     };
 
     let stack = ArgumentsStack {
@@ -385,9 +954,43 @@ fn parse_char_string(
     }
 
     let rect = bbox.to_rect().ok_or(CFFError::BboxOverflow)?;
+
+    // The hints of `seac` components don't apply to the composite glyph.
+    if let (Some(hints), Some(ctx_hints)) = (hints, ctx.hints) {
+        if !ctx.has_seac {
+            *hints = ctx_hints;
+        }
+    }
+
     Ok((rect, ctx.width))
 }
 
+// Records the stems on the stack, starting at `offset`.
+fn record_stems(
+    ctx: &mut CharStringParserContext,
+    p: &CharStringParser,
+    offset: usize,
+    horizontal: bool,
+) {
+    if let Some(ref mut hints) = ctx.hints {
+        // Each stem is relative to the end of the previous one.
+        let mut end = 0.0;
+        let mut i = offset;
+        while i + 1 < p.stack.len() {
+            let position = end + p.stack.at(i);
+            let width = p.stack.at(i + 1);
+            end = position + width;
+            let stem = Hint::Stem {
+                horizontal,
+                position,
+                width,
+            };
+            hints.push((p.builder.segments, stem));
+            i += 2;
+        }
+    }
+}
+
 fn _parse_char_string(
     ctx: &mut CharStringParserContext,
     char_string: &[u8],
@@ -421,7 +1024,11 @@ fn _parse_char_string(
 
                 ctx.stems_len += len as u32 >> 1;
 
-                // We are ignoring the hint operators.
+                let horizontal = matches!(
+                    op,
+                    operator::HORIZONTAL_STEM | operator::HORIZONTAL_STEM_HINT_MASK
+                );
+                record_stems(ctx, p, p.stack.len() - len, horizontal);
                 p.stack.clear();
             }
             operator::VERTICAL_MOVE_TO => {
@@ -489,7 +1096,6 @@ fn _parse_char_string(
                 break;
             }
             TWO_BYTE_OPERATOR_MARK => {
-                // flex
                 let op2 = s.read::<u8>().ok_or(CFFError::ReadOutOfBounds)?;
                 match op2 {
                     operator::HFLEX => p.parse_hflex()?,
@@ -497,6 +1103,46 @@ fn _parse_char_string(
                     operator::HFLEX1 => p.parse_hflex1()?,
                     operator::FLEX1 => p.parse_flex1()?,
                     operator::DOTSECTION => {}
+                    // Arithmetic, storage and conditional operators.
+                    // Adobe Technical Note #5177, chapter 4.4-4.6
+                    operator::AND
+                    | operator::OR
+                    | operator::ADD
+                    | operator::SUB
+                    | operator::DIV
+                    | operator::MUL
+                    | operator::EQ => p.parse_binary_op(|a, b| binary_op(op2, a, b))?,
+                    operator::NOT | operator::ABS | operator::NEG | operator::SQRT => {
+                        p.parse_unary_op(|a| unary_op(op2, a))?
+                    }
+                    operator::DROP => p.parse_drop()?,
+                    operator::PUT => {
+                        // val i
+                        if p.stack.len() < 2 {
+                            return Err(CFFError::InvalidArgumentsStackLength);
+                        }
+
+                        let index = transient_array_index(p.stack.pop())?;
+                        ctx.transient_array[index] = p.stack.pop();
+                    }
+                    operator::GET => {
+                        // i
+                        if p.stack.is_empty() {
+                            return Err(CFFError::InvalidArgumentsStackLength);
+                        }
+
+                        let index = transient_array_index(p.stack.pop())?;
+                        p.stack.push(ctx.transient_array[index])?;
+                    }
+                    operator::IF_ELSE => p.parse_if_else()?,
+                    operator::RANDOM => {
+                        let n = ctx.next_random();
+                        p.stack.push(n)?;
+                    }
+                    operator::DUP => p.parse_dup()?,
+                    operator::EXCH => p.parse_exch()?,
+                    operator::INDEX => p.parse_index()?,
+                    operator::ROLL => p.parse_roll()?,
                     _ => return Err(CFFError::UnsupportedOperator),
                 }
             }
@@ -516,6 +1162,9 @@ fn _parse_char_string(
 
                     ctx.has_seac = true;
 
+                    // The widths of the components don't apply to the composite glyph.
+                    let width = ctx.width;
+
                     if depth == STACK_LIMIT {
                         return Err(CFFError::NestingLimitReached);
                     }
@@ -535,6 +1184,7 @@ fn _parse_char_string(
                         .get(u32::from(accent_char.0))
                         .ok_or(CFFError::InvalidSeacCode)?;
                     _parse_char_string(ctx, accent_char_string, depth + 1, p)?;
+                    ctx.width = width;
                 } else if p.stack.len() == 1 && ctx.width.is_none() {
                     ctx.width = Some(p.stack.pop());
                 }
@@ -555,9 +1205,6 @@ fn _parse_char_string(
             operator::HINT_MASK | operator::COUNTER_MASK => {
                 let mut len = p.stack.len();
 
-                // We are ignoring the hint operators.
-                p.stack.clear();
-
                 // If the stack length is uneven, than the first value is a `width`.
                 if len.is_odd() {
                     len -= 1;
@@ -568,7 +1215,16 @@ fn _parse_char_string(
 
                 ctx.stems_len += len as u32 >> 1;
 
-                s.advance(usize::num_from((ctx.stems_len + 7) >> 3));
+                // The values on the stack are an implicit `vstem`.
+                record_stems(ctx, p, p.stack.len() - len, false);
+                p.stack.clear();
+
+                let mask = s
+                    .read_bytes(usize::num_from((ctx.stems_len + 7) >> 3))
+                    .ok_or(CFFError::ReadOutOfBounds)?;
+                if let (operator::HINT_MASK, Some(ref mut hints)) = (op, &mut ctx.hints) {
+                    hints.push((p.builder.segments, Hint::Mask(mask.to_vec())));
+                }
             }
             operator::MOVE_TO => {
                 let mut i = 0;
@@ -664,7 +1320,101 @@ fn _parse_char_string(
     Ok(())
 }
 
-fn seac_code_to_glyph_id(charset: &Charset, n: f32) -> Option<GlyphId> {
+/// Applies a Type 2 arithmetic operator with one operand.
+///
+/// Returns 0 for other operators.
+pub(crate) fn unary_op(op: u8, a: f32) -> f32 {
+    match op {
+        operator::NOT => bool_to_num(a == 0.0),
+        operator::ABS => f32_abs(a),
+        operator::NEG => -a,
+        // Negative numbers have no square root, so they give 0.
+        operator::SQRT if a > 0.0 => a.sqrt(),
+        _ => 0.0,
+    }
+}
+
+/// Applies a Type 2 arithmetic operator with two operands,
+/// where `b` is the top of the stack.
+///
+/// Returns 0 for other operators.
+pub(crate) fn binary_op(op: u8, a: f32, b: f32) -> f32 {
+    match op {
+        operator::AND => bool_to_num(a != 0.0 && b != 0.0),
+        operator::OR => bool_to_num(a != 0.0 || b != 0.0),
+        operator::ADD => a + b,
+        operator::SUB => a - b,
+        // Division by zero gives 0 instead of an infinite number.
+        operator::DIV if b != 0.0 => a / b,
+        operator::MUL => a * b,
+        operator::EQ => bool_to_num(a == b),
+        _ => 0.0,
+    }
+}
+
+#[inline]
+fn bool_to_num(b: bool) -> f32 {
+    if b {
+        1.0
+    } else {
+        0.0
+    }
+}
+
+#[inline]
+fn transient_array_index(n: f32) -> Result<usize, CFFError> {
+    u8::try_num_from(n)
+        .map(usize::from)
+        .filter(|i| *i < TRANSIENT_ARRAY_LEN)
+        .ok_or(CFFError::InvalidTransientArrayIndex)
+}
+
+/// Resolves subroutines and `seac` components for CFF fonts with `CharstringType 1`.
+struct Type1CharStringResolver<'a> {
+    metadata: &'a Table<'a>,
+    local_subrs: Option<Index<'a>>,
+}
+
+impl CharStringResolver for Type1CharStringResolver<'_> {
+    fn subroutine(&self, index: u32) -> Option<&[u8]> {
+        // Unlike Type 2 charstrings, Type 1 charstrings use unbiased subroutine numbers.
+        self.local_subrs?.get(index)
+    }
+
+    fn seac_char_string(&self, code: u8) -> Option<&[u8]> {
+        let glyph_id = seac_code_to_glyph_id(&self.metadata.charset, f32::from(code))?;
+        self.metadata.char_strings.get(u32::from(glyph_id.0))
+    }
+}
+
+fn parse_type1_char_string(
+    data: &[u8],
+    metadata: &Table,
+    glyph_id: GlyphId,
+    builder: &mut dyn OutlineBuilder,
+) -> Result<(Rect, Option<f32>), CFFError> {
+    let local_subrs = match metadata.kind {
+        FontKind::SID(ref sid) => Some(sid.local_subrs),
+        FontKind::CID(ref cid) => parse_cid_local_subrs(metadata.table_data, glyph_id, cid),
+    };
+
+    let resolver = Type1CharStringResolver {
+        metadata,
+        local_subrs,
+    };
+
+    let (bbox, width) = type1::charstring::parse_char_string(data, &resolver, builder)?;
+
+    // Check that bbox was changed.
+    if bbox.is_default() {
+        return Err(CFFError::ZeroBBox);
+    }
+
+    let rect = bbox.to_rect().ok_or(CFFError::BboxOverflow)?;
+    Ok((rect, width))
+}
+
+pub(super) fn seac_code_to_glyph_id(charset: &Charset, n: f32) -> Option<GlyphId> {
     let code = u8::try_num_from(n)?;
 
     let sid = STANDARD_ENCODING[usize::from(code)];
@@ -685,7 +1435,7 @@ fn seac_code_to_glyph_id(charset: &Charset, n: f32) -> Option<GlyphId> {
 }
 
 #[derive(Clone, Copy, Debug)]
-enum FDSelect<'a> {
+pub(super) enum FDSelect<'a> {
     Format0(LazyArray16<'a, u8>),
     Format3(&'a [u8]), // It's easier to parse it in-place.
 }
@@ -697,7 +1447,7 @@ impl Default for FDSelect<'_> {
 }
 
 impl FDSelect<'_> {
-    fn font_dict_index(&self, glyph_id: GlyphId) -> Option<u8> {
+    pub(super) fn font_dict_index(&self, glyph_id: GlyphId) -> Option<u8> {
         match self {
             FDSelect::Format0(ref array) => array.get(glyph_id.0),
             FDSelect::Format3(data) => {
@@ -744,13 +1494,14 @@ fn parse_fd_select<'a>(number_of_glyphs: u16, s: &mut Stream<'a>) -> Option<FDSe
 fn parse_sid_metadata<'a>(
     data: &'a [u8],
     top_dict: TopDict,
-    encoding: Encoding<'a>,
+    encoding: EncodingTable<'a>,
 ) -> Option<FontKind<'a>> {
     let mut metadata = SIDMetadata::default();
     metadata.encoding = encoding;
 
     let private_dict = if let Some(range) = top_dict.private_dict_range.clone() {
-        parse_private_dict(data.get(range)?)
+        metadata.private_dict = data.get(range)?;
+        parse_private_dict(metadata.private_dict)
     } else {
         return Some(FontKind::SID(metadata));
     };
@@ -805,26 +1556,59 @@ fn parse_cid_metadata(data: &[u8], top_dict: TopDict, number_of_glyphs: u16) ->
     Some(FontKind::CID(metadata))
 }
 
+/// The Registry-Ordering-Supplement of a CID font,
+/// which identifies the character collection used by the font.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Ros<'a> {
+    /// The issuer of the character collection, like `Adobe`.
+    pub registry: &'a str,
+    /// The name of the character collection, like `Japan1`.
+    pub ordering: &'a str,
+    /// The supplement number of the character collection.
+    pub supplement: u16,
+}
+
 /// A [Compact Font Format Table](
 /// https://docs.microsoft.com/en-us/typography/opentype/spec/cff).
 #[derive(Clone, Copy)]
 pub struct Table<'a> {
     // The whole CFF table.
     // Used to resolve a local subroutine in a CID font.
-    table_data: &'a [u8],
+    pub(super) table_data: &'a [u8],
+    // The Top DICT data, which is copied by the subsetter.
+    pub(super) top_dict_data: &'a [u8],
 
-    #[allow(dead_code)]
-    strings: Index<'a>,
-    global_subrs: Index<'a>,
-    charset: Charset<'a>,
+    pub(super) strings: Index<'a>,
+    pub(super) global_subrs: Index<'a>,
+    pub(super) charset: Charset<'a>,
     number_of_glyphs: NonZeroU16,
     matrix: Matrix,
-    char_strings: Index<'a>,
-    kind: FontKind<'a>,
+    // Whether the Top DICT has an explicit FontMatrix.
+    has_matrix: bool,
+    // Whether the CharStrings INDEX contains Type 1 charstrings instead of Type 2 ones.
+    pub(super) has_type1_char_strings: bool,
+    ros: Option<(StringId, StringId, u16)>,
+    pub(super) char_strings: Index<'a>,
+    pub(super) kind: FontKind<'a>,
+    name: Option<&'a str>,
 }
 
-impl<'a> Table<'a> {
-    /// Parses a table from raw data.
+/// A CFF FontSet.
+///
+/// A single CFF table can contain multiple fonts, which share the String
+/// and the Global Subrs INDEXes. In PDF, there is usually only one font,
+/// so [`Table::parse`] can be used directly instead.
+#[derive(Clone, Copy)]
+pub struct FontSet<'a> {
+    table_data: &'a [u8],
+    names: Index<'a>,
+    top_dicts: Index<'a>,
+    strings: Index<'a>,
+    global_subrs: Index<'a>,
+}
+
+impl<'a> FontSet<'a> {
+    /// Parses a FontSet from raw data.
     pub fn parse(data: &'a [u8]) -> Option<Self> {
         let mut s = Stream::new(data);
 
@@ -843,15 +1627,10 @@ impl<'a> Table<'a> {
             s.advance(usize::from(header_size) - 4);
         }
 
-        // Skip Name INDEX.
-        skip_index::<u16>(&mut s)?;
+        let names = parse_index::<u16>(&mut s)?;
 
-        let top_dict = parse_top_dict(&mut s)?;
-
-        // Must be set, otherwise there are nothing to parse.
-        if top_dict.char_strings_offset == 0 {
-            return None;
-        }
+        // 'The Top DICT INDEX has the same number of entries as the Name INDEX.'
+        let top_dicts = parse_index::<u16>(&mut s)?;
 
         // String INDEX.
         let strings = parse_index::<u16>(&mut s)?;
@@ -859,6 +1638,112 @@ impl<'a> Table<'a> {
         // Parse Global Subroutines INDEX.
         let global_subrs = parse_index::<u16>(&mut s)?;
 
+        Some(FontSet {
+            table_data: data,
+            names,
+            top_dicts,
+            strings,
+            global_subrs,
+        })
+    }
+
+    /// Returns the number of entries in the FontSet, including deleted ones.
+    #[inline]
+    pub fn len(&self) -> u32 {
+        self.names.len().min(self.top_dicts.len())
+    }
+
+    /// Checks if the FontSet has no entries.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the name of the font at `index`.
+    ///
+    /// Returns `None` if the index is out of bounds or the font was deleted.
+    pub fn name(&self, index: u32) -> Option<&'a str> {
+        if self.is_deleted(index) {
+            return None;
+        }
+
+        core::str::from_utf8(self.names.get(index)?).ok()
+    }
+
+    /// Checks if the font at `index` was deleted from the FontSet.
+    ///
+    /// Returns `true` if the index is out of bounds.
+    pub fn is_deleted(&self, index: u32) -> bool {
+        if index >= self.len() {
+            return true;
+        }
+
+        // 'A font may be deleted from a FontSet without removing its data
+        // by setting the first byte of its name in the Name INDEX to 0.'
+        match self.names.get(index) {
+            Some(name) => name.first() == Some(&0),
+            None => true,
+        }
+    }
+
+    /// Returns an iterator over the indices and names of all fonts that were not deleted.
+    pub fn names(&self) -> impl Iterator<Item = (u32, &'a str)> + 'a {
+        let set = *self;
+        (0..set.len()).filter_map(move |i| set.name(i).map(|name| (i, name)))
+    }
+
+    /// Parses the font at `index`.
+    ///
+    /// Returns `None` if the font was deleted or is malformed.
+    pub fn font(&self, index: u32) -> Option<Table<'a>> {
+        if self.is_deleted(index) {
+            return None;
+        }
+
+        let top_dict_data = self.top_dicts.get(index)?;
+        let top_dict = parse_top_dict(top_dict_data)?;
+        let mut table =
+            Table::parse_font(self.table_data, top_dict, self.strings, self.global_subrs)?;
+        table.top_dict_data = top_dict_data;
+        table.name = self.name(index);
+        Some(table)
+    }
+
+    /// Parses the font with the specified name.
+    pub fn font_by_name(&self, name: &str) -> Option<Table<'a>> {
+        let (index, _) = self.names().find(|(_, n)| *n == name)?;
+        self.font(index)
+    }
+}
+
+impl core::fmt::Debug for FontSet<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "FontSet {{ ... }}")
+    }
+}
+
+impl<'a> Table<'a> {
+    /// Parses a table from raw data.
+    ///
+    /// If the data contains multiple fonts, the first one that wasn't deleted is used.
+    /// Use [`FontSet`] to access the others.
+    pub fn parse(data: &'a [u8]) -> Option<Self> {
+        let set = FontSet::parse(data)?;
+        let index = (0..set.len()).find(|i| !set.is_deleted(*i))?;
+        set.font(index)
+    }
+
+    fn parse_font(
+        data: &'a [u8],
+        top_dict: TopDict,
+        strings: Index<'a>,
+        global_subrs: Index<'a>,
+    ) -> Option<Self> {
+        // Must be set, otherwise there are nothing to parse.
+        if top_dict.char_strings_offset == 0 {
+            return None;
+        }
+
         let char_strings = {
             let mut s = Stream::new_at(data, top_dict.char_strings_offset)?;
             parse_index::<u16>(&mut s)?
@@ -880,17 +1765,20 @@ impl<'a> Table<'a> {
             None => Charset::ISOAdobe, // default
         };
 
-        let matrix = top_dict.matrix;
+        let has_matrix = top_dict.matrix.is_some();
+        let has_type1_char_strings = top_dict.char_string_type == Some(1);
+        let ros = top_dict.ros;
+        let matrix = top_dict.matrix.unwrap_or_default();
 
         let kind = if top_dict.has_ros {
             parse_cid_metadata(data, top_dict, number_of_glyphs.get())?
         } else {
             // Only SID fonts are allowed to have an Encoding.
             let encoding = match top_dict.encoding_offset {
-                Some(encoding_id::STANDARD) => Encoding::new_standard(),
-                Some(encoding_id::EXPERT) => Encoding::new_expert(),
+                Some(encoding_id::STANDARD) => EncodingTable::new_standard(),
+                Some(encoding_id::EXPERT) => EncodingTable::new_expert(),
                 Some(offset) => parse_encoding(&mut Stream::new_at(data, offset)?)?,
-                None => Encoding::new_standard(), // default
+                None => EncodingTable::new_standard(), // default
             };
 
             parse_sid_metadata(data, top_dict, encoding)?
@@ -898,16 +1786,114 @@ impl<'a> Table<'a> {
 
         Some(Self {
             table_data: data,
+            top_dict_data: &[],
             strings,
             global_subrs,
             charset,
             number_of_glyphs,
             matrix,
+            has_matrix,
+            has_type1_char_strings,
+            ros,
             char_strings,
             kind,
+            name: None,
         })
     }
 
+    /// Returns the font name from the Name INDEX.
+    #[inline]
+    pub fn name(&self) -> Option<&'a str> {
+        self.name
+    }
+
+    /// Returns the FontInfo entries of the Top DICT, as they are stored in Type 1 fonts.
+    pub(crate) fn font_info(&self) -> type1::FontInfo {
+        let string = |operator| {
+            let sid = *find_dict_operands(self.top_dict_data, operator)?.first()?;
+            let sid = StringId(u16::try_from(sid as i32).ok()?);
+            self.string(sid).map(str::to_string)
+        };
+        let number = |operator, default| {
+            find_dict_operands(self.top_dict_data, operator)
+                .and_then(|operands| operands.first().copied())
+                .map_or(default, |n| n as f32)
+        };
+
+        type1::FontInfo {
+            version: string(top_dict_operator::VERSION),
+            notice: string(top_dict_operator::NOTICE),
+            copyright: string(top_dict_operator::COPYRIGHT),
+            full_name: string(top_dict_operator::FULL_NAME),
+            family_name: string(top_dict_operator::FAMILY_NAME),
+            weight: string(top_dict_operator::WEIGHT),
+            italic_angle: number(top_dict_operator::ITALIC_ANGLE, 0.0),
+            is_fixed_pitch: number(top_dict_operator::IS_FIXED_PITCH, 0.0) != 0.0,
+            underline_position: number(top_dict_operator::UNDERLINE_POSITION, -100.0),
+            underline_thickness: number(top_dict_operator::UNDERLINE_THICKNESS, 50.0),
+        }
+    }
+
+    /// Returns the FontBBox of the Top DICT.
+    pub(crate) fn font_bbox(&self) -> Option<RectF> {
+        match *find_dict_operands(self.top_dict_data, top_dict_operator::FONT_BBOX)? {
+            [x_min, y_min, x_max, y_max] => Some(RectF {
+                x_min: x_min as f32,
+                y_min: y_min as f32,
+                x_max: x_max as f32,
+                y_max: y_max as f32,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Returns the UniqueID of the Top DICT.
+    pub(crate) fn unique_id(&self) -> Option<i32> {
+        let operands = find_dict_operands(self.top_dict_data, top_dict_operator::UNIQUE_ID)?;
+        operands.first().map(|n| *n as i32)
+    }
+
+    /// Returns the hinting values of the Private DICT of an SID font.
+    pub(crate) fn type1_private_dict(&self) -> type1::PrivateDict {
+        let data = match self.kind {
+            FontKind::SID(ref sid) => sid.private_dict,
+            FontKind::CID(_) => &[],
+        };
+        let number = |operator| {
+            find_dict_operands(data, operator)
+                .and_then(|operands| operands.first().copied())
+                .map(|n| n as f32)
+        };
+        // Arrays are stored as deltas.
+        let array = |operator| {
+            let mut value = 0.0;
+            find_dict_operands(data, operator)
+                .unwrap_or_default()
+                .into_iter()
+                .map(|n| {
+                    value += n as f32;
+                    value
+                })
+                .collect()
+        };
+
+        type1::PrivateDict {
+            blue_values: array(private_dict_operator::BLUE_VALUES),
+            other_blues: array(private_dict_operator::OTHER_BLUES),
+            family_blues: array(private_dict_operator::FAMILY_BLUES),
+            family_other_blues: array(private_dict_operator::FAMILY_OTHER_BLUES),
+            blue_scale: number(private_dict_operator::BLUE_SCALE),
+            blue_shift: number(private_dict_operator::BLUE_SHIFT),
+            blue_fuzz: number(private_dict_operator::BLUE_FUZZ),
+            std_hw: number(private_dict_operator::STD_HW),
+            std_vw: number(private_dict_operator::STD_VW),
+            stem_snap_h: array(private_dict_operator::STEM_SNAP_H),
+            stem_snap_v: array(private_dict_operator::STEM_SNAP_V),
+            force_bold: number(private_dict_operator::FORCE_BOLD).map_or(false, |n| n != 0.0),
+            language_group: number(private_dict_operator::LANGUAGE_GROUP).map(|n| n as i32),
+        }
+    }
+
     /// Returns a total number of glyphs in the font.
     ///
     /// Never zero.
@@ -917,12 +1903,41 @@ impl<'a> Table<'a> {
     }
 
     /// Returns a font transformation matrix.
+    ///
+    /// In CID fonts, Font DICTs can have their own matrix,
+    /// so [`Table::glyph_matrix`] should be used instead.
     #[inline]
     pub fn matrix(&self) -> Matrix {
         self.matrix
     }
 
+    /// Returns a transformation matrix for a specific glyph.
+    ///
+    /// For CID fonts, the FontMatrix of the glyph's Font DICT is concatenated
+    /// with the Top DICT one. If the Top DICT doesn't define a FontMatrix,
+    /// the Font DICT one is used as is, since concatenating it with the default
+    /// matrix would scale the glyph twice.
+    ///
+    /// Outlines and widths are in the units of the glyph's Font DICT,
+    /// so this matrix has to be applied to them.
+    ///
+    /// For SID fonts, this is the same as [`Table::matrix`].
+    pub fn glyph_matrix(&self, glyph_id: GlyphId) -> Matrix {
+        let font_dict_matrix = match self.kind {
+            FontKind::SID(_) => None,
+            FontKind::CID(ref cid) => parse_cid_font_dict(glyph_id, cid).and_then(|d| d.matrix),
+        };
+
+        match font_dict_matrix {
+            Some(m) if self.has_matrix => m.multiply(&self.matrix),
+            Some(m) => m,
+            None => self.matrix,
+        }
+    }
+
     /// Outlines a glyph.
+    ///
+    /// The outline is in the units of [`Table::glyph_matrix`].
     pub fn outline(
         &self,
         glyph_id: GlyphId,
@@ -932,7 +1947,36 @@ impl<'a> Table<'a> {
             .char_strings
             .get(u32::from(glyph_id.0))
             .ok_or(CFFError::NoGlyph)?;
-        parse_char_string(data, self, glyph_id, false, builder).map(|v| v.0)
+
+        if self.has_type1_char_strings {
+            parse_type1_char_string(data, self, glyph_id, builder).map(|v| v.0)
+        } else {
+            parse_char_string(data, self, glyph_id, false, builder, None).map(|v| v.0)
+        }
+    }
+
+    /// Outlines a glyph and returns its hints.
+    ///
+    /// Each hint comes with the number of path segments passed to `builder` before it.
+    /// Glyphs with Type 1 charstrings or `seac` have no hints.
+    pub(crate) fn outline_with_hints(
+        &self,
+        glyph_id: GlyphId,
+        builder: &mut dyn OutlineBuilder,
+    ) -> Result<(Rect, Vec<(usize, Hint)>), CFFError> {
+        if self.has_type1_char_strings {
+            return self
+                .outline(glyph_id, builder)
+                .map(|rect| (rect, Vec::new()));
+        }
+
+        let data = self
+            .char_strings
+            .get(u32::from(glyph_id.0))
+            .ok_or(CFFError::NoGlyph)?;
+        let mut hints = Vec::new();
+        let (rect, _) = parse_char_string(data, self, glyph_id, false, builder, Some(&mut hints))?;
+        Ok((rect, hints))
     }
 
     /// Resolves a Glyph ID for a code point.
@@ -947,7 +1991,7 @@ impl<'a> Table<'a> {
                     None => {
                         // Try using the Standard encoding otherwise.
                         // Custom Encodings does not guarantee to include all glyphs.
-                        Encoding::new_standard().code_to_gid(&self.charset, code_point)
+                        EncodingTable::new_standard().code_to_gid(&self.charset, code_point)
                     }
                 }
             }
@@ -955,24 +1999,51 @@ impl<'a> Table<'a> {
         }
     }
 
+    /// Returns the encoding of the font.
+    ///
+    /// Returns `None` for CID fonts, since they don't have an encoding.
+    pub fn encoding(&self) -> Option<Encoding<'a>> {
+        match self.kind {
+            FontKind::SID(ref sid) => Some(Encoding::new(sid.encoding, self.charset)),
+            FontKind::CID(_) => None,
+        }
+    }
+
     /// Returns a glyph width.
     ///
     /// This value is different from outline bbox width and is stored separately.
     ///
     /// Technically similar to [`Face::glyph_hor_advance`](crate::Face::glyph_hor_advance).
     pub fn glyph_width(&self, glyph_id: GlyphId) -> Option<u16> {
-        match self.kind {
-            FontKind::SID(ref sid) => {
-                let data = self.char_strings.get(u32::from(glyph_id.0))?;
-                let (_, width) =
-                    parse_char_string(data, self, glyph_id, true, &mut DummyOutline).ok()?;
-                let width = width
-                    .map(|w| sid.nominal_width + w)
-                    .unwrap_or(sid.default_width);
-                u16::try_from(width as i32).ok()
+        let (default_width, nominal_width) = match self.kind {
+            FontKind::SID(ref sid) => (sid.default_width, sid.nominal_width),
+            FontKind::CID(ref cid) => {
+                // CID fonts store the widths in the Private DICT of the glyph's Font DICT.
+                let private_dict = parse_cid_font_dict(glyph_id, cid)?
+                    .private_dict_range
+                    .and_then(|range| self.table_data.get(range))
+                    .map(parse_private_dict)
+                    .unwrap_or_default();
+                (
+                    private_dict.default_width.unwrap_or(0.0),
+                    private_dict.nominal_width.unwrap_or(0.0),
+                )
             }
-            FontKind::CID(_) => None,
+        };
+
+        let data = self.char_strings.get(u32::from(glyph_id.0))?;
+
+        if self.has_type1_char_strings {
+            // Type 1 charstrings store the absolute width in `hsbw` or `sbw`.
+            let (_, width) =
+                parse_type1_char_string(data, self, glyph_id, &mut DummyOutline).ok()?;
+            return u16::try_from(width? as i32).ok();
         }
+
+        let (_, width) =
+            parse_char_string(data, self, glyph_id, true, &mut DummyOutline, None).ok()?;
+        let width = width.map(|w| nominal_width + w).unwrap_or(default_width);
+        u16::try_from(width as i32).ok()
     }
 
     pub fn glyph_index_by_cid(&self, cid: u16) -> Option<GlyphId> {
@@ -1006,25 +2077,81 @@ impl<'a> Table<'a> {
         }
     }
 
+    /// Returns the charstring of a glyph.
+    pub(crate) fn char_string(&self, glyph_id: GlyphId) -> Option<&'a [u8]> {
+        self.char_strings.get(u32::from(glyph_id.0))
+    }
+
+    /// Checks if the charstrings are Type 1 instead of Type 2 charstrings.
+    pub(crate) fn has_type1_char_strings(&self) -> bool {
+        self.has_type1_char_strings
+    }
+
+    /// Returns a global subroutine and its index by the number passed to `callgsubr`.
+    pub(crate) fn global_subroutine(&self, number: f32) -> Option<(u32, &'a [u8])> {
+        let bias = calc_subroutine_bias(self.global_subrs.len());
+        let index = conv_subroutine_index(number, bias).ok()?;
+        Some((index, self.global_subrs.get(index)?))
+    }
+
+    /// Returns a local subroutine of a glyph and its index by the number passed to `callsubr`.
+    ///
+    /// Unlike Type 2 charstrings, Type 1 charstrings use unbiased numbers.
+    pub(crate) fn local_subroutine(
+        &self,
+        glyph_id: GlyphId,
+        number: f32,
+    ) -> Option<(u32, &'a [u8])> {
+        let local_subrs = match self.kind {
+            FontKind::SID(ref sid) => sid.local_subrs,
+            FontKind::CID(ref cid) => parse_cid_local_subrs(self.table_data, glyph_id, cid)?,
+        };
+
+        let bias = if self.has_type1_char_strings {
+            0
+        } else {
+            calc_subroutine_bias(local_subrs.len())
+        };
+        let index = conv_subroutine_index(number, bias).ok()?;
+        Some((index, local_subrs.get(index)?))
+    }
+
     /// Returns a glyph name.
     pub fn glyph_name(&self, glyph_id: GlyphId) -> Option<&'a str> {
         match self.kind {
             FontKind::SID(_) => {
                 let sid = self.charset.gid_to_sid(glyph_id)?;
-                let sid = usize::from(sid.0);
-                match STANDARD_NAMES.get(sid) {
-                    Some(name) => Some(name),
-                    None => {
-                        let idx = u32::try_from(sid - STANDARD_NAMES.len()).ok()?;
-                        let name = self.strings.get(idx)?;
-                        core::str::from_utf8(name).ok()
-                    }
-                }
+                self.string(sid)
             }
             FontKind::CID(_) => None,
         }
     }
 
+    /// Resolves a string by its SID, either from the standard strings or the String INDEX.
+    fn string(&self, sid: StringId) -> Option<&'a str> {
+        let sid = usize::from(sid.0);
+        match STANDARD_NAMES.get(sid) {
+            Some(name) => Some(name),
+            None => {
+                let idx = u32::try_from(sid - STANDARD_NAMES.len()).ok()?;
+                let name = self.strings.get(idx)?;
+                core::str::from_utf8(name).ok()
+            }
+        }
+    }
+
+    /// Returns the Registry-Ordering-Supplement of a CID font.
+    ///
+    /// Returns `None` if this is not a CIDFont.
+    pub fn ros(&self) -> Option<Ros<'a>> {
+        let (registry, ordering, supplement) = self.ros?;
+        Some(Ros {
+            registry: self.string(registry)?,
+            ordering: self.string(ordering)?,
+            supplement,
+        })
+    }
+
     /// Returns the CID corresponding to a glyph ID.
     ///
     /// Returns `None` if this is not a CIDFont.
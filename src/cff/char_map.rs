@@ -0,0 +1,47 @@
+//! Lookup of the glyphs that draw characters.
+
+use std::collections::HashMap;
+
+use super::Table;
+use crate::GlyphId;
+
+/// Maps characters to the glyphs of a CFF font that draw them.
+///
+/// The glyphs are found through their names according to the Adobe Glyph List.
+/// Glyphs that are reachable through the encoding are preferred.
+///
+/// The map of a CID font is empty, since its glyphs don't have names.
+#[derive(Clone, Default, Debug)]
+pub struct CharMap {
+    map: HashMap<char, GlyphId>,
+}
+
+impl CharMap {
+    /// Builds the map of a font.
+    pub fn new(table: &Table) -> Self {
+        let encoding = match table.encoding() {
+            Some(encoding) => encoding,
+            None => return Self::default(),
+        };
+
+        // Glyphs that are reachable through the encoding take precedence.
+        let encoded = encoding.iter().map(|(_, gid)| gid);
+        let all = (1..table.number_of_glyphs()).map(GlyphId);
+        let glyphs = encoded
+            .chain(all)
+            .filter_map(|gid| table.glyph_name(gid).map(|name| (gid, name)));
+        Self {
+            map: crate::agl::char_map(glyphs),
+        }
+    }
+
+    /// Returns the glyph that draws a character.
+    pub fn glyph_for_char(&self, c: char) -> Option<GlyphId> {
+        self.map.get(&c).copied()
+    }
+
+    /// Returns all characters with the glyphs that draw them.
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (char, GlyphId)> + '_ {
+        self.map.iter().map(|(c, glyph_id)| (*c, *glyph_id))
+    }
+}
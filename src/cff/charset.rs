@@ -187,6 +187,17 @@ impl Charset<'_> {
     }
 }
 
+/// Writes a charset in format 0, given the SIDs of all glyphs.
+pub(crate) fn write_charset(sids: &[StringId]) -> Vec<u8> {
+    let mut data = vec![0];
+    // `.notdef` is omitted.
+    for sid in sids.iter().skip(1) {
+        data.extend_from_slice(&sid.0.to_be_bytes());
+    }
+
+    data
+}
+
 pub(crate) fn parse_charset<'a>(
     number_of_glyphs: NonZeroU16,
     s: &mut Stream<'a>,
@@ -580,6 +580,105 @@ impl CharStringParser<'_> {
         Ok(())
     }
 
+    #[inline]
+    fn check_stack_len(&self, len: usize) -> Result<(), CFFError> {
+        if self.stack.len() < len {
+            Err(CFFError::InvalidArgumentsStackLength)
+        } else {
+            Ok(())
+        }
+    }
+
+    #[inline]
+    pub fn parse_unary_op(&mut self, f: impl FnOnce(f32) -> f32) -> Result<(), CFFError> {
+        // num1
+        self.check_stack_len(1)?;
+        let num1 = self.stack.pop();
+        self.stack.push(f(num1))
+    }
+
+    #[inline]
+    pub fn parse_binary_op(&mut self, f: impl FnOnce(f32, f32) -> f32) -> Result<(), CFFError> {
+        // num1 num2
+        self.check_stack_len(2)?;
+        let num2 = self.stack.pop();
+        let num1 = self.stack.pop();
+        self.stack.push(f(num1, num2))
+    }
+
+    #[inline]
+    pub fn parse_drop(&mut self) -> Result<(), CFFError> {
+        // num
+        self.check_stack_len(1)?;
+        self.stack.pop();
+        Ok(())
+    }
+
+    #[inline]
+    pub fn parse_dup(&mut self) -> Result<(), CFFError> {
+        // any
+        self.check_stack_len(1)?;
+        let any = self.stack.at(self.stack.len() - 1);
+        self.stack.push(any)
+    }
+
+    #[inline]
+    pub fn parse_exch(&mut self) -> Result<(), CFFError> {
+        // num1 num2
+        self.check_stack_len(2)?;
+        self.stack.exch();
+        Ok(())
+    }
+
+    #[inline]
+    pub fn parse_if_else(&mut self) -> Result<(), CFFError> {
+        // s1 s2 v1 v2
+        self.check_stack_len(4)?;
+        let v2 = self.stack.pop();
+        let v1 = self.stack.pop();
+        let s2 = self.stack.pop();
+        let s1 = self.stack.pop();
+        self.stack.push(if v1 <= v2 { s1 } else { s2 })
+    }
+
+    #[inline]
+    pub fn parse_index(&mut self) -> Result<(), CFFError> {
+        // num(N-1) ... num0 i
+        self.check_stack_len(2)?;
+        let i = self.stack.pop();
+
+        // 'If i is negative, the top element is copied.'
+        let i = if i < 0.0 { 0 } else { i as usize };
+        if i >= self.stack.len() {
+            return Err(CFFError::InvalidArgumentsStackLength);
+        }
+
+        let any = self.stack.at(self.stack.len() - 1 - i);
+        self.stack.push(any)
+    }
+
+    #[inline]
+    pub fn parse_roll(&mut self) -> Result<(), CFFError> {
+        // num(N-1) ... num0 N J
+        self.check_stack_len(2)?;
+        let j = self.stack.pop() as i32;
+        let n = self.stack.pop();
+
+        if n <= 0.0 || n as usize > self.stack.len() {
+            return Err(CFFError::InvalidArgumentsStackLength);
+        }
+
+        let n = n as usize;
+        let len = self.stack.len();
+        let elements = &mut self.stack.data[len - n..len];
+
+        // 'Positive J indicates upward motion of the elements, negative J downward motion.'
+        let shift = j.rem_euclid(n as i32) as usize;
+        elements.rotate_right(shift);
+
+        Ok(())
+    }
+
     #[inline]
     pub fn parse_int1(&mut self, op: u8) -> Result<(), CFFError> {
         let n = i16::from(op) - 139;
@@ -106,6 +106,12 @@ impl<'a> DictionaryParser<'a> {
         Some(())
     }
 
+    /// Returns the raw data of the last parsed entry, including its operator.
+    #[inline]
+    pub fn entry_data(&self) -> &'a [u8] {
+        &self.data[self.operands_offset..self.offset]
+    }
+
     #[inline]
     pub fn operands(&self) -> &[f64] {
         &self.operands[..usize::from(self.operands_len)]
@@ -252,6 +258,74 @@ fn parse_float_nibble(nibble: u8, mut idx: usize, data: &mut [u8]) -> Option<usi
     Some(idx)
 }
 
+/// Writes an integer operand in its shortest form.
+pub fn write_int(n: i32, out: &mut Vec<u8>) {
+    match n {
+        -107..=107 => out.push((n + 139) as u8),
+        108..=1131 => {
+            let n = n - 108;
+            out.extend_from_slice(&[(n >> 8) as u8 + 247, n as u8]);
+        }
+        -1131..=-108 => {
+            let n = -n - 108;
+            out.extend_from_slice(&[(n >> 8) as u8 + 251, n as u8]);
+        }
+        -32768..=32767 => {
+            out.push(28);
+            out.extend_from_slice(&(n as i16).to_be_bytes());
+        }
+        _ => write_fixed_int(n, out),
+    }
+}
+
+/// Writes an integer operand in the five byte form.
+///
+/// Used for offsets, since the size of the DICT then doesn't
+/// depend on their values.
+pub(crate) fn write_fixed_int(n: i32, out: &mut Vec<u8>) {
+    out.push(29);
+    out.extend_from_slice(&n.to_be_bytes());
+}
+
+/// Writes a number operand, as an integer if possible.
+pub fn write_number(n: f32, out: &mut Vec<u8>) {
+    if n.fract() == 0.0 && n.abs() <= i32::MAX as f32 {
+        write_int(n as i32, out);
+    } else {
+        write_real(n, out);
+    }
+}
+
+/// Writes a real number operand in the BCD form.
+pub fn write_real(n: f32, out: &mut Vec<u8>) {
+    let n = if n.is_finite() { n } else { 0.0 };
+    let mut nibbles: Vec<u8> = n
+        .to_string()
+        .bytes()
+        .map(|c| match c {
+            b'.' => 0xA,
+            b'-' => 0xE,
+            c => c - b'0',
+        })
+        .collect();
+    nibbles.push(0xF);
+    if nibbles.len() % 2 == 1 {
+        nibbles.push(0xF);
+    }
+
+    out.push(30);
+    out.extend(nibbles.chunks(2).map(|pair| pair[0] << 4 | pair[1]));
+}
+
+/// Writes an operator, using the same numbering as [`Operator`].
+pub fn write_operator(operator: u16, out: &mut Vec<u8>) {
+    if operator >= 1200 {
+        out.extend_from_slice(&[TWO_BYTE_OPERATOR_MARK, (operator - 1200) as u8]);
+    } else {
+        out.push(operator as u8);
+    }
+}
+
 // Just like `parse_number`, but doesn't actually parses the data.
 pub fn skip_number(b0: u8, s: &mut Stream) -> Option<()> {
     match b0 {
@@ -307,4 +381,30 @@ mod tests {
             -100000.0
         );
     }
+
+    #[test]
+    fn write_dict_number() {
+        for n in [
+            0, 107, -107, 108, 1131, -1131, 1132, -32768, 100000, -100000,
+        ] {
+            let mut data = Vec::new();
+            write_int(n, &mut data);
+            let mut s = Stream::new(&data[1..]);
+            assert_eq!(parse_number(data[0], &mut s), Some(f64::from(n)));
+            assert!(s.at_end());
+        }
+
+        for n in [0.001, -12.5, 0.039625, 1.5] {
+            let mut data = Vec::new();
+            write_number(n, &mut data);
+            let mut s = Stream::new(&data[1..]);
+            assert_eq!(parse_number(data[0], &mut s).map(|n| n as f32), Some(n));
+            assert!(s.at_end());
+        }
+
+        let mut data = Vec::new();
+        write_fixed_int(5, &mut data);
+        write_operator(1236, &mut data);
+        assert_eq!(data, [0x1D, 0x00, 0x00, 0x00, 0x05, 0x0C, 0x24]);
+    }
 }
@@ -1,7 +1,7 @@
 use super::charset::Charset;
 use super::parser::{FromData, LazyArray16, Stream};
-use super::StringId;
-use crate::GlyphId;
+use super::{CFFError, StringId};
+use crate::{EncodingKind, GlyphId};
 
 /// The Standard Encoding as defined in the Adobe Technical Note #5176 Appendix B.
 #[rustfmt::skip]
@@ -24,6 +24,27 @@ pub const STANDARD_ENCODING: [u8; 256] = [
       0, 144,   0,   0,   0, 145,   0,   0, 146, 147, 148, 149,   0,   0,   0,   0,
 ];
 
+/// The Expert Encoding as defined in the Adobe Technical Note #5176 Appendix B.
+#[rustfmt::skip]
+pub const EXPERT_ENCODING: [u16; 256] = [
+      0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+      0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+      1, 229, 230,   0, 231, 232, 233, 234, 235, 236, 237, 238,  13,  14,  15,  99,
+    239, 240, 241, 242, 243, 244, 245, 246, 247, 248,  27,  28, 249, 250, 251, 252,
+      0, 253, 254, 255, 256, 257,   0,   0,   0, 258,   0,   0, 259, 260, 261, 262,
+      0,   0, 263, 264, 265,   0, 266, 109, 110, 267, 268, 269,   0, 270, 271, 272,
+    273, 274, 275, 276, 277, 278, 279, 280, 281, 282, 283, 284, 285, 286, 287, 288,
+    289, 290, 291, 292, 293, 294, 295, 296, 297, 298, 299, 300, 301, 302, 303,   0,
+      0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+      0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+      0, 304, 305, 306,   0,   0, 307, 308, 309, 310, 311,   0, 312,   0,   0, 313,
+      0,   0, 314, 315,   0,   0, 316, 317, 318,   0,   0,   0, 158, 155, 163, 319,
+    320, 321, 322, 323, 324, 325,   0,   0, 326, 150, 164, 169, 327, 328, 329, 330,
+    331, 332, 333, 334, 335, 336, 337, 338, 339, 340, 341, 342, 343, 344, 345, 346,
+    347, 348, 349, 350, 351, 352, 353, 354, 355, 356, 357, 358, 359, 360, 361, 362,
+    363, 364, 365, 366, 367, 368, 369, 370, 371, 372, 373, 374, 375, 376, 377, 378,
+];
+
 #[derive(Clone, Copy, Debug)]
 pub(crate) struct Format1Range {
     first: u8,
@@ -63,36 +84,36 @@ impl FromData for Supplement {
 }
 
 #[derive(Clone, Copy, Default, Debug)]
-pub(crate) struct Encoding<'a> {
-    kind: EncodingKind<'a>,
+pub(crate) struct EncodingTable<'a> {
+    format: EncodingFormat<'a>,
     supplemental: LazyArray16<'a, Supplement>,
 }
 
 #[derive(Clone, Copy, Debug)]
-pub(crate) enum EncodingKind<'a> {
+pub(crate) enum EncodingFormat<'a> {
     Standard,
     Expert,
     Format0(LazyArray16<'a, u8>),
     Format1(LazyArray16<'a, Format1Range>),
 }
 
-impl Default for EncodingKind<'_> {
+impl Default for EncodingFormat<'_> {
     fn default() -> Self {
         Self::Standard
     }
 }
 
-impl Encoding<'_> {
+impl EncodingTable<'_> {
     pub fn new_standard() -> Self {
-        Encoding {
-            kind: EncodingKind::Standard,
+        EncodingTable {
+            format: EncodingFormat::Standard,
             supplemental: LazyArray16::default(),
         }
     }
 
     pub fn new_expert() -> Self {
-        Encoding {
-            kind: EncodingKind::Expert,
+        EncodingTable {
+            format: EncodingFormat::Expert,
             supplemental: LazyArray16::default(),
         }
     }
@@ -105,20 +126,22 @@ impl Encoding<'_> {
         }
 
         let index = usize::from(code);
-        match self.kind {
-            // Standard encodings store a StringID/SID and not GlyphID/GID.
+        match self.format {
+            // Predefined encodings store a StringID/SID and not GlyphID/GID.
             // Therefore we have to get SID first and then convert it to GID via Charset.
             // Custom encodings (FormatN) store GID directly.
             //
             // Indexing for predefined encodings never fails,
             // because `code` is always `u8` and encodings have 256 entries.
-            //
-            // We treat `Expert` as `Standard` as well, since we allow only 8bit codepoints.
-            EncodingKind::Standard | EncodingKind::Expert => {
+            EncodingFormat::Standard => {
                 let sid = StringId(u16::from(STANDARD_ENCODING[index]));
                 charset.sid_to_gid(sid)
             }
-            EncodingKind::Format0(ref table) => {
+            EncodingFormat::Expert => {
+                let sid = StringId(EXPERT_ENCODING[index]);
+                charset.sid_to_gid(sid)
+            }
+            EncodingFormat::Format0(ref table) => {
                 // +1 because .notdef is implicit.
                 table
                     .into_iter()
@@ -126,7 +149,7 @@ impl Encoding<'_> {
                     .map(|i| (i + 1) as u16)
                     .map(GlyphId)
             }
-            EncodingKind::Format1(ref table) => {
+            EncodingFormat::Format1(ref table) => {
                 // Starts from 1 because .notdef is implicit.
                 let mut gid: u16 = 1;
                 for range in table.into_iter() {
@@ -145,7 +168,103 @@ impl Encoding<'_> {
     }
 }
 
-pub(crate) fn parse_encoding<'a>(s: &mut Stream<'a>) -> Option<Encoding<'a>> {
+/// An encoding of a SID-keyed CFF font.
+///
+/// Maps 8-bit character codes to glyphs and back.
+#[derive(Clone, Copy, Debug)]
+pub struct Encoding<'a> {
+    table: EncodingTable<'a>,
+    charset: Charset<'a>,
+}
+
+impl<'a> Encoding<'a> {
+    pub(crate) fn new(table: EncodingTable<'a>, charset: Charset<'a>) -> Self {
+        Encoding { table, charset }
+    }
+
+    /// Returns the kind of the encoding.
+    pub fn kind(&self) -> EncodingKind {
+        match self.table.format {
+            EncodingFormat::Standard => EncodingKind::Standard,
+            EncodingFormat::Expert => EncodingKind::Expert,
+            EncodingFormat::Format0(_) | EncodingFormat::Format1(_) => EncodingKind::Custom,
+        }
+    }
+
+    /// Checks if the encoding has supplements, which map additional codes to glyphs.
+    ///
+    /// Only custom encodings can have supplements.
+    pub fn has_supplements(&self) -> bool {
+        !self.table.supplemental.is_empty()
+    }
+
+    /// Returns the glyph mapped to a code.
+    ///
+    /// Unlike [`Table::glyph_index`](super::Table::glyph_index), this doesn't fall back
+    /// to the Standard Encoding for unmapped codes.
+    pub fn glyph_index(&self, code: u8) -> Option<GlyphId> {
+        self.table
+            .code_to_gid(&self.charset, code)
+            // The `.notdef` glyph is never encoded.
+            .filter(|gid| gid.0 != 0)
+    }
+
+    /// Returns an iterator over all mapped codes and their glyphs in ascending code order.
+    pub fn iter(&self) -> impl Iterator<Item = (u8, GlyphId)> + 'a {
+        let encoding = *self;
+        (0..=255).filter_map(move |code| encoding.glyph_index(code).map(|gid| (code, gid)))
+    }
+
+    /// Returns an iterator over all codes that map to a glyph.
+    ///
+    /// A glyph can be mapped to multiple codes, for example via supplements.
+    pub fn codes(&self, glyph_id: GlyphId) -> impl Iterator<Item = u8> + 'a {
+        self.iter()
+            .filter(move |(_, gid)| *gid == glyph_id)
+            .map(|(code, _)| code)
+    }
+}
+
+/// Writes a custom encoding, given the codes of each glyph and the glyph SIDs.
+///
+/// Format 0 maps codes to the glyphs up to the first unencoded one,
+/// all other codes become supplements. Returns an error if there are
+/// more than 255 supplements.
+pub(crate) fn write_encoding(codes: &[Vec<u8>], sids: &[StringId]) -> Result<Vec<u8>, CFFError> {
+    // `.notdef` is never encoded.
+    let number_of_codes = codes
+        .iter()
+        .skip(1)
+        .take(255)
+        .take_while(|codes| !codes.is_empty())
+        .count();
+
+    let mut supplements = Vec::new();
+    for (glyph_id, glyph_codes) in codes.iter().enumerate().skip(1) {
+        let skip = usize::from(glyph_id <= number_of_codes);
+        for code in glyph_codes.iter().skip(skip) {
+            supplements.push((*code, sids[glyph_id]));
+        }
+    }
+    if supplements.len() > 255 {
+        return Err(CFFError::TooManySupplements);
+    }
+
+    let format = if supplements.is_empty() { 0 } else { 0x80 };
+    let mut data = vec![format, number_of_codes as u8];
+    data.extend(codes[1..=number_of_codes].iter().map(|codes| codes[0]));
+    if !supplements.is_empty() {
+        data.push(supplements.len() as u8);
+        for (code, sid) in supplements {
+            data.push(code);
+            data.extend_from_slice(&sid.0.to_be_bytes());
+        }
+    }
+
+    Ok(data)
+}
+
+pub(crate) fn parse_encoding<'a>(s: &mut Stream<'a>) -> Option<EncodingTable<'a>> {
     let format = s.read::<u8>()?;
     // The first high-bit in format indicates that a Supplemental encoding is present.
     // Check it and clear.
@@ -153,12 +272,12 @@ pub(crate) fn parse_encoding<'a>(s: &mut Stream<'a>) -> Option<Encoding<'a>> {
     let format = format & 0x7f;
 
     let count = u16::from(s.read::<u8>()?);
-    let kind = match format {
+    let format = match format {
         // TODO: read_array8?
-        0 => s.read_array16::<u8>(count).map(EncodingKind::Format0)?,
+        0 => s.read_array16::<u8>(count).map(EncodingFormat::Format0)?,
         1 => s
             .read_array16::<Format1Range>(count)
-            .map(EncodingKind::Format1)?,
+            .map(EncodingFormat::Format1)?,
         _ => return None,
     };
 
@@ -169,5 +288,30 @@ pub(crate) fn parse_encoding<'a>(s: &mut Stream<'a>) -> Option<Encoding<'a>> {
         LazyArray16::default()
     };
 
-    Some(Encoding { kind, supplemental })
+    Some(EncodingTable {
+        format,
+        supplemental,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn too_many_supplements() {
+        let sids = [StringId(0), StringId(1), StringId(2)];
+        let all_codes: Vec<u8> = (0..=255).collect();
+
+        // The first code is mapped by format 0, the others are supplements.
+        let data = write_encoding(&[vec![], all_codes.clone()], &sids).unwrap();
+        assert_eq!(&data[..4], &[0x80, 1, 0, 255]);
+        assert_eq!(data.len(), 4 + 255 * 3);
+
+        let codes = [vec![], vec![], all_codes];
+        assert_eq!(
+            write_encoding(&codes, &sids),
+            Err(CFFError::TooManySupplements)
+        );
+    }
 }
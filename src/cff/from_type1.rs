@@ -0,0 +1,1022 @@
+//! Conversion of Type 1 fonts to CFF.
+
+// Useful links:
+// https://adobe-type-tools.github.io/font-tech-notes/pdfs/T1_SPEC.pdf
+// http://wwwimages.adobe.com/content/dam/Adobe/en/devnet/font/pdfs/5177.Type2.pdf
+
+use core::cmp::Ordering;
+
+use super::cff::{operator, private_dict_operator, top_dict_operator, TWO_BYTE_OPERATOR_MARK};
+use super::charset::write_charset;
+use super::dict::{write_fixed_int, write_int, write_number, write_operator};
+use super::encoding::write_encoding;
+use super::index::write_index;
+use super::std_names::STANDARD_NAMES;
+use super::{CFFError, StringId};
+use crate::type1::operator::{sb_operator, tb_operator};
+use crate::type1::stream::Stream;
+use crate::type1::{self, PrivateDict};
+use crate::{EncodingKind, GlyphId, Matrix};
+
+// Limits according to the Adobe Technical Note #5177 Appendix B.
+const STACK_LIMIT: u8 = 10;
+const MAX_ARGUMENTS_STACK_LEN: usize = 48;
+
+/// Converts a Type 1 font to a CFF font.
+///
+/// The glyphs keep the order described in [`type1::Table::glyph_name`].
+/// If the font has no `.notdef` glyph, an empty one is inserted as the first glyph.
+///
+/// Subroutines are inlined into the charstrings. Hints are kept,
+/// with hint replacement translated to `hintmask`.
+pub fn from_type1(table: &type1::Table) -> Result<Vec<u8>, CFFError> {
+    let mut names: Vec<&str> = (0..table.number_of_glyphs())
+        .filter_map(|glyph_id| table.glyph_name(GlyphId(glyph_id)))
+        .collect();
+    let mut glyphs = names
+        .iter()
+        .map(|name| {
+            let data = table.char_string(name).ok_or(CFFError::NoGlyph)?;
+            Converter::new(table).convert(data)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if names.first() != Some(&".notdef") {
+        names.insert(0, ".notdef");
+        glyphs.insert(0, Glyph::default());
+    }
+
+    let mut strings = Strings::default();
+    let sids: Vec<StringId> = names.iter().map(|name| strings.sid(name)).collect();
+
+    // The most common width is used as both defaultWidthX and nominalWidthX,
+    // so most charstrings don't need to store their width.
+    let default_width = most_common_width(&glyphs);
+    let char_strings: Vec<Vec<u8>> = glyphs
+        .iter()
+        .map(|glyph| glyph.encode(default_width))
+        .collect();
+
+    let encoding = table.encoding();
+    let encoding = match encoding.kind() {
+        EncodingKind::Custom => {
+            let mut codes = vec![Vec::new(); names.len()];
+            for (code, name) in encoding.iter() {
+                if let Some(glyph_id) = names.iter().position(|n| *n == name) {
+                    codes[glyph_id].push(code);
+                }
+            }
+
+            Some(write_encoding(&codes, &sids)?)
+        }
+        // The Standard Encoding is the default.
+        _ => None,
+    };
+
+    let private_dict = build_private_dict(table.private_dict(), default_width);
+    let top_dict = build_top_dict(table, &mut strings);
+
+    // The offsets are written in the five byte form,
+    // so the Top DICT size doesn't depend on them.
+    let offsets_len = if encoding.is_some() { 29 } else { 23 };
+    let mut data = vec![1, 0, 4, 4];
+    write_index(&[table.name().unwrap_or("Untitled").as_bytes()], &mut data);
+    let mut top_dict_index = Vec::new();
+    write_index(
+        &[vec![0; top_dict.len() + offsets_len]],
+        &mut top_dict_index,
+    );
+    let base = data.len() + top_dict_index.len();
+
+    let mut tail = Vec::new();
+    write_index(&strings.strings, &mut tail);
+    write_index::<&[u8]>(&[], &mut tail);
+
+    let mut top_dict = top_dict;
+    write_fixed_int((base + tail.len()) as i32, &mut top_dict);
+    write_operator(top_dict_operator::CHARSET_OFFSET, &mut top_dict);
+    tail.extend_from_slice(&write_charset(&sids));
+
+    if let Some(encoding) = encoding {
+        write_fixed_int((base + tail.len()) as i32, &mut top_dict);
+        write_operator(top_dict_operator::ENCODING_OFFSET, &mut top_dict);
+        tail.extend_from_slice(&encoding);
+    }
+
+    write_fixed_int((base + tail.len()) as i32, &mut top_dict);
+    write_operator(top_dict_operator::CHAR_STRINGS_OFFSET, &mut top_dict);
+    write_index(&char_strings, &mut tail);
+
+    write_fixed_int(private_dict.len() as i32, &mut top_dict);
+    write_fixed_int((base + tail.len()) as i32, &mut top_dict);
+    write_operator(
+        top_dict_operator::PRIVATE_DICT_SIZE_AND_OFFSET,
+        &mut top_dict,
+    );
+    tail.extend_from_slice(&private_dict);
+
+    write_index(&[top_dict], &mut data);
+    data.extend_from_slice(&tail);
+    Ok(data)
+}
+
+// The String INDEX of a written font.
+#[derive(Default)]
+pub(super) struct Strings {
+    pub(super) strings: Vec<String>,
+}
+
+impl Strings {
+    pub(super) fn sid(&mut self, string: &str) -> StringId {
+        if let Some(index) = STANDARD_NAMES.iter().position(|name| *name == string) {
+            return StringId(index as u16);
+        }
+
+        let index = match self.strings.iter().position(|s| s == string) {
+            Some(index) => index,
+            None => {
+                self.strings.push(string.to_string());
+                self.strings.len() - 1
+            }
+        };
+
+        StringId((STANDARD_NAMES.len() + index) as u16)
+    }
+}
+
+// Returns the top DICT entries, except for the offsets.
+fn build_top_dict(table: &type1::Table, strings: &mut Strings) -> Vec<u8> {
+    let mut dict = Vec::new();
+    write_font_info(table.font_info(), strings, &mut dict);
+
+    if let Some(unique_id) = table.unique_id() {
+        write_int(unique_id, &mut dict);
+        write_operator(top_dict_operator::UNIQUE_ID, &mut dict);
+    }
+
+    if let Some(bbox) = table.font_bbox() {
+        for n in [bbox.x_min, bbox.y_min, bbox.x_max, bbox.y_max] {
+            write_number(n, &mut dict);
+        }
+        write_operator(top_dict_operator::FONT_BBOX, &mut dict);
+    }
+
+    let matrix = table.matrix();
+    if matrix != Matrix::default() {
+        for n in [
+            matrix.sx, matrix.ky, matrix.kx, matrix.sy, matrix.tx, matrix.ty,
+        ] {
+            write_number(n, &mut dict);
+        }
+        write_operator(top_dict_operator::FONT_MATRIX, &mut dict);
+    }
+
+    dict
+}
+
+// Writes the top DICT entries of the `FontInfo` dictionary.
+pub(super) fn write_font_info(info: &type1::FontInfo, strings: &mut Strings, dict: &mut Vec<u8>) {
+    let string_entries = [
+        (&info.version, top_dict_operator::VERSION),
+        (&info.notice, top_dict_operator::NOTICE),
+        (&info.copyright, top_dict_operator::COPYRIGHT),
+        (&info.full_name, top_dict_operator::FULL_NAME),
+        (&info.family_name, top_dict_operator::FAMILY_NAME),
+        (&info.weight, top_dict_operator::WEIGHT),
+    ];
+    for (string, operator) in string_entries {
+        if let Some(string) = string {
+            write_int(i32::from(strings.sid(string).0), dict);
+            write_operator(operator, dict);
+        }
+    }
+
+    if info.is_fixed_pitch {
+        write_int(1, dict);
+        write_operator(top_dict_operator::IS_FIXED_PITCH, dict);
+    }
+
+    // Only values that differ from the CFF defaults are written.
+    let number_entries = [
+        (info.italic_angle, 0.0, top_dict_operator::ITALIC_ANGLE),
+        (
+            info.underline_position,
+            -100.0,
+            top_dict_operator::UNDERLINE_POSITION,
+        ),
+        (
+            info.underline_thickness,
+            50.0,
+            top_dict_operator::UNDERLINE_THICKNESS,
+        ),
+    ];
+    for (value, default, operator) in number_entries {
+        if value != default {
+            write_number(value, dict);
+            write_operator(operator, dict);
+        }
+    }
+}
+
+fn build_private_dict(private: &PrivateDict, default_width: f32) -> Vec<u8> {
+    let mut dict = Vec::new();
+
+    // Arrays are stored as deltas.
+    let array_entries = [
+        (&private.blue_values, private_dict_operator::BLUE_VALUES),
+        (&private.other_blues, private_dict_operator::OTHER_BLUES),
+        (&private.family_blues, private_dict_operator::FAMILY_BLUES),
+        (
+            &private.family_other_blues,
+            private_dict_operator::FAMILY_OTHER_BLUES,
+        ),
+        (&private.stem_snap_h, private_dict_operator::STEM_SNAP_H),
+        (&private.stem_snap_v, private_dict_operator::STEM_SNAP_V),
+    ];
+    for (values, operator) in array_entries {
+        if values.is_empty() {
+            continue;
+        }
+
+        let mut prev = 0.0;
+        for n in values {
+            write_number(n - prev, &mut dict);
+            prev = *n;
+        }
+        write_operator(operator, &mut dict);
+    }
+
+    let number_entries = [
+        (private.blue_scale, private_dict_operator::BLUE_SCALE),
+        (private.blue_shift, private_dict_operator::BLUE_SHIFT),
+        (private.blue_fuzz, private_dict_operator::BLUE_FUZZ),
+        (private.std_hw, private_dict_operator::STD_HW),
+        (private.std_vw, private_dict_operator::STD_VW),
+    ];
+    for (value, operator) in number_entries {
+        if let Some(value) = value {
+            write_number(value, &mut dict);
+            write_operator(operator, &mut dict);
+        }
+    }
+
+    if private.force_bold {
+        write_int(1, &mut dict);
+        write_operator(private_dict_operator::FORCE_BOLD, &mut dict);
+    }
+
+    if let Some(language_group) = private.language_group {
+        write_int(language_group, &mut dict);
+        write_operator(private_dict_operator::LANGUAGE_GROUP, &mut dict);
+    }
+
+    if default_width != 0.0 {
+        write_number(default_width, &mut dict);
+        write_operator(private_dict_operator::DEFAULT_WIDTH, &mut dict);
+        write_number(default_width, &mut dict);
+        write_operator(private_dict_operator::NOMINAL_WIDTH, &mut dict);
+    }
+
+    dict
+}
+
+fn most_common_width(glyphs: &[Glyph]) -> f32 {
+    let mut counts: Vec<(f32, usize)> = Vec::new();
+    for glyph in glyphs {
+        match counts.iter_mut().find(|(width, _)| *width == glyph.width) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((glyph.width, 1)),
+        }
+    }
+
+    // The first width wins a tie, so the result doesn't depend on sorting.
+    let mut best = (0.0, 0);
+    for (width, count) in counts {
+        if count > best.1 {
+            best = (width, count);
+        }
+    }
+
+    best.0
+}
+
+// A stem position and width.
+type Stem = (f32, f32);
+
+#[derive(Clone, Default, PartialEq, Debug)]
+struct HintSet {
+    hstems: Vec<Stem>,
+    vstems: Vec<Stem>,
+}
+
+impl HintSet {
+    fn add(&mut self, horizontal: bool, stem: Stem) {
+        let stems = if horizontal {
+            &mut self.hstems
+        } else {
+            &mut self.vstems
+        };
+
+        if !stems.contains(&stem) {
+            stems.push(stem);
+            stems.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+        }
+    }
+}
+
+// A path command with absolute coordinates.
+#[derive(Clone, Debug)]
+enum Command {
+    HintMask(HintSet),
+    MoveTo(f32, f32),
+    LineTo(f32, f32),
+    CurveTo([f32; 6]),
+    Flex([f32; 12], f32),
+}
+
+// A glyph translated from a Type 1 charstring.
+#[derive(Default, Debug)]
+struct Glyph {
+    width: f32,
+    // All stems of the glyph.
+    hints: HintSet,
+    commands: Vec<Command>,
+    // The accent offset and the character codes of `seac`.
+    seac: Option<[f32; 4]>,
+}
+
+impl Glyph {
+    fn encode(&self, default_width: f32) -> Vec<u8> {
+        let mut writer = CharStringWriter {
+            data: Vec::new(),
+            args: Vec::new(),
+            width: Some(self.width - default_width).filter(|w| *w != 0.0),
+            x: 0.0,
+            y: 0.0,
+        };
+
+        // Glyphs with more stems than the arguments stack can hold lose their hints.
+        let hints = &self.hints;
+        let has_hints = hints.hstems.len().max(hints.vstems.len()) * 2 < MAX_ARGUMENTS_STACK_LEN;
+        let has_masks = has_hints
+            && self
+                .commands
+                .iter()
+                .any(|command| matches!(command, Command::HintMask(set) if set != hints));
+
+        if has_hints {
+            for (stems, op, hm_op) in [
+                (
+                    &hints.hstems,
+                    operator::HORIZONTAL_STEM,
+                    operator::HORIZONTAL_STEM_HINT_MASK,
+                ),
+                (
+                    &hints.vstems,
+                    operator::VERTICAL_STEM,
+                    operator::VERTICAL_STEM_HINT_MASK,
+                ),
+            ] {
+                if stems.is_empty() {
+                    continue;
+                }
+
+                // Each stem is relative to the end of the previous one.
+                let mut prev_end = 0.0;
+                for (position, width) in stems {
+                    writer.args.push(position - prev_end);
+                    writer.args.push(*width);
+                    prev_end = position + width;
+                }
+                writer.op(&[if has_masks { hm_op } else { op }]);
+            }
+        }
+
+        let mut commands = self.commands.iter().peekable();
+        while let Some(command) = commands.next() {
+            match command {
+                Command::HintMask(set) => {
+                    if has_masks {
+                        let mut mask = vec![0u8; (hints.hstems.len() + hints.vstems.len() + 7) / 8];
+                        let stems = hints.hstems.iter().map(|stem| set.hstems.contains(stem));
+                        let stems =
+                            stems.chain(hints.vstems.iter().map(|stem| set.vstems.contains(stem)));
+                        for (i, is_active) in stems.enumerate() {
+                            if is_active {
+                                mask[i / 8] |= 0x80 >> (i % 8);
+                            }
+                        }
+
+                        writer.op(&[operator::HINT_MASK]);
+                        writer.data.extend_from_slice(&mask);
+                    }
+                }
+                Command::MoveTo(x, y) => {
+                    writer.point(*x, *y);
+                    writer.op(&[operator::MOVE_TO]);
+                }
+                Command::LineTo(x, y) => {
+                    writer.point(*x, *y);
+                    while writer.args.len() + 2 <= MAX_ARGUMENTS_STACK_LEN {
+                        match commands.peek() {
+                            Some(Command::LineTo(x, y)) => writer.point(*x, *y),
+                            _ => break,
+                        }
+                        commands.next();
+                    }
+                    writer.op(&[operator::LINE_TO]);
+                }
+                Command::CurveTo(points) => {
+                    writer.points(points);
+                    while writer.args.len() + 6 <= MAX_ARGUMENTS_STACK_LEN {
+                        match commands.peek() {
+                            Some(Command::CurveTo(points)) => writer.points(points),
+                            _ => break,
+                        }
+                        commands.next();
+                    }
+                    writer.op(&[operator::CURVE_TO]);
+                }
+                Command::Flex(points, depth) => {
+                    writer.points(points);
+                    writer.args.push(*depth);
+                    writer.op(&[TWO_BYTE_OPERATOR_MARK, operator::FLEX]);
+                }
+            }
+        }
+
+        if let Some(seac) = self.seac {
+            writer.args.extend_from_slice(&seac);
+        }
+        writer.op(&[operator::ENDCHAR]);
+
+        writer.data
+    }
+}
+
+// Writes a Type 2 charstring from absolute coordinates.
+struct CharStringWriter {
+    data: Vec<u8>,
+    args: Vec<f32>,
+    // The width, if it must be written before the first operator.
+    width: Option<f32>,
+    // The current point, as written.
+    x: f32,
+    y: f32,
+}
+
+impl CharStringWriter {
+    fn point(&mut self, x: f32, y: f32) {
+        let dx = quantize(x - self.x);
+        let dy = quantize(y - self.y);
+        self.x += dx;
+        self.y += dy;
+        self.args.push(dx);
+        self.args.push(dy);
+    }
+
+    fn points(&mut self, points: &[f32]) {
+        for point in points.chunks(2) {
+            self.point(point[0], point[1]);
+        }
+    }
+
+    fn op(&mut self, op: &[u8]) {
+        if let Some(width) = self.width.take() {
+            write_char_string_number(width, &mut self.data);
+        }
+
+        for n in self.args.drain(..) {
+            write_char_string_number(n, &mut self.data);
+        }
+
+        self.data.extend_from_slice(op);
+    }
+}
+
+// Rounds to the precision of a 16.16 fixed number, unless the number is a small integer.
+fn quantize(n: f32) -> f32 {
+    if n.fract() == 0.0 && n.abs() <= 32767.0 {
+        n
+    } else {
+        (n * 65536.0).round() / 65536.0
+    }
+}
+
+fn write_char_string_number(n: f32, out: &mut Vec<u8>) {
+    if n.fract() == 0.0 && n.abs() <= 32767.0 {
+        // Small integers are encoded the same way as in DICTs.
+        write_int(n as i32, out);
+    } else {
+        out.push(operator::FIXED_16_16);
+        out.extend_from_slice(&((n * 65536.0).round() as i32).to_be_bytes());
+    }
+}
+
+// Interprets a Type 1 charstring and records its hints and path.
+struct Converter<'a, 'b> {
+    table: &'b type1::Table<'a>,
+    stack: Vec<f32>,
+    // The results of `callothersubr`, which are read by `pop`.
+    ps_stack: Vec<f32>,
+    x: f32,
+    y: f32,
+    // The left sidebearing point, which stems are relative to.
+    sbx: f32,
+    sby: f32,
+    // The points of a flex in progress.
+    flex: Option<Vec<(f32, f32)>>,
+    // The current hints and whether the next stem starts a new set.
+    hints: HintSet,
+    replace_hints: bool,
+    hints_changed: bool,
+    has_move_to: bool,
+    has_endchar: bool,
+    glyph: Glyph,
+}
+
+impl<'a, 'b> Converter<'a, 'b> {
+    fn new(table: &'b type1::Table<'a>) -> Self {
+        Converter {
+            table,
+            stack: Vec::new(),
+            ps_stack: Vec::new(),
+            x: 0.0,
+            y: 0.0,
+            sbx: 0.0,
+            sby: 0.0,
+            flex: None,
+            hints: HintSet::default(),
+            replace_hints: false,
+            hints_changed: false,
+            has_move_to: false,
+            has_endchar: false,
+            glyph: Glyph::default(),
+        }
+    }
+
+    fn convert(mut self, data: &[u8]) -> Result<Glyph, CFFError> {
+        self.run(data, 0)?;
+        if !self.has_endchar {
+            return Err(CFFError::MissingEndChar);
+        }
+
+        Ok(self.glyph)
+    }
+
+    fn run(&mut self, data: &[u8], depth: u8) -> Result<(), CFFError> {
+        if depth > STACK_LIMIT {
+            return Err(CFFError::NestingLimitReached);
+        }
+
+        let mut s = Stream::new(data);
+        while !s.at_end() && !self.has_endchar {
+            let op = s.read_byte().ok_or(CFFError::ReadOutOfBounds)?;
+            match op {
+                sb_operator::HORIZONTAL_STEM => {
+                    let [y, dy] = self.args()?;
+                    self.add_stem(true, (self.sby + y, dy));
+                }
+                sb_operator::VERTICAL_STEM => {
+                    let [x, dx] = self.args()?;
+                    self.add_stem(false, (self.sbx + x, dx));
+                }
+                sb_operator::MOVE_TO => {
+                    let [dx, dy] = self.args()?;
+                    self.move_to(dx, dy);
+                }
+                sb_operator::HORIZONTAL_MOVE_TO => {
+                    let [dx] = self.args()?;
+                    self.move_to(dx, 0.0);
+                }
+                sb_operator::VERTICAL_MOVE_TO => {
+                    let [dy] = self.args()?;
+                    self.move_to(0.0, dy);
+                }
+                sb_operator::LINE_TO => {
+                    let [dx, dy] = self.args()?;
+                    self.line_to(dx, dy);
+                }
+                sb_operator::HORIZONTAL_LINE_TO => {
+                    let [dx] = self.args()?;
+                    self.line_to(dx, 0.0);
+                }
+                sb_operator::VERTICAL_LINE_TO => {
+                    let [dy] = self.args()?;
+                    self.line_to(0.0, dy);
+                }
+                sb_operator::CURVE_TO => {
+                    let [dx1, dy1, dx2, dy2, dx3, dy3] = self.args()?;
+                    self.curve_to(dx1, dy1, dx2, dy2, dx3, dy3);
+                }
+                sb_operator::VH_CURVE_TO => {
+                    let [dy1, dx2, dy2, dx3] = self.args()?;
+                    self.curve_to(0.0, dy1, dx2, dy2, dx3, 0.0);
+                }
+                sb_operator::HV_CURVE_TO => {
+                    let [dx1, dx2, dy2, dy3] = self.args()?;
+                    self.curve_to(dx1, 0.0, dx2, dy2, 0.0, dy3);
+                }
+                // Type 2 contours are closed implicitly.
+                sb_operator::CLOSE_PATH => self.stack.clear(),
+                sb_operator::CALL_SUBR => {
+                    let index = self.pop()?;
+                    let subr = self
+                        .table
+                        .subroutine(index as u32)
+                        .ok_or(CFFError::NoLocalSubroutines)?;
+                    self.run(subr, depth + 1)?;
+                }
+                sb_operator::RETURN => return Ok(()),
+                sb_operator::HSBW => {
+                    let [sbx, wx] = self.args()?;
+                    self.set_side_bearing(sbx, 0.0, wx);
+                }
+                sb_operator::ENDCHAR => {
+                    self.stack.clear();
+                    self.has_endchar = true;
+                }
+                sb_operator::ESCAPE => {
+                    let op = s.read_byte().ok_or(CFFError::ReadOutOfBounds)?;
+                    self.run_escape(op)?;
+                }
+                32..=246 => self.push(f32::from(i16::from(op) - 139))?,
+                247..=250 => {
+                    let b1 = s.read_byte().ok_or(CFFError::ReadOutOfBounds)?;
+                    let n = (i16::from(op) - 247) * 256 + i16::from(b1) + 108;
+                    self.push(f32::from(n))?;
+                }
+                251..=254 => {
+                    let b1 = s.read_byte().ok_or(CFFError::ReadOutOfBounds)?;
+                    let n = -(i16::from(op) - 251) * 256 - i16::from(b1) - 108;
+                    self.push(f32::from(n))?;
+                }
+                255 => {
+                    let b = s.read_bytes(4).ok_or(CFFError::ReadOutOfBounds)?;
+                    self.push(i32::from_be_bytes([b[0], b[1], b[2], b[3]]) as f32)?;
+                }
+                _ => return Err(CFFError::InvalidOperator),
+            }
+        }
+
+        Ok(())
+    }
+
+    fn run_escape(&mut self, op: u8) -> Result<(), CFFError> {
+        match op {
+            tb_operator::DOTSECTION => self.stack.clear(),
+            tb_operator::VSTEM3 => {
+                let [x0, dx0, x1, dx1, x2, dx2] = self.args()?;
+                for stem in [(x0, dx0), (x1, dx1), (x2, dx2)] {
+                    self.add_stem(false, (self.sbx + stem.0, stem.1));
+                }
+            }
+            tb_operator::HSTEM3 => {
+                let [y0, dy0, y1, dy1, y2, dy2] = self.args()?;
+                for stem in [(y0, dy0), (y1, dy1), (y2, dy2)] {
+                    self.add_stem(true, (self.sby + stem.0, stem.1));
+                }
+            }
+            tb_operator::SEAC => {
+                let [asb, adx, ady, base_char, accent_char] = self.args()?;
+                // In Type 2, the accent outline includes its sidebearing.
+                self.glyph.seac = Some([adx - asb, ady, base_char, accent_char]);
+                self.has_endchar = true;
+            }
+            tb_operator::SBW => {
+                let [sbx, sby, wx, _] = self.args()?;
+                self.set_side_bearing(sbx, sby, wx);
+            }
+            tb_operator::DIV => {
+                let b = self.pop()?;
+                let a = self.pop()?;
+                self.push(a / b)?;
+            }
+            tb_operator::CALL_OTHER_SUBR => self.call_other_subr()?,
+            tb_operator::POP => {
+                let n = self.ps_stack.pop().unwrap_or(0.0);
+                self.push(n)?;
+            }
+            tb_operator::SET_CURRENT_POINT => {
+                let [x, y] = self.args()?;
+                self.x = x;
+                self.y = y;
+            }
+            _ => return Err(CFFError::InvalidOperator),
+        }
+
+        Ok(())
+    }
+
+    fn call_other_subr(&mut self) -> Result<(), CFFError> {
+        let index = self.pop()? as i32;
+        let len = self.pop()? as usize;
+        let start = self
+            .stack
+            .len()
+            .checked_sub(len)
+            .ok_or(CFFError::InvalidArgumentsStackLength)?;
+        let args = self.stack.split_off(start);
+
+        match index {
+            // The end of a flex.
+            0 => {
+                let points = self.flex.take().unwrap_or_default();
+                if args.len() != 3 || points.len() != 7 {
+                    return Err(CFFError::InvalidArgumentsStackLength);
+                }
+
+                // The first point is the reference point, which isn't drawn.
+                let mut flex = [0.0; 12];
+                for (i, (x, y)) in points[1..].iter().enumerate() {
+                    flex[i * 2] = *x;
+                    flex[i * 2 + 1] = *y;
+                }
+                self.draw(Command::Flex(flex, args[0]));
+
+                // The end point is read by `pop pop setcurrentpoint`.
+                self.ps_stack = vec![args[2], args[1]];
+            }
+            // The start of a flex.
+            1 => self.flex = Some(Vec::new()),
+            // A flex point.
+            2 => {
+                if let Some(ref mut points) = self.flex {
+                    points.push((self.x, self.y));
+                }
+            }
+            // Hint replacement. The argument is the subroutine with the new hints.
+            3 => {
+                self.replace_hints = true;
+                self.ps_stack = args;
+            }
+            _ => self.ps_stack = args.into_iter().rev().collect(),
+        }
+
+        Ok(())
+    }
+
+    fn set_side_bearing(&mut self, sbx: f32, sby: f32, width: f32) {
+        self.x = sbx;
+        self.y = sby;
+        self.sbx = sbx;
+        self.sby = sby;
+        self.glyph.width = width;
+    }
+
+    fn add_stem(&mut self, horizontal: bool, stem: Stem) {
+        if self.replace_hints {
+            self.hints = HintSet::default();
+            self.replace_hints = false;
+        }
+
+        self.hints.add(horizontal, stem);
+        self.glyph.hints.add(horizontal, stem);
+        self.hints_changed = true;
+    }
+
+    fn move_to(&mut self, dx: f32, dy: f32) {
+        self.x += dx;
+        self.y += dy;
+
+        // Flex points are collected by `callothersubr`.
+        if self.flex.is_none() {
+            self.draw(Command::MoveTo(self.x, self.y));
+            self.has_move_to = true;
+        }
+    }
+
+    fn line_to(&mut self, dx: f32, dy: f32) {
+        self.x += dx;
+        self.y += dy;
+        self.draw(Command::LineTo(self.x, self.y));
+    }
+
+    fn curve_to(&mut self, dx1: f32, dy1: f32, dx2: f32, dy2: f32, dx3: f32, dy3: f32) {
+        let (x1, y1) = (self.x + dx1, self.y + dy1);
+        let (x2, y2) = (x1 + dx2, y1 + dy2);
+        self.x = x2 + dx3;
+        self.y = y2 + dy3;
+        self.draw(Command::CurveTo([x1, y1, x2, y2, self.x, self.y]));
+    }
+
+    fn draw(&mut self, command: Command) {
+        if self.hints_changed {
+            self.glyph
+                .commands
+                .push(Command::HintMask(self.hints.clone()));
+            self.hints_changed = false;
+        }
+
+        // Type 2 paths must start with a move.
+        if !self.has_move_to && !matches!(command, Command::MoveTo(..)) {
+            self.glyph.commands.push(Command::MoveTo(0.0, 0.0));
+            self.has_move_to = true;
+        }
+
+        self.glyph.commands.push(command);
+    }
+
+    fn args<const N: usize>(&mut self) -> Result<[f32; N], CFFError> {
+        let start = self
+            .stack
+            .len()
+            .checked_sub(N)
+            .ok_or(CFFError::InvalidArgumentsStackLength)?;
+        let mut args = [0.0; N];
+        args.copy_from_slice(&self.stack[start..]);
+        self.stack.clear();
+        Ok(args)
+    }
+
+    fn push(&mut self, n: f32) -> Result<(), CFFError> {
+        if self.stack.len() == MAX_ARGUMENTS_STACK_LEN {
+            return Err(CFFError::ArgumentsStackLimitReached);
+        }
+
+        self.stack.push(n);
+        Ok(())
+    }
+
+    fn pop(&mut self) -> Result<f32, CFFError> {
+        self.stack
+            .pop()
+            .ok_or(CFFError::InvalidArgumentsStackLength)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::from_type1;
+    use crate::asm::assemble;
+    use crate::cff::Table;
+    use crate::disasm::CharStringKind;
+    use crate::{type1, GlyphId, OutlineBuilder, RectF};
+    use std::fmt::Write;
+
+    struct Builder(String);
+
+    impl OutlineBuilder for Builder {
+        fn move_to(&mut self, x: f32, y: f32) {
+            write!(&mut self.0, "M {} {} ", x, y).unwrap();
+        }
+
+        fn line_to(&mut self, x: f32, y: f32) {
+            write!(&mut self.0, "L {} {} ", x, y).unwrap();
+        }
+
+        fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+            write!(&mut self.0, "Q {} {} {} {} ", x1, y1, x, y).unwrap();
+        }
+
+        fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+            write!(&mut self.0, "C {} {} {} {} {} {} ", x1, y1, x2, y2, x, y).unwrap();
+        }
+
+        fn close(&mut self) {
+            write!(&mut self.0, "Z ").unwrap();
+        }
+    }
+
+    fn build_font() -> Vec<u8> {
+        let type1 = |text| assemble(text, CharStringKind::Type1).unwrap();
+        let subrs = [
+            "3 0 callothersubr pop pop setcurrentpoint return",
+            "0 1 callothersubr return",
+            "0 2 callothersubr return",
+            "return",
+            "1 3 callothersubr pop callsubr return",
+            "600 50 hstem return",
+            "700 vlineto 500 hlineto -700 vlineto return",
+        ];
+        let glyphs = [
+            (".notdef", "0 500 hsbw endchar"),
+            (
+                "A",
+                "20 600 hsbw 0 50 hstem 0 80 vstem 0 0 rmoveto 6 callsubr closepath endchar",
+            ),
+            (
+                "O",
+                "50 600 hsbw 0 300 rmoveto 1 callsubr 100 0 rmoveto 2 callsubr \
+                 -70 20 rmoveto 2 callsubr 40 0 rmoveto 2 callsubr \
+                 30 0 rmoveto 2 callsubr 30 0 rmoveto 2 callsubr \
+                 40 0 rmoveto 2 callsubr 30 -20 rmoveto 2 callsubr \
+                 50 250 300 0 callsubr -200 0 rlineto closepath endchar",
+            ),
+            (
+                "B",
+                "20 600 hsbw 0 50 hstem 0 80 vstem 0 0 rmoveto 0 300 rlineto \
+                 5 4 callsubr 300 0 rlineto 0 -300 rlineto closepath endchar",
+            ),
+            (
+                "acute",
+                "0 300 hsbw 100 500 rmoveto 50 100 rlineto 30 -10 rlineto closepath endchar",
+            ),
+            ("Aacute", "0 600 hsbw 0 100 50 65 194 seac"),
+        ];
+
+        let mut builder = type1::FontBuilder::new("Test")
+            .font_info(type1::FontInfo {
+                version: Some("001.000".to_string()),
+                notice: Some("Test (c) font".to_string()),
+                full_name: Some("Test Regular".to_string()),
+                family_name: Some("Test".to_string()),
+                weight: Some("Regular".to_string()),
+                italic_angle: -12.0,
+                ..type1::FontInfo::default()
+            })
+            .font_bbox(RectF {
+                x_min: -10.0,
+                y_min: -20.0,
+                x_max: 700.0,
+                y_max: 710.0,
+            })
+            .private_entry("BlueValues", "[-10 0 700 710]")
+            .private_entry("BlueScale", "0.039625")
+            .private_entry("StdHW", "[50]")
+            .private_entry("StdVW", "[80]");
+        for subr in subrs {
+            builder = builder.subroutine(&type1(subr));
+        }
+        for (name, text) in glyphs {
+            builder = builder.glyph(name, &type1(text));
+        }
+
+        builder.build(type1::Format::Pfa)
+    }
+
+    #[test]
+    fn convert() {
+        let font = build_font();
+        let type1 = type1::Table::parse(&font).unwrap();
+        let data = from_type1(&type1).unwrap();
+        let cff = Table::parse(&data).unwrap();
+
+        assert_eq!(cff.name(), Some("Test"));
+        assert_eq!(cff.number_of_glyphs(), 6);
+        assert_eq!(cff.matrix(), type1.matrix());
+
+        for glyph_id in 0..cff.number_of_glyphs() {
+            let glyph_id = GlyphId(glyph_id);
+            let name = type1.glyph_name(glyph_id).unwrap();
+            assert_eq!(cff.glyph_name(glyph_id), Some(name));
+            assert_eq!(
+                cff.glyph_width(glyph_id).map(f32::from),
+                type1.glyph_width(name)
+            );
+
+            let mut expected = Builder(String::new());
+            let mut actual = Builder(String::new());
+            type1.outline(name, &mut expected);
+            let bbox = cff.outline(glyph_id, &mut actual);
+            assert_eq!(bbox.is_ok(), !expected.0.is_empty());
+            assert_eq!(actual.0, expected.0, "glyph {}", name);
+        }
+
+        // The accent is moved by the `seac` offset of (100, 50).
+        let mut outline = Builder(String::new());
+        type1.outline("Aacute", &mut outline);
+        assert!(outline.0.ends_with("M 200 550 L 250 650 L 280 640 Z "));
+    }
+
+    #[test]
+    fn convert_char_strings() {
+        let font = build_font();
+        let type1 = type1::Table::parse(&font).unwrap();
+        let data = from_type1(&type1).unwrap();
+        let cff = Table::parse(&data).unwrap();
+
+        let type2 = |text| assemble(text, CharStringKind::Type2).unwrap();
+
+        // Hint replacement becomes `hintmask`, so all stems are declared as `hstemhm` and `vstemhm`.
+        let glyph_id = cff.glyph_index_by_name("B").unwrap();
+        let char_string = cff.char_strings.get(u32::from(glyph_id.0)).unwrap();
+        let start = type2("0 50 550 50 hstemhm 20 80 vstemhm hintmask 0b10100000 20 0 rmoveto");
+        assert!(char_string.starts_with(&start));
+        let mask = type2("hintmask 0b01000000");
+        assert!(char_string.windows(mask.len()).any(|w| w == mask));
+
+        // The accented glyph has the default width and `seac` becomes `endchar`.
+        let glyph_id = cff.glyph_index_by_name("Aacute").unwrap();
+        let char_string = cff.char_strings.get(u32::from(glyph_id.0)).unwrap();
+        assert_eq!(char_string, type2("100 50 65 194 endchar"));
+    }
+
+    #[test]
+    fn convert_dicts() {
+        let font = build_font();
+        let type1 = type1::Table::parse(&font).unwrap();
+        let data = from_type1(&type1).unwrap();
+
+        // `001.000` is a standard string.
+        assert!(data.windows(4).any(|w| w == [248, 15, 0, 248]));
+        for string in ["Test (c) font", "Test Regular"] {
+            let string = string.as_bytes();
+            assert!(data.windows(string.len()).any(|w| w == string));
+        }
+
+        // BlueValues are delta-encoded.
+        let blue_values = [0x81, 0x95, 0xF9, 0x50, 0x95, 6];
+        assert!(data.windows(blue_values.len()).any(|w| w == blue_values));
+    }
+}
@@ -45,30 +45,39 @@ fn parse_index_impl<'a>(count: u32, s: &mut Stream<'a>) -> Option<Index<'a>> {
     }
 }
 
-#[inline]
-pub fn skip_index<T: IndexSize>(s: &mut Stream) -> Option<()> {
-    let count = s.read::<T>()?;
-    skip_index_impl(count.to_u32(), s)
-}
-
-#[inline(never)]
-fn skip_index_impl(count: u32, s: &mut Stream) -> Option<()> {
-    if count == 0 || count == u32::MAX {
-        return Some(());
+/// Writes an INDEX with the given objects.
+///
+/// Uses the smallest offset size that can represent all offsets.
+pub(crate) fn write_index<T: AsRef<[u8]>>(objects: &[T], out: &mut Vec<u8>) {
+    out.extend_from_slice(&(objects.len() as u16).to_be_bytes());
+    if objects.is_empty() {
+        return;
     }
 
-    let offset_size = s.read::<OffsetSize>()?;
-    let offsets_len = (count + 1).checked_mul(offset_size.to_u32())?;
-    let offsets = VarOffsets {
-        data: s.read_bytes(usize::num_from(offsets_len))?,
-        offset_size,
+    // Offsets start at 1, so the last one is the data size plus one.
+    let data_len: usize = objects.iter().map(|o| o.as_ref().len()).sum();
+    let offset_size = match data_len + 1 {
+        0..=0xFF => OffsetSize::Size1,
+        0x100..=0xFFFF => OffsetSize::Size2,
+        0x1_0000..=0xFF_FFFF => OffsetSize::Size3,
+        _ => OffsetSize::Size4,
     };
+    out.push(offset_size as u8);
 
-    if let Some(last_offset) = offsets.last() {
-        s.advance(usize::num_from(last_offset));
+    let mut offset = 1u32;
+    let write_offset = |offset: u32, out: &mut Vec<u8>| {
+        let bytes = offset.to_be_bytes();
+        out.extend_from_slice(&bytes[4 - offset_size.to_usize()..]);
+    };
+    write_offset(offset, out);
+    for object in objects {
+        offset += object.as_ref().len() as u32;
+        write_offset(offset, out);
     }
 
-    Some(())
+    for object in objects {
+        out.extend_from_slice(object.as_ref());
+    }
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -233,4 +242,23 @@ mod tests {
         );
         assert_eq!(Stream::new(&[0x05]).read::<OffsetSize>(), None);
     }
+
+    #[test]
+    fn write_and_parse_index() {
+        let mut data = Vec::new();
+        write_index::<&[u8]>(&[], &mut data);
+        assert_eq!(data, [0x00, 0x00]);
+
+        let big = vec![7; 300];
+        let objects: [&[u8]; 3] = [b"ab", b"", &big];
+        data.clear();
+        write_index(&objects, &mut data);
+        assert_eq!(
+            &data[..11],
+            &[0x00, 0x03, 0x02, 0x00, 0x01, 0x00, 0x03, 0x00, 0x03, 0x01, 0x2F]
+        );
+
+        let index = parse_index::<u16>(&mut Stream::new(&data)).unwrap();
+        assert_eq!(index.into_iter().collect::<Vec<_>>(), objects);
+    }
 }
@@ -1,17 +1,26 @@
+mod builder;
 pub mod cff;
+mod char_map;
 mod charset;
 pub(crate) mod charstring;
 mod dict;
 mod encoding;
+mod from_type1;
 mod index;
 pub(crate) mod parser;
 mod std_names;
+mod subset;
 
 use core::convert::TryFrom;
 
 use parser::{FromData, TryNumFrom};
 
-pub use cff::Table;
+pub use builder::FontBuilder;
+pub use cff::{FontSet, Ros, Table};
+pub use char_map::CharMap;
+pub use encoding::Encoding;
+pub use from_type1::from_type1;
+pub use subset::{Subset, Subsetter};
 
 /// A list of errors that can occur during a CFF glyph outlining.
 #[allow(missing_docs)]
@@ -32,6 +41,9 @@ pub enum CFFError {
     InvalidSubroutineIndex,
     NoLocalSubroutines,
     InvalidSeacCode,
+    InvalidTransientArrayIndex,
+    UnsupportedFontKind,
+    TooManySupplements,
 }
 
 /// A type-safe wrapper for string ID.
@@ -0,0 +1,1127 @@
+//! A CFF subsetter.
+
+// Useful links:
+// http://wwwimages.adobe.com/content/dam/Adobe/en/devnet/font/pdfs/5176.CFF.pdf
+// http://wwwimages.adobe.com/content/dam/Adobe/en/devnet/font/pdfs/5177.Type2.pdf
+
+use std::collections::BTreeSet;
+
+use super::cff::{
+    operator, parse_font_dict, parse_private_dict, private_dict_operator, seac_code_to_glyph_id,
+    top_dict_operator, FontKind, Table, TWO_BYTE_OPERATOR_MARK,
+};
+use super::charset::write_charset;
+use super::dict::{write_fixed_int, write_operator, DictionaryParser};
+use super::encoding::write_encoding;
+use super::index::{parse_index, write_index, Index};
+use super::parser::Stream;
+use super::{calc_subroutine_bias, conv_subroutine_index, CFFError};
+use crate::{EncodingKind, GlyphId};
+
+// Limits according to the Adobe Technical Note #5176, chapter 4 DICT Data.
+const MAX_OPERANDS_LEN: usize = 48;
+
+// Limits according to the Adobe Technical Note #5177 Appendix B.
+const STACK_LIMIT: u8 = 10;
+const MAX_ARGUMENTS_STACK_LEN: usize = 48;
+
+// Replaces unused subroutines, so the indices of the other ones stay the same.
+const EMPTY_SUBR: &[u8] = &[operator::RETURN];
+
+// Replace glyphs that are not part of the subset when the glyph IDs are kept.
+const EMPTY_TYPE2_GLYPH: &[u8] = &[operator::ENDCHAR];
+// `0 0 hsbw endchar`
+const EMPTY_TYPE1_GLYPH: &[u8] = &[0x8B, 0x8B, 0x0D, 0x0E];
+
+/// A CFF font subsetter.
+///
+/// The subset contains the requested glyphs, the `.notdef` glyph and
+/// the components of accented glyphs built with `seac`.
+/// Unused subroutines are replaced by empty ones, so the subroutine
+/// indices in the remaining charstrings stay valid.
+#[derive(Clone, Debug)]
+pub struct Subsetter<'a, 'b> {
+    table: &'b Table<'a>,
+    glyphs: BTreeSet<GlyphId>,
+    keep_glyph_ids: bool,
+}
+
+impl<'a, 'b> Subsetter<'a, 'b> {
+    /// Creates a new subsetter for a font.
+    pub fn new(table: &'b Table<'a>) -> Self {
+        Subsetter {
+            table,
+            glyphs: BTreeSet::new(),
+            keep_glyph_ids: false,
+        }
+    }
+
+    /// Adds glyphs to the subset.
+    ///
+    /// Glyphs that are not present in the font are ignored.
+    pub fn glyphs<I: IntoIterator<Item = GlyphId>>(mut self, glyphs: I) -> Self {
+        self.glyphs.extend(glyphs);
+        self
+    }
+
+    /// Sets whether the subset keeps the glyph IDs of the font.
+    ///
+    /// By default, the glyphs are renumbered. When the glyph IDs are kept,
+    /// glyphs that are not part of the subset are replaced by empty ones.
+    pub fn keep_glyph_ids(mut self, keep: bool) -> Self {
+        self.keep_glyph_ids = keep;
+        self
+    }
+
+    /// Creates the subset.
+    pub fn subset(&self) -> Result<Subset, CFFError> {
+        let table = self.table;
+        let mut private_data = collect_private_data(table)?;
+
+        let mut global_usage = SubrUsage::new(table.global_subrs.len());
+        if table.has_type1_char_strings {
+            // Type 1 charstrings are not scanned for subroutine calls.
+            global_usage.mark_all();
+            for private in &mut private_data {
+                private.local_usage.mark_all();
+            }
+        }
+
+        let mut glyphs: BTreeSet<GlyphId> = self
+            .glyphs
+            .iter()
+            .copied()
+            .filter(|gid| gid.0 < table.number_of_glyphs())
+            .collect();
+        glyphs.insert(GlyphId(0));
+
+        let mut queue: Vec<GlyphId> = glyphs.iter().copied().collect();
+        while let Some(glyph_id) = queue.pop() {
+            let data = table
+                .char_strings
+                .get(u32::from(glyph_id.0))
+                .ok_or(CFFError::NoGlyph)?;
+
+            let seac = if table.has_type1_char_strings {
+                scan_type1_seac(data)
+            } else {
+                let private = &mut private_data[self.font_dict_index(glyph_id)?];
+                let mut scanner = Scanner {
+                    global_subrs: table.global_subrs,
+                    local_subrs: private.local_subrs,
+                    global_usage: &mut global_usage,
+                    local_usage: &mut private.local_usage,
+                    stack: Vec::new(),
+                    stems: 0,
+                    seac: None,
+                };
+                scanner.scan(data, 0)?;
+                scanner.seac
+            };
+
+            if let Some((base_char, accent_char)) = seac {
+                for code in [base_char, accent_char] {
+                    let component = seac_code_to_glyph_id(&table.charset, code)
+                        .ok_or(CFFError::InvalidSeacCode)?;
+                    if glyphs.insert(component) {
+                        queue.push(component);
+                    }
+                }
+            }
+        }
+
+        let mut subset = Subset {
+            data: Vec::new(),
+            glyphs: glyphs.into_iter().collect(),
+            keep_glyph_ids: self.keep_glyph_ids,
+        };
+
+        let new_glyphs: Vec<GlyphId> = if self.keep_glyph_ids {
+            (0..table.number_of_glyphs()).map(GlyphId).collect()
+        } else {
+            subset.glyphs.clone()
+        };
+
+        let empty_glyph = if table.has_type1_char_strings {
+            EMPTY_TYPE1_GLYPH
+        } else {
+            EMPTY_TYPE2_GLYPH
+        };
+        let char_strings = new_glyphs
+            .iter()
+            .map(|&glyph_id| {
+                if subset.glyph_id(glyph_id).is_some() {
+                    table
+                        .char_strings
+                        .get(u32::from(glyph_id.0))
+                        .ok_or(CFFError::NoGlyph)
+                } else {
+                    Ok(empty_glyph)
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let sids = new_glyphs
+            .iter()
+            .map(|glyph_id| table.charset.gid_to_sid(*glyph_id).ok_or(CFFError::NoGlyph))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // Only the Font DICTs of the glyphs in the subset are kept.
+        let mut fd_map = vec![None; private_data.len()];
+        let mut used_fds = Vec::new();
+        for glyph_id in &subset.glyphs {
+            let fd = self.font_dict_index(*glyph_id)?;
+            if fd_map[fd].is_none() {
+                fd_map[fd] = Some(0);
+                used_fds.push(fd);
+            }
+        }
+        used_fds.sort_unstable();
+        for (new_fd, fd) in used_fds.iter().enumerate() {
+            fd_map[*fd] = Some(new_fd as u8);
+        }
+
+        let private_blobs: Vec<(usize, Vec<u8>)> = used_fds
+            .iter()
+            .map(|fd| {
+                let private = &private_data[*fd];
+                let subrs = prune_subrs(private.local_subrs, &private.local_usage);
+                let mut data = build_private_dict(private.private_dict, !subrs.is_empty());
+                let dict_len = data.len();
+                if !subrs.is_empty() {
+                    write_index(&subrs, &mut data);
+                }
+
+                (dict_len, data)
+            })
+            .collect();
+
+        let mut offsets = Offsets::default();
+        let encoding = match table.encoding() {
+            Some(encoding) => match encoding.kind() {
+                EncodingKind::Standard => None,
+                EncodingKind::Expert => {
+                    offsets.encoding = Some(1);
+                    None
+                }
+                EncodingKind::Custom => {
+                    // A placeholder, the offset is known only after the layout.
+                    offsets.encoding = Some(0);
+
+                    let mut codes = vec![Vec::new(); new_glyphs.len()];
+                    for (code, glyph_id) in encoding.iter() {
+                        if let Some(new_glyph_id) = subset.glyph_id(glyph_id) {
+                            codes[usize::from(new_glyph_id.0)].push(code);
+                        }
+                    }
+
+                    Some(write_encoding(&codes, &sids)?)
+                }
+            },
+            None => None,
+        };
+
+        let is_cid = matches!(table.kind, FontKind::CID(_));
+        let fd_select = if is_cid {
+            let mut ranges: Vec<(u16, u8)> = Vec::new();
+            for (new_glyph_id, glyph_id) in new_glyphs.iter().enumerate() {
+                let fd = self
+                    .font_dict_index(*glyph_id)
+                    .ok()
+                    .and_then(|fd| fd_map[fd])
+                    .unwrap_or(0);
+                if ranges.last().map(|range| range.1) != Some(fd) {
+                    ranges.push((new_glyph_id as u16, fd));
+                }
+            }
+
+            let mut data = vec![3];
+            data.extend_from_slice(&(ranges.len() as u16).to_be_bytes());
+            for (first, fd) in ranges {
+                data.extend_from_slice(&first.to_be_bytes());
+                data.push(fd);
+            }
+            data.extend_from_slice(&(new_glyphs.len() as u16).to_be_bytes());
+            Some(data)
+        } else {
+            None
+        };
+
+        let mut data = vec![1, 0, 4, 4];
+        let name = table.name().unwrap_or("Subset");
+        write_index(&[name.as_bytes()], &mut data);
+
+        // All offsets in the Top DICT are written in the five byte form,
+        // so its size doesn't depend on them.
+        let mut top_dict_index = Vec::new();
+        write_index(
+            &[build_top_dict(table.top_dict_data, is_cid, &offsets)],
+            &mut top_dict_index,
+        );
+        let base = data.len() + top_dict_index.len();
+
+        let mut tail = Vec::new();
+        write_index(&table.strings.into_iter().collect::<Vec<_>>(), &mut tail);
+        let global_subrs = prune_subrs(table.global_subrs, &global_usage);
+        write_index(&global_subrs, &mut tail);
+
+        offsets.charset = base + tail.len();
+        tail.extend_from_slice(&write_charset(&sids));
+
+        if let Some(ref encoding) = encoding {
+            offsets.encoding = Some(base + tail.len());
+            tail.extend_from_slice(encoding);
+        }
+
+        if let Some(ref fd_select) = fd_select {
+            offsets.fd_select = base + tail.len();
+            tail.extend_from_slice(fd_select);
+        }
+
+        offsets.char_strings = base + tail.len();
+        write_index(&char_strings, &mut tail);
+
+        if is_cid {
+            offsets.fd_array = base + tail.len();
+
+            let build_font_dicts = |mut private_offset: usize| -> Vec<Vec<u8>> {
+                used_fds
+                    .iter()
+                    .zip(&private_blobs)
+                    .map(|(fd, (dict_len, blob))| {
+                        let dict =
+                            build_font_dict(private_data[*fd].font_dict, *dict_len, private_offset);
+                        private_offset += blob.len();
+                        dict
+                    })
+                    .collect()
+            };
+
+            let mut fd_array = Vec::new();
+            write_index(&build_font_dicts(0), &mut fd_array);
+            let private_offset = offsets.fd_array + fd_array.len();
+            write_index(&build_font_dicts(private_offset), &mut tail);
+        } else if let Some((dict_len, _)) = private_blobs.first() {
+            offsets.private = Some((*dict_len, base + tail.len()));
+        }
+
+        for (_, blob) in &private_blobs {
+            tail.extend_from_slice(blob);
+        }
+
+        write_index(
+            &[build_top_dict(table.top_dict_data, is_cid, &offsets)],
+            &mut data,
+        );
+        data.extend_from_slice(&tail);
+
+        subset.data = data;
+        Ok(subset)
+    }
+
+    fn font_dict_index(&self, glyph_id: GlyphId) -> Result<usize, CFFError> {
+        match self.table.kind {
+            FontKind::SID(_) => Ok(0),
+            FontKind::CID(ref cid) => cid
+                .fd_select
+                .font_dict_index(glyph_id)
+                .filter(|fd| u32::from(*fd) < cid.fd_array.len())
+                .map(usize::from)
+                .ok_or(CFFError::NoGlyph),
+        }
+    }
+}
+
+/// A subset of a CFF font, created by a [`Subsetter`].
+#[derive(Clone, Debug)]
+pub struct Subset {
+    data: Vec<u8>,
+    // The glyphs of the font that are part of the subset, in ascending order.
+    glyphs: Vec<GlyphId>,
+    keep_glyph_ids: bool,
+}
+
+impl Subset {
+    /// Returns the CFF data of the subset.
+    #[inline]
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Returns the CFF data of the subset.
+    #[inline]
+    pub fn into_data(self) -> Vec<u8> {
+        self.data
+    }
+
+    /// Returns the ID in the subset of a glyph from the original font.
+    ///
+    /// Returns `None` if the glyph is not part of the subset.
+    pub fn glyph_id(&self, glyph_id: GlyphId) -> Option<GlyphId> {
+        let index = self.glyphs.binary_search(&glyph_id).ok()?;
+        if self.keep_glyph_ids {
+            Some(glyph_id)
+        } else {
+            Some(GlyphId(index as u16))
+        }
+    }
+
+    /// Returns an iterator over the glyphs of the original font that are part of the subset.
+    ///
+    /// Includes the `.notdef` glyph and `seac` components.
+    pub fn glyphs(&self) -> impl Iterator<Item = GlyphId> + '_ {
+        self.glyphs.iter().copied()
+    }
+}
+
+// The offsets written to the Top DICT.
+#[derive(Default)]
+struct Offsets {
+    charset: usize,
+    // The offset of a custom encoding or the ID of a predefined one.
+    encoding: Option<usize>,
+    char_strings: usize,
+    // The size and the offset of the Private DICT in SID fonts.
+    private: Option<(usize, usize)>,
+    fd_array: usize,
+    fd_select: usize,
+}
+
+// The Private DICT of a SID font or of a Font DICT in a CID font.
+struct PrivateData<'a> {
+    // Empty in SID fonts.
+    font_dict: &'a [u8],
+    private_dict: &'a [u8],
+    local_subrs: Index<'a>,
+    local_usage: SubrUsage,
+}
+
+impl<'a> PrivateData<'a> {
+    fn new(font_dict: &'a [u8], private_dict: &'a [u8], local_subrs: Index<'a>) -> Self {
+        PrivateData {
+            font_dict,
+            private_dict,
+            local_subrs,
+            local_usage: SubrUsage::new(local_subrs.len()),
+        }
+    }
+}
+
+fn collect_private_data<'a>(table: &Table<'a>) -> Result<Vec<PrivateData<'a>>, CFFError> {
+    match table.kind {
+        FontKind::SID(ref sid) => Ok(vec![PrivateData::new(
+            &[],
+            sid.private_dict,
+            sid.local_subrs,
+        )]),
+        FontKind::CID(ref cid) => cid
+            .fd_array
+            .into_iter()
+            .map(|font_dict| {
+                let range = match parse_font_dict(font_dict).private_dict_range {
+                    Some(range) => range,
+                    None => return Ok(PrivateData::new(font_dict, &[], Index::default())),
+                };
+
+                let private_dict = table
+                    .table_data
+                    .get(range.clone())
+                    .ok_or(CFFError::ReadOutOfBounds)?;
+
+                let mut local_subrs = Index::default();
+                if let Some(offset) = parse_private_dict(private_dict).local_subroutines_offset {
+                    // 'The local subroutines offset is relative to the beginning
+                    // of the Private DICT data.'
+                    local_subrs = range
+                        .start
+                        .checked_add(offset)
+                        .and_then(|start| Stream::new_at(table.table_data, start))
+                        .and_then(|mut s| parse_index::<u16>(&mut s))
+                        .ok_or(CFFError::ReadOutOfBounds)?;
+                }
+
+                Ok(PrivateData::new(font_dict, private_dict, local_subrs))
+            })
+            .collect(),
+    }
+}
+
+// Tracks which subroutines of an INDEX are called.
+struct SubrUsage {
+    used: Vec<bool>,
+    all: bool,
+}
+
+impl SubrUsage {
+    fn new(len: u32) -> Self {
+        SubrUsage {
+            used: vec![false; len as usize],
+            all: false,
+        }
+    }
+
+    fn mark(&mut self, index: u32) {
+        if let Some(used) = self.used.get_mut(index as usize) {
+            *used = true;
+        }
+    }
+
+    fn mark_all(&mut self) {
+        self.all = true;
+    }
+
+    fn is_used(&self, index: usize) -> bool {
+        self.all || self.used.get(index).copied().unwrap_or(false)
+    }
+}
+
+// Replaces unused subroutines and drops the trailing ones,
+// as long as this doesn't change the subroutine bias.
+fn prune_subrs<'a>(subrs: Index<'a>, usage: &SubrUsage) -> Vec<&'a [u8]> {
+    let mut objects: Vec<&[u8]> = subrs
+        .into_iter()
+        .enumerate()
+        .map(|(i, data)| if usage.is_used(i) { data } else { EMPTY_SUBR })
+        .collect();
+
+    let bias = calc_subroutine_bias(subrs.len());
+    while let Some(last) = objects.len().checked_sub(1) {
+        if usage.is_used(last) || calc_subroutine_bias(last as u32) != bias {
+            break;
+        }
+
+        objects.pop();
+    }
+
+    objects
+}
+
+// Finds the subroutines and the `seac` components used by a Type 2 charstring.
+struct Scanner<'a, 'b> {
+    global_subrs: Index<'a>,
+    local_subrs: Index<'a>,
+    global_usage: &'b mut SubrUsage,
+    local_usage: &'b mut SubrUsage,
+    // Operands that cannot be known without interpreting the charstring,
+    // like results of arithmetic operators, are `None`.
+    stack: Vec<Option<f32>>,
+    stems: usize,
+    seac: Option<(f32, f32)>,
+}
+
+impl Scanner<'_, '_> {
+    // Returns `true` when `endchar` was reached.
+    fn scan(&mut self, data: &[u8], depth: u8) -> Result<bool, CFFError> {
+        if depth > STACK_LIMIT {
+            return Err(CFFError::NestingLimitReached);
+        }
+
+        let mut s = Stream::new(data);
+        while !s.at_end() {
+            let op = s.read::<u8>().ok_or(CFFError::ReadOutOfBounds)?;
+            match op {
+                operator::SHORT_INT => {
+                    let n = s.read::<i16>().ok_or(CFFError::ReadOutOfBounds)?;
+                    self.push(Some(f32::from(n)))?;
+                }
+                32..=246 => {
+                    self.push(Some(f32::from(i16::from(op) - 139)))?;
+                }
+                247..=250 => {
+                    let b1 = s.read::<u8>().ok_or(CFFError::ReadOutOfBounds)?;
+                    let n = (i16::from(op) - 247) * 256 + i16::from(b1) + 108;
+                    self.push(Some(f32::from(n)))?;
+                }
+                251..=254 => {
+                    let b1 = s.read::<u8>().ok_or(CFFError::ReadOutOfBounds)?;
+                    let n = -(i16::from(op) - 251) * 256 - i16::from(b1) - 108;
+                    self.push(Some(f32::from(n)))?;
+                }
+                operator::FIXED_16_16 => {
+                    let n = s.read::<i32>().ok_or(CFFError::ReadOutOfBounds)?;
+                    self.push(Some(n as f32 / 65536.0))?;
+                }
+                operator::CALL_LOCAL_SUBROUTINE | operator::CALL_GLOBAL_SUBROUTINE => {
+                    let is_local = op == operator::CALL_LOCAL_SUBROUTINE;
+                    let subrs = if is_local {
+                        self.local_subrs
+                    } else {
+                        self.global_subrs
+                    };
+
+                    let bias = calc_subroutine_bias(subrs.len());
+                    let index = self
+                        .stack
+                        .pop()
+                        .flatten()
+                        .and_then(|n| conv_subroutine_index(n, bias).ok());
+
+                    let usage = if is_local {
+                        &mut *self.local_usage
+                    } else {
+                        &mut *self.global_usage
+                    };
+
+                    match index {
+                        Some(index) => {
+                            usage.mark(index);
+                            if let Some(subr) = subrs.get(index) {
+                                if self.scan(subr, depth + 1)? {
+                                    return Ok(true);
+                                }
+                            }
+                        }
+                        // The index is computed, so any subroutine can be called.
+                        None => usage.mark_all(),
+                    }
+                }
+                operator::RETURN => return Ok(false),
+                operator::ENDCHAR => {
+                    if self.stack.len() == 4 || self.stack.len() == 5 {
+                        let len = self.stack.len();
+                        if let (Some(base_char), Some(accent_char)) =
+                            (self.stack[len - 2], self.stack[len - 1])
+                        {
+                            self.seac = Some((base_char, accent_char));
+                        }
+                    }
+
+                    return Ok(true);
+                }
+                operator::HORIZONTAL_STEM
+                | operator::VERTICAL_STEM
+                | operator::HORIZONTAL_STEM_HINT_MASK
+                | operator::VERTICAL_STEM_HINT_MASK => {
+                    self.stems += self.stack.len() / 2;
+                    self.stack.clear();
+                }
+                operator::HINT_MASK | operator::COUNTER_MASK => {
+                    // Operands before a mask are an implicit `vstem`.
+                    self.stems += self.stack.len() / 2;
+                    self.stack.clear();
+                    s.read_bytes((self.stems + 7) / 8)
+                        .ok_or(CFFError::ReadOutOfBounds)?;
+                }
+                TWO_BYTE_OPERATOR_MARK => {
+                    let op = s.read::<u8>().ok_or(CFFError::ReadOutOfBounds)?;
+                    self.scan_escape(op)?;
+                }
+                _ => self.stack.clear(),
+            }
+        }
+
+        Ok(false)
+    }
+
+    fn scan_escape(&mut self, op: u8) -> Result<(), CFFError> {
+        match op {
+            operator::AND
+            | operator::OR
+            | operator::ADD
+            | operator::SUB
+            | operator::DIV
+            | operator::MUL
+            | operator::EQ => {
+                self.pop(2);
+                self.push(None)?;
+            }
+            operator::NOT
+            | operator::ABS
+            | operator::NEG
+            | operator::SQRT
+            | operator::GET
+            | operator::INDEX => {
+                self.pop(1);
+                self.push(None)?;
+            }
+            operator::IF_ELSE => {
+                self.pop(4);
+                self.push(None)?;
+            }
+            operator::RANDOM => self.push(None)?,
+            operator::DROP => self.pop(1),
+            operator::PUT => self.pop(2),
+            operator::DUP => {
+                if let Some(n) = self.stack.last().copied() {
+                    self.push(n)?;
+                }
+            }
+            operator::EXCH => {
+                let len = self.stack.len();
+                if len >= 2 {
+                    self.stack.swap(len - 1, len - 2);
+                }
+            }
+            operator::ROLL => {
+                self.pop(2);
+                for n in &mut self.stack {
+                    *n = None;
+                }
+            }
+            _ => self.stack.clear(),
+        }
+
+        Ok(())
+    }
+
+    fn push(&mut self, n: Option<f32>) -> Result<(), CFFError> {
+        if self.stack.len() == MAX_ARGUMENTS_STACK_LEN {
+            return Err(CFFError::ArgumentsStackLimitReached);
+        }
+
+        self.stack.push(n);
+        Ok(())
+    }
+
+    fn pop(&mut self, n: usize) {
+        self.stack.truncate(self.stack.len().saturating_sub(n));
+    }
+}
+
+// Returns the `seac` codes of a Type 1 charstring.
+fn scan_type1_seac(data: &[u8]) -> Option<(f32, f32)> {
+    let mut s = Stream::new(data);
+    let mut stack = Vec::new();
+    while !s.at_end() {
+        let op = s.read::<u8>()?;
+        match op {
+            32..=246 => stack.push(f32::from(i16::from(op) - 139)),
+            247..=250 => {
+                let b1 = s.read::<u8>()?;
+                stack.push(f32::from((i16::from(op) - 247) * 256 + i16::from(b1) + 108));
+            }
+            251..=254 => {
+                let b1 = s.read::<u8>()?;
+                stack.push(f32::from(
+                    -(i16::from(op) - 251) * 256 - i16::from(b1) - 108,
+                ));
+            }
+            255 => stack.push(s.read::<i32>()? as f32),
+            // `seac`
+            TWO_BYTE_OPERATOR_MARK if s.read::<u8>()? == 6 => {
+                let len = stack.len();
+                return if len >= 2 {
+                    Some((stack[len - 2], stack[len - 1]))
+                } else {
+                    None
+                };
+            }
+            _ => stack.clear(),
+        }
+    }
+
+    None
+}
+
+// Copies all DICT entries except the ones with the given operators.
+fn copy_dict(data: &[u8], skip: &[u16]) -> Vec<u8> {
+    let mut operands_buffer = [0.0; MAX_OPERANDS_LEN];
+    let mut dict_parser = DictionaryParser::new(data, &mut operands_buffer);
+    let mut dict = Vec::new();
+    while let Some(operator) = dict_parser.parse_next() {
+        if !skip.contains(&operator.get()) {
+            dict.extend_from_slice(dict_parser.entry_data());
+        }
+    }
+
+    dict
+}
+
+fn write_offset_entry(operands: &[usize], operator: u16, dict: &mut Vec<u8>) {
+    for n in operands {
+        write_fixed_int(*n as i32, dict);
+    }
+
+    write_operator(operator, dict);
+}
+
+fn build_top_dict(data: &[u8], is_cid: bool, offsets: &Offsets) -> Vec<u8> {
+    // ROS must be the first entry of a CID font,
+    // which is preserved since the entries are copied in order.
+    let mut dict = copy_dict(
+        data,
+        &[
+            top_dict_operator::CHARSET_OFFSET,
+            top_dict_operator::ENCODING_OFFSET,
+            top_dict_operator::CHAR_STRINGS_OFFSET,
+            top_dict_operator::PRIVATE_DICT_SIZE_AND_OFFSET,
+            top_dict_operator::FD_ARRAY,
+            top_dict_operator::FD_SELECT,
+        ],
+    );
+
+    write_offset_entry(
+        &[offsets.charset],
+        top_dict_operator::CHARSET_OFFSET,
+        &mut dict,
+    );
+    if let Some(encoding) = offsets.encoding {
+        write_offset_entry(&[encoding], top_dict_operator::ENCODING_OFFSET, &mut dict);
+    }
+    write_offset_entry(
+        &[offsets.char_strings],
+        top_dict_operator::CHAR_STRINGS_OFFSET,
+        &mut dict,
+    );
+
+    if is_cid {
+        write_offset_entry(&[offsets.fd_array], top_dict_operator::FD_ARRAY, &mut dict);
+        write_offset_entry(
+            &[offsets.fd_select],
+            top_dict_operator::FD_SELECT,
+            &mut dict,
+        );
+    } else {
+        let (size, offset) = offsets.private.unwrap_or_default();
+        write_offset_entry(
+            &[size, offset],
+            top_dict_operator::PRIVATE_DICT_SIZE_AND_OFFSET,
+            &mut dict,
+        );
+    }
+
+    dict
+}
+
+fn build_font_dict(data: &[u8], private_dict_len: usize, private_dict_offset: usize) -> Vec<u8> {
+    let mut dict = copy_dict(data, &[top_dict_operator::PRIVATE_DICT_SIZE_AND_OFFSET]);
+    write_offset_entry(
+        &[private_dict_len, private_dict_offset],
+        top_dict_operator::PRIVATE_DICT_SIZE_AND_OFFSET,
+        &mut dict,
+    );
+    dict
+}
+
+fn build_private_dict(data: &[u8], has_subrs: bool) -> Vec<u8> {
+    let mut dict = copy_dict(data, &[private_dict_operator::LOCAL_SUBROUTINES_OFFSET]);
+    if has_subrs {
+        // The Subrs INDEX directly follows the Private DICT,
+        // after the five byte offset and the operator.
+        let offset = dict.len() + 6;
+        write_offset_entry(
+            &[offset],
+            private_dict_operator::LOCAL_SUBROUTINES_OFFSET,
+            &mut dict,
+        );
+    }
+
+    dict
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cff::dict::write_int;
+    use crate::outline::{PathRecorder, PathSegment};
+
+    // Global subrs: `50 hlineto return` and `40 vlineto return`.
+    const GLOBAL_SUBRS: &[&[u8]] = &[&[0xBD, 0x06, 0x0B], &[0xB3, 0x07, 0x0B]];
+    // Local subrs: `30 hlineto return`.
+    const LOCAL_SUBRS: &[&[u8]] = &[&[0xA9, 0x06, 0x0B]];
+    // A, B, acute and Aacute.
+    const CHARSET: &[u16] = &[34, 35, 125, 171];
+    const CHAR_STRINGS: &[&[u8]] = &[
+        // endchar
+        &[0x0E],
+        // 10 20 rmoveto -107 callsubr -106 callgsubr endchar
+        &[0x95, 0x9F, 0x15, 0x20, 0x0A, 0x21, 0x1D, 0x0E],
+        // 0 0 rmoveto -107 callgsubr endchar
+        &[0x8B, 0x8B, 0x15, 0x20, 0x1D, 0x0E],
+        // 0 10 hstem hintmask 0x0A 0 0 rmoveto 30 vlineto endchar
+        // The mask byte must not be treated as `callsubr`.
+        &[
+            0x8B, 0x95, 0x01, 0x13, 0x0A, 0x8B, 0x8B, 0x15, 0xA9, 0x07, 0x0E,
+        ],
+        // 0 0 65 194 endchar
+        &[0x8B, 0x8B, 0xCC, 0xF7, 0x56, 0x0E],
+    ];
+
+    // Builds a SID font, or a CID font with one Font DICT
+    // per local subrs list when `fd_select` is not empty.
+    fn build_font(
+        global_subrs: &[&[u8]],
+        local_subrs: &[&[&[u8]]],
+        fd_select: &[u8],
+        charset: &[u16],
+        encoding: Option<&[u8]>,
+        char_strings: &[&[u8]],
+    ) -> Vec<u8> {
+        let is_cid = !fd_select.is_empty();
+        let top_dict = |offsets: &[usize]| {
+            let mut dict = Vec::new();
+            if is_cid {
+                write_int(391, &mut dict);
+                write_int(392, &mut dict);
+                write_int(0, &mut dict);
+                write_operator(top_dict_operator::ROS, &mut dict);
+            }
+
+            write_offset_entry(&offsets[0..1], top_dict_operator::CHARSET_OFFSET, &mut dict);
+            write_offset_entry(
+                &offsets[1..2],
+                top_dict_operator::CHAR_STRINGS_OFFSET,
+                &mut dict,
+            );
+            if encoding.is_some() {
+                write_offset_entry(
+                    &offsets[2..3],
+                    top_dict_operator::ENCODING_OFFSET,
+                    &mut dict,
+                );
+            }
+            if is_cid {
+                write_offset_entry(&offsets[3..4], top_dict_operator::FD_SELECT, &mut dict);
+                write_offset_entry(&offsets[4..5], top_dict_operator::FD_ARRAY, &mut dict);
+            } else {
+                write_offset_entry(
+                    &offsets[4..6],
+                    top_dict_operator::PRIVATE_DICT_SIZE_AND_OFFSET,
+                    &mut dict,
+                );
+            }
+            dict
+        };
+
+        let mut data = vec![1, 0, 4, 4];
+        write_index(&[b"Test"], &mut data);
+        let mut top_dict_index = Vec::new();
+        write_index(&[top_dict(&[0; 6])], &mut top_dict_index);
+        let base = data.len() + top_dict_index.len();
+
+        let mut offsets = [0; 6];
+        let mut tail = Vec::new();
+        write_index(&[&b"Adobe"[..], b"Identity"], &mut tail);
+        write_index(global_subrs, &mut tail);
+
+        offsets[0] = base + tail.len();
+        tail.push(0);
+        for sid in charset {
+            tail.extend_from_slice(&sid.to_be_bytes());
+        }
+
+        if let Some(encoding) = encoding {
+            offsets[2] = base + tail.len();
+            tail.extend_from_slice(encoding);
+        }
+
+        if is_cid {
+            offsets[3] = base + tail.len();
+            tail.push(0);
+            tail.extend_from_slice(fd_select);
+        }
+
+        offsets[1] = base + tail.len();
+        write_index(char_strings, &mut tail);
+
+        let privates: Vec<Vec<u8>> = local_subrs
+            .iter()
+            .map(|subrs| {
+                let mut private = Vec::new();
+                if !subrs.is_empty() {
+                    write_offset_entry(
+                        &[6],
+                        private_dict_operator::LOCAL_SUBROUTINES_OFFSET,
+                        &mut private,
+                    );
+                    write_index(subrs, &mut private);
+                }
+                private
+            })
+            .collect();
+        let private_dict_len = |subrs: &[&[u8]]| if subrs.is_empty() { 0 } else { 6 };
+
+        if is_cid {
+            offsets[4] = base + tail.len();
+            // Each Font DICT has 11 bytes.
+            let mut private_offset =
+                offsets[4] + 3 + (local_subrs.len() + 1) + 11 * local_subrs.len();
+            let font_dicts: Vec<Vec<u8>> = local_subrs
+                .iter()
+                .zip(&privates)
+                .map(|(subrs, private)| {
+                    let dict = build_font_dict(&[], private_dict_len(subrs), private_offset);
+                    private_offset += private.len();
+                    dict
+                })
+                .collect();
+            write_index(&font_dicts, &mut tail);
+        } else {
+            offsets[4] = private_dict_len(local_subrs[0]);
+            offsets[5] = base + tail.len();
+        }
+
+        for private in privates {
+            tail.extend_from_slice(&private);
+        }
+
+        write_index(&[top_dict(&offsets)], &mut data);
+        data.extend_from_slice(&tail);
+        data
+    }
+
+    fn outline(table: &Table, glyph_id: u16) -> Vec<PathSegment> {
+        let mut recorder = PathRecorder::new();
+        table.outline(GlyphId(glyph_id), &mut recorder).unwrap();
+        recorder.into_segments()
+    }
+
+    #[test]
+    fn subset_sid_font() {
+        // A, B and acute are encoded.
+        let encoding = [0, 3, 65, 66, 194];
+        let data = build_font(
+            GLOBAL_SUBRS,
+            &[LOCAL_SUBRS],
+            &[],
+            CHARSET,
+            Some(&encoding),
+            CHAR_STRINGS,
+        );
+        let table = Table::parse(&data).unwrap();
+
+        let subset = Subsetter::new(&table)
+            .glyphs([GlyphId(4)])
+            .subset()
+            .unwrap();
+        assert_eq!(
+            subset.glyphs().collect::<Vec<_>>(),
+            [GlyphId(0), GlyphId(1), GlyphId(3), GlyphId(4)]
+        );
+        assert_eq!(subset.glyph_id(GlyphId(3)), Some(GlyphId(2)));
+        assert_eq!(subset.glyph_id(GlyphId(2)), None);
+
+        let subset_table = Table::parse(subset.data()).unwrap();
+        assert_eq!(subset_table.name(), Some("Test"));
+        assert_eq!(subset_table.number_of_glyphs(), 4);
+        assert_eq!(subset_table.glyph_name(GlyphId(1)), Some("A"));
+        assert_eq!(subset_table.glyph_name(GlyphId(2)), Some("acute"));
+        assert_eq!(subset_table.glyph_name(GlyphId(3)), Some("Aacute"));
+        assert_eq!(outline(&subset_table, 3), outline(&table, 4));
+
+        // The unused global subr is emptied, the local one is kept.
+        let global_subrs: Vec<_> = subset_table.global_subrs.into_iter().collect();
+        assert_eq!(global_subrs, [EMPTY_SUBR, GLOBAL_SUBRS[1]]);
+
+        let encoding = subset_table.encoding().unwrap();
+        assert_eq!(encoding.kind(), EncodingKind::Custom);
+        assert_eq!(encoding.glyph_index(65), Some(GlyphId(1)));
+        assert_eq!(encoding.glyph_index(66), None);
+        assert_eq!(encoding.glyph_index(194), Some(GlyphId(2)));
+    }
+
+    #[test]
+    fn drop_trailing_subrs() {
+        let data = build_font(
+            GLOBAL_SUBRS,
+            &[LOCAL_SUBRS],
+            &[],
+            CHARSET,
+            None,
+            CHAR_STRINGS,
+        );
+        let table = Table::parse(&data).unwrap();
+
+        let subset = Subsetter::new(&table)
+            .glyphs([GlyphId(2)])
+            .subset()
+            .unwrap();
+        let subset_table = Table::parse(subset.data()).unwrap();
+        assert_eq!(subset_table.global_subrs.len(), 1);
+        assert_eq!(outline(&subset_table, 1), outline(&table, 2));
+        assert_eq!(
+            subset_table.encoding().unwrap().kind(),
+            EncodingKind::Standard
+        );
+        match subset_table.kind {
+            FontKind::SID(ref sid) => assert_eq!(sid.local_subrs.len(), 0),
+            FontKind::CID(_) => panic!("expected a SID font"),
+        }
+    }
+
+    #[test]
+    fn keep_glyph_ids() {
+        let encoding = [0, 3, 65, 66, 194];
+        let data = build_font(
+            GLOBAL_SUBRS,
+            &[LOCAL_SUBRS],
+            &[],
+            CHARSET,
+            Some(&encoding),
+            CHAR_STRINGS,
+        );
+        let table = Table::parse(&data).unwrap();
+
+        let subset = Subsetter::new(&table)
+            .glyphs([GlyphId(4)])
+            .keep_glyph_ids(true)
+            .subset()
+            .unwrap();
+        assert_eq!(subset.glyph_id(GlyphId(3)), Some(GlyphId(3)));
+        assert_eq!(subset.glyph_id(GlyphId(2)), None);
+
+        let subset_table = Table::parse(subset.data()).unwrap();
+        assert_eq!(subset_table.number_of_glyphs(), 5);
+        assert_eq!(subset_table.glyph_name(GlyphId(2)), Some("B"));
+        assert_eq!(outline(&subset_table, 4), outline(&table, 4));
+        assert_eq!(
+            subset_table.outline(GlyphId(2), &mut PathRecorder::new()),
+            Err(CFFError::ZeroBBox)
+        );
+
+        // The code of acute is moved to a supplement.
+        let encoding = subset_table.encoding().unwrap();
+        assert!(encoding.has_supplements());
+        assert_eq!(encoding.glyph_index(65), Some(GlyphId(1)));
+        assert_eq!(encoding.glyph_index(66), None);
+        assert_eq!(encoding.glyph_index(194), Some(GlyphId(3)));
+    }
+
+    #[test]
+    fn subset_cid_font() {
+        // Glyph 1 uses the first Font DICT, glyph 2 the second one.
+        let local_subrs: &[&[&[u8]]] = &[&[&[0xA9, 0x07, 0x0B]], LOCAL_SUBRS];
+        let char_strings: &[&[u8]] = &[
+            &[0x0E],
+            &[0x8B, 0x8B, 0x15, 0x20, 0x0A, 0x0E],
+            &[0x8B, 0x8B, 0x15, 0x20, 0x0A, 0x21, 0x1D, 0x0E],
+        ];
+        let data = build_font(
+            GLOBAL_SUBRS,
+            local_subrs,
+            &[0, 0, 1],
+            &[10, 20],
+            None,
+            char_strings,
+        );
+        let table = Table::parse(&data).unwrap();
+        assert!(table.is_cid());
+
+        let subset = Subsetter::new(&table)
+            .glyphs([GlyphId(2)])
+            .subset()
+            .unwrap();
+        let subset_table = Table::parse(subset.data()).unwrap();
+        assert!(subset_table.is_cid());
+        assert_eq!(subset_table.number_of_glyphs(), 2);
+        assert_eq!(subset_table.glyph_cid(GlyphId(1)), Some(20));
+        assert_eq!(outline(&subset_table, 1), outline(&table, 2));
+
+        match subset_table.kind {
+            FontKind::CID(ref cid) => {
+                assert_eq!(cid.fd_array.len(), 2);
+                assert_eq!(cid.fd_select.font_dict_index(GlyphId(0)), Some(0));
+                assert_eq!(cid.fd_select.font_dict_index(GlyphId(1)), Some(1));
+            }
+            FontKind::SID(_) => panic!("expected a CID font"),
+        }
+
+        let subset = Subsetter::new(&table).subset().unwrap();
+        let subset_table = Table::parse(subset.data()).unwrap();
+        assert_eq!(subset_table.number_of_glyphs(), 1);
+        match subset_table.kind {
+            FontKind::CID(ref cid) => assert_eq!(cid.fd_array.len(), 1),
+            FontKind::SID(_) => panic!("expected a CID font"),
+        }
+    }
+}
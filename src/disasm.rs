@@ -0,0 +1,1044 @@
+//! Disassembly of Type 1 and Type 2 charstrings.
+//!
+//! Every operator is printed on its own line after its operands, similar to
+//! `tx -dump -6` or ttx. Annotations are appended as PostScript comments:
+//!
+//! ```text
+//! -250 20 0 rmoveto  % width 350, (20, 0)
+//! 700 vlineto  % (20, 700)
+//! -107 callgsubr  % gsubr 0
+//! ```
+
+use core::fmt::Write;
+use std::collections::VecDeque;
+
+use crate::cff::parser::{Fixed, Stream};
+use crate::encoding::STANDARD;
+use crate::{cff, type1, GlyphId};
+
+// Limits the nesting of subroutine calls, like the interpreters do.
+const STACK_LIMIT: u8 = 10;
+const TRANSIENT_ARRAY_LEN: usize = 32;
+
+/// The format of a charstring.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CharStringKind {
+    /// A Type 1 charstring, as used by Type 1 fonts and CFF fonts with `CharstringType 1`.
+    Type1,
+    /// A Type 2 charstring, as used by CFF fonts.
+    Type2,
+}
+
+#[derive(Clone, Copy)]
+enum Source<'a> {
+    None,
+    Cff(&'a cff::Table<'a>),
+    Type1(&'a type1::Table<'a>),
+}
+
+/// Prints charstrings in a human-readable form.
+///
+/// Subroutines are always interpreted to keep track of the operand stack,
+/// the hints and the current point, but only printed when inlined.
+#[derive(Clone, Copy)]
+pub struct Disassembler<'a> {
+    source: Source<'a>,
+    inline_subroutines: bool,
+    current_point: bool,
+}
+
+impl Default for Disassembler<'_> {
+    fn default() -> Self {
+        Disassembler {
+            source: Source::None,
+            inline_subroutines: false,
+            current_point: false,
+        }
+    }
+}
+
+impl<'a> Disassembler<'a> {
+    /// Creates a disassembler for charstrings without a font,
+    /// so subroutine calls can't be followed.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a disassembler for the glyphs of a CFF font.
+    pub fn from_cff(table: &'a cff::Table<'a>) -> Self {
+        Disassembler {
+            source: Source::Cff(table),
+            ..Self::default()
+        }
+    }
+
+    /// Creates a disassembler for the glyphs of a Type 1 font.
+    pub fn from_type1(table: &'a type1::Table<'a>) -> Self {
+        Disassembler {
+            source: Source::Type1(table),
+            ..Self::default()
+        }
+    }
+
+    /// Prints the operators of called subroutines, indented, after each call.
+    pub fn inline_subroutines(mut self, inline: bool) -> Self {
+        self.inline_subroutines = inline;
+        self
+    }
+
+    /// Annotates path operators with the current point after them.
+    pub fn current_point(mut self, annotate: bool) -> Self {
+        self.current_point = annotate;
+        self
+    }
+
+    /// Disassembles the charstring of a glyph.
+    ///
+    /// Returns `None` if the glyph doesn't exist or there is no font.
+    pub fn glyph(&self, glyph_id: GlyphId) -> Option<String> {
+        let (data, kind) = match self.source {
+            Source::None => return None,
+            Source::Cff(table) => {
+                let kind = if table.has_type1_char_strings() {
+                    CharStringKind::Type1
+                } else {
+                    CharStringKind::Type2
+                };
+                (table.char_string(glyph_id)?, kind)
+            }
+            Source::Type1(table) => {
+                let name = table.glyph_name(glyph_id)?;
+                (table.char_string(name)?, CharStringKind::Type1)
+            }
+        };
+
+        Some(self.disassemble(data, kind, glyph_id))
+    }
+
+    /// Disassembles a charstring.
+    ///
+    /// Local subroutines of CID fonts are resolved as for the first glyph.
+    /// Charstrings of Type 1 fonts must already be decrypted.
+    pub fn char_string(&self, data: &[u8], kind: CharStringKind) -> String {
+        self.disassemble(data, kind, GlyphId(0))
+    }
+
+    fn disassemble(&self, data: &[u8], kind: CharStringKind, glyph_id: GlyphId) -> String {
+        let mut interpreter = Interpreter {
+            disassembler: self,
+            kind,
+            glyph_id,
+            out: String::new(),
+            line: String::new(),
+            stack: Vec::new(),
+            depth: 0,
+            hidden: 0,
+            x: 0.0,
+            y: 0.0,
+            flex: None,
+            stems: 0,
+            has_width: false,
+            other_subr_results: VecDeque::new(),
+            transient_array: [0.0; TRANSIENT_ARRAY_LEN],
+            has_endchar: false,
+        };
+        interpreter.run(data);
+        interpreter.out
+    }
+
+    fn subroutine(&self, glyph_id: GlyphId, global: bool, number: f32) -> Option<(u32, &'a [u8])> {
+        match self.source {
+            Source::None => None,
+            Source::Cff(table) if global => table.global_subroutine(number),
+            Source::Cff(table) => table.local_subroutine(glyph_id, number),
+            Source::Type1(table) => {
+                if number < 0.0 || global {
+                    return None;
+                }
+
+                let index = number as u32;
+                Some((index, table.subroutine(index)?))
+            }
+        }
+    }
+
+    // Returns the absolute width of a glyph whose Type 2 charstring sets a width.
+    fn nominal_width(&self, glyph_id: GlyphId) -> Option<f32> {
+        match self.source {
+            Source::Cff(table) => table.glyph_width(glyph_id).map(f32::from),
+            _ => None,
+        }
+    }
+}
+
+// A path operator whose operands move the current point.
+#[derive(Clone, Copy)]
+enum PathOperator {
+    // Type 1 path operators have the same codes as the one-byte Type 2 ones.
+    OneByte(u8),
+    // A Type 2 flex operator, which follows the escape byte.
+    Flex(u8),
+}
+
+struct Interpreter<'a, 'b> {
+    disassembler: &'b Disassembler<'a>,
+    kind: CharStringKind,
+    glyph_id: GlyphId,
+    out: String,
+    // The operands of the next operator.
+    line: String,
+    stack: Vec<f32>,
+    depth: u8,
+    // The nesting of subroutines that are interpreted, but not printed.
+    hidden: u8,
+    x: f32,
+    y: f32,
+    // The current point of a Type 1 flex, which doesn't move the real current point.
+    flex: Option<(f32, f32)>,
+    stems: u32,
+    // Whether the first stack-clearing operator, which can set the width, was seen.
+    has_width: bool,
+    // The values passed back by `callothersubr` and retrieved by `pop`.
+    other_subr_results: VecDeque<f32>,
+    transient_array: [f32; TRANSIENT_ARRAY_LEN],
+    has_endchar: bool,
+}
+
+impl Interpreter<'_, '_> {
+    fn run(&mut self, data: &[u8]) {
+        let mut s = Stream::new(data);
+        while !s.at_end() && !self.has_endchar {
+            let result = match self.kind {
+                CharStringKind::Type1 => self.type1_operator(&mut s),
+                CharStringKind::Type2 => self.type2_operator(&mut s),
+            };
+
+            match result {
+                Some(true) => {}
+                Some(false) => return,
+                None => {
+                    self.emit("", &["unexpected end of data".to_string()]);
+                    return;
+                }
+            }
+        }
+
+        if !self.line.is_empty() {
+            self.emit("", &["missing operator".to_string()]);
+        }
+    }
+
+    // Reads a number or an operator and returns whether to continue.
+    fn type2_operator(&mut self, s: &mut Stream) -> Option<bool> {
+        use crate::cff::cff::operator;
+
+        let op = s.read::<u8>()?;
+        let mut notes = Vec::new();
+        let name = match op {
+            operator::SHORT_INT => {
+                let n = s.read::<i16>()?;
+                self.push_number(f32::from(n));
+                return Some(true);
+            }
+            32..=246 => {
+                self.push_number(f32::from(i16::from(op) - 139));
+                return Some(true);
+            }
+            247..=254 => {
+                let n = read_two_byte_number(op, s)?;
+                self.push_number(n);
+                return Some(true);
+            }
+            operator::FIXED_16_16 => {
+                let n = s.read::<Fixed>()?.0;
+                self.push_number(n);
+                return Some(true);
+            }
+            operator::HORIZONTAL_STEM
+            | operator::VERTICAL_STEM
+            | operator::HORIZONTAL_STEM_HINT_MASK
+            | operator::VERTICAL_STEM_HINT_MASK => {
+                self.take_width(self.stack.len() % 2 == 1, &mut notes);
+                self.stems += self.stack.len() as u32 / 2;
+                self.stack.clear();
+                match op {
+                    operator::HORIZONTAL_STEM => "hstem",
+                    operator::VERTICAL_STEM => "vstem",
+                    operator::HORIZONTAL_STEM_HINT_MASK => "hstemhm",
+                    _ => "vstemhm",
+                }
+                .to_string()
+            }
+            operator::HINT_MASK | operator::COUNTER_MASK => {
+                // Operands are the arguments of an implicit `vstemhm`.
+                if !self.stack.is_empty() {
+                    self.take_width(self.stack.len() % 2 == 1, &mut notes);
+                    self.stems += self.stack.len() as u32 / 2;
+                    self.stack.clear();
+                }
+
+                let mut name = if op == operator::HINT_MASK {
+                    "hintmask"
+                } else {
+                    "cntrmask"
+                }
+                .to_string();
+                if self.stems > 0 {
+                    name.push_str(" 0b");
+                }
+                for _ in 0..(self.stems + 7) / 8 {
+                    write!(&mut name, "{:08b}", s.read::<u8>()?).unwrap();
+                }
+                name
+            }
+            operator::MOVE_TO | operator::HORIZONTAL_MOVE_TO | operator::VERTICAL_MOVE_TO => {
+                let (name, len) = match op {
+                    operator::MOVE_TO => ("rmoveto", 2),
+                    operator::HORIZONTAL_MOVE_TO => ("hmoveto", 1),
+                    _ => ("vmoveto", 1),
+                };
+                self.take_width(self.stack.len() > len, &mut notes);
+                self.move_path(PathOperator::OneByte(op), &mut notes);
+                name.to_string()
+            }
+            operator::LINE_TO
+            | operator::HORIZONTAL_LINE_TO
+            | operator::VERTICAL_LINE_TO
+            | operator::CURVE_TO
+            | operator::CURVE_LINE
+            | operator::LINE_CURVE
+            | operator::VV_CURVE_TO
+            | operator::HH_CURVE_TO
+            | operator::VH_CURVE_TO
+            | operator::HV_CURVE_TO => {
+                self.move_path(PathOperator::OneByte(op), &mut notes);
+                match op {
+                    operator::LINE_TO => "rlineto",
+                    operator::HORIZONTAL_LINE_TO => "hlineto",
+                    operator::VERTICAL_LINE_TO => "vlineto",
+                    operator::CURVE_TO => "rrcurveto",
+                    operator::CURVE_LINE => "rcurveline",
+                    operator::LINE_CURVE => "rlinecurve",
+                    operator::VV_CURVE_TO => "vvcurveto",
+                    operator::HH_CURVE_TO => "hhcurveto",
+                    operator::VH_CURVE_TO => "vhcurveto",
+                    _ => "hvcurveto",
+                }
+                .to_string()
+            }
+            operator::CALL_LOCAL_SUBROUTINE | operator::CALL_GLOBAL_SUBROUTINE => {
+                let global = op == operator::CALL_GLOBAL_SUBROUTINE;
+                let name = if global { "callgsubr" } else { "callsubr" };
+                self.call_subroutine(name, global);
+                return Some(!self.has_endchar);
+            }
+            operator::RETURN => {
+                self.emit("return", &[]);
+                return Some(false);
+            }
+            operator::ENDCHAR => {
+                let len = self.stack.len();
+                self.take_width(len == 1 || len == 5, &mut notes);
+                if self.stack.len() == 4 {
+                    seac_note(self.stack[2], self.stack[3], &mut notes);
+                }
+                self.stack.clear();
+                self.has_endchar = true;
+                "endchar".to_string()
+            }
+            12 => {
+                let op = s.read::<u8>()?;
+                self.type2_escape_operator(op, &mut notes)
+            }
+            _ => {
+                self.stack.clear();
+                format!("reserved{}", op)
+            }
+        };
+
+        self.emit(&name, &notes);
+        Some(true)
+    }
+
+    fn type2_escape_operator(&mut self, op: u8, notes: &mut Vec<String>) -> String {
+        use crate::cff::cff::operator;
+
+        let name = match op {
+            operator::DOTSECTION => {
+                self.stack.clear();
+                "dotsection"
+            }
+            operator::HFLEX | operator::FLEX | operator::HFLEX1 | operator::FLEX1 => {
+                self.move_path(PathOperator::Flex(op), notes);
+                match op {
+                    operator::HFLEX => "hflex",
+                    operator::FLEX => "flex",
+                    operator::HFLEX1 => "hflex1",
+                    _ => "flex1",
+                }
+            }
+            operator::AND => self.binary_op(op, "and"),
+            operator::OR => self.binary_op(op, "or"),
+            operator::NOT => self.unary_op(op, "not"),
+            operator::ABS => self.unary_op(op, "abs"),
+            operator::ADD => self.binary_op(op, "add"),
+            operator::SUB => self.binary_op(op, "sub"),
+            operator::DIV => self.binary_op(op, "div"),
+            operator::NEG => self.unary_op(op, "neg"),
+            operator::EQ => self.binary_op(op, "eq"),
+            operator::DROP => {
+                self.pop();
+                "drop"
+            }
+            operator::PUT => {
+                let (index, value) = (self.pop(), self.pop());
+                if let Some(n) = self.transient_array.get_mut(index as usize) {
+                    *n = value;
+                }
+                "put"
+            }
+            operator::GET => {
+                let index = self.pop();
+                let value = self
+                    .transient_array
+                    .get(index as usize)
+                    .copied()
+                    .unwrap_or(0.0);
+                self.stack.push(value);
+                "get"
+            }
+            operator::IF_ELSE => {
+                let (v2, v1, s2, s1) = (self.pop(), self.pop(), self.pop(), self.pop());
+                self.stack.push(if v1 <= v2 { s1 } else { s2 });
+                "ifelse"
+            }
+            operator::RANDOM => {
+                // The value is arbitrary, so the glyph's outline can't depend on it.
+                self.stack.push(0.5);
+                "random"
+            }
+            operator::MUL => self.binary_op(op, "mul"),
+            operator::SQRT => self.unary_op(op, "sqrt"),
+            operator::DUP => {
+                let a = self.pop();
+                self.stack.extend_from_slice(&[a, a]);
+                "dup"
+            }
+            operator::EXCH => {
+                let (b, a) = (self.pop(), self.pop());
+                self.stack.extend_from_slice(&[b, a]);
+                "exch"
+            }
+            operator::INDEX => {
+                let index = self.pop().max(0.0) as usize;
+                let value = self
+                    .stack
+                    .len()
+                    .checked_sub(index + 1)
+                    .map_or(0.0, |i| self.stack[i]);
+                self.stack.push(value);
+                "index"
+            }
+            operator::ROLL => {
+                let (shift, len) = (self.pop(), self.pop().max(0.0) as usize);
+                if len > 0 && len <= self.stack.len() {
+                    let start = self.stack.len() - len;
+                    let shift = (shift as isize).rem_euclid(len as isize) as usize;
+                    self.stack[start..].rotate_right(shift);
+                }
+                "roll"
+            }
+            _ => {
+                self.stack.clear();
+                return format!("reserved12.{}", op);
+            }
+        };
+
+        name.to_string()
+    }
+
+    // Applies an arithmetic operator the same way as the outline interpreter.
+    fn unary_op(&mut self, op: u8, name: &'static str) -> &'static str {
+        let a = self.pop();
+        self.stack.push(cff::cff::unary_op(op, a));
+        name
+    }
+
+    fn binary_op(&mut self, op: u8, name: &'static str) -> &'static str {
+        let (b, a) = (self.pop(), self.pop());
+        self.stack.push(cff::cff::binary_op(op, a, b));
+        name
+    }
+
+    // Reads a number or an operator and returns whether to continue.
+    fn type1_operator(&mut self, s: &mut Stream) -> Option<bool> {
+        use type1::operator::{sb_operator, tb_operator};
+
+        let op = s.read::<u8>()?;
+        let mut notes = Vec::new();
+        let name = match op {
+            32..=246 => {
+                self.push_number(f32::from(i16::from(op) - 139));
+                return Some(true);
+            }
+            247..=254 => {
+                let n = read_two_byte_number(op, s)?;
+                self.push_number(n);
+                return Some(true);
+            }
+            255 => {
+                let n = s.read::<i32>()?;
+                self.push_number(n as f32);
+                return Some(true);
+            }
+            sb_operator::HORIZONTAL_STEM | sb_operator::VERTICAL_STEM => {
+                self.stems += 1;
+                self.stack.clear();
+                if op == sb_operator::HORIZONTAL_STEM {
+                    "hstem"
+                } else {
+                    "vstem"
+                }
+            }
+            sb_operator::MOVE_TO
+            | sb_operator::HORIZONTAL_MOVE_TO
+            | sb_operator::VERTICAL_MOVE_TO => {
+                self.type1_move(op, &mut notes);
+                match op {
+                    sb_operator::MOVE_TO => "rmoveto",
+                    sb_operator::HORIZONTAL_MOVE_TO => "hmoveto",
+                    _ => "vmoveto",
+                }
+            }
+            sb_operator::LINE_TO
+            | sb_operator::HORIZONTAL_LINE_TO
+            | sb_operator::VERTICAL_LINE_TO
+            | sb_operator::CURVE_TO
+            | sb_operator::VH_CURVE_TO
+            | sb_operator::HV_CURVE_TO => {
+                self.move_path(PathOperator::OneByte(op), &mut notes);
+                match op {
+                    sb_operator::LINE_TO => "rlineto",
+                    sb_operator::HORIZONTAL_LINE_TO => "hlineto",
+                    sb_operator::VERTICAL_LINE_TO => "vlineto",
+                    sb_operator::CURVE_TO => "rrcurveto",
+                    sb_operator::VH_CURVE_TO => "vhcurveto",
+                    _ => "hvcurveto",
+                }
+            }
+            sb_operator::CLOSE_PATH => {
+                self.stack.clear();
+                "closepath"
+            }
+            sb_operator::CALL_SUBR => {
+                self.call_subroutine("callsubr", false);
+                return Some(!self.has_endchar);
+            }
+            sb_operator::RETURN => {
+                self.emit("return", &[]);
+                return Some(false);
+            }
+            sb_operator::HSBW => {
+                self.x = self.stack.first().copied().unwrap_or(0.0);
+                self.y = 0.0;
+                if let Some(width) = self.stack.get(1) {
+                    notes.push(format!("width {}", number(*width)));
+                }
+                self.point_note(&mut notes);
+                self.stack.clear();
+                "hsbw"
+            }
+            sb_operator::ENDCHAR => {
+                self.stack.clear();
+                self.has_endchar = true;
+                "endchar"
+            }
+            sb_operator::ESCAPE => {
+                let op = s.read::<u8>()?;
+                match op {
+                    tb_operator::DOTSECTION => {
+                        self.stack.clear();
+                        "dotsection"
+                    }
+                    tb_operator::VSTEM3 | tb_operator::HSTEM3 => {
+                        self.stems += 3;
+                        self.stack.clear();
+                        if op == tb_operator::VSTEM3 {
+                            "vstem3"
+                        } else {
+                            "hstem3"
+                        }
+                    }
+                    tb_operator::SEAC => {
+                        if self.stack.len() == 5 {
+                            seac_note(self.stack[3], self.stack[4], &mut notes);
+                        }
+                        self.stack.clear();
+                        // The accent is the last part of the glyph.
+                        self.has_endchar = true;
+                        "seac"
+                    }
+                    tb_operator::SBW => {
+                        self.x = self.stack.first().copied().unwrap_or(0.0);
+                        self.y = self.stack.get(1).copied().unwrap_or(0.0);
+                        if let Some(width) = self.stack.get(2) {
+                            notes.push(format!("width {}", number(*width)));
+                        }
+                        self.point_note(&mut notes);
+                        self.stack.clear();
+                        "sbw"
+                    }
+                    tb_operator::DIV => {
+                        let (b, a) = (self.pop(), self.pop());
+                        self.stack.push(a / b);
+                        "div"
+                    }
+                    tb_operator::CALL_OTHER_SUBR => {
+                        self.call_other_subroutine(&mut notes);
+                        "callothersubr"
+                    }
+                    tb_operator::POP => {
+                        let n = self.other_subr_results.pop_front().unwrap_or(0.0);
+                        self.stack.push(n);
+                        "pop"
+                    }
+                    tb_operator::SET_CURRENT_POINT => {
+                        self.x = self.stack.first().copied().unwrap_or(0.0);
+                        self.y = self.stack.get(1).copied().unwrap_or(0.0);
+                        self.point_note(&mut notes);
+                        self.stack.clear();
+                        "setcurrentpoint"
+                    }
+                    _ => {
+                        self.stack.clear();
+                        self.emit(&format!("reserved12.{}", op), &notes);
+                        return Some(true);
+                    }
+                }
+            }
+            _ => {
+                self.stack.clear();
+                self.emit(&format!("reserved{}", op), &notes);
+                return Some(true);
+            }
+        };
+
+        self.emit(name, &notes);
+        Some(true)
+    }
+
+    // Moves inside a flex only move the flex's current point.
+    fn type1_move(&mut self, op: u8, notes: &mut Vec<String>) {
+        let (x, y) = (self.x, self.y);
+        if let Some(point) = self.flex {
+            self.x = point.0;
+            self.y = point.1;
+        }
+
+        self.move_path(PathOperator::OneByte(op), notes);
+
+        if self.flex.is_some() {
+            self.flex = Some((self.x, self.y));
+            self.x = x;
+            self.y = y;
+            notes.retain(|note| !note.starts_with('('));
+            if self.disassembler.current_point {
+                let (fx, fy) = self.flex.unwrap_or_default();
+                notes.push(format!("flex point ({}, {})", number(fx), number(fy)));
+            }
+        }
+    }
+
+    fn call_other_subroutine(&mut self, notes: &mut Vec<String>) {
+        let index = self.pop();
+        let len = self.pop().max(0.0) as usize;
+        let start = self.stack.len().saturating_sub(len);
+        let args = self.stack.split_off(start);
+        self.other_subr_results.clear();
+
+        // The standard OtherSubrs for flex and hint replacement.
+        match index as i32 {
+            0 => {
+                notes.push("end flex".to_string());
+                self.flex = None;
+                self.other_subr_results.extend(args.iter().skip(1).take(2));
+            }
+            1 => {
+                notes.push("start flex".to_string());
+                self.flex = Some((self.x, self.y));
+            }
+            2 => notes.push("add flex point".to_string()),
+            3 => {
+                notes.push("replace hints".to_string());
+                self.other_subr_results.extend(args.first());
+            }
+            _ => self.other_subr_results.extend(args),
+        }
+    }
+
+    fn call_subroutine(&mut self, name: &str, global: bool) {
+        let number = self.pop();
+        let subroutine = self.disassembler.subroutine(self.glyph_id, global, number);
+
+        let data = match subroutine {
+            Some((index, data)) if self.depth < STACK_LIMIT => {
+                let kind = if global { "gsubr" } else { "subr" };
+                self.emit(name, &[format!("{} {}", kind, index)]);
+                data
+            }
+            Some(_) => {
+                self.emit(name, &["nesting limit reached".to_string()]);
+                return;
+            }
+            None => {
+                self.emit(name, &["invalid subroutine".to_string()]);
+                return;
+            }
+        };
+
+        let hide = !self.disassembler.inline_subroutines;
+        self.depth += 1;
+        self.hidden += u8::from(hide);
+        self.run(data);
+        self.hidden -= u8::from(hide);
+        self.depth -= 1;
+    }
+
+    // Updates the current point by the operands of a path operator and clears them.
+    fn move_path(&mut self, op: PathOperator, notes: &mut Vec<String>) {
+        use crate::cff::cff::operator;
+
+        let args = core::mem::take(&mut self.stack);
+        let (mut dx, mut dy) = (0.0, 0.0);
+        match op {
+            PathOperator::OneByte(
+                operator::MOVE_TO
+                | operator::LINE_TO
+                | operator::CURVE_TO
+                | operator::CURVE_LINE
+                | operator::LINE_CURVE,
+            ) => {
+                let (x, y) = sum_pairs(&args);
+                dx = x;
+                dy = y;
+            }
+            PathOperator::OneByte(operator::HORIZONTAL_MOVE_TO) => {
+                dx = args.first().copied().unwrap_or(0.0)
+            }
+            PathOperator::OneByte(operator::VERTICAL_MOVE_TO) => {
+                dy = args.first().copied().unwrap_or(0.0)
+            }
+            PathOperator::OneByte(
+                op @ (operator::HORIZONTAL_LINE_TO | operator::VERTICAL_LINE_TO),
+            ) => {
+                let horizontal = op == operator::HORIZONTAL_LINE_TO;
+                for (i, d) in args.iter().enumerate() {
+                    if (i % 2 == 0) == horizontal {
+                        dx += d;
+                    } else {
+                        dy += d;
+                    }
+                }
+            }
+            PathOperator::OneByte(op @ (operator::HH_CURVE_TO | operator::VV_CURVE_TO)) => {
+                let horizontal = op == operator::HH_CURVE_TO;
+                let (first, curves) = if args.len() % 2 == 1 {
+                    (args[0], &args[1..])
+                } else {
+                    (0.0, &args[..])
+                };
+                // The first curve can start in the other direction.
+                let (along, across) = curves.chunks(4).fold((0.0, first), |(a, c), curve| {
+                    let get = |i: usize| curve.get(i).copied().unwrap_or(0.0);
+                    (a + get(0) + get(1) + get(3), c + get(2))
+                });
+                if horizontal {
+                    dx = along;
+                    dy = across;
+                } else {
+                    dx = across;
+                    dy = along;
+                }
+            }
+            PathOperator::OneByte(op @ (operator::VH_CURVE_TO | operator::HV_CURVE_TO)) => {
+                let mut horizontal = op == operator::HV_CURVE_TO;
+                for curve in args.chunks_exact(4) {
+                    if horizontal {
+                        dx += curve[0] + curve[1];
+                        dy += curve[2] + curve[3];
+                    } else {
+                        dx += curve[1] + curve[3];
+                        dy += curve[0] + curve[2];
+                    }
+                    horizontal = !horizontal;
+                }
+
+                // A fifth operand moves the end of the last curve along its end tangent,
+                // which is vertical for curves starting horizontally.
+                if let [last] = args.chunks_exact(4).remainder() {
+                    if horizontal {
+                        dy += last;
+                    } else {
+                        dx += last;
+                    }
+                }
+            }
+            PathOperator::Flex(operator::FLEX) => {
+                let (x, y) = sum_pairs(&args[..args.len().min(12)]);
+                dx = x;
+                dy = y;
+            }
+            PathOperator::Flex(operator::HFLEX) => {
+                dx = [0, 1, 3, 4, 5, 6].iter().filter_map(|i| args.get(*i)).sum();
+            }
+            PathOperator::Flex(operator::HFLEX1) => {
+                dx = [0, 2, 4, 5, 6, 8].iter().filter_map(|i| args.get(*i)).sum();
+            }
+            PathOperator::Flex(operator::FLEX1) => {
+                let (x, y) = sum_pairs(&args[..args.len().min(10)]);
+                let last = args.get(10).copied().unwrap_or(0.0);
+                if x.abs() > y.abs() {
+                    dx = x + last;
+                } else {
+                    dy = y + last;
+                }
+            }
+            _ => {}
+        }
+
+        self.x += dx;
+        self.y += dy;
+        self.point_note(notes);
+    }
+
+    // Removes the width from the operands of the first stack-clearing operator.
+    fn take_width(&mut self, has_width: bool, notes: &mut Vec<String>) {
+        if self.has_width {
+            return;
+        }
+
+        self.has_width = true;
+        if has_width && !self.stack.is_empty() {
+            let width = self.stack.remove(0);
+            notes.push(match self.disassembler.nominal_width(self.glyph_id) {
+                Some(width) => format!("width {}", number(width)),
+                None if width < 0.0 => format!("width nominalWidthX - {}", number(-width)),
+                None => format!("width nominalWidthX + {}", number(width)),
+            });
+        }
+    }
+
+    fn point_note(&self, notes: &mut Vec<String>) {
+        if self.disassembler.current_point {
+            notes.push(format!("({}, {})", number(self.x), number(self.y)));
+        }
+    }
+
+    fn pop(&mut self) -> f32 {
+        self.stack.pop().unwrap_or(0.0)
+    }
+
+    fn push_number(&mut self, n: f32) {
+        self.stack.push(n);
+        if !self.line.is_empty() {
+            self.line.push(' ');
+        }
+        self.line.push_str(&number(n));
+    }
+
+    // Prints a line with the pending operands.
+    fn emit(&mut self, name: &str, notes: &[String]) {
+        let line = core::mem::take(&mut self.line);
+        if self.hidden > 0 {
+            return;
+        }
+
+        for _ in 0..self.depth {
+            self.out.push_str("  ");
+        }
+        self.out.push_str(&line);
+        if !line.is_empty() && !name.is_empty() {
+            self.out.push(' ');
+        }
+        self.out.push_str(name);
+        if !notes.is_empty() {
+            if !line.is_empty() || !name.is_empty() {
+                self.out.push_str("  ");
+            }
+            self.out.push_str("% ");
+            self.out.push_str(&notes.join(", "));
+        }
+        self.out.push('\n');
+    }
+}
+
+fn read_two_byte_number(op: u8, s: &mut Stream) -> Option<f32> {
+    let b1 = i16::from(s.read::<u8>()?);
+    let n = if op < 251 {
+        (i16::from(op) - 247) * 256 + b1 + 108
+    } else {
+        -(i16::from(op) - 251) * 256 - b1 - 108
+    };
+    Some(f32::from(n))
+}
+
+// Sums the x and y coordinates of alternating operands.
+fn sum_pairs(args: &[f32]) -> (f32, f32) {
+    args.chunks(2).fold((0.0, 0.0), |(x, y), pair| {
+        (x + pair[0], y + pair.get(1).copied().unwrap_or(0.0))
+    })
+}
+
+fn seac_note(base: f32, accent: f32, notes: &mut Vec<String>) {
+    let name = |code: f32| STANDARD.get(&(code as u8)).copied().unwrap_or("?");
+    notes.push(format!("base {}, accent {}", name(base), name(accent)));
+}
+
+fn number(n: f32) -> String {
+    // Avoid printing negative zero.
+    if n == 0.0 {
+        "0".to_string()
+    } else {
+        n.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CharStringKind, Disassembler};
+    use crate::asm::assemble;
+    use crate::{cff, GlyphId};
+
+    #[test]
+    fn type2() {
+        let data = [
+            0xFB, 0x8E, 0x8B, 0xBD, 0x12, // -250 0 50 hstemhm
+            0x9F, 0xDB, 0x13, 0xC0, // 20 80 hintmask
+            0x9F, 0x8B, 0x15, // 20 0 rmoveto
+            0x95, 0x9F, 0xA9, 0xB3, 0x90, 0x1F, // 10 20 30 40 5 hvcurveto
+            0x95, 0x95, 0x95, 0x95, 0x95, 0x8B, 0x95, 0x81, 0x95, 0x81, 0x90, 0x0C,
+            0x25, // 10 10 10 10 10 0 10 -10 10 -10 5 flex1
+            0x8B, 0x8B, 0xCC, 0xF7, 0x56, 0x0E, // 0 0 65 194 endchar
+        ];
+
+        let text = Disassembler::new()
+            .current_point(true)
+            .char_string(&data, CharStringKind::Type2);
+        assert_eq!(
+            text,
+            "-250 0 50 hstemhm  % width nominalWidthX - 250\n\
+             20 80 hintmask 0b11000000\n\
+             20 0 rmoveto  % (20, 0)\n\
+             10 20 30 40 5 hvcurveto  % (55, 70)\n\
+             10 10 10 10 10 0 10 -10 10 -10 5 flex1  % (110, 70)\n\
+             0 0 65 194 endchar  % base A, accent acute\n"
+        );
+
+        // Without annotations, only operators with other notes have comments.
+        let text = Disassembler::new().char_string(&data[..12], CharStringKind::Type2);
+        assert_eq!(
+            text,
+            "-250 0 50 hstemhm  % width nominalWidthX - 250\n\
+             20 80 hintmask 0b11000000\n\
+             20 0 rmoveto\n"
+        );
+    }
+
+    #[test]
+    fn type1() {
+        let data = [
+            0xBD, 0xF8, 0x88, 0x0D, // 50 500 hsbw
+            0x8B, 0xEF, 0x15, // 0 100 rmoveto
+            0x8B, 0x8C, 0x0C, 0x10, // 0 1 callothersubr
+            0x95, 0x8B, 0x15, // 10 0 rmoveto
+            0xBD, 0xF7, 0x5C, 0xEF, 0x8E, 0x8B, 0x0C, 0x10, // 50 200 100 3 0 callothersubr
+            0x0C, 0x11, 0x0C, 0x11, 0x0C, 0x21, // pop pop setcurrentpoint
+            0x09, 0x0E, // closepath endchar
+        ];
+
+        let text = Disassembler::new()
+            .current_point(true)
+            .char_string(&data, CharStringKind::Type1);
+        assert_eq!(
+            text,
+            "50 500 hsbw  % width 500, (50, 0)\n\
+             0 100 rmoveto  % (50, 100)\n\
+             0 1 callothersubr  % start flex\n\
+             10 0 rmoveto  % flex point (60, 100)\n\
+             50 200 100 3 0 callothersubr  % end flex\n\
+             pop\n\
+             pop\n\
+             setcurrentpoint  % (200, 100)\n\
+             closepath\n\
+             endchar\n"
+        );
+
+        let text = Disassembler::new().char_string(&data[..5], CharStringKind::Type1);
+        assert_eq!(text, "50 500 hsbw  % width 500\n0  % missing operator\n");
+        let text = Disassembler::new().char_string(&data[..2], CharStringKind::Type1);
+        assert_eq!(text, "50  % unexpected end of data\n");
+    }
+
+    #[test]
+    fn arithmetic() {
+        // Like the outline interpreter, the square root of a negative number is 0.
+        let data = assemble("-4 sqrt 9 sqrt rmoveto endchar", CharStringKind::Type2).unwrap();
+        let text = Disassembler::new()
+            .current_point(true)
+            .char_string(&data, CharStringKind::Type2);
+        assert_eq!(text, "-4 sqrt\n9 sqrt\nrmoveto  % (0, 3)\nendchar\n");
+    }
+
+    #[test]
+    fn subroutines() {
+        let data = [
+            0x01, 0x00, 0x04, 0x01, // header
+            // Name INDEX
+            0x00, 0x01, 0x01, 0x01, 0x02, 0x41, // A
+            // Top DICT INDEX
+            0x00, 0x01, 0x01, 0x01, 0x03, //
+            0xA6, 0x11, // charstrings offset: 27
+            // String INDEX
+            0x00, 0x00, //
+            // Global Subrs INDEX
+            0x00, 0x01, 0x01, 0x01, 0x04, //
+            0xEF, 0x06, 0x0B, // 100 hlineto return
+            // CharStrings INDEX
+            0x00, 0x01, 0x01, 0x01, 0x09, //
+            0x8B, 0x8B, 0x15, // 0 0 rmoveto
+            0x20, 0x1D, // -107 callgsubr
+            0xAB, 0x07, 0x0E, // 32 vlineto endchar
+        ];
+
+        let table = cff::Table::parse(&data).unwrap();
+        let disassembler = Disassembler::from_cff(&table).current_point(true);
+        assert_eq!(
+            disassembler.glyph(GlyphId(0)).unwrap(),
+            concat!(
+                "0 0 rmoveto  % (0, 0)\n",
+                "-107 callgsubr  % gsubr 0\n",
+                "32 vlineto  % (100, 32)\n",
+                "endchar\n",
+            )
+        );
+        assert_eq!(
+            disassembler
+                .inline_subroutines(true)
+                .glyph(GlyphId(0))
+                .unwrap(),
+            concat!(
+                "0 0 rmoveto  % (0, 0)\n",
+                "-107 callgsubr  % gsubr 0\n",
+                "  100 hlineto  % (100, 0)\n",
+                "  return\n",
+                "32 vlineto  % (100, 32)\n",
+                "endchar\n",
+            )
+        );
+        assert_eq!(disassembler.glyph(GlyphId(1)), None);
+        assert_eq!(
+            Disassembler::new().char_string(&[0x20, 0x1D, 0x0E], CharStringKind::Type2),
+            "-107 callgsubr  % invalid subroutine\nendchar\n"
+        );
+    }
+}
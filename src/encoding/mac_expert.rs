@@ -0,0 +1,171 @@
+// THIS FILE WAS AUTO-GENERATED, DO NOT EDIT MANUALLY!
+use phf::phf_map;
+
+/// The `MacExpertEncoding`.
+pub static MAC_EXPERT: phf::Map<u8, &'static str> = phf_map! {
+    32u8 => "space",
+    33u8 => "exclamsmall",
+    34u8 => "Hungarumlautsmall",
+    35u8 => "centoldstyle",
+    36u8 => "dollaroldstyle",
+    37u8 => "dollarsuperior",
+    38u8 => "ampersandsmall",
+    39u8 => "Acutesmall",
+    40u8 => "parenleftsuperior",
+    41u8 => "parenrightsuperior",
+    42u8 => "twodotenleader",
+    43u8 => "onedotenleader",
+    44u8 => "comma",
+    45u8 => "hyphen",
+    46u8 => "period",
+    47u8 => "fraction",
+    48u8 => "zerooldstyle",
+    49u8 => "oneoldstyle",
+    50u8 => "twooldstyle",
+    51u8 => "threeoldstyle",
+    52u8 => "fouroldstyle",
+    53u8 => "fiveoldstyle",
+    54u8 => "sixoldstyle",
+    55u8 => "sevenoldstyle",
+    56u8 => "eightoldstyle",
+    57u8 => "nineoldstyle",
+    58u8 => "colon",
+    59u8 => "semicolon",
+    61u8 => "threequartersemdash",
+    63u8 => "questionsmall",
+    68u8 => "Ethsmall",
+    71u8 => "onequarter",
+    72u8 => "onehalf",
+    73u8 => "threequarters",
+    74u8 => "oneeighth",
+    75u8 => "threeeighths",
+    76u8 => "fiveeighths",
+    77u8 => "seveneighths",
+    78u8 => "onethird",
+    79u8 => "twothirds",
+    86u8 => "ff",
+    87u8 => "fi",
+    88u8 => "fl",
+    89u8 => "ffi",
+    90u8 => "ffl",
+    91u8 => "parenleftinferior",
+    93u8 => "parenrightinferior",
+    94u8 => "Circumflexsmall",
+    95u8 => "hypheninferior",
+    96u8 => "Gravesmall",
+    97u8 => "Asmall",
+    98u8 => "Bsmall",
+    99u8 => "Csmall",
+    100u8 => "Dsmall",
+    101u8 => "Esmall",
+    102u8 => "Fsmall",
+    103u8 => "Gsmall",
+    104u8 => "Hsmall",
+    105u8 => "Ismall",
+    106u8 => "Jsmall",
+    107u8 => "Ksmall",
+    108u8 => "Lsmall",
+    109u8 => "Msmall",
+    110u8 => "Nsmall",
+    111u8 => "Osmall",
+    112u8 => "Psmall",
+    113u8 => "Qsmall",
+    114u8 => "Rsmall",
+    115u8 => "Ssmall",
+    116u8 => "Tsmall",
+    117u8 => "Usmall",
+    118u8 => "Vsmall",
+    119u8 => "Wsmall",
+    120u8 => "Xsmall",
+    121u8 => "Ysmall",
+    122u8 => "Zsmall",
+    123u8 => "colonmonetary",
+    124u8 => "onefitted",
+    125u8 => "rupiah",
+    126u8 => "Tildesmall",
+    129u8 => "asuperior",
+    130u8 => "centsuperior",
+    135u8 => "Aacutesmall",
+    136u8 => "Agravesmall",
+    137u8 => "Acircumflexsmall",
+    138u8 => "Adieresissmall",
+    139u8 => "Atildesmall",
+    140u8 => "Aringsmall",
+    141u8 => "Ccedillasmall",
+    142u8 => "Eacutesmall",
+    143u8 => "Egravesmall",
+    144u8 => "Ecircumflexsmall",
+    145u8 => "Edieresissmall",
+    146u8 => "Iacutesmall",
+    147u8 => "Igravesmall",
+    148u8 => "Icircumflexsmall",
+    149u8 => "Idieresissmall",
+    150u8 => "Ntildesmall",
+    151u8 => "Oacutesmall",
+    152u8 => "Ogravesmall",
+    153u8 => "Ocircumflexsmall",
+    154u8 => "Odieresissmall",
+    155u8 => "Otildesmall",
+    156u8 => "Uacutesmall",
+    157u8 => "Ugravesmall",
+    158u8 => "Ucircumflexsmall",
+    159u8 => "Udieresissmall",
+    161u8 => "eightsuperior",
+    162u8 => "fourinferior",
+    163u8 => "threeinferior",
+    164u8 => "sixinferior",
+    165u8 => "eightinferior",
+    166u8 => "seveninferior",
+    167u8 => "Scaronsmall",
+    169u8 => "centinferior",
+    170u8 => "twoinferior",
+    172u8 => "Dieresissmall",
+    174u8 => "Caronsmall",
+    175u8 => "osuperior",
+    176u8 => "fiveinferior",
+    178u8 => "commainferior",
+    179u8 => "periodinferior",
+    180u8 => "Yacutesmall",
+    182u8 => "dollarinferior",
+    185u8 => "Thornsmall",
+    187u8 => "nineinferior",
+    188u8 => "zeroinferior",
+    189u8 => "Zcaronsmall",
+    190u8 => "AEsmall",
+    191u8 => "Oslashsmall",
+    192u8 => "questiondownsmall",
+    193u8 => "oneinferior",
+    194u8 => "Lslashsmall",
+    201u8 => "Cedillasmall",
+    207u8 => "OEsmall",
+    208u8 => "figuredash",
+    209u8 => "hyphensuperior",
+    214u8 => "exclamdownsmall",
+    216u8 => "Ydieresissmall",
+    218u8 => "onesuperior",
+    219u8 => "twosuperior",
+    220u8 => "threesuperior",
+    221u8 => "foursuperior",
+    222u8 => "fivesuperior",
+    223u8 => "sixsuperior",
+    224u8 => "sevensuperior",
+    225u8 => "ninesuperior",
+    226u8 => "zerosuperior",
+    228u8 => "esuperior",
+    229u8 => "rsuperior",
+    230u8 => "tsuperior",
+    233u8 => "isuperior",
+    234u8 => "ssuperior",
+    235u8 => "dsuperior",
+    241u8 => "lsuperior",
+    242u8 => "Ogoneksmall",
+    243u8 => "Brevesmall",
+    244u8 => "Macronsmall",
+    245u8 => "bsuperior",
+    246u8 => "nsuperior",
+    247u8 => "msuperior",
+    248u8 => "commasuperior",
+    249u8 => "periodsuperior",
+    250u8 => "Dotaccentsmall",
+    251u8 => "Ringsmall",
+};
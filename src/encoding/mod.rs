@@ -0,0 +1,360 @@
+//! Encodings of PDF simple fonts.
+//!
+//! Provides the predefined encodings from Annex D of PDF 32000-1:2008
+//! as code to glyph name tables, and implements the character encoding
+//! rules for Type 1 fonts from sections 9.6.5 and 9.6.6, which also apply
+//! to embedded CFF fonts.
+
+mod mac_expert;
+mod mac_roman;
+mod pdf_doc;
+mod standard;
+mod symbol;
+mod win_ansi;
+mod zapf_dingbats;
+
+use std::collections::HashMap;
+
+use crate::{agl, cff, type1, Font, GlyphId};
+
+pub use mac_expert::MAC_EXPERT;
+pub use mac_roman::MAC_ROMAN;
+pub use pdf_doc::PDF_DOC;
+pub use standard::STANDARD;
+pub use symbol::SYMBOL;
+pub use win_ansi::WIN_ANSI;
+pub use zapf_dingbats::ZAPF_DINGBATS;
+
+/// A predefined encoding that can be used as `/BaseEncoding`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BaseEncoding {
+    /// `/StandardEncoding`.
+    Standard,
+    /// `/WinAnsiEncoding`.
+    WinAnsi,
+    /// `/MacRomanEncoding`.
+    MacRoman,
+    /// `/MacExpertEncoding`.
+    MacExpert,
+}
+
+impl BaseEncoding {
+    /// Returns the base encoding for a PDF name, like `WinAnsiEncoding`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "StandardEncoding" => Some(BaseEncoding::Standard),
+            "WinAnsiEncoding" => Some(BaseEncoding::WinAnsi),
+            "MacRomanEncoding" => Some(BaseEncoding::MacRoman),
+            "MacExpertEncoding" => Some(BaseEncoding::MacExpert),
+            _ => None,
+        }
+    }
+
+    /// Returns the name of the glyph mapped to a code.
+    pub fn glyph_name(&self, code: u8) -> Option<&'static str> {
+        let map = match self {
+            BaseEncoding::Standard => &STANDARD,
+            BaseEncoding::WinAnsi => &WIN_ANSI,
+            BaseEncoding::MacRoman => &MAC_ROMAN,
+            BaseEncoding::MacExpert => &MAC_EXPERT,
+        };
+
+        map.get(&code).copied()
+    }
+}
+
+/// The encoding of a PDF simple font.
+///
+/// Combines the `/BaseEncoding` and `/Differences` entries of an
+/// encoding dictionary with the built-in encoding of the font program.
+/// An explicit base encoding always applies. Without one, non-symbolic
+/// fonts that aren't embedded use `StandardEncoding`, and all other fonts
+/// use their built-in encoding.
+///
+/// ```
+/// use pdf_font_parser::encoding::{BaseEncoding, PdfEncoding};
+///
+/// let encoding = PdfEncoding::new()
+///     .base_encoding(BaseEncoding::WinAnsi)
+///     .differences(39, ["quoteright", "parenleft"]);
+/// assert_eq!(encoding.glyph_name(39), Some("quoteright"));
+/// assert_eq!(encoding.glyph_name(65), Some("A"));
+/// ```
+#[derive(Clone, Debug)]
+pub struct PdfEncoding {
+    base: Option<BaseEncoding>,
+    differences: HashMap<u8, String>,
+    symbolic: bool,
+    embedded: bool,
+    char_map: Option<CharMap>,
+}
+
+impl Default for PdfEncoding {
+    fn default() -> Self {
+        Self {
+            base: None,
+            differences: HashMap::new(),
+            symbolic: false,
+            embedded: true,
+            char_map: None,
+        }
+    }
+}
+
+impl PdfEncoding {
+    /// Creates an encoding of an embedded, non-symbolic font.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the base encoding, either from the `/BaseEncoding` entry
+    /// or from an `/Encoding` entry that is a name.
+    pub fn base_encoding(mut self, base: BaseEncoding) -> Self {
+        self.base = Some(base);
+        self
+    }
+
+    /// Adds a run of the `/Differences` array.
+    ///
+    /// `names` are assigned to consecutive codes, starting at `first_code`.
+    /// Names that would exceed code 255 are ignored.
+    pub fn differences<I, S>(mut self, first_code: u8, names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        for (code, name) in (first_code..=u8::MAX).zip(names) {
+            self.differences.insert(code, name.into());
+        }
+
+        self
+    }
+
+    /// Sets whether the font is symbolic, as set by the `Symbolic` flag
+    /// of the font descriptor.
+    ///
+    /// Symbolic fonts use their built-in encoding if there is
+    /// no base encoding, even if they aren't embedded.
+    pub fn symbolic(mut self, symbolic: bool) -> Self {
+        self.symbolic = symbolic;
+        self
+    }
+
+    /// Sets whether the font program is embedded in the PDF.
+    ///
+    /// Defaults to `true`. Non-symbolic fonts that aren't embedded
+    /// use `StandardEncoding` if there is no base encoding.
+    pub fn embedded(mut self, embedded: bool) -> Self {
+        self.embedded = embedded;
+        self
+    }
+
+    /// Sets the font whose glyphs are resolved.
+    ///
+    /// Builds the map from characters to glyphs that
+    /// [`PdfEncoding::glyph_index`] falls back to, so it has to be
+    /// the font that is passed there.
+    pub fn font(mut self, font: &Font) -> Self {
+        self.char_map = Some(match font {
+            Font::Type1(table) => CharMap::Type1(type1::CharMap::new(table)),
+            Font::CFF(table) => CharMap::CFF(cff::CharMap::new(table)),
+        });
+        self
+    }
+
+    /// Returns the glyph name of a code as defined by the PDF encoding.
+    ///
+    /// Returns `None` if the code isn't mapped by the differences or the
+    /// base encoding, in which case the built-in encoding of the font applies.
+    pub fn glyph_name(&self, code: u8) -> Option<&str> {
+        if let Some(name) = self.differences.get(&code) {
+            return Some(name);
+        }
+
+        let base = match self.base {
+            Some(base) => base,
+            None if !self.symbolic && !self.embedded => BaseEncoding::Standard,
+            None => return None,
+        };
+
+        base.glyph_name(code)
+    }
+
+    /// Resolves the glyph of a code.
+    ///
+    /// If the font doesn't contain a glyph with the name from the encoding,
+    /// the glyph is looked up by the character the name maps to,
+    /// so that `uni0041` can be drawn by `A` and vice versa.
+    /// This requires the font to be set with [`PdfEncoding::font`].
+    ///
+    /// Always returns `None` for CID-keyed fonts, since they can't be used
+    /// as simple fonts.
+    pub fn glyph_index(&self, font: &Font, code: u8) -> Option<GlyphId> {
+        if font.is_cid() {
+            return None;
+        }
+
+        let name = match self.glyph_name(code) {
+            Some(name) => name,
+            None => return font.glyph_index(code),
+        };
+
+        if name == ".notdef" {
+            return None;
+        }
+
+        font.glyph_index_by_name(name).or_else(|| {
+            let c = agl::name_to_char(name)?;
+            match self.char_map.as_ref()? {
+                CharMap::Type1(char_map) => char_map.glyph_for_char(c),
+                CharMap::CFF(char_map) => char_map.glyph_for_char(c),
+            }
+        })
+    }
+}
+
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone, Debug)]
+enum CharMap {
+    Type1(type1::CharMap),
+    CFF(cff::CharMap),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn base_encodings() {
+        assert_eq!(BaseEncoding::Standard.glyph_name(39), Some("quoteright"));
+        assert_eq!(BaseEncoding::WinAnsi.glyph_name(39), Some("quotesingle"));
+        assert_eq!(BaseEncoding::WinAnsi.glyph_name(128), Some("Euro"));
+        assert_eq!(BaseEncoding::WinAnsi.glyph_name(129), Some("bullet"));
+        assert_eq!(BaseEncoding::MacRoman.glyph_name(128), Some("Adieresis"));
+        assert_eq!(BaseEncoding::MacRoman.glyph_name(219), Some("currency"));
+        assert_eq!(BaseEncoding::MacExpert.glyph_name(97), Some("Asmall"));
+        assert_eq!(BaseEncoding::MacExpert.glyph_name(64), None);
+        assert_eq!(
+            BaseEncoding::from_name("MacRomanEncoding"),
+            Some(BaseEncoding::MacRoman)
+        );
+        assert_eq!(BaseEncoding::from_name("Identity-H"), None);
+    }
+
+    #[test]
+    fn other_encodings() {
+        assert_eq!(PDF_DOC.get(&0x18), Some(&"breve"));
+        assert_eq!(PDF_DOC.get(&0xA0), Some(&"Euro"));
+        assert_eq!(PDF_DOC.get(&0xAD), None);
+        assert_eq!(SYMBOL.get(&0x61), Some(&"alpha"));
+        assert_eq!(SYMBOL.get(&0xF0), None);
+        assert_eq!(ZAPF_DINGBATS.get(&0x21), Some(&"a1"));
+        assert_eq!(ZAPF_DINGBATS.get(&0xFE), Some(&"a191"));
+    }
+
+    #[test]
+    fn differences() {
+        let encoding = PdfEncoding::new()
+            .base_encoding(BaseEncoding::MacRoman)
+            .differences(254, ["a", "b", "c"]);
+        assert_eq!(encoding.glyph_name(65), Some("A"));
+        assert_eq!(encoding.glyph_name(254), Some("a"));
+        assert_eq!(encoding.glyph_name(255), Some("b"));
+
+        let encoding = PdfEncoding::new().differences(254, ["a", "b", "c"]);
+        assert_eq!(encoding.glyph_name(65), None);
+        assert_eq!(encoding.glyph_name(255), Some("b"));
+
+        assert_eq!(PdfEncoding::new().glyph_name(65), None);
+    }
+
+    #[test]
+    fn symbolic_and_embedded() {
+        // Non-embedded, non-symbolic fonts default to StandardEncoding.
+        let encoding = PdfEncoding::new().embedded(false);
+        assert_eq!(encoding.glyph_name(39), Some("quoteright"));
+        assert_eq!(encoding.glyph_name(128), None);
+
+        let encoding = encoding.symbolic(true);
+        assert_eq!(encoding.glyph_name(39), None);
+
+        let encoding = encoding.differences(39, ["a"]);
+        assert_eq!(encoding.glyph_name(39), Some("a"));
+        assert_eq!(encoding.glyph_name(65), None);
+
+        // An explicit base encoding also applies to symbolic fonts.
+        let encoding = encoding.base_encoding(BaseEncoding::WinAnsi);
+        assert_eq!(encoding.glyph_name(128), Some("Euro"));
+
+        let encoding = PdfEncoding::new().symbolic(true);
+        assert_eq!(encoding.glyph_name(65), None);
+    }
+
+    #[test]
+    fn resolve_glyphs() {
+        let data = &[
+            0x01, 0x00, 0x04, 0x01, // header
+            // Name INDEX
+            0x00, 0x01, // count: 1
+            0x01, // offset size: 1
+            0x01, 0x02, // offsets
+            0x41, // A
+            // Top DICT INDEX
+            0x00, 0x01, // count: 1
+            0x01, // offset size: 1
+            0x01, 0x13, // offsets
+            0x1D, 0x00, 0x00, 0x00, 0x25, 0x0F, // charset offset: 37
+            0x1D, 0x00, 0x00, 0x00, 0x2A, 0x10, // encoding offset: 42
+            0x1D, 0x00, 0x00, 0x00, 0x32, 0x11, // charstrings offset: 50
+            // String INDEX
+            0x00, 0x00, // count: 0
+            // Global Subrs INDEX
+            0x00, 0x00, // count: 0
+            // Charset
+            0x00, // format: 0
+            0x00, 0x22, // A
+            0x00, 0x23, // B
+            // Encoding
+            0x80, // format: 0 with supplements
+            0x02, // count: 2
+            0x41, 0x42, // codes
+            0x01, // supplements count: 1
+            0x61, 0x00, 0x22, // a -> A
+            // CharStrings INDEX
+            0x00, 0x03, // count: 3
+            0x01, // offset size: 1
+            0x01, 0x02, 0x03, 0x04, // offsets
+            0x0E, 0x0E, 0x0E, // endchar
+        ];
+
+        let font = Font::parse(data).unwrap();
+
+        let built_in = PdfEncoding::new();
+        assert_eq!(built_in.glyph_index(&font, 0x61), Some(GlyphId(1)));
+
+        let encoding = PdfEncoding::new()
+            .base_encoding(BaseEncoding::WinAnsi)
+            .differences(0x43, ["B", "uni0041", ".notdef"]);
+        assert_eq!(encoding.glyph_index(&font, 0x44), None);
+
+        let encoding = encoding.font(&font);
+        assert_eq!(encoding.glyph_index(&font, 0x41), Some(GlyphId(1)));
+        assert_eq!(encoding.glyph_index(&font, 0x61), None);
+        assert_eq!(encoding.glyph_index(&font, 0x43), Some(GlyphId(2)));
+        assert_eq!(encoding.glyph_index(&font, 0x44), Some(GlyphId(1)));
+        assert_eq!(encoding.glyph_index(&font, 0x45), None);
+
+        // Without a base encoding, codes not in the differences use the built-in encoding.
+        let encoding = PdfEncoding::new().differences(0x43, ["B"]);
+        assert_eq!(encoding.glyph_index(&font, 0x61), Some(GlyphId(1)));
+        assert_eq!(encoding.glyph_index(&font, 0x43), Some(GlyphId(2)));
+
+        // Without a base encoding, non-embedded fonts use StandardEncoding.
+        let encoding = encoding.embedded(false);
+        assert_eq!(encoding.glyph_index(&font, 0x61), None);
+        assert_eq!(encoding.glyph_index(&font, 0x41), Some(GlyphId(1)));
+
+        let symbolic = encoding.symbolic(true);
+        assert_eq!(symbolic.glyph_index(&font, 0x61), Some(GlyphId(1)));
+    }
+}
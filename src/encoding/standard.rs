@@ -1,7 +1,8 @@
 // THIS FILE WAS AUTO-GENERATED, DO NOT EDIT MANUALLY!
 use phf::phf_map;
 
-pub(crate) static STANDARD: phf::Map<u8, &'static str> = phf_map! {
+/// The Adobe Standard Encoding.
+pub static STANDARD: phf::Map<u8, &'static str> = phf_map! {
     32u8 => "space",
     33u8 => "exclam",
     34u8 => "quotedbl",
@@ -0,0 +1,208 @@
+// THIS FILE WAS AUTO-GENERATED, DO NOT EDIT MANUALLY!
+use phf::phf_map;
+
+/// The built-in encoding of the ZapfDingbats font.
+pub static ZAPF_DINGBATS: phf::Map<u8, &'static str> = phf_map! {
+    32u8 => "space",
+    33u8 => "a1",
+    34u8 => "a2",
+    35u8 => "a202",
+    36u8 => "a3",
+    37u8 => "a4",
+    38u8 => "a5",
+    39u8 => "a119",
+    40u8 => "a118",
+    41u8 => "a117",
+    42u8 => "a11",
+    43u8 => "a12",
+    44u8 => "a13",
+    45u8 => "a14",
+    46u8 => "a15",
+    47u8 => "a16",
+    48u8 => "a105",
+    49u8 => "a17",
+    50u8 => "a18",
+    51u8 => "a19",
+    52u8 => "a20",
+    53u8 => "a21",
+    54u8 => "a22",
+    55u8 => "a23",
+    56u8 => "a24",
+    57u8 => "a25",
+    58u8 => "a26",
+    59u8 => "a27",
+    60u8 => "a28",
+    61u8 => "a6",
+    62u8 => "a7",
+    63u8 => "a8",
+    64u8 => "a9",
+    65u8 => "a10",
+    66u8 => "a29",
+    67u8 => "a30",
+    68u8 => "a31",
+    69u8 => "a32",
+    70u8 => "a33",
+    71u8 => "a34",
+    72u8 => "a35",
+    73u8 => "a36",
+    74u8 => "a37",
+    75u8 => "a38",
+    76u8 => "a39",
+    77u8 => "a40",
+    78u8 => "a41",
+    79u8 => "a42",
+    80u8 => "a43",
+    81u8 => "a44",
+    82u8 => "a45",
+    83u8 => "a46",
+    84u8 => "a47",
+    85u8 => "a48",
+    86u8 => "a49",
+    87u8 => "a50",
+    88u8 => "a51",
+    89u8 => "a52",
+    90u8 => "a53",
+    91u8 => "a54",
+    92u8 => "a55",
+    93u8 => "a56",
+    94u8 => "a57",
+    95u8 => "a58",
+    96u8 => "a59",
+    97u8 => "a60",
+    98u8 => "a61",
+    99u8 => "a62",
+    100u8 => "a63",
+    101u8 => "a64",
+    102u8 => "a65",
+    103u8 => "a66",
+    104u8 => "a67",
+    105u8 => "a68",
+    106u8 => "a69",
+    107u8 => "a70",
+    108u8 => "a71",
+    109u8 => "a72",
+    110u8 => "a73",
+    111u8 => "a74",
+    112u8 => "a203",
+    113u8 => "a75",
+    114u8 => "a204",
+    115u8 => "a76",
+    116u8 => "a77",
+    117u8 => "a78",
+    118u8 => "a79",
+    119u8 => "a81",
+    120u8 => "a82",
+    121u8 => "a83",
+    122u8 => "a84",
+    123u8 => "a97",
+    124u8 => "a98",
+    125u8 => "a99",
+    126u8 => "a100",
+    128u8 => "a89",
+    129u8 => "a90",
+    130u8 => "a93",
+    131u8 => "a94",
+    132u8 => "a91",
+    133u8 => "a92",
+    134u8 => "a205",
+    135u8 => "a85",
+    136u8 => "a206",
+    137u8 => "a86",
+    138u8 => "a87",
+    139u8 => "a88",
+    140u8 => "a95",
+    141u8 => "a96",
+    161u8 => "a101",
+    162u8 => "a102",
+    163u8 => "a103",
+    164u8 => "a104",
+    165u8 => "a106",
+    166u8 => "a107",
+    167u8 => "a108",
+    168u8 => "a112",
+    169u8 => "a111",
+    170u8 => "a110",
+    171u8 => "a109",
+    172u8 => "a120",
+    173u8 => "a121",
+    174u8 => "a122",
+    175u8 => "a123",
+    176u8 => "a124",
+    177u8 => "a125",
+    178u8 => "a126",
+    179u8 => "a127",
+    180u8 => "a128",
+    181u8 => "a129",
+    182u8 => "a130",
+    183u8 => "a131",
+    184u8 => "a132",
+    185u8 => "a133",
+    186u8 => "a134",
+    187u8 => "a135",
+    188u8 => "a136",
+    189u8 => "a137",
+    190u8 => "a138",
+    191u8 => "a139",
+    192u8 => "a140",
+    193u8 => "a141",
+    194u8 => "a142",
+    195u8 => "a143",
+    196u8 => "a144",
+    197u8 => "a145",
+    198u8 => "a146",
+    199u8 => "a147",
+    200u8 => "a148",
+    201u8 => "a149",
+    202u8 => "a150",
+    203u8 => "a151",
+    204u8 => "a152",
+    205u8 => "a153",
+    206u8 => "a154",
+    207u8 => "a155",
+    208u8 => "a156",
+    209u8 => "a157",
+    210u8 => "a158",
+    211u8 => "a159",
+    212u8 => "a160",
+    213u8 => "a161",
+    214u8 => "a163",
+    215u8 => "a164",
+    216u8 => "a196",
+    217u8 => "a165",
+    218u8 => "a192",
+    219u8 => "a166",
+    220u8 => "a167",
+    221u8 => "a168",
+    222u8 => "a169",
+    223u8 => "a170",
+    224u8 => "a171",
+    225u8 => "a172",
+    226u8 => "a173",
+    227u8 => "a162",
+    228u8 => "a174",
+    229u8 => "a175",
+    230u8 => "a176",
+    231u8 => "a177",
+    232u8 => "a178",
+    233u8 => "a179",
+    234u8 => "a193",
+    235u8 => "a180",
+    236u8 => "a199",
+    237u8 => "a181",
+    238u8 => "a200",
+    239u8 => "a182",
+    241u8 => "a201",
+    242u8 => "a183",
+    243u8 => "a184",
+    244u8 => "a197",
+    245u8 => "a185",
+    246u8 => "a194",
+    247u8 => "a198",
+    248u8 => "a186",
+    249u8 => "a195",
+    250u8 => "a187",
+    251u8 => "a188",
+    252u8 => "a189",
+    253u8 => "a190",
+    254u8 => "a191",
+};
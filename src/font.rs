@@ -0,0 +1,292 @@
+//! A format-independent font program.
+
+use core::fmt;
+
+use crate::cff::{self, CFFError};
+use crate::type1;
+use crate::{GlyphId, Matrix, OutlineBuilder, Rect};
+
+/// The format of a font program, as detected from its first bytes.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum FontFormat {
+    /// A Type 1 font in the printable (PFA) format.
+    Type1,
+    /// A Type 1 font in the binary, segmented (PFB) format.
+    Type1Binary,
+    /// A bare CFF font.
+    CFF,
+    /// An OpenType font with CFF outlines.
+    OpenType,
+    /// A TrueType font.
+    TrueType,
+    /// A TrueType or OpenType collection.
+    Collection,
+    /// A WOFF or WOFF2 font.
+    Woff,
+    /// An unknown format.
+    Unknown,
+}
+
+impl FontFormat {
+    /// Detects the format of a font program.
+    pub fn detect(data: &[u8]) -> Self {
+        match data {
+            [b'%', b'!', ..] => FontFormat::Type1,
+            [0x80, 0x01, ..] => FontFormat::Type1Binary,
+            [b'O', b'T', b'T', b'O', ..] => FontFormat::OpenType,
+            [0x00, 0x01, 0x00, 0x00, ..] | [b't', b'r', b'u', b'e', ..] => FontFormat::TrueType,
+            [b't', b't', b'c', b'f', ..] => FontFormat::Collection,
+            [b'w', b'O', b'F', b'F', ..] | [b'w', b'O', b'F', b'2', ..] => FontFormat::Woff,
+            // 'major', 'minor', 'hdrSize' and 'offSize' of the CFF header.
+            [1, _, 4..=u8::MAX, 1..=4, ..] => FontFormat::CFF,
+            _ => FontFormat::Unknown,
+        }
+    }
+}
+
+/// An error that can occur while parsing a [`Font`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum FontError {
+    /// The font program has a format that isn't supported.
+    UnsupportedFormat(FontFormat),
+    /// The font program has a supported format, but couldn't be parsed.
+    MalformedFont(FontFormat),
+}
+
+impl fmt::Display for FontError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FontError::UnsupportedFormat(FontFormat::Unknown) => {
+                write!(f, "unknown font format")
+            }
+            FontError::UnsupportedFormat(format) => {
+                write!(f, "unsupported font format: {:?}", format)
+            }
+            FontError::MalformedFont(format) => write!(f, "malformed {:?} font", format),
+        }
+    }
+}
+
+impl std::error::Error for FontError {}
+
+/// A font program of any supported format.
+///
+/// Provides the same API for all formats. Since Type 1 fonts don't have
+/// glyph IDs, they are assigned as described in [`type1::Table::glyph_name`].
+/// Glyphs are looked up by characters with [`cff::CharMap`] and
+/// [`type1::CharMap`], which are built once per font.
+#[derive(Clone, Debug)]
+pub enum Font<'a> {
+    /// A Type 1 font.
+    Type1(type1::Table<'a>),
+    /// A CFF font.
+    CFF(Box<cff::Table<'a>>),
+}
+
+impl<'a> Font<'a> {
+    /// Detects the format of a font program and parses it.
+    pub fn parse(data: &'a [u8]) -> Result<Self, FontError> {
+        let format = FontFormat::detect(data);
+        let font = match format {
+            FontFormat::Type1 => type1::Table::parse(data).map(Font::Type1),
+            FontFormat::CFF => cff::Table::parse(data).map(|t| Font::CFF(Box::new(t))),
+            _ => return Err(FontError::UnsupportedFormat(format)),
+        };
+
+        font.ok_or(FontError::MalformedFont(format))
+    }
+
+    /// Returns the format of the font.
+    pub fn format(&self) -> FontFormat {
+        match self {
+            Font::Type1(_) => FontFormat::Type1,
+            Font::CFF(_) => FontFormat::CFF,
+        }
+    }
+
+    /// Returns the font name.
+    pub fn name(&self) -> Option<&str> {
+        match self {
+            Font::Type1(table) => table.name(),
+            Font::CFF(table) => table.name(),
+        }
+    }
+
+    /// Checks if the font is a CID-keyed font.
+    pub fn is_cid(&self) -> bool {
+        match self {
+            Font::Type1(_) => false,
+            Font::CFF(table) => table.is_cid(),
+        }
+    }
+
+    /// Returns the font transformation matrix.
+    pub fn matrix(&self) -> Matrix {
+        match self {
+            Font::Type1(table) => table.matrix(),
+            Font::CFF(table) => table.matrix(),
+        }
+    }
+
+    /// Returns the transformation matrix for a specific glyph.
+    ///
+    /// Only differs from [`Font::matrix`] in CID-keyed CFF fonts.
+    pub fn glyph_matrix(&self, glyph_id: GlyphId) -> Matrix {
+        match self {
+            Font::Type1(table) => table.matrix(),
+            Font::CFF(table) => table.glyph_matrix(glyph_id),
+        }
+    }
+
+    /// Returns the number of glyphs in the font.
+    pub fn number_of_glyphs(&self) -> u16 {
+        match self {
+            Font::Type1(table) => table.number_of_glyphs(),
+            Font::CFF(table) => table.number_of_glyphs(),
+        }
+    }
+
+    /// Resolves a glyph ID for a code using the font's built-in encoding.
+    pub fn glyph_index(&self, code: u8) -> Option<GlyphId> {
+        match self {
+            Font::Type1(table) => table.glyph_index_by_name(table.code_to_string(code)?),
+            Font::CFF(table) => table.glyph_index(code),
+        }
+    }
+
+    /// Returns a glyph ID by a name.
+    pub fn glyph_index_by_name(&self, name: &str) -> Option<GlyphId> {
+        match self {
+            Font::Type1(table) => table.glyph_index_by_name(name),
+            Font::CFF(table) => table.glyph_index_by_name(name),
+        }
+    }
+
+    /// Returns a glyph name.
+    ///
+    /// Glyphs in CID-keyed fonts don't have names.
+    pub fn glyph_name(&self, glyph_id: GlyphId) -> Option<&str> {
+        match self {
+            Font::Type1(table) => table.glyph_name(glyph_id),
+            Font::CFF(table) => table.glyph_name(glyph_id),
+        }
+    }
+
+    /// Outlines a glyph and returns its bounding box.
+    ///
+    /// Glyphs without an outline, like `space`, have a zero bounding box.
+    pub fn outline(&self, glyph_id: GlyphId, builder: &mut dyn OutlineBuilder) -> Option<Rect> {
+        match self {
+            Font::Type1(table) => table.outline(table.glyph_name(glyph_id)?, builder),
+            Font::CFF(table) => match table.outline(glyph_id, builder) {
+                Ok(bbox) => Some(bbox),
+                Err(CFFError::ZeroBBox) => Some(Rect::zero()),
+                Err(_) => None,
+            },
+        }
+    }
+
+    /// Outlines the glyph of a code using the font's built-in encoding.
+    pub fn outline_by_code(&self, code: u8, builder: &mut dyn OutlineBuilder) -> Option<Rect> {
+        self.outline(self.glyph_index(code)?, builder)
+    }
+
+    /// Outlines a glyph by its name.
+    pub fn outline_by_name(&self, name: &str, builder: &mut dyn OutlineBuilder) -> Option<Rect> {
+        self.outline(self.glyph_index_by_name(name)?, builder)
+    }
+
+    /// Returns the advance width of a glyph in font units.
+    pub fn glyph_width(&self, glyph_id: GlyphId) -> Option<f32> {
+        match self {
+            Font::Type1(table) => table.glyph_width(table.glyph_name(glyph_id)?),
+            Font::CFF(table) => table.glyph_width(glyph_id).map(f32::from),
+        }
+    }
+}
+
+impl<'a> From<type1::Table<'a>> for Font<'a> {
+    fn from(table: type1::Table<'a>) -> Self {
+        Font::Type1(table)
+    }
+}
+
+impl<'a> From<cff::Table<'a>> for Font<'a> {
+    fn from(table: cff::Table<'a>) -> Self {
+        Font::CFF(Box::new(table))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detect_format() {
+        assert_eq!(FontFormat::detect(b"%!PS-AdobeFont-1.0"), FontFormat::Type1);
+        assert_eq!(
+            FontFormat::detect(&[0x80, 0x01, 0x10]),
+            FontFormat::Type1Binary
+        );
+        assert_eq!(
+            FontFormat::detect(&[0x01, 0x00, 0x04, 0x01]),
+            FontFormat::CFF
+        );
+        assert_eq!(FontFormat::detect(b"OTTO\x00\x0A"), FontFormat::OpenType);
+        assert_eq!(
+            FontFormat::detect(&[0, 1, 0, 0, 0, 10]),
+            FontFormat::TrueType
+        );
+        assert_eq!(FontFormat::detect(b"ttcf"), FontFormat::Collection);
+        assert_eq!(FontFormat::detect(b"wOF2"), FontFormat::Woff);
+        assert_eq!(FontFormat::detect(&[0x01, 0x00, 0x04]), FontFormat::Unknown);
+        assert_eq!(FontFormat::detect(&[]), FontFormat::Unknown);
+    }
+
+    #[test]
+    fn unsupported_formats() {
+        assert_eq!(
+            Font::parse(b"OTTO\x00\x0A").unwrap_err(),
+            FontError::UnsupportedFormat(FontFormat::OpenType)
+        );
+        assert_eq!(
+            Font::parse(b"hello").unwrap_err(),
+            FontError::UnsupportedFormat(FontFormat::Unknown)
+        );
+        assert_eq!(
+            Font::parse(&[0x01, 0x00, 0x04, 0x01, 0x00]).unwrap_err(),
+            FontError::MalformedFont(FontFormat::CFF)
+        );
+    }
+
+    #[test]
+    fn malformed_type1() {
+        let inputs: [&[u8]; 3] = [
+            b"%!PS\n/Encoding",
+            b"%!PS\n/Encoding 256 array dup x /A put",
+            b"%!PS\n/FontMatrix [a b c d e f] def",
+        ];
+        for data in inputs {
+            assert_eq!(
+                Font::parse(data).unwrap_err(),
+                FontError::MalformedFont(FontFormat::Type1)
+            );
+        }
+    }
+
+    #[test]
+    fn truncated_eexec() {
+        let inputs: [&[u8]; 4] = [
+            b"%!PS-AdobeFont-1.0: X\ncurrentfile eexec\n",
+            b"%!PS-AdobeFont-1.0: X\ncurrentfile eexec\n  \n",
+            b"%!PS-AdobeFont-1.0: X\ncurrentfile eexec\nzz",
+            b"%!PS-AdobeFont-1.0: X\ncurrentfile eexec\n\x80\xF1",
+        ];
+        for data in inputs {
+            assert_eq!(
+                Font::parse(data).unwrap_err(),
+                FontError::MalformedFont(FontFormat::Type1)
+            );
+        }
+    }
+}
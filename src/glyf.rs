@@ -0,0 +1,468 @@
+//! Export of outlines as TrueType `glyf` and `loca` tables.
+//!
+//! Cubic curves are approximated by quadratic ones with a [`QuadraticBuilder`],
+//! and contours are reversed, since TrueType fills clockwise outer contours
+//! while PostScript fonts use counter-clockwise ones.
+
+// Useful links:
+// https://learn.microsoft.com/en-us/typography/opentype/spec/glyf
+// https://learn.microsoft.com/en-us/typography/opentype/spec/loca
+
+use crate::cff::{self, CFFError};
+use crate::outline::{Outline, QuadraticBuilder, Segment};
+use crate::{GlyphId, Rect};
+
+const ON_CURVE_POINT: u8 = 0x01;
+const X_SHORT_VECTOR: u8 = 0x02;
+const Y_SHORT_VECTOR: u8 = 0x04;
+const REPEAT_FLAG: u8 = 0x08;
+const X_IS_SAME_OR_POSITIVE_X_SHORT_VECTOR: u8 = 0x10;
+const Y_IS_SAME_OR_POSITIVE_Y_SHORT_VECTOR: u8 = 0x20;
+
+/// The `glyf` and `loca` tables of a TrueType font.
+#[derive(Clone, Debug)]
+pub struct Glyf {
+    tolerance: f32,
+    data: Vec<u8>,
+    offsets: Vec<u32>,
+    bboxes: Vec<Option<Rect>>,
+    max_points: u16,
+    max_contours: u16,
+}
+
+impl Glyf {
+    /// Creates empty tables.
+    ///
+    /// Cubic curves are approximated within the tolerance, which is given in font units.
+    pub fn new(tolerance: f32) -> Self {
+        Glyf {
+            tolerance,
+            data: Vec::new(),
+            offsets: vec![0],
+            bboxes: Vec::new(),
+            max_points: 0,
+            max_contours: 0,
+        }
+    }
+
+    /// Converts all glyphs of a CFF font, keeping the glyph IDs.
+    ///
+    /// Glyphs are stored in font units, i.e. untransformed by [`cff::Table::matrix`].
+    pub fn from_cff(table: &cff::Table, tolerance: f32) -> Result<Self, CFFError> {
+        let mut glyf = Glyf::new(tolerance);
+        for glyph_id in 0..table.number_of_glyphs() {
+            let mut outline = Outline::new();
+            match table.outline(GlyphId(glyph_id), &mut outline) {
+                Ok(_) | Err(CFFError::ZeroBBox) => glyf.push(&outline),
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(glyf)
+    }
+
+    /// Appends a glyph with the next glyph ID.
+    ///
+    /// Points are rounded to integers. Glyphs without an outline, like `space`, take no space.
+    pub fn push(&mut self, outline: &Outline) {
+        let mut quadratic = QuadraticBuilder::new(self.tolerance, Outline::new());
+        outline.replay(&mut quadratic);
+        let mut outline = quadratic.into_inner();
+        outline.reverse();
+
+        let contours: Vec<Vec<Point>> = outline
+            .contours()
+            .iter()
+            .map(|contour| {
+                let mut points = vec![Point::new(contour.start(), true)];
+                for segment in contour.segments() {
+                    match *segment {
+                        Segment::Line(x, y) => points.push(Point::new((x, y), true)),
+                        Segment::Quad(x1, y1, x, y) => {
+                            points.push(Point::new((x1, y1), false));
+                            points.push(Point::new((x, y), true));
+                        }
+                        // Already converted.
+                        Segment::Curve(..) => {}
+                    }
+                }
+
+                simplify(points)
+            })
+            .filter(|points| points.len() > 1)
+            .collect();
+
+        let bbox = write_glyph(&contours, &mut self.data);
+        while self.data.len() % 4 != 0 {
+            self.data.push(0);
+        }
+
+        let number_of_points: usize = contours.iter().map(Vec::len).sum();
+        self.max_points = self.max_points.max(number_of_points as u16);
+        self.max_contours = self.max_contours.max(contours.len() as u16);
+        self.offsets.push(self.data.len() as u32);
+        self.bboxes.push(bbox);
+    }
+
+    /// Returns the number of glyphs.
+    pub fn number_of_glyphs(&self) -> u16 {
+        self.bboxes.len() as u16
+    }
+
+    /// Returns the bounding box of a glyph's points, or `None` if it has no outline.
+    ///
+    /// The left side bearing of the glyph must be the minimum x coordinate.
+    pub fn glyph_bbox(&self, glyph_id: GlyphId) -> Option<Rect> {
+        *self.bboxes.get(usize::from(glyph_id.0))?
+    }
+
+    /// Returns the `glyf` table data.
+    pub fn glyf_data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Returns the `loca` table data in the format of [`Glyf::index_to_loc_format`].
+    pub fn loca_data(&self) -> Vec<u8> {
+        let mut data = Vec::new();
+        for offset in &self.offsets {
+            if self.index_to_loc_format() == 0 {
+                data.extend_from_slice(&((offset / 2) as u16).to_be_bytes());
+            } else {
+                data.extend_from_slice(&offset.to_be_bytes());
+            }
+        }
+
+        data
+    }
+
+    /// Returns the `indexToLocFormat` of the `head` table,
+    /// which is 0 for short and 1 for long offsets.
+    pub fn index_to_loc_format(&self) -> i16 {
+        if self.data.len() / 2 <= usize::from(u16::MAX) {
+            0
+        } else {
+            1
+        }
+    }
+
+    pub(crate) fn max_points(&self) -> u16 {
+        self.max_points
+    }
+
+    pub(crate) fn max_contours(&self) -> u16 {
+        self.max_contours
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct Point {
+    x: i16,
+    y: i16,
+    on_curve: bool,
+}
+
+impl Point {
+    fn new((x, y): (f32, f32), on_curve: bool) -> Self {
+        let round = |n: f32| n.round().clamp(f32::from(i16::MIN), f32::from(i16::MAX)) as i16;
+        Point {
+            x: round(x),
+            y: round(y),
+            on_curve,
+        }
+    }
+}
+
+// Removes points that TrueType doesn't need after rounding.
+fn simplify(points: Vec<Point>) -> Vec<Point> {
+    let mut simplified: Vec<Point> = Vec::with_capacity(points.len());
+    for (i, point) in points.iter().enumerate() {
+        if point.on_curve && i > 0 {
+            // A repeated on-curve point, as left by a line of zero length.
+            if simplified.last() == Some(point) {
+                continue;
+            }
+
+            // An on-curve point midway between two off-curve points is implied.
+            if let (Some(prev), Some(next)) = (simplified.last(), points.get(i + 1)) {
+                if !prev.on_curve
+                    && !next.on_curve
+                    && i32::from(prev.x) + i32::from(next.x) == 2 * i32::from(point.x)
+                    && i32::from(prev.y) + i32::from(next.y) == 2 * i32::from(point.y)
+                {
+                    continue;
+                }
+            }
+        }
+
+        simplified.push(*point);
+    }
+
+    // Contours are closed implicitly.
+    if simplified.len() > 1 && simplified.last() == simplified.first() {
+        simplified.pop();
+    }
+
+    simplified
+}
+
+// Writes a simple glyph and returns its bounding box.
+fn write_glyph(contours: &[Vec<Point>], data: &mut Vec<u8>) -> Option<Rect> {
+    let points: Vec<Point> = contours.iter().flatten().copied().collect();
+    let first = points.first()?;
+    let mut bbox = Rect {
+        x_min: first.x,
+        y_min: first.y,
+        x_max: first.x,
+        y_max: first.y,
+    };
+    for point in &points {
+        bbox.x_min = bbox.x_min.min(point.x);
+        bbox.y_min = bbox.y_min.min(point.y);
+        bbox.x_max = bbox.x_max.max(point.x);
+        bbox.y_max = bbox.y_max.max(point.y);
+    }
+
+    data.extend_from_slice(&(contours.len() as i16).to_be_bytes());
+    for n in [bbox.x_min, bbox.y_min, bbox.x_max, bbox.y_max] {
+        data.extend_from_slice(&n.to_be_bytes());
+    }
+
+    let mut end = 0;
+    for contour in contours {
+        end += contour.len();
+        data.extend_from_slice(&((end - 1) as u16).to_be_bytes());
+    }
+
+    // No instructions.
+    data.extend_from_slice(&0u16.to_be_bytes());
+
+    let mut flags = Vec::with_capacity(points.len());
+    let mut xs = Vec::new();
+    let mut ys = Vec::new();
+    let mut previous = (0, 0);
+    for point in &points {
+        let mut flag = if point.on_curve { ON_CURVE_POINT } else { 0 };
+        let dx = i32::from(point.x) - i32::from(previous.0);
+        let dy = i32::from(point.y) - i32::from(previous.1);
+        flag |= write_delta(
+            dx,
+            X_SHORT_VECTOR,
+            X_IS_SAME_OR_POSITIVE_X_SHORT_VECTOR,
+            &mut xs,
+        );
+        flag |= write_delta(
+            dy,
+            Y_SHORT_VECTOR,
+            Y_IS_SAME_OR_POSITIVE_Y_SHORT_VECTOR,
+            &mut ys,
+        );
+        flags.push(flag);
+        previous = (point.x, point.y);
+    }
+
+    let mut i = 0;
+    while i < flags.len() {
+        let flag = flags[i];
+        let repeats = flags[i + 1..]
+            .iter()
+            .take(255)
+            .take_while(|f| **f == flag)
+            .count();
+        if repeats > 0 {
+            data.push(flag | REPEAT_FLAG);
+            data.push(repeats as u8);
+        } else {
+            data.push(flag);
+        }
+
+        i += repeats + 1;
+    }
+
+    data.extend_from_slice(&xs);
+    data.extend_from_slice(&ys);
+    Some(bbox)
+}
+
+// Writes a coordinate delta and returns its flags.
+fn write_delta(delta: i32, short_flag: u8, same_or_positive_flag: u8, data: &mut Vec<u8>) -> u8 {
+    if delta == 0 {
+        same_or_positive_flag
+    } else if delta.abs() < 256 {
+        data.push(delta.unsigned_abs() as u8);
+        if delta > 0 {
+            short_flag | same_or_positive_flag
+        } else {
+            short_flag
+        }
+    } else {
+        // Deltas between two i16 coordinates wrap around like the coordinates do.
+        data.extend_from_slice(&(delta as i16).to_be_bytes());
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::outline::{Direction, FillRule};
+    use crate::OutlineBuilder;
+
+    // Decodes the points of a simple glyph, adding implied on-curve points.
+    fn decode(data: &[u8]) -> Outline {
+        let u16_at = |offset: usize| u16::from_be_bytes([data[offset], data[offset + 1]]);
+        let number_of_contours = usize::from(u16_at(0));
+        let end_points: Vec<usize> = (0..number_of_contours)
+            .map(|i| usize::from(u16_at(10 + 2 * i)))
+            .collect();
+        let number_of_points = end_points.last().map_or(0, |end| end + 1);
+        let mut offset = 10 + 2 * number_of_contours;
+        offset += 2 + usize::from(u16_at(offset));
+
+        let mut flags = Vec::new();
+        while flags.len() < number_of_points {
+            let flag = data[offset];
+            offset += 1;
+            flags.push(flag);
+            if flag & REPEAT_FLAG != 0 {
+                for _ in 0..data[offset] {
+                    flags.push(flag);
+                }
+                offset += 1;
+            }
+        }
+
+        let mut read = |short_flag: u8, same_flag: u8| {
+            let mut n = 0i16;
+            let mut coords = Vec::new();
+            for flag in &flags {
+                if flag & short_flag != 0 {
+                    let d = i16::from(data[offset]);
+                    n += if flag & same_flag != 0 { d } else { -d };
+                    offset += 1;
+                } else if flag & same_flag == 0 {
+                    n += i16::from_be_bytes([data[offset], data[offset + 1]]);
+                    offset += 2;
+                }
+                coords.push(f32::from(n));
+            }
+            coords
+        };
+        let xs = read(X_SHORT_VECTOR, X_IS_SAME_OR_POSITIVE_X_SHORT_VECTOR);
+        let ys = read(Y_SHORT_VECTOR, Y_IS_SAME_OR_POSITIVE_Y_SHORT_VECTOR);
+
+        let mut outline = Outline::new();
+        let mut start = 0;
+        for end in end_points {
+            let point = |i: usize| {
+                let i = start + (i - start) % (end + 1 - start);
+                (xs[i], ys[i], flags[i] & ON_CURVE_POINT != 0)
+            };
+
+            let (x, y, on_curve) = point(start);
+            assert!(on_curve);
+            outline.move_to(x, y);
+            let mut control = None;
+            for i in start + 1..=end + 1 {
+                let (x, y, on_curve) = point(i);
+                match (control, on_curve) {
+                    (None, true) => outline.line_to(x, y),
+                    (None, false) => control = Some((x, y)),
+                    (Some((x1, y1)), true) => {
+                        outline.quad_to(x1, y1, x, y);
+                        control = None;
+                    }
+                    (Some((x1, y1)), false) => {
+                        outline.quad_to(x1, y1, (x1 + x) / 2.0, (y1 + y) / 2.0);
+                        control = Some((x, y));
+                    }
+                }
+            }
+            outline.close();
+            start = end + 1;
+        }
+
+        outline
+    }
+
+    #[test]
+    fn square() {
+        let mut outline = Outline::new();
+        outline.move_to(0.0, 0.0);
+        outline.line_to(100.0, 0.0);
+        outline.line_to(100.0, 100.0);
+        outline.line_to(0.0, 100.0);
+        outline.close();
+
+        let mut glyf = Glyf::new(1.0);
+        glyf.push(&outline);
+        glyf.push(&Outline::new());
+
+        // The points are (0, 100), (100, 100), (100, 0) and (0, 0), which is clockwise.
+        assert_eq!(
+            glyf.glyf_data(),
+            &[
+                0, 1, 0, 0, 0, 0, 0, 100, 0, 100, 0, 3, 0, 0, 0x35, 0x33, 0x15, 0x23, 100, 100,
+                100, 100, 0, 0
+            ]
+        );
+        assert_eq!(glyf.loca_data(), [0, 0, 0, 12, 0, 12]);
+        assert_eq!(glyf.index_to_loc_format(), 0);
+        assert_eq!(glyf.number_of_glyphs(), 2);
+        assert_eq!(glyf.glyph_bbox(GlyphId(1)), None);
+        assert_eq!((glyf.max_points(), glyf.max_contours()), (4, 1));
+    }
+
+    #[test]
+    fn curves() {
+        // An outer contour with a hole, both in PostScript direction.
+        let mut outline = Outline::new();
+        outline.move_to(0.0, 0.0);
+        outline.line_to(300.0, 0.0);
+        outline.curve_to(300.0, 400.0, 0.0, 400.0, 0.0, 0.0);
+        outline.close();
+        outline.move_to(100.0, 100.0);
+        outline.curve_to(100.0, 200.0, 200.0, 200.0, 200.0, 100.0);
+        outline.close();
+
+        let mut glyf = Glyf::new(0.5);
+        glyf.push(&outline);
+        let decoded = decode(glyf.glyf_data());
+
+        assert_eq!(decoded.contours().len(), 2);
+        for contour in decoded.contours() {
+            assert!(contour
+                .segments()
+                .iter()
+                .all(|s| !matches!(s, Segment::Curve(..))));
+        }
+        assert_eq!(
+            decoded.contours()[0].direction(),
+            Some(Direction::Clockwise)
+        );
+        assert_eq!(
+            decoded.contours()[1].direction(),
+            Some(Direction::CounterClockwise)
+        );
+
+        let bbox = glyf.glyph_bbox(GlyphId(0)).unwrap();
+        assert_eq!((bbox.x_min, bbox.y_min, bbox.x_max), (0, 0, 300));
+        assert!(bbox.y_max >= 300);
+
+        // The areas only differ by the rounding.
+        assert!((decoded.signed_area() + outline.signed_area()).abs() < 300.0);
+        for (x, y) in [
+            (150.0, 50.0),
+            (150.0, 150.0),
+            (150.0, 290.0),
+            (150.0, 310.0),
+        ] {
+            assert_eq!(
+                decoded.contains(x, y, FillRule::NonZero),
+                outline.contains(x, y, FillRule::NonZero),
+                "({}, {})",
+                x,
+                y
+            );
+        }
+    }
+}
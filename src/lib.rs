@@ -1,7 +1,24 @@
+pub mod afm;
+pub mod agl;
+pub mod asm;
 pub mod cff;
+pub mod disasm;
+pub mod encoding;
+pub mod glyf;
+pub mod otf;
+pub mod outline;
+pub mod pfm;
+#[cfg(feature = "raster")]
+pub mod raster;
+#[cfg(feature = "standard14")]
+pub mod standard14;
+pub mod svg;
 pub mod type1;
 
 pub(crate) mod argstack;
+mod font;
+
+pub use font::{Font, FontError, FontFormat};
 
 use crate::cff::parser::TryNumFrom;
 
@@ -41,6 +58,17 @@ impl OutlineBuilder for DummyOutline {
     fn close(&mut self) {}
 }
 
+/// The kind of a font encoding.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum EncodingKind {
+    /// The Adobe Standard Encoding.
+    Standard,
+    /// The Adobe Expert Encoding.
+    Expert,
+    /// An encoding defined by the font itself.
+    Custom,
+}
+
 /// A rectangle.
 ///
 /// Doesn't guarantee that `x_min` <= `x_max` and/or `y_min` <= `y_max`.
@@ -132,6 +160,8 @@ impl RectF {
 pub(crate) struct Builder<'a> {
     pub(crate) builder: &'a mut dyn OutlineBuilder,
     pub(crate) bbox: RectF,
+    // The number of segments passed on, so that hints can refer to a position in the path.
+    pub(crate) segments: usize,
 }
 
 impl<'a> Builder<'a> {
@@ -139,12 +169,14 @@ impl<'a> Builder<'a> {
     fn move_to(&mut self, x: f32, y: f32) {
         self.bbox.extend_by(x, y);
         self.builder.move_to(x, y);
+        self.segments += 1;
     }
 
     #[inline]
     fn line_to(&mut self, x: f32, y: f32) {
         self.bbox.extend_by(x, y);
         self.builder.line_to(x, y);
+        self.segments += 1;
     }
 
     #[inline]
@@ -153,17 +185,21 @@ impl<'a> Builder<'a> {
         self.bbox.extend_by(x2, y2);
         self.bbox.extend_by(x, y);
         self.builder.curve_to(x1, y1, x2, y2, x, y);
+        self.segments += 1;
     }
 
     #[inline]
     fn close(&mut self) {
         self.builder.close();
+        self.segments += 1;
     }
 }
 
 /// An affine transformation matrix.
+///
+/// Maps a point `(x, y)` to `(sx * x + kx * y + tx, ky * x + sy * y + ty)`.
 #[allow(missing_docs)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, PartialEq, Debug)]
 pub struct Matrix {
     pub sx: f32,
     pub ky: f32,
@@ -185,3 +221,54 @@ impl Default for Matrix {
         }
     }
 }
+
+impl Matrix {
+    /// Returns the identity matrix.
+    pub fn identity() -> Self {
+        Self {
+            sx: 1.0,
+            ky: 0.0,
+            kx: 0.0,
+            sy: 1.0,
+            tx: 0.0,
+            ty: 0.0,
+        }
+    }
+
+    /// Returns a matrix that applies `self` first and `other` afterwards.
+    pub fn multiply(&self, other: &Matrix) -> Matrix {
+        Matrix {
+            sx: self.sx * other.sx + self.ky * other.kx,
+            ky: self.sx * other.ky + self.ky * other.sy,
+            kx: self.kx * other.sx + self.sy * other.kx,
+            sy: self.kx * other.ky + self.sy * other.sy,
+            tx: self.tx * other.sx + self.ty * other.kx + other.tx,
+            ty: self.tx * other.ky + self.ty * other.sy + other.ty,
+        }
+    }
+
+    /// Returns the inverse matrix, or `None` if the matrix isn't invertible.
+    pub fn invert(&self) -> Option<Matrix> {
+        let det = self.sx * self.sy - self.kx * self.ky;
+        if det == 0.0 || !det.is_finite() {
+            return None;
+        }
+
+        Some(Matrix {
+            sx: self.sy / det,
+            ky: -self.ky / det,
+            kx: -self.kx / det,
+            sy: self.sx / det,
+            tx: (self.kx * self.ty - self.sy * self.tx) / det,
+            ty: (self.ky * self.tx - self.sx * self.ty) / det,
+        })
+    }
+
+    /// Transforms a point.
+    pub fn apply(&self, x: f32, y: f32) -> (f32, f32) {
+        (
+            self.sx * x + self.kx * y + self.tx,
+            self.ky * x + self.sy * y + self.ty,
+        )
+    }
+}
@@ -0,0 +1,1006 @@
+//! Export of fonts as OpenType fonts with CFF or TrueType outlines.
+//!
+//! Besides the outline tables, all required tables are synthesized from the
+//! charset, encoding, widths, outlines and Top DICT strings of the font.
+
+// Useful links:
+// https://learn.microsoft.com/en-us/typography/opentype/spec/otff
+
+use std::collections::BTreeMap;
+
+use crate::cff::{self, CFFError, Subsetter};
+use crate::glyf::Glyf;
+use crate::type1;
+use crate::{DummyOutline, GlyphId, Rect};
+
+// A `cmap` with this offset is used for fonts without Unicode glyph names.
+const SYMBOL_OFFSET: u32 = 0xF000;
+
+/// Wraps a CFF font into an OpenType font.
+///
+/// The `cmap` table is built from the glyph names according to the Adobe Glyph List.
+/// If no glyph maps to Unicode, the encoding is used for a symbol `cmap`,
+/// which maps the codes shifted to `0xF000`.
+pub fn from_cff(table: &cff::Table) -> Result<Vec<u8>, CFFError> {
+    let number_of_glyphs = table.number_of_glyphs();
+
+    // The CFF table of an OpenType font must contain a single font.
+    let cff = Subsetter::new(table)
+        .glyphs((0..number_of_glyphs).map(GlyphId))
+        .subset()?
+        .into_data();
+
+    let metrics: Vec<Metrics> = (0..number_of_glyphs)
+        .map(GlyphId)
+        .map(|glyph_id| Metrics {
+            width: table.glyph_width(glyph_id).unwrap_or(0),
+            bbox: table.outline(glyph_id, &mut DummyOutline).ok(),
+        })
+        .collect();
+
+    let (char_map, is_symbolic) = build_char_map(table);
+    let font = FontData::new(table, &metrics, &char_map, is_symbolic, None);
+
+    let tables: [(&[u8; 4], Vec<u8>); 9] = [
+        (b"CFF ", cff),
+        (b"OS/2", font.os2()),
+        (b"cmap", build_cmap(&char_map, is_symbolic)),
+        (b"head", font.head()),
+        (b"hhea", font.hhea()),
+        (b"hmtx", font.hmtx()),
+        (b"maxp", font.maxp()),
+        (b"name", font.name()),
+        (b"post", font.post()),
+    ];
+
+    Ok(write_font(b"OTTO", &tables))
+}
+
+/// Converts a Type 1 font to CFF with [`cff::from_type1`] and wraps it into an OpenType font.
+pub fn from_type1(table: &type1::Table) -> Result<Vec<u8>, CFFError> {
+    let data = cff::from_type1(table)?;
+    let table = cff::Table::parse(&data).ok_or(CFFError::ReadOutOfBounds)?;
+    from_cff(&table)
+}
+
+/// Converts a CFF font into an OpenType font with TrueType outlines.
+///
+/// Cubic curves are approximated within the tolerance, which is given in font units.
+/// The other tables are synthesized like by [`from_cff`], but the glyph names are lost,
+/// since they are only stored in the CFF table. No hinting instructions are generated.
+pub fn truetype_from_cff(table: &cff::Table, tolerance: f32) -> Result<Vec<u8>, CFFError> {
+    let glyf = Glyf::from_cff(table, tolerance)?;
+
+    // The left side bearings must match the rounded points.
+    let metrics: Vec<Metrics> = (0..table.number_of_glyphs())
+        .map(GlyphId)
+        .map(|glyph_id| Metrics {
+            width: table.glyph_width(glyph_id).unwrap_or(0),
+            bbox: glyf.glyph_bbox(glyph_id),
+        })
+        .collect();
+
+    let (char_map, is_symbolic) = build_char_map(table);
+    let font = FontData::new(table, &metrics, &char_map, is_symbolic, Some(&glyf));
+
+    let tables: [(&[u8; 4], Vec<u8>); 10] = [
+        (b"OS/2", font.os2()),
+        (b"cmap", build_cmap(&char_map, is_symbolic)),
+        (b"glyf", glyf.glyf_data().to_vec()),
+        (b"head", font.head()),
+        (b"hhea", font.hhea()),
+        (b"hmtx", font.hmtx()),
+        (b"loca", glyf.loca_data()),
+        (b"maxp", font.maxp()),
+        (b"name", font.name()),
+        (b"post", font.post()),
+    ];
+
+    Ok(write_font(&[0, 1, 0, 0], &tables))
+}
+
+/// Converts a Type 1 font to CFF with [`cff::from_type1`] and then into
+/// an OpenType font with TrueType outlines, like [`truetype_from_cff`].
+pub fn truetype_from_type1(table: &type1::Table, tolerance: f32) -> Result<Vec<u8>, CFFError> {
+    let data = cff::from_type1(table)?;
+    let table = cff::Table::parse(&data).ok_or(CFFError::ReadOutOfBounds)?;
+    truetype_from_cff(&table, tolerance)
+}
+
+struct Metrics {
+    width: u16,
+    // `None` for glyphs without an outline.
+    bbox: Option<Rect>,
+}
+
+// Maps Unicode code points to glyph IDs.
+fn build_char_map(table: &cff::Table) -> (BTreeMap<u32, u16>, bool) {
+    let mut map: BTreeMap<u32, u16> = cff::CharMap::new(table)
+        .iter()
+        .map(|(c, glyph_id)| (u32::from(c), glyph_id.0))
+        .collect();
+
+    if !map.is_empty() {
+        return (map, false);
+    }
+
+    if let Some(encoding) = table.encoding() {
+        for (code, glyph_id) in encoding.iter() {
+            map.insert(SYMBOL_OFFSET + u32::from(code), glyph_id.0);
+        }
+    }
+
+    (map, true)
+}
+
+// The values shared by the synthesized tables.
+struct FontData<'a> {
+    metrics: &'a [Metrics],
+    units_per_em: u16,
+    bbox: Rect,
+    font_revision: i32,
+    italic_angle: f32,
+    is_fixed_pitch: bool,
+    underline_position: i16,
+    underline_thickness: i16,
+    weight_class: u16,
+    is_italic: bool,
+    x_height: i16,
+    cap_height: i16,
+    // The first and last character in the `cmap`.
+    char_range: (u16, u16),
+    unicode_ranges: u32,
+    is_symbolic: bool,
+    names: Vec<(u16, String)>,
+    // Only present for TrueType outlines.
+    glyf: Option<&'a Glyf>,
+}
+
+impl<'a> FontData<'a> {
+    fn new(
+        table: &cff::Table,
+        metrics: &'a [Metrics],
+        char_map: &BTreeMap<u32, u16>,
+        is_symbolic: bool,
+        glyf: Option<&'a Glyf>,
+    ) -> Self {
+        let info = table.font_info();
+
+        let sx = table.matrix().sx;
+        let units_per_em = if sx > 0.0 {
+            (1.0 / sx).round().clamp(16.0, 16384.0) as u16
+        } else {
+            1000
+        };
+
+        let mut bbox: Option<Rect> = None;
+        for glyph_bbox in metrics.iter().filter_map(|m| m.bbox) {
+            bbox = Some(match bbox {
+                Some(b) => Rect {
+                    x_min: b.x_min.min(glyph_bbox.x_min),
+                    y_min: b.y_min.min(glyph_bbox.y_min),
+                    x_max: b.x_max.max(glyph_bbox.x_max),
+                    y_max: b.y_max.max(glyph_bbox.y_max),
+                },
+                None => glyph_bbox,
+            });
+        }
+
+        let glyph_height = |c: char| {
+            let glyph_id = *char_map.get(&u32::from(c))?;
+            let bbox = metrics.get(usize::from(glyph_id))?.bbox?;
+            Some(bbox.y_max)
+        };
+
+        let char_range = match (char_map.keys().next(), char_map.keys().next_back()) {
+            (Some(first), Some(last)) => ((*first).min(0xFFFF) as u16, (*last).min(0xFFFF) as u16),
+            _ => (0, 0),
+        };
+
+        // Basic Latin, Latin-1 Supplement, Latin Extended-A, Greek and Cyrillic.
+        let mut unicode_ranges = 0;
+        for (bit, range) in [
+            (0, 0x20..=0x7E),
+            (1, 0xA0..=0xFF),
+            (2, 0x100..=0x17F),
+            (7, 0x370..=0x3FF),
+            (9, 0x400..=0x4FF),
+        ] {
+            if !is_symbolic && char_map.range(range).next().is_some() {
+                unicode_ranges |= 1 << bit;
+            }
+        }
+
+        let weight_class = weight_class(info.weight.as_deref().unwrap_or(""));
+        let is_italic = info.italic_angle != 0.0;
+        let names = build_names(
+            table.name().unwrap_or("Untitled"),
+            &info,
+            weight_class,
+            is_italic,
+        );
+
+        FontData {
+            metrics,
+            units_per_em,
+            bbox: bbox.unwrap_or_else(Rect::zero),
+            font_revision: parse_revision(info.version.as_deref().unwrap_or("")),
+            italic_angle: info.italic_angle,
+            is_fixed_pitch: info.is_fixed_pitch,
+            underline_position: info.underline_position as i16,
+            underline_thickness: info.underline_thickness as i16,
+            weight_class,
+            is_italic,
+            x_height: glyph_height('x').unwrap_or(0),
+            cap_height: glyph_height('H').unwrap_or(0),
+            char_range,
+            unicode_ranges,
+            is_symbolic,
+            names,
+            glyf,
+        }
+    }
+
+    fn is_bold(&self) -> bool {
+        self.weight_class >= 700
+    }
+
+    fn head(&self) -> Vec<u8> {
+        let mut w = Writer::default();
+        w.u16(1);
+        w.u16(0);
+        w.i32(self.font_revision);
+        // The checksum adjustment is written after all tables.
+        w.u32(0);
+        w.u32(0x5F0F_3CF5);
+        // The baseline is at y = 0 and the left sidebearing point at x = 0.
+        w.u16(0b11);
+        w.u16(self.units_per_em);
+        // The created and modified dates.
+        w.u64(0);
+        w.u64(0);
+        w.rect(self.bbox);
+        w.u16(u16::from(self.is_bold()) | u16::from(self.is_italic) << 1);
+        // The lowest readable size.
+        w.u16(3);
+        // The font direction hint is deprecated.
+        w.i16(2);
+        // `indexToLocFormat` and `glyphDataFormat` are only used by TrueType outlines.
+        w.i16(self.glyf.map_or(0, Glyf::index_to_loc_format));
+        w.i16(0);
+        w.data
+    }
+
+    fn hhea(&self) -> Vec<u8> {
+        let mut advance_width_max = 0;
+        let mut min_left_side_bearing = i16::MAX;
+        let mut min_right_side_bearing = i16::MAX;
+        let mut x_max_extent = i16::MIN;
+        for m in self.metrics {
+            advance_width_max = advance_width_max.max(m.width);
+            if let Some(bbox) = m.bbox {
+                let extent = bbox.x_max;
+                min_left_side_bearing = min_left_side_bearing.min(bbox.x_min);
+                min_right_side_bearing =
+                    min_right_side_bearing.min((i32::from(m.width) - i32::from(extent)) as i16);
+                x_max_extent = x_max_extent.max(extent);
+            }
+        }
+
+        if x_max_extent == i16::MIN {
+            min_left_side_bearing = 0;
+            min_right_side_bearing = 0;
+            x_max_extent = 0;
+        }
+
+        // The caret is slanted like the glyphs.
+        let caret_slope_rise = self.units_per_em as i16;
+        let caret_slope_run =
+            (f32::from(caret_slope_rise) * (-self.italic_angle).to_radians().tan()).round() as i16;
+
+        let mut w = Writer::default();
+        w.u16(1);
+        w.u16(0);
+        w.i16(self.bbox.y_max);
+        w.i16(self.bbox.y_min);
+        // The line gap.
+        w.i16(0);
+        w.u16(advance_width_max);
+        w.i16(min_left_side_bearing);
+        w.i16(min_right_side_bearing);
+        w.i16(x_max_extent);
+        w.i16(caret_slope_rise);
+        w.i16(caret_slope_run);
+        // The caret offset and four reserved fields.
+        for _ in 0..5 {
+            w.i16(0);
+        }
+        // The metric data format.
+        w.i16(0);
+        w.u16(self.metrics.len() as u16);
+        w.data
+    }
+
+    fn hmtx(&self) -> Vec<u8> {
+        let mut w = Writer::default();
+        for m in self.metrics {
+            w.u16(m.width);
+            w.i16(m.bbox.map_or(0, |bbox| bbox.x_min));
+        }
+        w.data
+    }
+
+    fn maxp(&self) -> Vec<u8> {
+        let mut w = Writer::default();
+        let glyf = match self.glyf {
+            Some(glyf) => glyf,
+            None => {
+                // Version 0.5, which is used by fonts with CFF outlines.
+                w.u32(0x0000_5000);
+                w.u16(self.metrics.len() as u16);
+                return w.data;
+            }
+        };
+
+        w.u32(0x0001_0000);
+        w.u16(self.metrics.len() as u16);
+        w.u16(glyf.max_points());
+        w.u16(glyf.max_contours());
+        // There are no composite glyphs.
+        w.u16(0);
+        w.u16(0);
+        // The twilight zone isn't used, since there are no instructions.
+        w.u16(1);
+        for _ in 0..8 {
+            w.u16(0);
+        }
+        w.data
+    }
+
+    fn os2(&self) -> Vec<u8> {
+        let widths: Vec<u32> = self
+            .metrics
+            .iter()
+            .map(|m| u32::from(m.width))
+            .filter(|width| *width != 0)
+            .collect();
+        let average_width = if widths.is_empty() {
+            0
+        } else {
+            widths.iter().sum::<u32>() / widths.len() as u32
+        };
+
+        let em = f32::from(self.units_per_em);
+        let scaled = |factor: f32| (em * factor).round() as i16;
+
+        let mut w = Writer::default();
+        w.u16(4);
+        w.i16(average_width as i16);
+        w.u16(self.weight_class);
+        // The normal width class.
+        w.u16(5);
+        // Installable embedding.
+        w.u16(0);
+        // The subscript and superscript sizes and offsets.
+        for (x_size, y_size, y_offset) in [(0.65, 0.6, 0.075), (0.65, 0.6, 0.35)] {
+            w.i16(scaled(x_size));
+            w.i16(scaled(y_size));
+            w.i16(0);
+            w.i16(scaled(y_offset));
+        }
+        // The strikeout size and position.
+        w.i16(self.underline_thickness);
+        w.i16(scaled(0.25));
+        // The family class and PANOSE classification are unknown.
+        w.i16(0);
+        w.bytes(&[0; 10]);
+        w.u32(self.unicode_ranges);
+        w.u32(0);
+        w.u32(0);
+        w.u32(0);
+        w.bytes(b"UKWN");
+
+        let fs_selection = if self.is_bold() || self.is_italic {
+            u16::from(self.is_italic) | u16::from(self.is_bold()) << 5
+        } else {
+            1 << 6
+        };
+        w.u16(fs_selection);
+        w.u16(self.char_range.0);
+        w.u16(self.char_range.1);
+        w.i16(self.bbox.y_max);
+        w.i16(self.bbox.y_min);
+        // The typographic line gap.
+        w.i16(0);
+        w.u16(self.bbox.y_max.max(0) as u16);
+        w.u16((-i32::from(self.bbox.y_min)).clamp(0, 0xFFFF) as u16);
+        // The Latin 1 or the Symbol character set.
+        w.u32(if self.is_symbolic { 1 << 31 } else { 1 });
+        w.u32(0);
+        w.i16(self.x_height);
+        w.i16(self.cap_height);
+        // The default and break characters.
+        w.u16(0);
+        w.u16(0x20);
+        // There are no OpenType layout features.
+        w.u16(0);
+        w.data
+    }
+
+    fn name(&self) -> Vec<u8> {
+        let count = self.names.len() as u16;
+        let mut w = Writer::default();
+        w.u16(0);
+        w.u16(count);
+        w.u16(6 + 12 * count);
+
+        let mut strings = Vec::new();
+        for (name_id, string) in &self.names {
+            let string: Vec<u8> = string.encode_utf16().flat_map(u16::to_be_bytes).collect();
+            // Windows, Unicode BMP, English (United States).
+            w.u16(3);
+            w.u16(1);
+            w.u16(0x409);
+            w.u16(*name_id);
+            w.u16(string.len() as u16);
+            w.u16(strings.len() as u16);
+            strings.extend_from_slice(&string);
+        }
+
+        w.bytes(&strings);
+        w.data
+    }
+
+    fn post(&self) -> Vec<u8> {
+        let mut w = Writer::default();
+        // Version 3.0, since the glyph names are stored in the CFF table.
+        w.u32(0x0003_0000);
+        w.i32((self.italic_angle * 65536.0).round() as i32);
+        w.i16(self.underline_position);
+        w.i16(self.underline_thickness);
+        w.u32(u32::from(self.is_fixed_pitch));
+        // The memory usage hints.
+        for _ in 0..4 {
+            w.u32(0);
+        }
+        w.data
+    }
+}
+
+fn weight_class(weight: &str) -> u16 {
+    let weight = weight.to_ascii_lowercase().replace([' ', '-'], "");
+    // Prefixed weights must be checked first.
+    let weights = [
+        ("extralight", 200),
+        ("ultralight", 200),
+        ("semibold", 600),
+        ("demibold", 600),
+        ("extrabold", 800),
+        ("ultrabold", 800),
+        ("thin", 100),
+        ("light", 300),
+        ("medium", 500),
+        ("demi", 600),
+        ("bold", 700),
+        ("heavy", 800),
+        ("black", 900),
+    ];
+
+    weights
+        .iter()
+        .find(|(name, _)| weight.contains(name))
+        .map_or(400, |(_, class)| *class)
+}
+
+// Parses the first number of a version string, like `001.002` or `Version 1.2`, as a 16.16 fixed number.
+fn parse_revision(version: &str) -> i32 {
+    let number: String = version
+        .chars()
+        .skip_while(|c| !c.is_ascii_digit())
+        .take_while(|c| c.is_ascii_digit() || *c == '.')
+        .collect();
+    let revision = number.parse::<f32>().unwrap_or(1.0);
+    (revision * 65536.0).round() as i32
+}
+
+fn build_names(
+    font_name: &str,
+    info: &type1::FontInfo,
+    weight_class: u16,
+    is_italic: bool,
+) -> Vec<(u16, String)> {
+    // PostScript names consist of printable ASCII characters without delimiters.
+    let postscript_name: String = font_name
+        .chars()
+        .filter(|c| matches!(c, '!'..='~') && !"[](){}<>/%".contains(*c))
+        .take(63)
+        .collect();
+    let family = info
+        .family_name
+        .clone()
+        .unwrap_or_else(|| postscript_name.clone());
+    let subfamily = match (weight_class >= 700, is_italic) {
+        (false, false) => "Regular",
+        (true, false) => "Bold",
+        (false, true) => "Italic",
+        (true, true) => "Bold Italic",
+    };
+    let full_name = info
+        .full_name
+        .clone()
+        .unwrap_or_else(|| format!("{} {}", family, subfamily));
+    let version = info.version.as_deref().unwrap_or("1.000");
+
+    let mut names = Vec::new();
+    if let Some(copyright) = info.copyright.as_ref().or(info.notice.as_ref()) {
+        names.push((0, copyright.clone()));
+    }
+    names.push((1, family));
+    names.push((2, subfamily.to_string()));
+    names.push((3, format!("{};{}", version, postscript_name)));
+    names.push((4, full_name));
+    if version.starts_with("Version ") {
+        names.push((5, version.to_string()));
+    } else {
+        names.push((5, format!("Version {}", version)));
+    }
+    names.push((6, postscript_name));
+    names
+}
+
+fn build_cmap(char_map: &BTreeMap<u32, u16>, is_symbolic: bool) -> Vec<u8> {
+    // 0xFFFF is reserved for the last segment of format 4.
+    let bmp: Vec<(u32, u16)> = char_map
+        .range(..0xFFFF)
+        .map(|(c, glyph_id)| (*c, *glyph_id))
+        .collect();
+    let all: Vec<(u32, u16)> = char_map
+        .iter()
+        .map(|(c, glyph_id)| (*c, *glyph_id))
+        .collect();
+
+    let mut subtables = vec![build_cmap_format_4(&bmp)];
+    let mut records: Vec<(u16, u16, usize)> = Vec::new();
+    if is_symbolic {
+        records.push((3, 0, 0));
+    } else {
+        records.push((0, 3, 0));
+        records.push((3, 1, 0));
+        if all.len() != bmp.len() {
+            subtables.push(build_cmap_format_12(&all));
+            records.push((0, 4, 1));
+            records.push((3, 10, 1));
+            records.sort_unstable();
+        }
+    }
+
+    let mut w = Writer::default();
+    w.u16(0);
+    w.u16(records.len() as u16);
+    let mut offsets = Vec::new();
+    let mut offset = 4 + 8 * records.len();
+    for subtable in &subtables {
+        offsets.push(offset as u32);
+        offset += subtable.len();
+    }
+    for (platform_id, encoding_id, subtable) in records {
+        w.u16(platform_id);
+        w.u16(encoding_id);
+        w.u32(offsets[subtable]);
+    }
+    for subtable in &subtables {
+        w.bytes(subtable);
+    }
+    w.data
+}
+
+// Groups consecutive characters that map to consecutive glyphs.
+fn cmap_groups(chars: &[(u32, u16)]) -> Vec<(u32, u32, u16)> {
+    let mut groups: Vec<(u32, u32, u16)> = Vec::new();
+    for (c, glyph_id) in chars {
+        match groups.last_mut() {
+            Some((start, end, start_glyph_id))
+                if *c == *end + 1
+                    && u32::from(*glyph_id) == u32::from(*start_glyph_id) + (*c - *start) =>
+            {
+                *end = *c;
+            }
+            _ => groups.push((*c, *c, *glyph_id)),
+        }
+    }
+    groups
+}
+
+fn build_cmap_format_4(chars: &[(u32, u16)]) -> Vec<u8> {
+    let mut segments: Vec<(u16, u16, u16)> = cmap_groups(chars)
+        .into_iter()
+        .map(|(start, end, glyph_id)| {
+            let start = start as u16;
+            (start, end as u16, glyph_id.wrapping_sub(start))
+        })
+        .collect();
+    segments.push((0xFFFF, 0xFFFF, 1));
+
+    let seg_count = segments.len() as u16;
+    let entry_selector = 15 - seg_count.leading_zeros() as u16;
+    let search_range = 2 << entry_selector;
+
+    let mut w = Writer::default();
+    w.u16(4);
+    w.u16(16 + 8 * seg_count);
+    // The language.
+    w.u16(0);
+    w.u16(seg_count * 2);
+    w.u16(search_range);
+    w.u16(entry_selector);
+    w.u16(seg_count * 2 - search_range);
+    for (_, end, _) in &segments {
+        w.u16(*end);
+    }
+    // Reserved padding.
+    w.u16(0);
+    for (start, _, _) in &segments {
+        w.u16(*start);
+    }
+    for (_, _, delta) in &segments {
+        w.u16(*delta);
+    }
+    // The range offsets aren't used, since all glyphs are mapped by deltas.
+    for _ in &segments {
+        w.u16(0);
+    }
+    w.data
+}
+
+fn build_cmap_format_12(chars: &[(u32, u16)]) -> Vec<u8> {
+    let groups = cmap_groups(chars);
+
+    let mut w = Writer::default();
+    w.u16(12);
+    w.u16(0);
+    w.u32(16 + 12 * groups.len() as u32);
+    // The language.
+    w.u32(0);
+    w.u32(groups.len() as u32);
+    for (start, end, glyph_id) in groups {
+        w.u32(start);
+        w.u32(end);
+        w.u32(u32::from(glyph_id));
+    }
+    w.data
+}
+
+fn write_font(sfnt_version: &[u8; 4], tables: &[(&[u8; 4], Vec<u8>)]) -> Vec<u8> {
+    let num_tables = tables.len() as u16;
+    let entry_selector = 15 - num_tables.leading_zeros() as u16;
+    let search_range = 16 << entry_selector;
+
+    let mut w = Writer::default();
+    w.bytes(sfnt_version);
+    w.u16(num_tables);
+    w.u16(search_range);
+    w.u16(entry_selector);
+    w.u16(num_tables * 16 - search_range);
+
+    let mut offset = 12 + 16 * tables.len();
+    let mut head_offset = 0;
+    for (tag, data) in tables {
+        if *tag == b"head" {
+            head_offset = offset;
+        }
+
+        w.bytes(*tag);
+        w.u32(checksum(data));
+        w.u32(offset as u32);
+        w.u32(data.len() as u32);
+        offset += (data.len() + 3) & !3;
+    }
+
+    for (_, data) in tables {
+        w.bytes(data);
+        while w.data.len() % 4 != 0 {
+            w.data.push(0);
+        }
+    }
+
+    let adjustment = 0xB1B0_AFBAu32.wrapping_sub(checksum(&w.data));
+    w.data[head_offset + 8..head_offset + 12].copy_from_slice(&adjustment.to_be_bytes());
+    w.data
+}
+
+fn checksum(data: &[u8]) -> u32 {
+    data.chunks(4).fold(0u32, |sum, chunk| {
+        let mut word = [0; 4];
+        word[..chunk.len()].copy_from_slice(chunk);
+        sum.wrapping_add(u32::from_be_bytes(word))
+    })
+}
+
+#[derive(Default)]
+struct Writer {
+    data: Vec<u8>,
+}
+
+impl Writer {
+    fn bytes(&mut self, bytes: &[u8]) {
+        self.data.extend_from_slice(bytes);
+    }
+
+    fn u16(&mut self, n: u16) {
+        self.bytes(&n.to_be_bytes());
+    }
+
+    fn i16(&mut self, n: i16) {
+        self.bytes(&n.to_be_bytes());
+    }
+
+    fn u32(&mut self, n: u32) {
+        self.bytes(&n.to_be_bytes());
+    }
+
+    fn i32(&mut self, n: i32) {
+        self.bytes(&n.to_be_bytes());
+    }
+
+    fn u64(&mut self, n: u64) {
+        self.bytes(&n.to_be_bytes());
+    }
+
+    fn rect(&mut self, rect: Rect) {
+        self.i16(rect.x_min);
+        self.i16(rect.y_min);
+        self.i16(rect.x_max);
+        self.i16(rect.y_max);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{build_cmap, checksum, from_cff, truetype_from_cff};
+    use crate::asm::assemble;
+    use crate::disasm::CharStringKind;
+    use crate::{cff, type1};
+    use std::collections::BTreeMap;
+
+    fn build_font() -> Vec<u8> {
+        let type2 = |text| assemble(text, CharStringKind::Type2).unwrap();
+        cff::FontBuilder::new("Test-Bold")
+            .glyph(".notdef", &type2("endchar"))
+            .glyph("space", &type2("-250 endchar"))
+            .glyph(
+                "A",
+                &type2("0 0 rmoveto 300 700 rlineto 300 -700 rlineto endchar"),
+            )
+            .glyph(
+                "H",
+                &type2("20 0 rmoveto 700 vlineto 50 hlineto -700 vlineto endchar"),
+            )
+            .glyph("x", &type2("10 0 rmoveto 500 500 rlineto endchar"))
+            .font_info(type1::FontInfo {
+                notice: Some("Test (c) font".to_string()),
+                full_name: Some("Test Bold".to_string()),
+                family_name: Some("Test".to_string()),
+                weight: Some("Bold".to_string()),
+                italic_angle: -12.0,
+                ..type1::FontInfo::default()
+            })
+            .default_width(500.0)
+            .nominal_width(600.0)
+            .build()
+    }
+
+    fn u16_at(data: &[u8], offset: usize) -> u16 {
+        u16::from_be_bytes([data[offset], data[offset + 1]])
+    }
+
+    fn u32_at(data: &[u8], offset: usize) -> u32 {
+        u32::from_be_bytes([
+            data[offset],
+            data[offset + 1],
+            data[offset + 2],
+            data[offset + 3],
+        ])
+    }
+
+    fn tables(font: &[u8]) -> BTreeMap<[u8; 4], &[u8]> {
+        (0..usize::from(u16_at(font, 4)))
+            .map(|i| {
+                let record = 12 + 16 * i;
+                let tag = [
+                    font[record],
+                    font[record + 1],
+                    font[record + 2],
+                    font[record + 3],
+                ];
+                let offset = u32_at(font, record + 8) as usize;
+                let length = u32_at(font, record + 12) as usize;
+                let data = &font[offset..offset + length];
+                if &tag != b"head" {
+                    assert_eq!(checksum(data), u32_at(font, record + 4));
+                }
+                (tag, data)
+            })
+            .collect()
+    }
+
+    // Looks up a character in the first subtable of a `cmap`, which is in format 4.
+    fn lookup(cmap: &[u8], c: char) -> u16 {
+        let subtable = &cmap[u32_at(cmap, 8) as usize..];
+        assert_eq!(u16_at(subtable, 0), 4);
+        let seg_count = usize::from(u16_at(subtable, 6) / 2);
+        let c = c as u16;
+        for i in 0..seg_count {
+            let end = u16_at(subtable, 14 + 2 * i);
+            let start = u16_at(subtable, 16 + 2 * (seg_count + i));
+            if (start..=end).contains(&c) {
+                let delta = u16_at(subtable, 16 + 2 * (2 * seg_count + i));
+                return c.wrapping_add(delta);
+            }
+        }
+        0
+    }
+
+    #[test]
+    fn convert() {
+        let data = build_font();
+        let font = from_cff(&cff::Table::parse(&data).unwrap()).unwrap();
+        assert_eq!(&font[..4], b"OTTO");
+        assert_eq!(checksum(&font), 0xB1B0_AFBA);
+
+        let tables = tables(&font);
+        let tags: Vec<&[u8]> = tables.keys().map(|tag| &tag[..]).collect();
+        assert_eq!(
+            tags,
+            [b"CFF ", b"OS/2", b"cmap", b"head", b"hhea", b"hmtx", b"maxp", b"name", b"post"]
+        );
+
+        let cff = cff::Table::parse(tables[b"CFF "]).unwrap();
+        assert_eq!(cff.number_of_glyphs(), 5);
+        assert_eq!(cff.name(), Some("Test-Bold"));
+
+        let head = tables[b"head"];
+        assert_eq!(u16_at(head, 18), 1000);
+        // xMin, yMin, xMax and yMax.
+        assert_eq!(&head[36..44], &[0, 0, 0, 0, 0x02, 0x58, 0x02, 0xBC]);
+        // Bold and italic.
+        assert_eq!(u16_at(head, 44), 0b11);
+
+        assert_eq!(u16_at(tables[b"maxp"], 4), 5);
+        let hhea = tables[b"hhea"];
+        assert_eq!(u16_at(hhea, 4), 700);
+        assert_eq!(u16_at(hhea, 34), 5);
+
+        let hmtx = tables[b"hmtx"];
+        let metrics: Vec<(u16, u16)> = hmtx
+            .chunks(4)
+            .map(|chunk| (u16_at(chunk, 0), u16_at(chunk, 2)))
+            .collect();
+        assert_eq!(
+            metrics,
+            [(500, 0), (350, 0), (500, 0), (500, 20), (500, 10)]
+        );
+
+        let cmap = tables[b"cmap"];
+        assert_eq!(u16_at(cmap, 2), 2);
+        assert_eq!(lookup(cmap, ' '), 1);
+        assert_eq!(lookup(cmap, 'A'), 2);
+        assert_eq!(lookup(cmap, 'H'), 3);
+        assert_eq!(lookup(cmap, 'x'), 4);
+        assert_eq!(lookup(cmap, 'B'), 0);
+
+        let os2 = tables[b"OS/2"];
+        assert_eq!(os2.len(), 96);
+        // Weight class and selection flags.
+        assert_eq!(u16_at(os2, 4), 700);
+        assert_eq!(u16_at(os2, 62), 0x21);
+        // First and last character.
+        assert_eq!(u16_at(os2, 64), 0x20);
+        assert_eq!(u16_at(os2, 66), 0x78);
+        // x-height and cap height.
+        assert_eq!(u16_at(os2, 86), 500);
+        assert_eq!(u16_at(os2, 88), 700);
+
+        let name = tables[b"name"];
+        let strings = &name[usize::from(u16_at(name, 4))..];
+        let names: Vec<(u16, String)> = (0..usize::from(u16_at(name, 2)))
+            .map(|i| {
+                let record = &name[6 + 12 * i..];
+                let string =
+                    &strings[usize::from(u16_at(record, 10))..][..usize::from(u16_at(record, 8))];
+                let units: Vec<u16> = string.chunks(2).map(|c| u16_at(c, 0)).collect();
+                (u16_at(record, 6), String::from_utf16(&units).unwrap())
+            })
+            .collect();
+        assert_eq!(
+            names,
+            [
+                (0, "Test (c) font".to_string()),
+                (1, "Test".to_string()),
+                (2, "Bold Italic".to_string()),
+                (3, "1.000;Test-Bold".to_string()),
+                (4, "Test Bold".to_string()),
+                (5, "Version 1.000".to_string()),
+                (6, "Test-Bold".to_string()),
+            ]
+        );
+
+        let post = tables[b"post"];
+        assert_eq!(u32_at(post, 0), 0x0003_0000);
+        assert_eq!(u32_at(post, 4) as i32, -12 * 65536);
+    }
+
+    #[test]
+    fn convert_to_truetype() {
+        let data = build_font();
+        let font = truetype_from_cff(&cff::Table::parse(&data).unwrap(), 0.5).unwrap();
+        assert_eq!(&font[..4], &[0, 1, 0, 0]);
+        assert_eq!(checksum(&font), 0xB1B0_AFBA);
+
+        let tables = tables(&font);
+        let tags: Vec<&[u8]> = tables.keys().map(|tag| &tag[..]).collect();
+        assert_eq!(
+            tags,
+            [
+                b"OS/2", b"cmap", b"glyf", b"head", b"hhea", b"hmtx", b"loca", b"maxp", b"name",
+                b"post"
+            ]
+        );
+
+        // Short offsets.
+        assert_eq!(u16_at(tables[b"head"], 50), 0);
+        let loca: Vec<u16> = tables[b"loca"].chunks(2).map(|c| u16_at(c, 0)).collect();
+        assert_eq!(loca.len(), 6);
+        assert_eq!(usize::from(loca[5]) * 2, tables[b"glyf"].len());
+        // .notdef and space have no outline.
+        assert_eq!((loca[0], loca[1], loca[2]), (0, 0, 0));
+
+        let maxp = tables[b"maxp"];
+        assert_eq!(maxp.len(), 32);
+        assert_eq!(u32_at(maxp, 0), 0x0001_0000);
+        // The glyph count, and the points and contours of H.
+        assert_eq!(
+            (u16_at(maxp, 4), u16_at(maxp, 6), u16_at(maxp, 8)),
+            (5, 4, 1)
+        );
+
+        let hmtx = tables[b"hmtx"];
+        assert_eq!((u16_at(hmtx, 12), u16_at(hmtx, 14)), (500, 20));
+        // The minimum x of H.
+        let h = usize::from(loca[3]) * 2;
+        assert_eq!(&tables[b"glyf"][h + 2..h + 4], &[0, 20]);
+    }
+
+    #[test]
+    fn cmap_with_supplementary_characters() {
+        let char_map: BTreeMap<u32, u16> =
+            [(0x41, 1), (0x42, 2), (0x43, 3), (0x1F600, 4), (0x1F601, 5)]
+                .iter()
+                .copied()
+                .collect();
+        let cmap = build_cmap(&char_map, false);
+
+        let records: Vec<(u16, u16, u32)> = (0..usize::from(u16_at(&cmap, 2)))
+            .map(|i| {
+                let record = 4 + 8 * i;
+                (
+                    u16_at(&cmap, record),
+                    u16_at(&cmap, record + 2),
+                    u32_at(&cmap, record + 4),
+                )
+            })
+            .collect();
+        assert_eq!(records, [(0, 3, 36), (0, 4, 68), (3, 1, 36), (3, 10, 68)]);
+
+        // A single segment for A to C besides the final one.
+        assert_eq!(lookup(&cmap, 'B'), 2);
+        assert_eq!(u16_at(&cmap, 36 + 6), 4);
+
+        let format_12 = &cmap[68..];
+        assert_eq!(u16_at(format_12, 0), 12);
+        assert_eq!(u32_at(format_12, 12), 2);
+        assert_eq!(
+            &format_12[16..],
+            &[0, 0, 0, 0x41, 0, 0, 0, 0x43, 0, 0, 0, 1, 0, 1, 0xF6, 0, 0, 1, 0xF6, 1, 0, 0, 0, 4,]
+        );
+    }
+}
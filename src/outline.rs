@@ -0,0 +1,1061 @@
+//! Owned glyph outlines and composable [`OutlineBuilder`] adapters.
+//!
+//! The adapters wrap another builder and can be nested, e.g. to transform
+//! an outline by [`Table::matrix`](crate::cff::Table::matrix), flatten it
+//! and record the result:
+//!
+//! ```
+//! use pdf_font_parser::outline::{FlatteningBuilder, PathRecorder, TransformBuilder};
+//! use pdf_font_parser::{Matrix, OutlineBuilder};
+//!
+//! let flip = Matrix { sx: 0.01, ky: 0.0, kx: 0.0, sy: -0.01, tx: 0.0, ty: 10.0 };
+//! let mut builder = TransformBuilder::new(flip, FlatteningBuilder::new(0.1, PathRecorder::new()));
+//! builder.move_to(0.0, 0.0);
+//! builder.line_to(100.0, 0.0);
+//! builder.close();
+//!
+//! let path = builder.into_inner().into_inner();
+//! assert_eq!(path.segments().len(), 3);
+//! ```
+
+use crate::cff::{self, CFFError};
+use crate::type1;
+use crate::{Font, GlyphId, Matrix, OutlineBuilder, RectF};
+
+/// A builder that transforms all points by a matrix before passing them on.
+#[derive(Clone, Debug)]
+pub struct TransformBuilder<B> {
+    matrix: Matrix,
+    builder: B,
+}
+
+impl<B: OutlineBuilder> TransformBuilder<B> {
+    /// Creates a new transforming builder.
+    pub fn new(matrix: Matrix, builder: B) -> Self {
+        TransformBuilder { matrix, builder }
+    }
+
+    /// Returns the wrapped builder.
+    pub fn into_inner(self) -> B {
+        self.builder
+    }
+}
+
+impl<B: OutlineBuilder> OutlineBuilder for TransformBuilder<B> {
+    fn move_to(&mut self, x: f32, y: f32) {
+        let (x, y) = self.matrix.apply(x, y);
+        self.builder.move_to(x, y);
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        let (x, y) = self.matrix.apply(x, y);
+        self.builder.line_to(x, y);
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        let (x1, y1) = self.matrix.apply(x1, y1);
+        let (x, y) = self.matrix.apply(x, y);
+        self.builder.quad_to(x1, y1, x, y);
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        let (x1, y1) = self.matrix.apply(x1, y1);
+        let (x2, y2) = self.matrix.apply(x2, y2);
+        let (x, y) = self.matrix.apply(x, y);
+        self.builder.curve_to(x1, y1, x2, y2, x, y);
+    }
+
+    fn close(&mut self) {
+        self.builder.close();
+    }
+}
+
+/// Tracks the current point and the start of the current contour.
+#[derive(Clone, Copy, Default, Debug)]
+struct Cursor {
+    start: (f32, f32),
+    current: (f32, f32),
+}
+
+impl Cursor {
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.start = (x, y);
+        self.current = (x, y);
+    }
+
+    fn close(&mut self) {
+        self.current = self.start;
+    }
+}
+
+/// A builder that computes the exact bounding box of an outline.
+///
+/// Unlike the bounding boxes returned by the tables, which include
+/// the control points, this one only includes the extrema of curves.
+#[derive(Clone, Debug)]
+pub struct BoundsBuilder {
+    bbox: RectF,
+    cursor: Cursor,
+}
+
+impl Default for BoundsBuilder {
+    fn default() -> Self {
+        BoundsBuilder {
+            bbox: RectF::new(),
+            cursor: Cursor::default(),
+        }
+    }
+}
+
+impl BoundsBuilder {
+    /// Creates a new bounds builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the bounding box, or `None` if the outline is empty.
+    pub fn bbox(&self) -> Option<RectF> {
+        if self.bbox.is_default() {
+            None
+        } else {
+            Some(self.bbox)
+        }
+    }
+}
+
+impl OutlineBuilder for BoundsBuilder {
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.bbox.extend_by(x, y);
+        self.cursor.move_to(x, y);
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        self.bbox.extend_by(x, y);
+        self.cursor.current = (x, y);
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        let (x0, y0) = self.cursor.current;
+        for t in quad_extrema(x0, x1, x).chain(quad_extrema(y0, y1, y)) {
+            self.bbox
+                .extend_by(quad_at(x0, x1, x, t), quad_at(y0, y1, y, t));
+        }
+
+        self.bbox.extend_by(x, y);
+        self.cursor.current = (x, y);
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        let (x0, y0) = self.cursor.current;
+        for t in cubic_extrema(x0, x1, x2, x).chain(cubic_extrema(y0, y1, y2, y)) {
+            self.bbox
+                .extend_by(cubic_at(x0, x1, x2, x, t), cubic_at(y0, y1, y2, y, t));
+        }
+
+        self.bbox.extend_by(x, y);
+        self.cursor.current = (x, y);
+    }
+
+    fn close(&mut self) {
+        self.cursor.close();
+    }
+}
+
+/// A segment of a recorded path.
+#[allow(missing_docs)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum PathSegment {
+    MoveTo(f32, f32),
+    LineTo(f32, f32),
+    QuadTo(f32, f32, f32, f32),
+    CurveTo(f32, f32, f32, f32, f32, f32),
+    Close,
+}
+
+/// A builder that records the path segments, so that they can be
+/// inspected or replayed later.
+#[derive(Clone, Default, Debug)]
+pub struct PathRecorder {
+    segments: Vec<PathSegment>,
+}
+
+impl PathRecorder {
+    /// Creates a new, empty recorder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the recorded segments.
+    pub fn segments(&self) -> &[PathSegment] {
+        &self.segments
+    }
+
+    /// Returns the recorded segments by value.
+    pub fn into_segments(self) -> Vec<PathSegment> {
+        self.segments
+    }
+
+    /// Passes the recorded segments to another builder.
+    pub fn replay(&self, builder: &mut dyn OutlineBuilder) {
+        for segment in &self.segments {
+            match *segment {
+                PathSegment::MoveTo(x, y) => builder.move_to(x, y),
+                PathSegment::LineTo(x, y) => builder.line_to(x, y),
+                PathSegment::QuadTo(x1, y1, x, y) => builder.quad_to(x1, y1, x, y),
+                PathSegment::CurveTo(x1, y1, x2, y2, x, y) => {
+                    builder.curve_to(x1, y1, x2, y2, x, y)
+                }
+                PathSegment::Close => builder.close(),
+            }
+        }
+    }
+}
+
+impl OutlineBuilder for PathRecorder {
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.segments.push(PathSegment::MoveTo(x, y));
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        self.segments.push(PathSegment::LineTo(x, y));
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        self.segments.push(PathSegment::QuadTo(x1, y1, x, y));
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        self.segments
+            .push(PathSegment::CurveTo(x1, y1, x2, y2, x, y));
+    }
+
+    fn close(&mut self) {
+        self.segments.push(PathSegment::Close);
+    }
+}
+
+// Limits the number of lines a single curve is split into.
+const MAX_FLATTENING_STEPS: f32 = 1000.0;
+
+/// A builder that approximates curves by lines.
+///
+/// The lines don't deviate from the curves by more than the tolerance,
+/// which is given in the units of the incoming points.
+#[derive(Clone, Debug)]
+pub struct FlatteningBuilder<B> {
+    tolerance: f32,
+    cursor: Cursor,
+    builder: B,
+}
+
+impl<B: OutlineBuilder> FlatteningBuilder<B> {
+    /// Creates a new flattening builder.
+    pub fn new(tolerance: f32, builder: B) -> Self {
+        FlatteningBuilder {
+            tolerance,
+            cursor: Cursor::default(),
+            builder,
+        }
+    }
+
+    /// Returns the wrapped builder.
+    pub fn into_inner(self) -> B {
+        self.builder
+    }
+
+    // Returns the number of lines needed for a curve with the given
+    // maximum second difference of its control points (Wang's formula,
+    // where `factor` depends on the degree).
+    fn steps(&self, dd: f32, factor: f32) -> u32 {
+        let steps = (factor * dd / self.tolerance).sqrt().ceil();
+        if steps.is_nan() {
+            1
+        } else {
+            steps.clamp(1.0, MAX_FLATTENING_STEPS) as u32
+        }
+    }
+}
+
+impl<B: OutlineBuilder> OutlineBuilder for FlatteningBuilder<B> {
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.cursor.move_to(x, y);
+        self.builder.move_to(x, y);
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        self.cursor.current = (x, y);
+        self.builder.line_to(x, y);
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        let (x0, y0) = self.cursor.current;
+        let dd = (x0 - 2.0 * x1 + x).hypot(y0 - 2.0 * y1 + y);
+        let steps = self.steps(dd, 0.25);
+        for i in 1..steps {
+            let t = i as f32 / steps as f32;
+            self.builder
+                .line_to(quad_at(x0, x1, x, t), quad_at(y0, y1, y, t));
+        }
+
+        self.line_to(x, y);
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        let (x0, y0) = self.cursor.current;
+        let dd1 = (x0 - 2.0 * x1 + x2).hypot(y0 - 2.0 * y1 + y2);
+        let dd2 = (x1 - 2.0 * x2 + x).hypot(y1 - 2.0 * y2 + y);
+        let steps = self.steps(dd1.max(dd2), 0.75);
+        for i in 1..steps {
+            let t = i as f32 / steps as f32;
+            self.builder
+                .line_to(cubic_at(x0, x1, x2, x, t), cubic_at(y0, y1, y2, y, t));
+        }
+
+        self.line_to(x, y);
+    }
+
+    fn close(&mut self) {
+        self.cursor.close();
+        self.builder.close();
+    }
+}
+
+// Limits the number of quadratic curves a single cubic curve is split into.
+const MAX_QUADRATIC_STEPS: f32 = 100.0;
+
+/// A builder that approximates cubic curves by quadratic ones, as used by TrueType.
+///
+/// Each cubic curve is split into as many pieces as needed for the quadratic
+/// curves not to deviate from it by more than the tolerance, which is given
+/// in the units of the incoming points.
+#[derive(Clone, Debug)]
+pub struct QuadraticBuilder<B> {
+    tolerance: f32,
+    cursor: Cursor,
+    builder: B,
+}
+
+impl<B: OutlineBuilder> QuadraticBuilder<B> {
+    /// Creates a new quadratic builder.
+    pub fn new(tolerance: f32, builder: B) -> Self {
+        QuadraticBuilder {
+            tolerance,
+            cursor: Cursor::default(),
+            builder,
+        }
+    }
+
+    /// Returns the wrapped builder.
+    pub fn into_inner(self) -> B {
+        self.builder
+    }
+}
+
+impl<B: OutlineBuilder> OutlineBuilder for QuadraticBuilder<B> {
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.cursor.move_to(x, y);
+        self.builder.move_to(x, y);
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        self.cursor.current = (x, y);
+        self.builder.line_to(x, y);
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        self.cursor.current = (x, y);
+        self.builder.quad_to(x1, y1, x, y);
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        let (x0, y0) = self.cursor.current;
+
+        // A quadratic curve with the control point (3 * (p1 + p2) - p0 - p3) / 4
+        // deviates by at most sqrt(3) / 36 times the length of the third difference,
+        // which shrinks with the cube of the number of pieces.
+        let ddd = (x - 3.0 * x2 + 3.0 * x1 - x0).hypot(y - 3.0 * y2 + 3.0 * y1 - y0);
+        let steps = (3f32.sqrt() / 36.0 * ddd / self.tolerance).cbrt().ceil();
+        let steps = if steps.is_nan() {
+            1
+        } else {
+            steps.clamp(1.0, MAX_QUADRATIC_STEPS) as u32
+        };
+
+        // The derivative divided by 3 at a parameter.
+        let tangent = |t: f32| {
+            let mt = 1.0 - t;
+            let d = |p0: f32, p1: f32, p2: f32, p3: f32| {
+                mt * mt * (p1 - p0) + 2.0 * mt * t * (p2 - p1) + t * t * (p3 - p2)
+            };
+            (d(x0, x1, x2, x), d(y0, y1, y2, y))
+        };
+
+        let step = 1.0 / steps as f32;
+        let mut start = (x0, y0);
+        let mut start_tangent = tangent(0.0);
+        for i in 1..=steps {
+            let t = i as f32 * step;
+            let end = if i == steps {
+                (x, y)
+            } else {
+                (cubic_at(x0, x1, x2, x, t), cubic_at(y0, y1, y2, y, t))
+            };
+            let end_tangent = tangent(t);
+
+            // The inner control points of the piece.
+            let c1x = start.0 + step * start_tangent.0;
+            let c1y = start.1 + step * start_tangent.1;
+            let c2x = end.0 - step * end_tangent.0;
+            let c2y = end.1 - step * end_tangent.1;
+
+            self.builder.quad_to(
+                (3.0 * (c1x + c2x) - start.0 - end.0) / 4.0,
+                (3.0 * (c1y + c2y) - start.1 - end.1) / 4.0,
+                end.0,
+                end.1,
+            );
+
+            start = end;
+            start_tangent = end_tangent;
+        }
+
+        self.cursor.current = (x, y);
+    }
+
+    fn close(&mut self) {
+        self.cursor.close();
+        self.builder.close();
+    }
+}
+
+fn quad_at(p0: f32, p1: f32, p2: f32, t: f32) -> f32 {
+    let mt = 1.0 - t;
+    mt * mt * p0 + 2.0 * mt * t * p1 + t * t * p2
+}
+
+fn cubic_at(p0: f32, p1: f32, p2: f32, p3: f32, t: f32) -> f32 {
+    let mt = 1.0 - t;
+    mt * mt * mt * p0 + 3.0 * mt * mt * t * p1 + 3.0 * mt * t * t * p2 + t * t * t * p3
+}
+
+// Returns the parameter in (0, 1) where the derivative of a quadratic
+// Bézier coordinate is zero, if any.
+fn quad_extrema(p0: f32, p1: f32, p2: f32) -> impl Iterator<Item = f32> {
+    let denom = p0 - 2.0 * p1 + p2;
+    let t = if denom != 0.0 {
+        Some((p0 - p1) / denom)
+    } else {
+        None
+    };
+
+    t.into_iter().filter(|t| *t > 0.0 && *t < 1.0)
+}
+
+// Returns the parameters in (0, 1) where the derivative of a cubic
+// Bézier coordinate is zero.
+fn cubic_extrema(p0: f32, p1: f32, p2: f32, p3: f32) -> impl Iterator<Item = f32> {
+    // The derivative divided by 3 is a*t^2 + b*t + c.
+    let a = -p0 + 3.0 * p1 - 3.0 * p2 + p3;
+    let b = 2.0 * (p0 - 2.0 * p1 + p2);
+    let c = p1 - p0;
+
+    let mut roots = [None, None];
+    if a.abs() < f32::EPSILON {
+        if b != 0.0 {
+            roots[0] = Some(-c / b);
+        }
+    } else {
+        let discriminant = b * b - 4.0 * a * c;
+        if discriminant >= 0.0 {
+            let sqrt = discriminant.sqrt();
+            roots[0] = Some((-b + sqrt) / (2.0 * a));
+            roots[1] = Some((-b - sqrt) / (2.0 * a));
+        }
+    }
+
+    let [r0, r1] = roots;
+    r0.into_iter().chain(r1).filter(|t| *t > 0.0 && *t < 1.0)
+}
+
+/// A fill rule that decides which points are inside an outline.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum FillRule {
+    /// Points with a non-zero winding number are inside.
+    NonZero,
+    /// Points with an odd winding number are inside.
+    EvenOdd,
+}
+
+/// The winding direction of a contour, with the y axis pointing up.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Direction {
+    /// Clockwise, as used by TrueType outer contours.
+    Clockwise,
+    /// Counter-clockwise, as used by PostScript outer contours.
+    CounterClockwise,
+}
+
+/// A segment of a contour, ending at its last point.
+#[allow(missing_docs)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Segment {
+    Line(f32, f32),
+    Quad(f32, f32, f32, f32),
+    Curve(f32, f32, f32, f32, f32, f32),
+}
+
+impl Segment {
+    /// Returns the end point of the segment.
+    pub fn end(&self) -> (f32, f32) {
+        match *self {
+            Segment::Line(x, y) | Segment::Quad(_, _, x, y) | Segment::Curve(_, _, _, _, x, y) => {
+                (x, y)
+            }
+        }
+    }
+
+    fn transform(&mut self, matrix: &Matrix) {
+        match self {
+            Segment::Line(x, y) => transform_point(matrix, x, y),
+            Segment::Quad(x1, y1, x, y) => {
+                transform_point(matrix, x1, y1);
+                transform_point(matrix, x, y);
+            }
+            Segment::Curve(x1, y1, x2, y2, x, y) => {
+                transform_point(matrix, x1, y1);
+                transform_point(matrix, x2, y2);
+                transform_point(matrix, x, y);
+            }
+        }
+    }
+
+    // Returns twice the signed area between the segment and the origin.
+    fn double_area(&self, from: (f32, f32)) -> f32 {
+        let p0 = from;
+        match *self {
+            Segment::Line(x, y) => cross(p0, (x, y)),
+            Segment::Quad(x1, y1, x, y) => {
+                let (p1, p2) = ((x1, y1), (x, y));
+                (2.0 * cross(p0, p1) + 2.0 * cross(p1, p2) + cross(p0, p2)) / 3.0
+            }
+            Segment::Curve(x1, y1, x2, y2, x, y) => {
+                let (p1, p2, p3) = ((x1, y1), (x2, y2), (x, y));
+                (6.0 * cross(p0, p1)
+                    + 3.0 * cross(p0, p2)
+                    + cross(p0, p3)
+                    + 3.0 * cross(p1, p2)
+                    + 3.0 * cross(p1, p3)
+                    + 6.0 * cross(p2, p3))
+                    / 10.0
+            }
+        }
+    }
+}
+
+/// A contour of an [`Outline`].
+///
+/// Contours are implicitly closed by a line from the last to the first
+/// point when computing areas or testing points.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Contour {
+    start: (f32, f32),
+    segments: Vec<Segment>,
+    closed: bool,
+}
+
+impl Contour {
+    /// Returns the start point.
+    pub fn start(&self) -> (f32, f32) {
+        self.start
+    }
+
+    /// Returns the segments.
+    pub fn segments(&self) -> &[Segment] {
+        &self.segments
+    }
+
+    /// Checks if the contour was explicitly closed.
+    pub fn is_closed(&self) -> bool {
+        self.closed
+    }
+
+    /// Returns the signed area, which is positive for
+    /// counter-clockwise contours.
+    pub fn signed_area(&self) -> f32 {
+        let mut from = self.start;
+        let mut area = 0.0;
+        for segment in &self.segments {
+            area += segment.double_area(from);
+            from = segment.end();
+        }
+
+        area += cross(from, self.start);
+        area / 2.0
+    }
+
+    /// Returns the winding direction, or `None` if the contour has no area.
+    pub fn direction(&self) -> Option<Direction> {
+        let area = self.signed_area();
+        if area > 0.0 {
+            Some(Direction::CounterClockwise)
+        } else if area < 0.0 {
+            Some(Direction::Clockwise)
+        } else {
+            None
+        }
+    }
+
+    /// Reverses the direction of the contour without changing its shape.
+    pub fn reverse(&mut self) {
+        let mut reversed = Vec::with_capacity(self.segments.len());
+        let mut from = self.start;
+        for segment in &self.segments {
+            let (x, y) = from;
+            reversed.push(match *segment {
+                Segment::Line(..) => Segment::Line(x, y),
+                Segment::Quad(x1, y1, ..) => Segment::Quad(x1, y1, x, y),
+                Segment::Curve(x1, y1, x2, y2, ..) => Segment::Curve(x2, y2, x1, y1, x, y),
+            });
+            from = segment.end();
+        }
+
+        reversed.reverse();
+        self.start = from;
+        self.segments = reversed;
+    }
+
+    fn replay(&self, builder: &mut dyn OutlineBuilder) {
+        builder.move_to(self.start.0, self.start.1);
+        for segment in &self.segments {
+            match *segment {
+                Segment::Line(x, y) => builder.line_to(x, y),
+                Segment::Quad(x1, y1, x, y) => builder.quad_to(x1, y1, x, y),
+                Segment::Curve(x1, y1, x2, y2, x, y) => builder.curve_to(x1, y1, x2, y2, x, y),
+            }
+        }
+
+        if self.closed {
+            builder.close();
+        }
+    }
+}
+
+// The tolerance used to flatten curves for point-in-glyph tests.
+const CONTAINS_TOLERANCE: f32 = 0.01;
+
+/// An owned glyph outline.
+///
+/// Can be built from a glyph of either table, or by passing it
+/// to any function that takes an [`OutlineBuilder`].
+#[derive(Clone, Default, PartialEq, Debug)]
+pub struct Outline {
+    contours: Vec<Contour>,
+}
+
+impl Outline {
+    /// Creates a new, empty outline.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Outlines a glyph of a CFF font.
+    ///
+    /// Glyphs without an outline, like `space`, produce an empty outline.
+    pub fn from_cff(table: &cff::Table, glyph_id: GlyphId) -> Option<Self> {
+        let mut outline = Outline::new();
+        match table.outline(glyph_id, &mut outline) {
+            Ok(_) | Err(CFFError::ZeroBBox) => Some(outline),
+            Err(_) => None,
+        }
+    }
+
+    /// Outlines a glyph of a Type 1 font by its name.
+    pub fn from_type1(table: &type1::Table, name: &str) -> Option<Self> {
+        let mut outline = Outline::new();
+        table.outline(name, &mut outline)?;
+        Some(outline)
+    }
+
+    /// Outlines a glyph of a font.
+    pub fn from_font(font: &Font, glyph_id: GlyphId) -> Option<Self> {
+        let mut outline = Outline::new();
+        font.outline(glyph_id, &mut outline)?;
+        Some(outline)
+    }
+
+    /// Returns the contours.
+    pub fn contours(&self) -> &[Contour] {
+        &self.contours
+    }
+
+    /// Checks if the outline has no contours.
+    pub fn is_empty(&self) -> bool {
+        self.contours.is_empty()
+    }
+
+    /// Transforms all points by a matrix.
+    pub fn transform(&mut self, matrix: &Matrix) {
+        for contour in &mut self.contours {
+            let (x, y) = &mut contour.start;
+            transform_point(matrix, x, y);
+            for segment in &mut contour.segments {
+                segment.transform(matrix);
+            }
+        }
+    }
+
+    /// Returns the exact bounding box, or `None` if the outline is empty.
+    pub fn bbox(&self) -> Option<RectF> {
+        let mut builder = BoundsBuilder::new();
+        self.replay(&mut builder);
+        builder.bbox()
+    }
+
+    /// Returns the sum of the signed areas of all contours.
+    pub fn signed_area(&self) -> f32 {
+        self.contours.iter().map(Contour::signed_area).sum()
+    }
+
+    /// Reverses the direction of all contours.
+    pub fn reverse(&mut self) {
+        for contour in &mut self.contours {
+            contour.reverse();
+        }
+    }
+
+    /// Checks if a point is inside the outline.
+    ///
+    /// Curves are approximated with a tolerance of 0.01 units.
+    pub fn contains(&self, x: f32, y: f32, fill_rule: FillRule) -> bool {
+        let mut builder = FlatteningBuilder::new(CONTAINS_TOLERANCE, Outline::new());
+        self.replay(&mut builder);
+
+        let mut winding = 0;
+        for contour in builder.into_inner().contours {
+            let mut from = contour.start;
+            let ends = contour.segments.iter().map(Segment::end);
+            for to in ends.chain(core::iter::once(contour.start)) {
+                winding += crossing(from, to, x, y);
+                from = to;
+            }
+        }
+
+        match fill_rule {
+            FillRule::NonZero => winding != 0,
+            FillRule::EvenOdd => winding % 2 != 0,
+        }
+    }
+
+    /// Passes the outline to a builder.
+    pub fn replay(&self, builder: &mut dyn OutlineBuilder) {
+        for contour in &self.contours {
+            contour.replay(builder);
+        }
+    }
+
+    // Returns the contour that a segment should be appended to,
+    // starting a new one at the current point if needed.
+    fn current_contour(&mut self) -> &mut Contour {
+        let start = match self.contours.last() {
+            Some(contour) if !contour.closed => None,
+            Some(contour) => Some(contour.start),
+            None => Some((0.0, 0.0)),
+        };
+
+        if let Some(start) = start {
+            self.contours.push(Contour {
+                start,
+                segments: Vec::new(),
+                closed: false,
+            });
+        }
+
+        self.contours.last_mut().unwrap()
+    }
+}
+
+impl OutlineBuilder for Outline {
+    fn move_to(&mut self, x: f32, y: f32) {
+        // Drop a previous contour that consists only of a move.
+        if let Some(contour) = self.contours.last() {
+            if !contour.closed && contour.segments.is_empty() {
+                self.contours.pop();
+            }
+        }
+
+        self.contours.push(Contour {
+            start: (x, y),
+            segments: Vec::new(),
+            closed: false,
+        });
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        self.current_contour().segments.push(Segment::Line(x, y));
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        self.current_contour()
+            .segments
+            .push(Segment::Quad(x1, y1, x, y));
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        self.current_contour()
+            .segments
+            .push(Segment::Curve(x1, y1, x2, y2, x, y));
+    }
+
+    fn close(&mut self) {
+        if let Some(contour) = self.contours.last_mut() {
+            contour.closed = true;
+        }
+    }
+}
+
+fn transform_point(matrix: &Matrix, x: &mut f32, y: &mut f32) {
+    let (tx, ty) = matrix.apply(*x, *y);
+    *x = tx;
+    *y = ty;
+}
+
+fn cross(a: (f32, f32), b: (f32, f32)) -> f32 {
+    a.0 * b.1 - a.1 * b.0
+}
+
+// Returns the contribution of a line to the winding number of a point,
+// using a ray to the right of it.
+fn crossing(from: (f32, f32), to: (f32, f32), x: f32, y: f32) -> i32 {
+    let ((x0, y0), (x1, y1)) = (from, to);
+    if (y0 <= y) == (y1 <= y) {
+        return 0;
+    }
+
+    let side = (x1 - x0) * (y - y0) - (x - x0) * (y1 - y0);
+    if y1 > y0 && side > 0.0 {
+        1
+    } else if y1 < y0 && side < 0.0 {
+        -1
+    } else {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transform() {
+        let matrix = Matrix {
+            sx: 2.0,
+            ky: 0.0,
+            kx: 0.0,
+            sy: -2.0,
+            tx: 10.0,
+            ty: 20.0,
+        };
+
+        let mut builder = TransformBuilder::new(matrix, PathRecorder::new());
+        builder.move_to(1.0, 1.0);
+        builder.quad_to(2.0, 3.0, 4.0, 5.0);
+        builder.close();
+
+        let mut recorder = PathRecorder::new();
+        builder.into_inner().replay(&mut recorder);
+        assert_eq!(
+            recorder.into_segments(),
+            vec![
+                PathSegment::MoveTo(12.0, 18.0),
+                PathSegment::QuadTo(14.0, 14.0, 18.0, 10.0),
+                PathSegment::Close,
+            ]
+        );
+    }
+
+    #[test]
+    fn matrix() {
+        let scale = Matrix {
+            sx: 2.0,
+            ky: 0.0,
+            kx: 1.0,
+            sy: 4.0,
+            tx: 3.0,
+            ty: -1.0,
+        };
+        let combined = Matrix::default().multiply(&scale);
+        assert_eq!(combined.apply(1000.0, 1000.0), scale.apply(1.0, 1.0));
+        assert_eq!(scale.apply(1.0, 1.0), (6.0, 3.0));
+
+        let inverse = scale.invert().unwrap();
+        assert_eq!(scale.multiply(&inverse), Matrix::identity());
+        assert_eq!(inverse.apply(6.0, 3.0), (1.0, 1.0));
+
+        let singular = Matrix {
+            sy: 0.0,
+            kx: 0.0,
+            ..scale
+        };
+        assert!(singular.invert().is_none());
+    }
+
+    #[test]
+    fn exact_bounds() {
+        let mut builder = BoundsBuilder::new();
+        assert_eq!(builder.bbox(), None);
+
+        builder.move_to(0.0, 0.0);
+        builder.curve_to(0.0, 100.0, 100.0, 100.0, 100.0, 0.0);
+        builder.quad_to(50.0, -100.0, 0.0, 0.0);
+        builder.close();
+
+        let bbox = builder.bbox().unwrap();
+        assert_eq!(bbox.x_min, 0.0);
+        assert_eq!(bbox.x_max, 100.0);
+        assert_eq!(bbox.y_min, -50.0);
+        assert_eq!(bbox.y_max, 75.0);
+    }
+
+    #[test]
+    fn flatten() {
+        let mut builder = FlatteningBuilder::new(0.5, PathRecorder::new());
+        builder.move_to(0.0, 0.0);
+        builder.line_to(10.0, 0.0);
+        builder.curve_to(10.0, 100.0, 100.0, 100.0, 100.0, 0.0);
+        builder.close();
+
+        let segments = builder.into_inner().into_segments();
+        assert!(segments.len() > 10);
+        assert_eq!(segments[1], PathSegment::LineTo(10.0, 0.0));
+        assert_eq!(
+            segments[segments.len() - 2],
+            PathSegment::LineTo(100.0, 0.0)
+        );
+        assert!(segments[1..segments.len() - 1]
+            .iter()
+            .all(|s| matches!(s, PathSegment::LineTo(..))));
+    }
+
+    #[test]
+    fn quadratic() {
+        let mut builder = QuadraticBuilder::new(0.1, PathRecorder::new());
+        builder.move_to(0.0, 0.0);
+        builder.quad_to(50.0, 50.0, 100.0, 0.0);
+        // A cubic curve that is exactly quadratic isn't split.
+        builder.curve_to(
+            200.0 / 3.0,
+            -100.0 / 3.0,
+            100.0 / 3.0,
+            -100.0 / 3.0,
+            0.0,
+            0.0,
+        );
+        builder.close();
+
+        let segments = builder.into_inner().into_segments();
+        assert_eq!(segments.len(), 4);
+        assert_eq!(segments[1], PathSegment::QuadTo(50.0, 50.0, 100.0, 0.0));
+        match segments[2] {
+            PathSegment::QuadTo(x1, y1, x, y) => {
+                assert!((x1 - 50.0).abs() < 1e-4 && (y1 + 50.0).abs() < 1e-4);
+                assert_eq!((x, y), (0.0, 0.0));
+            }
+            segment => panic!("unexpected segment {:?}", segment),
+        }
+
+        // An S-shaped curve needs several pieces.
+        let (x0, y0, x1, y1, x2, y2, x3, y3) = (0.0, 0.0, 100.0, 300.0, 200.0, -300.0, 300.0, 0.0);
+        let mut builder = QuadraticBuilder::new(0.5, PathRecorder::new());
+        builder.move_to(x0, y0);
+        builder.curve_to(x1, y1, x2, y2, x3, y3);
+
+        let segments = builder.into_inner().into_segments();
+        assert!(segments.len() > 4);
+        let mut start = (x0, y0);
+        for (i, segment) in segments[1..].iter().enumerate() {
+            let (qx1, qy1, qx, qy) = match *segment {
+                PathSegment::QuadTo(x1, y1, x, y) => (x1, y1, x, y),
+                segment => panic!("unexpected segment {:?}", segment),
+            };
+
+            // Compare the midpoints of the pieces with the cubic curve.
+            let t = (i as f32 + 0.5) / (segments.len() - 1) as f32;
+            let mx = quad_at(start.0, qx1, qx, 0.5);
+            let my = quad_at(start.1, qy1, qy, 0.5);
+            let cx = cubic_at(x0, x1, x2, x3, t);
+            let cy = cubic_at(y0, y1, y2, y3, t);
+            assert!((mx - cx).hypot(my - cy) <= 0.5, "piece {}", i);
+            start = (qx, qy);
+        }
+        assert_eq!(start, (x3, y3));
+    }
+
+    fn square(builder: &mut dyn OutlineBuilder, x: f32, y: f32, size: f32) {
+        builder.move_to(x, y);
+        builder.line_to(x + size, y);
+        builder.line_to(x + size, y + size);
+        builder.line_to(x, y + size);
+        builder.close();
+    }
+
+    #[test]
+    fn outline_geometry() {
+        let mut outline = Outline::new();
+        square(&mut outline, 0.0, 0.0, 100.0);
+        outline.move_to(25.0, 25.0);
+        outline.quad_to(25.0, 75.0, 75.0, 75.0);
+        outline.curve_to(75.0, 50.0, 50.0, 25.0, 25.0, 25.0);
+        outline.close();
+
+        let contours = outline.contours();
+        assert_eq!(contours.len(), 2);
+        assert_eq!(contours[0].signed_area(), 10000.0);
+        assert_eq!(contours[0].direction(), Some(Direction::CounterClockwise));
+        assert_eq!(contours[1].direction(), Some(Direction::Clockwise));
+
+        // The inner contour winds the other way, so it's a hole for both rules.
+        assert!(outline.contains(10.0, 10.0, FillRule::NonZero));
+        assert!(!outline.contains(40.0, 60.0, FillRule::NonZero));
+        assert!(!outline.contains(40.0, 60.0, FillRule::EvenOdd));
+        assert!(!outline.contains(150.0, 50.0, FillRule::NonZero));
+
+        let mut reversed = outline.clone();
+        reversed.contours[1].reverse();
+        assert_eq!(
+            reversed.contours[1].signed_area(),
+            -contours[1].signed_area()
+        );
+        assert!(reversed.contains(40.0, 60.0, FillRule::NonZero));
+        assert!(!reversed.contains(40.0, 60.0, FillRule::EvenOdd));
+        reversed.contours[1].reverse();
+        assert_eq!(reversed, outline);
+
+        let mut recorder = PathRecorder::new();
+        outline.replay(&mut recorder);
+        assert_eq!(recorder.segments().len(), 9);
+        assert_eq!(recorder.segments()[5], PathSegment::MoveTo(25.0, 25.0));
+
+        let area = outline.signed_area();
+        outline.transform(&Matrix::default());
+        let bbox = outline.bbox().unwrap();
+        assert_eq!((bbox.x_min, bbox.y_min), (0.0, 0.0));
+        assert_eq!((bbox.x_max, bbox.y_max), (0.1, 0.1));
+        assert!((outline.signed_area() - area / 1e6).abs() < 1e-6);
+    }
+
+    #[test]
+    fn implicit_contours() {
+        let mut outline = Outline::new();
+        outline.move_to(5.0, 5.0);
+        square(&mut outline, 0.0, 0.0, 10.0);
+        outline.line_to(0.0, -10.0);
+        outline.line_to(10.0, -10.0);
+
+        let contours = outline.contours();
+        assert_eq!(contours.len(), 2);
+        assert_eq!(contours[1].start(), (0.0, 0.0));
+        assert!(!contours[1].is_closed());
+        assert_eq!(contours[1].signed_area(), 50.0);
+        assert!(Outline::new().bbox().is_none());
+    }
+}
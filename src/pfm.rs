@@ -0,0 +1,287 @@
+//! A Printer Font Metrics parser.
+//!
+//! PFM files accompany Type 1 fonts installed on Windows and store
+//! the metrics by character code in the font's Windows character set.
+//! See the Adobe Technical Note #5178.
+
+use std::collections::HashMap;
+
+// Offsets in the PFMHEADER structure.
+const VERSION: usize = 0;
+const SIZE: usize = 2;
+const COPYRIGHT: usize = 6;
+const ASCENT: usize = 74;
+const ITALIC: usize = 80;
+const WEIGHT: usize = 83;
+const CHAR_SET: usize = 85;
+const PITCH_AND_FAMILY: usize = 90;
+const AVG_WIDTH: usize = 91;
+const MAX_WIDTH: usize = 93;
+const FIRST_CHAR: usize = 95;
+const LAST_CHAR: usize = 96;
+const DEFAULT_CHAR: usize = 97;
+const BREAK_CHAR: usize = 98;
+const FACE: usize = 105;
+
+// Offsets in the PFMEXTENSION structure, which follows the header.
+const EXT_METRICS_OFFSET: usize = 119;
+const EXTENT_TABLE: usize = 123;
+const PAIR_KERN_TABLE: usize = 131;
+const DRIVER_INFO: usize = 139;
+const EXTENSION_END: usize = 147;
+
+/// The extended text metrics, from the EXTTEXTMETRIC structure.
+#[allow(missing_docs)]
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub struct ExtendedMetrics {
+    pub master_units: u16,
+    pub cap_height: u16,
+    pub x_height: u16,
+    pub lowercase_ascent: u16,
+    pub lowercase_descent: u16,
+    /// The slant in tenths of a degree, clockwise from the vertical.
+    pub slant: i16,
+    pub underline_offset: i16,
+    pub underline_width: u16,
+    pub strike_out_offset: u16,
+    pub strike_out_width: u16,
+}
+
+/// A parsed PFM file.
+#[derive(Clone, Debug)]
+pub struct Pfm {
+    /// The version of the file format, usually `0x100`.
+    pub version: u16,
+    /// The copyright notice.
+    pub copyright: String,
+    /// The Windows face name, like `Times New Roman`.
+    pub face_name: Option<String>,
+    /// The PostScript name of the font from the driver info.
+    pub postscript_name: Option<String>,
+    /// The ascent.
+    pub ascent: u16,
+    /// Whether the font is italic.
+    pub is_italic: bool,
+    /// The weight, like 400 for regular and 700 for bold.
+    pub weight: u16,
+    /// The Windows character set, like 0 for ANSI and 2 for symbol fonts.
+    pub char_set: u8,
+    /// The pitch and family flags.
+    pub pitch_and_family: u8,
+    /// The average character width.
+    pub avg_width: u16,
+    /// The maximum character width.
+    pub max_width: u16,
+    /// The first character code with a width.
+    pub first_char: u8,
+    /// The last character code with a width.
+    pub last_char: u8,
+    /// The code of the default character, relative to `first_char`.
+    pub default_char: u8,
+    /// The code of the word break character, relative to `first_char`.
+    pub break_char: u8,
+    /// The extended text metrics.
+    pub extended_metrics: Option<ExtendedMetrics>,
+    widths: Vec<u16>,
+    kern_pairs: HashMap<(u8, u8), i16>,
+}
+
+impl Pfm {
+    /// Parses a PFM file.
+    pub fn parse(data: &[u8]) -> Option<Self> {
+        let size = read_u32(data, SIZE)? as usize;
+        if data.len() < EXTENSION_END || size < EXTENSION_END {
+            return None;
+        }
+
+        let first_char = *data.get(FIRST_CHAR)?;
+        let last_char = *data.get(LAST_CHAR)?;
+        if last_char < first_char {
+            return None;
+        }
+
+        let copyright = data.get(COPYRIGHT..COPYRIGHT + 60)?;
+        let copyright = read_c_string(copyright, 0).unwrap_or_default();
+
+        let mut pfm = Pfm {
+            version: read_u16(data, VERSION)?,
+            copyright,
+            face_name: read_offset(data, FACE).and_then(|o| read_c_string(data, o)),
+            postscript_name: read_offset(data, DRIVER_INFO).and_then(|o| read_c_string(data, o)),
+            ascent: read_u16(data, ASCENT)?,
+            is_italic: *data.get(ITALIC)? != 0,
+            weight: read_u16(data, WEIGHT)?,
+            char_set: *data.get(CHAR_SET)?,
+            pitch_and_family: *data.get(PITCH_AND_FAMILY)?,
+            avg_width: read_u16(data, AVG_WIDTH)?,
+            max_width: read_u16(data, MAX_WIDTH)?,
+            first_char,
+            last_char,
+            default_char: *data.get(DEFAULT_CHAR)?,
+            break_char: *data.get(BREAK_CHAR)?,
+            extended_metrics: read_offset(data, EXT_METRICS_OFFSET)
+                .and_then(|o| parse_extended_metrics(data, o)),
+            widths: Vec::new(),
+            kern_pairs: HashMap::new(),
+        };
+
+        if let Some(offset) = read_offset(data, EXTENT_TABLE) {
+            let count = usize::from(last_char - first_char) + 1;
+            pfm.widths = (0..count)
+                .map(|i| read_u16(data, offset + i * 2))
+                .collect::<Option<_>>()?;
+        }
+
+        if let Some(offset) = read_offset(data, PAIR_KERN_TABLE) {
+            let count = usize::from(read_u16(data, offset)?);
+            for i in 0..count {
+                let record = offset + 2 + i * 4;
+                let first = *data.get(record)?;
+                let second = *data.get(record + 1)?;
+                let amount = read_u16(data, record + 2)? as i16;
+                pfm.kern_pairs.insert((first, second), amount);
+            }
+        }
+
+        Some(pfm)
+    }
+
+    /// Returns the advance width of a character code.
+    pub fn glyph_width(&self, code: u8) -> Option<u16> {
+        let index = code.checked_sub(self.first_char)?;
+        self.widths.get(usize::from(index)).copied()
+    }
+
+    /// Returns the horizontal kerning between two character codes.
+    pub fn kerning(&self, first: u8, second: u8) -> Option<i16> {
+        self.kern_pairs.get(&(first, second)).copied()
+    }
+
+    /// Returns an iterator over all kerning pairs.
+    pub fn kern_pairs(&self) -> impl Iterator<Item = (u8, u8, i16)> + '_ {
+        self.kern_pairs
+            .iter()
+            .map(|(&(first, second), &amount)| (first, second, amount))
+    }
+}
+
+fn parse_extended_metrics(data: &[u8], offset: usize) -> Option<ExtendedMetrics> {
+    let field = |index: usize| read_u16(data, offset + index * 2);
+
+    Some(ExtendedMetrics {
+        master_units: field(6)?,
+        cap_height: field(7)?,
+        x_height: field(8)?,
+        lowercase_ascent: field(9)?,
+        lowercase_descent: field(10)?,
+        slant: field(11)? as i16,
+        underline_offset: field(16)? as i16,
+        underline_width: field(17)?,
+        strike_out_offset: field(22)?,
+        strike_out_width: field(23)?,
+    })
+}
+
+fn read_u16(data: &[u8], offset: usize) -> Option<u16> {
+    let bytes = data.get(offset..offset.checked_add(2)?)?;
+    Some(u16::from_le_bytes([bytes[0], bytes[1]]))
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    let bytes = data.get(offset..offset.checked_add(4)?)?;
+    Some(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+// Reads an offset field. Zero means that the referenced data is absent.
+fn read_offset(data: &[u8], offset: usize) -> Option<usize> {
+    match read_u32(data, offset)? {
+        0 => None,
+        n => Some(n as usize),
+    }
+}
+
+fn read_c_string(data: &[u8], offset: usize) -> Option<String> {
+    let data = data.get(offset..)?;
+    let len = data.iter().position(|b| *b == 0).unwrap_or(data.len());
+    Some(String::from_utf8_lossy(&data[..len]).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_u16(data: &mut [u8], offset: usize, value: u16) {
+        data[offset..offset + 2].copy_from_slice(&value.to_le_bytes());
+    }
+
+    fn write_u32(data: &mut [u8], offset: usize, value: u32) {
+        data[offset..offset + 4].copy_from_slice(&value.to_le_bytes());
+    }
+
+    #[test]
+    fn parse_pfm() {
+        // Header and extension, followed by the extended metrics (147),
+        // the extent table (199), the kerning table (205),
+        // the driver info (215) and the face name (225).
+        let mut data = vec![0; 233];
+        write_u16(&mut data, VERSION, 0x100);
+        write_u32(&mut data, SIZE, 233);
+        data[COPYRIGHT..COPYRIGHT + 4].copy_from_slice(b"Test");
+        write_u16(&mut data, ASCENT, 718);
+        data[ITALIC] = 1;
+        write_u16(&mut data, WEIGHT, 700);
+        data[FIRST_CHAR] = 65;
+        data[LAST_CHAR] = 67;
+        write_u32(&mut data, FACE, 225);
+        write_u32(&mut data, EXT_METRICS_OFFSET, 147);
+        write_u32(&mut data, EXTENT_TABLE, 199);
+        write_u32(&mut data, PAIR_KERN_TABLE, 205);
+        write_u32(&mut data, DRIVER_INFO, 215);
+
+        write_u16(&mut data, 147, 52); // etmSize
+        write_u16(&mut data, 147 + 12, 1000); // etmMasterUnits
+        write_u16(&mut data, 147 + 14, 662); // etmCapHeight
+        write_u16(&mut data, 147 + 22, (-120i16) as u16); // etmSlant
+
+        write_u16(&mut data, 199, 667);
+        write_u16(&mut data, 201, 611);
+        write_u16(&mut data, 203, 722);
+
+        write_u16(&mut data, 205, 2);
+        data[207..209].copy_from_slice(b"AV");
+        write_u16(&mut data, 209, (-80i16) as u16);
+        data[211..213].copy_from_slice(b"AC");
+        write_u16(&mut data, 213, (-20i16) as u16);
+
+        data[215..224].copy_from_slice(b"Test-Bold");
+        data[225..232].copy_from_slice(b"Test PS");
+
+        let pfm = Pfm::parse(&data).unwrap();
+        assert_eq!(pfm.version, 0x100);
+        assert_eq!(pfm.copyright, "Test");
+        assert_eq!(pfm.face_name.as_deref(), Some("Test PS"));
+        assert_eq!(pfm.postscript_name.as_deref(), Some("Test-Bold"));
+        assert_eq!(pfm.ascent, 718);
+        assert!(pfm.is_italic);
+        assert_eq!(pfm.weight, 700);
+
+        let metrics = pfm.extended_metrics.unwrap();
+        assert_eq!(metrics.master_units, 1000);
+        assert_eq!(metrics.cap_height, 662);
+        assert_eq!(metrics.slant, -120);
+
+        assert_eq!(pfm.glyph_width(64), None);
+        assert_eq!(pfm.glyph_width(65), Some(667));
+        assert_eq!(pfm.glyph_width(67), Some(722));
+        assert_eq!(pfm.glyph_width(68), None);
+
+        assert_eq!(pfm.kerning(b'A', b'V'), Some(-80));
+        assert_eq!(pfm.kerning(b'V', b'A'), None);
+        assert_eq!(pfm.kern_pairs().count(), 2);
+    }
+
+    #[test]
+    fn truncated_pfm() {
+        assert!(Pfm::parse(&[0; 100]).is_none());
+    }
+}
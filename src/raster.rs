@@ -0,0 +1,479 @@
+//! A CPU rasterizer for glyph outlines.
+//!
+//! Produces anti-aliased 8-bit coverage masks. Curves are flattened with
+//! a tolerance of 1/100 pixel, and each pixel row is sampled at 32 evenly
+//! spaced scanlines, where the horizontal coverage is computed exactly.
+//!
+//! ```
+//! use pdf_font_parser::raster::Rasterizer;
+//! use pdf_font_parser::outline::Outline;
+//! use pdf_font_parser::{Matrix, OutlineBuilder};
+//!
+//! let mut outline = Outline::new();
+//! outline.move_to(0.0, 0.0);
+//! outline.line_to(1000.0, 0.0);
+//! outline.line_to(1000.0, 1000.0);
+//! outline.close();
+//!
+//! // Scale 1000 units to 4 pixels and move by half a pixel.
+//! let scale = Matrix { sx: 0.004, ky: 0.0, kx: 0.0, sy: 0.004, tx: 0.0, ty: 0.0 };
+//! let mask = Rasterizer::new(scale).offset(0.5, 0.0).rasterize(&outline).unwrap();
+//! assert_eq!((mask.width(), mask.height()), (5, 4));
+//! ```
+
+use crate::cff;
+use crate::outline::{FillRule, FlatteningBuilder, Outline, Segment, TransformBuilder};
+use crate::type1;
+use crate::{GlyphId, Matrix};
+
+// The maximum distance between a curve and its approximation in pixels.
+const TOLERANCE: f32 = 0.01;
+// The number of scanlines sampled per pixel row.
+const SUBSCANLINES: usize = 32;
+// The maximum width and height of a mask in pixels.
+const MAX_SIZE: f32 = 8192.0;
+// The maximum distance of a mask from the origin in pixels,
+// up to which pixel coordinates are exact.
+const MAX_OFFSET: f32 = 16_777_216.0;
+
+/// An 8-bit coverage mask.
+///
+/// Rows are stored from top to bottom, where 0 means that a pixel
+/// is not covered and 255 that it is fully covered.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Mask {
+    width: u32,
+    height: u32,
+    left: i32,
+    top: i32,
+    data: Vec<u8>,
+}
+
+impl Mask {
+    /// Returns the width in pixels.
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Returns the height in pixels.
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Returns the x coordinate of the left edge of the mask.
+    pub fn left(&self) -> i32 {
+        self.left
+    }
+
+    /// Returns the y coordinate of the top edge of the mask, with the
+    /// y axis pointing up as in the transformed outline.
+    pub fn top(&self) -> i32 {
+        self.top
+    }
+
+    /// Returns the coverage values, row by row.
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Returns the coverage of a pixel, with row 0 at the top.
+    pub fn get(&self, x: u32, y: u32) -> Option<u8> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+
+        self.data.get((y * self.width + x) as usize).copied()
+    }
+}
+
+/// Rasterizes outlines into coverage masks.
+#[derive(Clone, Copy, Debug)]
+pub struct Rasterizer {
+    transform: Matrix,
+    offset: (f32, f32),
+    fill_rule: FillRule,
+}
+
+impl Rasterizer {
+    /// Creates a rasterizer that maps outlines to pixels by a transform.
+    ///
+    /// The transform has to include the font matrix, e.g.
+    /// `table.matrix().multiply(&Matrix { sx: 16.0, sy: 16.0, .. })`
+    /// for a size of 16 pixels per em. The y axis points up.
+    pub fn new(transform: Matrix) -> Self {
+        Rasterizer {
+            transform,
+            offset: (0.0, 0.0),
+            fill_rule: FillRule::NonZero,
+        }
+    }
+
+    /// Sets a subpixel offset that is applied after the transform.
+    pub fn offset(mut self, x: f32, y: f32) -> Self {
+        self.offset = (x, y);
+        self
+    }
+
+    /// Sets the fill rule. The default is [`FillRule::NonZero`].
+    pub fn fill_rule(mut self, fill_rule: FillRule) -> Self {
+        self.fill_rule = fill_rule;
+        self
+    }
+
+    /// Rasterizes a glyph of a CFF font.
+    pub fn rasterize_cff(&self, table: &cff::Table, glyph_id: GlyphId) -> Option<Mask> {
+        Outline::from_cff(table, glyph_id).and_then(|outline| self.rasterize(&outline))
+    }
+
+    /// Rasterizes a glyph of a Type 1 font by its name.
+    pub fn rasterize_type1(&self, table: &type1::Table, name: &str) -> Option<Mask> {
+        Outline::from_type1(table, name).and_then(|outline| self.rasterize(&outline))
+    }
+
+    /// Rasterizes an outline.
+    ///
+    /// Empty outlines produce an empty mask. Returns `None` if a transformed
+    /// coordinate isn't finite, if the mask would be wider or higher than
+    /// 8192 pixels, or if it would be too far away from the origin.
+    pub fn rasterize(&self, outline: &Outline) -> Option<Mask> {
+        let mut transform = self.transform;
+        transform.tx += self.offset.0;
+        transform.ty += self.offset.1;
+
+        let mut builder =
+            TransformBuilder::new(transform, FlatteningBuilder::new(TOLERANCE, Outline::new()));
+        outline.replay(&mut builder);
+        let edges = collect_edges(&builder.into_inner().into_inner());
+        if edges.iter().any(|edge| !edge.is_finite()) {
+            return None;
+        }
+
+        let bbox = match edges_bbox(&edges) {
+            Some(bbox) => bbox,
+            None => {
+                return Some(Mask {
+                    width: 0,
+                    height: 0,
+                    left: 0,
+                    top: 0,
+                    data: Vec::new(),
+                })
+            }
+        };
+
+        let (left, bottom) = (bbox.0.floor(), bbox.1.floor());
+        let (right, top) = (bbox.2.ceil(), bbox.3.ceil());
+        if right - left > MAX_SIZE
+            || top - bottom > MAX_SIZE
+            || left < -MAX_OFFSET
+            || bottom < -MAX_OFFSET
+            || right > MAX_OFFSET
+            || top > MAX_OFFSET
+        {
+            return None;
+        }
+
+        let left = left as i32;
+        let top = top as i32;
+        let width = (right as i32 - left).max(1) as usize;
+        let height = (top - bottom as i32).max(1) as usize;
+
+        let mut data = Vec::with_capacity(width * height);
+        let mut row = vec![0.0f32; width];
+        let mut crossings = Vec::new();
+        let weight = 1.0 / SUBSCANLINES as f32;
+
+        for r in 0..height {
+            row.iter_mut().for_each(|c| *c = 0.0);
+            let row_bottom = (top - r as i32 - 1) as f32;
+
+            for s in 0..SUBSCANLINES {
+                let y = row_bottom + (s as f32 + 0.5) * weight;
+                crossings.clear();
+                for edge in &edges {
+                    if let Some(crossing) = edge.crossing(y) {
+                        crossings.push(crossing);
+                    }
+                }
+
+                crossings.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+                let mut winding = 0;
+                for pair in crossings.windows(2) {
+                    winding += pair[0].1;
+                    let inside = match self.fill_rule {
+                        FillRule::NonZero => winding != 0,
+                        FillRule::EvenOdd => winding % 2 != 0,
+                    };
+
+                    if inside {
+                        let x0 = pair[0].0 - left as f32;
+                        let x1 = pair[1].0 - left as f32;
+                        add_span(&mut row, x0, x1, weight);
+                    }
+                }
+            }
+
+            data.extend(row.iter().map(|c| (c.clamp(0.0, 1.0) * 255.0 + 0.5) as u8));
+        }
+
+        Some(Mask {
+            width: width as u32,
+            height: height as u32,
+            left,
+            top,
+            data,
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Edge {
+    x0: f32,
+    y0: f32,
+    x1: f32,
+    y1: f32,
+}
+
+impl Edge {
+    fn is_finite(&self) -> bool {
+        self.x0.is_finite() && self.y0.is_finite() && self.x1.is_finite() && self.y1.is_finite()
+    }
+
+    // Returns the x coordinate and the winding direction
+    // where a horizontal line crosses the edge.
+    fn crossing(&self, y: f32) -> Option<(f32, i32)> {
+        if (self.y0 <= y) == (self.y1 <= y) {
+            return None;
+        }
+
+        let x = self.x0 + (y - self.y0) * (self.x1 - self.x0) / (self.y1 - self.y0);
+        let direction = if self.y1 > self.y0 { 1 } else { -1 };
+        Some((x, direction))
+    }
+}
+
+// Converts a flattened outline into edges, including the lines
+// that implicitly close the contours.
+fn collect_edges(outline: &Outline) -> Vec<Edge> {
+    let mut edges = Vec::new();
+    for contour in outline.contours() {
+        let mut from = contour.start();
+        let ends = contour.segments().iter().map(Segment::end);
+        for to in ends.chain(core::iter::once(contour.start())) {
+            if from.1 != to.1 {
+                edges.push(Edge {
+                    x0: from.0,
+                    y0: from.1,
+                    x1: to.0,
+                    y1: to.1,
+                });
+            }
+
+            from = to;
+        }
+    }
+
+    edges
+}
+
+fn edges_bbox(edges: &[Edge]) -> Option<(f32, f32, f32, f32)> {
+    let mut iter = edges.iter();
+    let first = iter.next()?;
+    let mut bbox = (
+        first.x0.min(first.x1),
+        first.y0.min(first.y1),
+        first.x0.max(first.x1),
+        first.y0.max(first.y1),
+    );
+
+    for edge in iter {
+        bbox.0 = bbox.0.min(edge.x0).min(edge.x1);
+        bbox.1 = bbox.1.min(edge.y0).min(edge.y1);
+        bbox.2 = bbox.2.max(edge.x0).max(edge.x1);
+        bbox.3 = bbox.3.max(edge.y0).max(edge.y1);
+    }
+
+    Some(bbox)
+}
+
+// Adds the exact coverage of the span from `x0` to `x1` to a row.
+fn add_span(row: &mut [f32], x0: f32, x1: f32, weight: f32) {
+    let width = row.len() as f32;
+    let x0 = x0.clamp(0.0, width);
+    let x1 = x1.clamp(0.0, width);
+    if x1 <= x0 {
+        return;
+    }
+
+    let i0 = x0.floor() as usize;
+    let i1 = x1.floor() as usize;
+    if i0 == i1 {
+        row[i0] += (x1 - x0) * weight;
+        return;
+    }
+
+    row[i0] += (i0 as f32 + 1.0 - x0) * weight;
+    for c in &mut row[i0 + 1..i1] {
+        *c += weight;
+    }
+
+    if let Some(c) = row.get_mut(i1) {
+        *c += (x1 - i1 as f32) * weight;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::OutlineBuilder;
+
+    fn polygon(points: &[(f32, f32)]) -> Outline {
+        let mut outline = Outline::new();
+        outline.move_to(points[0].0, points[0].1);
+        for &(x, y) in &points[1..] {
+            outline.line_to(x, y);
+        }
+        outline.close();
+        outline
+    }
+
+    #[test]
+    fn aligned_square() {
+        let outline = polygon(&[(1.0, 1.0), (3.0, 1.0), (3.0, 3.0), (1.0, 3.0)]);
+        let mask = Rasterizer::new(Matrix::identity())
+            .rasterize(&outline)
+            .unwrap();
+        assert_eq!((mask.left(), mask.top()), (1, 3));
+        assert_eq!((mask.width(), mask.height()), (2, 2));
+        assert_eq!(mask.data(), &[255; 4]);
+        assert_eq!(mask.get(2, 0), None);
+    }
+
+    #[test]
+    fn subpixel_offset() {
+        let outline = polygon(&[(0.0, 0.0), (2.0, 0.0), (2.0, 1.0), (0.0, 1.0)]);
+        let mask = Rasterizer::new(Matrix::identity())
+            .offset(0.5, 0.25)
+            .rasterize(&outline)
+            .unwrap();
+        assert_eq!((mask.left(), mask.top()), (0, 2));
+        #[rustfmt::skip]
+        assert_eq!(mask.data(), &[
+            32, 64, 32,
+            96, 191, 96,
+        ]);
+    }
+
+    #[test]
+    fn triangle() {
+        let outline = polygon(&[(0.0, 0.0), (4.0, 0.0), (0.0, 4.0)]);
+        let mask = Rasterizer::new(Matrix::identity())
+            .rasterize(&outline)
+            .unwrap();
+        #[rustfmt::skip]
+        assert_eq!(mask.data(), &[
+            128, 0, 0, 0,
+            255, 128, 0, 0,
+            255, 255, 128, 0,
+            255, 255, 255, 128,
+        ]);
+    }
+
+    #[test]
+    fn fill_rules() {
+        let mut outline = polygon(&[(0.0, 0.0), (3.0, 0.0), (3.0, 1.0), (0.0, 1.0)]);
+        outline.move_to(1.0, 0.0);
+        outline.line_to(2.0, 0.0);
+        outline.line_to(2.0, 1.0);
+        outline.line_to(1.0, 1.0);
+        outline.close();
+
+        let rasterizer = Rasterizer::new(Matrix::identity());
+        let non_zero = rasterizer.rasterize(&outline).unwrap();
+        assert_eq!(non_zero.data(), &[255, 255, 255]);
+
+        let even_odd = rasterizer
+            .fill_rule(FillRule::EvenOdd)
+            .rasterize(&outline)
+            .unwrap();
+        assert_eq!(even_odd.data(), &[255, 0, 255]);
+    }
+
+    #[test]
+    fn circle() {
+        // A circle with a radius of 3 pixels, made from four cubic curves,
+        // centered at (3.25, 3.5). The expected mask was computed by
+        // integrating over the exact circle.
+        const K: f32 = 0.552_284_8 * 3.0;
+        let mut outline = Outline::new();
+        outline.move_to(3.0, 0.0);
+        outline.curve_to(3.0, K, K, 3.0, 0.0, 3.0);
+        outline.curve_to(-K, 3.0, -3.0, K, -3.0, 0.0);
+        outline.curve_to(-3.0, -K, -K, -3.0, 0.0, -3.0);
+        outline.curve_to(K, -3.0, 3.0, -K, 3.0, 0.0);
+        outline.close();
+
+        let mask = Rasterizer::new(Matrix::identity())
+            .offset(3.25, 3.5)
+            .rasterize(&outline)
+            .unwrap();
+        assert_eq!((mask.left(), mask.top()), (0, 7));
+
+        #[rustfmt::skip]
+        let expected: [u8; 49] = [
+            0, 13, 99, 121, 54, 0, 0,
+            23, 220, 255, 255, 254, 116, 0,
+            143, 255, 255, 255, 255, 249, 21,
+            188, 255, 255, 255, 255, 255, 60,
+            143, 255, 255, 255, 255, 249, 21,
+            23, 220, 255, 255, 254, 116, 0,
+            0, 13, 99, 121, 54, 0, 0,
+        ];
+
+        assert_eq!((mask.width(), mask.height()), (7, 7));
+        for (actual, expected) in mask.data().iter().zip(expected.iter()) {
+            assert!((*actual as i32 - *expected as i32).abs() <= 2);
+        }
+    }
+
+    #[test]
+    fn empty_outline() {
+        let mask = Rasterizer::new(Matrix::identity())
+            .rasterize(&Outline::new())
+            .unwrap();
+        assert_eq!((mask.width(), mask.height()), (0, 0));
+        assert!(mask.data().is_empty());
+    }
+
+    #[test]
+    fn invalid_outlines() {
+        let rasterizer = Rasterizer::new(Matrix::identity());
+        let outline = polygon(&[(0.0, 0.0), (f32::INFINITY, 0.0), (0.0, 1.0)]);
+        assert_eq!(rasterizer.rasterize(&outline), None);
+        let outline = polygon(&[(0.0, 0.0), (f32::NAN, 0.0), (0.0, 1.0)]);
+        assert_eq!(rasterizer.rasterize(&outline), None);
+
+        // Too large.
+        let outline = polygon(&[(0.0, 0.0), (8193.0, 0.0), (0.0, 1.0)]);
+        assert_eq!(rasterizer.rasterize(&outline), None);
+        let outline = polygon(&[(-3.0e9, 0.0), (3.0e9, 0.0), (0.0, 1.0)]);
+        assert_eq!(rasterizer.rasterize(&outline), None);
+        let outline = polygon(&[(0.0, 0.0), (8192.0, 0.0), (0.0, 1.0)]);
+        assert_eq!(rasterizer.rasterize(&outline).unwrap().width(), 8192);
+
+        // Too far away.
+        let outline = polygon(&[(3.0e9, 0.0), (3.0e9 + 1.0, 0.0), (3.0e9, 1.0)]);
+        assert_eq!(rasterizer.rasterize(&outline), None);
+        let scale = Matrix {
+            sx: 1.0e30,
+            ky: 0.0,
+            kx: 0.0,
+            sy: 1.0e30,
+            tx: 0.0,
+            ty: 0.0,
+        };
+        let outline = polygon(&[(0.0, 0.0), (1.0, 0.0), (0.0, 1.0)]);
+        assert_eq!(Rasterizer::new(scale).rasterize(&outline), None);
+    }
+}
@@ -0,0 +1,21185 @@
+// THIS FILE WAS AUTO-GENERATED, DO NOT EDIT MANUALLY!
+use super::{FontMetrics, GlyphMetrics};
+use crate::Rect;
+
+pub(crate) static HELVETICA: FontMetrics = FontMetrics {
+    font_name: "Helvetica",
+    bbox: Rect {
+        x_min: -166,
+        y_min: -225,
+        x_max: 1000,
+        y_max: 931,
+    },
+    italic_angle: 0.0,
+    is_fixed_pitch: false,
+    ascent: Some(718),
+    descent: Some(-207),
+    cap_height: Some(718),
+    x_height: Some(523),
+    stem_v: 88,
+    stem_h: 76,
+    underline_position: -100,
+    underline_thickness: 50,
+    glyphs: &[
+        GlyphMetrics {
+            name: "A",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "AE",
+            width: 1000,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Aacute",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Abreve",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Acircumflex",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Adieresis",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Agrave",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Amacron",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Aogonek",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Aring",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Atilde",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "B",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "C",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Cacute",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ccaron",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ccedilla",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "D",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Dcaron",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Dcroat",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Delta",
+            width: 612,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "E",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Eacute",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ecaron",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ecircumflex",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Edieresis",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Edotaccent",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Egrave",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Emacron",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Eogonek",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Eth",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Euro",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "F",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "G",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Gbreve",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Gcommaaccent",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "H",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "I",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Iacute",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Icircumflex",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Idieresis",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Idotaccent",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Igrave",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Imacron",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Iogonek",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "J",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "K",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Kcommaaccent",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "L",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Lacute",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Lcaron",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Lcommaaccent",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Lslash",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "M",
+            width: 833,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "N",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Nacute",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ncaron",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ncommaaccent",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ntilde",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "O",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "OE",
+            width: 1000,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Oacute",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ocircumflex",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Odieresis",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ograve",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ohungarumlaut",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Omacron",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Oslash",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Otilde",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "P",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Q",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "R",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Racute",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Rcaron",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Rcommaaccent",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "S",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Sacute",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Scaron",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Scedilla",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Scommaaccent",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "T",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Tcaron",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Tcommaaccent",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Thorn",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "U",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Uacute",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ucircumflex",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Udieresis",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ugrave",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Uhungarumlaut",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Umacron",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Uogonek",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Uring",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "V",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "W",
+            width: 944,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "X",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Y",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Yacute",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ydieresis",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Z",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Zacute",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Zcaron",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Zdotaccent",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "aacute",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "abreve",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "acircumflex",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "acute",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "adieresis",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ae",
+            width: 889,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "agrave",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "amacron",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ampersand",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "aogonek",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "aring",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "asciicircum",
+            width: 469,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "asciitilde",
+            width: 584,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "asterisk",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "at",
+            width: 1015,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "atilde",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "b",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "backslash",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "bar",
+            width: 260,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "braceleft",
+            width: 334,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "braceright",
+            width: 334,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "bracketleft",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "bracketright",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "breve",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "brokenbar",
+            width: 260,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "bullet",
+            width: 350,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "c",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "cacute",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "caron",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ccaron",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ccedilla",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "cedilla",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "cent",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "circumflex",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "colon",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "comma",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "commaaccent",
+            width: 250,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "copyright",
+            width: 737,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "currency",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "d",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dagger",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "daggerdbl",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dcaron",
+            width: 643,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dcroat",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "degree",
+            width: 400,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dieresis",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "divide",
+            width: 584,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dollar",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dotaccent",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dotlessi",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "e",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "eacute",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ecaron",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ecircumflex",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "edieresis",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "edotaccent",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "egrave",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "eight",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ellipsis",
+            width: 1000,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "emacron",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "emdash",
+            width: 1000,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "endash",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "eogonek",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "equal",
+            width: 584,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "eth",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "exclam",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "exclamdown",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "f",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "fi",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "five",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "fl",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "florin",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "four",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "fraction",
+            width: 167,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "g",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "gbreve",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "gcommaaccent",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "germandbls",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "grave",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "greater",
+            width: 584,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "greaterequal",
+            width: 549,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "guillemotleft",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "guillemotright",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "guilsinglleft",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "guilsinglright",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "h",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "hungarumlaut",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "hyphen",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "i",
+            width: 222,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "iacute",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "icircumflex",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "idieresis",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "igrave",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "imacron",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "iogonek",
+            width: 222,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "j",
+            width: 222,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "k",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "kcommaaccent",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "l",
+            width: 222,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lacute",
+            width: 222,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lcaron",
+            width: 299,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lcommaaccent",
+            width: 222,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "less",
+            width: 584,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lessequal",
+            width: 549,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "logicalnot",
+            width: 584,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lozenge",
+            width: 471,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lslash",
+            width: 222,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "m",
+            width: 833,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "macron",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "minus",
+            width: 584,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "mu",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "multiply",
+            width: 584,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "n",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "nacute",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ncaron",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ncommaaccent",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "nine",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "notequal",
+            width: 549,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ntilde",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "numbersign",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "o",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "oacute",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ocircumflex",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "odieresis",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "oe",
+            width: 944,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ogonek",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ograve",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ohungarumlaut",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "omacron",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "one",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "onehalf",
+            width: 834,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "onequarter",
+            width: 834,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "onesuperior",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ordfeminine",
+            width: 370,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ordmasculine",
+            width: 365,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "oslash",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "otilde",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "p",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "paragraph",
+            width: 537,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "parenleft",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "parenright",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "partialdiff",
+            width: 476,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "percent",
+            width: 889,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "period",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "periodcentered",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "perthousand",
+            width: 1000,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "plus",
+            width: 584,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "plusminus",
+            width: 584,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "q",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "question",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "questiondown",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotedbl",
+            width: 355,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotedblbase",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotedblleft",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotedblright",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quoteleft",
+            width: 222,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quoteright",
+            width: 222,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotesinglbase",
+            width: 222,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotesingle",
+            width: 191,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "r",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "racute",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "radical",
+            width: 453,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "rcaron",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "rcommaaccent",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "registered",
+            width: 737,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ring",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "s",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "sacute",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "scaron",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "scedilla",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "scommaaccent",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "section",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "semicolon",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "seven",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "six",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "slash",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "space",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "sterling",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "summation",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "t",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "tcaron",
+            width: 317,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "tcommaaccent",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "thorn",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "three",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "threequarters",
+            width: 834,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "threesuperior",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "tilde",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "trademark",
+            width: 1000,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "two",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "twosuperior",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "u",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "uacute",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ucircumflex",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "udieresis",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ugrave",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "uhungarumlaut",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "umacron",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "underscore",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "uogonek",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "uring",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "v",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "w",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "x",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "y",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "yacute",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ydieresis",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "yen",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "z",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "zacute",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "zcaron",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "zdotaccent",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "zero",
+            width: 556,
+            bbox: None,
+        },
+    ],
+    kern_pairs: &[],
+};
+
+pub(crate) static HELVETICA_BOLD: FontMetrics = FontMetrics {
+    font_name: "Helvetica-Bold",
+    bbox: Rect {
+        x_min: -170,
+        y_min: -228,
+        x_max: 1003,
+        y_max: 962,
+    },
+    italic_angle: 0.0,
+    is_fixed_pitch: false,
+    ascent: Some(718),
+    descent: Some(-207),
+    cap_height: Some(718),
+    x_height: Some(532),
+    stem_v: 140,
+    stem_h: 118,
+    underline_position: -100,
+    underline_thickness: 50,
+    glyphs: &[
+        GlyphMetrics {
+            name: "A",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "AE",
+            width: 1000,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Aacute",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Abreve",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Acircumflex",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Adieresis",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Agrave",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Amacron",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Aogonek",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Aring",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Atilde",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "B",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "C",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Cacute",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ccaron",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ccedilla",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "D",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Dcaron",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Dcroat",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Delta",
+            width: 612,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "E",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Eacute",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ecaron",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ecircumflex",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Edieresis",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Edotaccent",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Egrave",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Emacron",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Eogonek",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Eth",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Euro",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "F",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "G",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Gbreve",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Gcommaaccent",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "H",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "I",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Iacute",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Icircumflex",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Idieresis",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Idotaccent",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Igrave",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Imacron",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Iogonek",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "J",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "K",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Kcommaaccent",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "L",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Lacute",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Lcaron",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Lcommaaccent",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Lslash",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "M",
+            width: 833,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "N",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Nacute",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ncaron",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ncommaaccent",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ntilde",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "O",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "OE",
+            width: 1000,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Oacute",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ocircumflex",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Odieresis",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ograve",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ohungarumlaut",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Omacron",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Oslash",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Otilde",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "P",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Q",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "R",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Racute",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Rcaron",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Rcommaaccent",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "S",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Sacute",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Scaron",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Scedilla",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Scommaaccent",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "T",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Tcaron",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Tcommaaccent",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Thorn",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "U",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Uacute",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ucircumflex",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Udieresis",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ugrave",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Uhungarumlaut",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Umacron",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Uogonek",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Uring",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "V",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "W",
+            width: 944,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "X",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Y",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Yacute",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ydieresis",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Z",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Zacute",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Zcaron",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Zdotaccent",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "aacute",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "abreve",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "acircumflex",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "acute",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "adieresis",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ae",
+            width: 889,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "agrave",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "amacron",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ampersand",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "aogonek",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "aring",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "asciicircum",
+            width: 584,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "asciitilde",
+            width: 584,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "asterisk",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "at",
+            width: 975,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "atilde",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "b",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "backslash",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "bar",
+            width: 280,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "braceleft",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "braceright",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "bracketleft",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "bracketright",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "breve",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "brokenbar",
+            width: 280,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "bullet",
+            width: 350,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "c",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "cacute",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "caron",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ccaron",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ccedilla",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "cedilla",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "cent",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "circumflex",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "colon",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "comma",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "commaaccent",
+            width: 250,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "copyright",
+            width: 737,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "currency",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "d",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dagger",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "daggerdbl",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dcaron",
+            width: 743,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dcroat",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "degree",
+            width: 400,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dieresis",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "divide",
+            width: 584,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dollar",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dotaccent",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dotlessi",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "e",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "eacute",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ecaron",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ecircumflex",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "edieresis",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "edotaccent",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "egrave",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "eight",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ellipsis",
+            width: 1000,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "emacron",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "emdash",
+            width: 1000,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "endash",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "eogonek",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "equal",
+            width: 584,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "eth",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "exclam",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "exclamdown",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "f",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "fi",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "five",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "fl",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "florin",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "four",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "fraction",
+            width: 167,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "g",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "gbreve",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "gcommaaccent",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "germandbls",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "grave",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "greater",
+            width: 584,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "greaterequal",
+            width: 549,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "guillemotleft",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "guillemotright",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "guilsinglleft",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "guilsinglright",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "h",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "hungarumlaut",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "hyphen",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "i",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "iacute",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "icircumflex",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "idieresis",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "igrave",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "imacron",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "iogonek",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "j",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "k",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "kcommaaccent",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "l",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lacute",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lcaron",
+            width: 400,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lcommaaccent",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "less",
+            width: 584,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lessequal",
+            width: 549,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "logicalnot",
+            width: 584,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lozenge",
+            width: 494,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lslash",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "m",
+            width: 889,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "macron",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "minus",
+            width: 584,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "mu",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "multiply",
+            width: 584,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "n",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "nacute",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ncaron",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ncommaaccent",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "nine",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "notequal",
+            width: 549,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ntilde",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "numbersign",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "o",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "oacute",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ocircumflex",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "odieresis",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "oe",
+            width: 944,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ogonek",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ograve",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ohungarumlaut",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "omacron",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "one",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "onehalf",
+            width: 834,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "onequarter",
+            width: 834,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "onesuperior",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ordfeminine",
+            width: 370,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ordmasculine",
+            width: 365,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "oslash",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "otilde",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "p",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "paragraph",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "parenleft",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "parenright",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "partialdiff",
+            width: 494,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "percent",
+            width: 889,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "period",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "periodcentered",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "perthousand",
+            width: 1000,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "plus",
+            width: 584,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "plusminus",
+            width: 584,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "q",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "question",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "questiondown",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotedbl",
+            width: 474,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotedblbase",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotedblleft",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotedblright",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quoteleft",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quoteright",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotesinglbase",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotesingle",
+            width: 238,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "r",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "racute",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "radical",
+            width: 549,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "rcaron",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "rcommaaccent",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "registered",
+            width: 737,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ring",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "s",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "sacute",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "scaron",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "scedilla",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "scommaaccent",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "section",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "semicolon",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "seven",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "six",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "slash",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "space",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "sterling",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "summation",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "t",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "tcaron",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "tcommaaccent",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "thorn",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "three",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "threequarters",
+            width: 834,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "threesuperior",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "tilde",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "trademark",
+            width: 1000,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "two",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "twosuperior",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "u",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "uacute",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ucircumflex",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "udieresis",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ugrave",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "uhungarumlaut",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "umacron",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "underscore",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "uogonek",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "uring",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "v",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "w",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "x",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "y",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "yacute",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ydieresis",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "yen",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "z",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "zacute",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "zcaron",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "zdotaccent",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "zero",
+            width: 556,
+            bbox: None,
+        },
+    ],
+    kern_pairs: &[],
+};
+
+pub(crate) static HELVETICA_OBLIQUE: FontMetrics = FontMetrics {
+    font_name: "Helvetica-Oblique",
+    bbox: Rect {
+        x_min: -170,
+        y_min: -225,
+        x_max: 1116,
+        y_max: 931,
+    },
+    italic_angle: -12.0,
+    is_fixed_pitch: false,
+    ascent: Some(718),
+    descent: Some(-207),
+    cap_height: Some(718),
+    x_height: Some(523),
+    stem_v: 88,
+    stem_h: 76,
+    underline_position: -100,
+    underline_thickness: 50,
+    glyphs: &[
+        GlyphMetrics {
+            name: "A",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "AE",
+            width: 1000,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Aacute",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Abreve",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Acircumflex",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Adieresis",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Agrave",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Amacron",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Aogonek",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Aring",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Atilde",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "B",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "C",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Cacute",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ccaron",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ccedilla",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "D",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Dcaron",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Dcroat",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Delta",
+            width: 612,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "E",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Eacute",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ecaron",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ecircumflex",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Edieresis",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Edotaccent",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Egrave",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Emacron",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Eogonek",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Eth",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Euro",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "F",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "G",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Gbreve",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Gcommaaccent",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "H",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "I",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Iacute",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Icircumflex",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Idieresis",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Idotaccent",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Igrave",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Imacron",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Iogonek",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "J",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "K",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Kcommaaccent",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "L",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Lacute",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Lcaron",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Lcommaaccent",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Lslash",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "M",
+            width: 833,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "N",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Nacute",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ncaron",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ncommaaccent",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ntilde",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "O",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "OE",
+            width: 1000,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Oacute",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ocircumflex",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Odieresis",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ograve",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ohungarumlaut",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Omacron",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Oslash",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Otilde",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "P",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Q",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "R",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Racute",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Rcaron",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Rcommaaccent",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "S",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Sacute",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Scaron",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Scedilla",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Scommaaccent",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "T",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Tcaron",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Tcommaaccent",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Thorn",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "U",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Uacute",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ucircumflex",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Udieresis",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ugrave",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Uhungarumlaut",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Umacron",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Uogonek",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Uring",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "V",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "W",
+            width: 944,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "X",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Y",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Yacute",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ydieresis",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Z",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Zacute",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Zcaron",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Zdotaccent",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "aacute",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "abreve",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "acircumflex",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "acute",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "adieresis",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ae",
+            width: 889,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "agrave",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "amacron",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ampersand",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "aogonek",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "aring",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "asciicircum",
+            width: 469,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "asciitilde",
+            width: 584,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "asterisk",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "at",
+            width: 1015,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "atilde",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "b",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "backslash",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "bar",
+            width: 260,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "braceleft",
+            width: 334,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "braceright",
+            width: 334,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "bracketleft",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "bracketright",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "breve",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "brokenbar",
+            width: 260,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "bullet",
+            width: 350,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "c",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "cacute",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "caron",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ccaron",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ccedilla",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "cedilla",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "cent",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "circumflex",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "colon",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "comma",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "commaaccent",
+            width: 250,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "copyright",
+            width: 737,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "currency",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "d",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dagger",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "daggerdbl",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dcaron",
+            width: 643,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dcroat",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "degree",
+            width: 400,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dieresis",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "divide",
+            width: 584,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dollar",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dotaccent",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dotlessi",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "e",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "eacute",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ecaron",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ecircumflex",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "edieresis",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "edotaccent",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "egrave",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "eight",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ellipsis",
+            width: 1000,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "emacron",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "emdash",
+            width: 1000,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "endash",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "eogonek",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "equal",
+            width: 584,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "eth",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "exclam",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "exclamdown",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "f",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "fi",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "five",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "fl",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "florin",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "four",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "fraction",
+            width: 167,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "g",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "gbreve",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "gcommaaccent",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "germandbls",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "grave",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "greater",
+            width: 584,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "greaterequal",
+            width: 549,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "guillemotleft",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "guillemotright",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "guilsinglleft",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "guilsinglright",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "h",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "hungarumlaut",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "hyphen",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "i",
+            width: 222,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "iacute",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "icircumflex",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "idieresis",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "igrave",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "imacron",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "iogonek",
+            width: 222,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "j",
+            width: 222,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "k",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "kcommaaccent",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "l",
+            width: 222,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lacute",
+            width: 222,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lcaron",
+            width: 299,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lcommaaccent",
+            width: 222,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "less",
+            width: 584,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lessequal",
+            width: 549,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "logicalnot",
+            width: 584,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lozenge",
+            width: 471,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lslash",
+            width: 222,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "m",
+            width: 833,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "macron",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "minus",
+            width: 584,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "mu",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "multiply",
+            width: 584,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "n",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "nacute",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ncaron",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ncommaaccent",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "nine",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "notequal",
+            width: 549,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ntilde",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "numbersign",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "o",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "oacute",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ocircumflex",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "odieresis",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "oe",
+            width: 944,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ogonek",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ograve",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ohungarumlaut",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "omacron",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "one",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "onehalf",
+            width: 834,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "onequarter",
+            width: 834,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "onesuperior",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ordfeminine",
+            width: 370,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ordmasculine",
+            width: 365,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "oslash",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "otilde",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "p",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "paragraph",
+            width: 537,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "parenleft",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "parenright",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "partialdiff",
+            width: 476,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "percent",
+            width: 889,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "period",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "periodcentered",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "perthousand",
+            width: 1000,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "plus",
+            width: 584,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "plusminus",
+            width: 584,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "q",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "question",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "questiondown",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotedbl",
+            width: 355,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotedblbase",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotedblleft",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotedblright",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quoteleft",
+            width: 222,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quoteright",
+            width: 222,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotesinglbase",
+            width: 222,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotesingle",
+            width: 191,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "r",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "racute",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "radical",
+            width: 453,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "rcaron",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "rcommaaccent",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "registered",
+            width: 737,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ring",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "s",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "sacute",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "scaron",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "scedilla",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "scommaaccent",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "section",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "semicolon",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "seven",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "six",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "slash",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "space",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "sterling",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "summation",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "t",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "tcaron",
+            width: 317,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "tcommaaccent",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "thorn",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "three",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "threequarters",
+            width: 834,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "threesuperior",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "tilde",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "trademark",
+            width: 1000,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "two",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "twosuperior",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "u",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "uacute",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ucircumflex",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "udieresis",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ugrave",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "uhungarumlaut",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "umacron",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "underscore",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "uogonek",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "uring",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "v",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "w",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "x",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "y",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "yacute",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ydieresis",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "yen",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "z",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "zacute",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "zcaron",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "zdotaccent",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "zero",
+            width: 556,
+            bbox: None,
+        },
+    ],
+    kern_pairs: &[],
+};
+
+pub(crate) static HELVETICA_BOLD_OBLIQUE: FontMetrics = FontMetrics {
+    font_name: "Helvetica-BoldOblique",
+    bbox: Rect {
+        x_min: -174,
+        y_min: -228,
+        x_max: 1114,
+        y_max: 962,
+    },
+    italic_angle: -12.0,
+    is_fixed_pitch: false,
+    ascent: Some(718),
+    descent: Some(-207),
+    cap_height: Some(718),
+    x_height: Some(532),
+    stem_v: 140,
+    stem_h: 118,
+    underline_position: -100,
+    underline_thickness: 50,
+    glyphs: &[
+        GlyphMetrics {
+            name: "A",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "AE",
+            width: 1000,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Aacute",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Abreve",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Acircumflex",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Adieresis",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Agrave",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Amacron",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Aogonek",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Aring",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Atilde",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "B",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "C",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Cacute",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ccaron",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ccedilla",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "D",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Dcaron",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Dcroat",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Delta",
+            width: 612,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "E",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Eacute",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ecaron",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ecircumflex",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Edieresis",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Edotaccent",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Egrave",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Emacron",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Eogonek",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Eth",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Euro",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "F",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "G",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Gbreve",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Gcommaaccent",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "H",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "I",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Iacute",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Icircumflex",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Idieresis",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Idotaccent",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Igrave",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Imacron",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Iogonek",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "J",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "K",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Kcommaaccent",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "L",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Lacute",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Lcaron",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Lcommaaccent",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Lslash",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "M",
+            width: 833,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "N",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Nacute",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ncaron",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ncommaaccent",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ntilde",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "O",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "OE",
+            width: 1000,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Oacute",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ocircumflex",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Odieresis",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ograve",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ohungarumlaut",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Omacron",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Oslash",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Otilde",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "P",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Q",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "R",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Racute",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Rcaron",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Rcommaaccent",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "S",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Sacute",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Scaron",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Scedilla",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Scommaaccent",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "T",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Tcaron",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Tcommaaccent",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Thorn",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "U",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Uacute",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ucircumflex",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Udieresis",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ugrave",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Uhungarumlaut",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Umacron",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Uogonek",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Uring",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "V",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "W",
+            width: 944,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "X",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Y",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Yacute",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ydieresis",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Z",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Zacute",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Zcaron",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Zdotaccent",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "aacute",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "abreve",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "acircumflex",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "acute",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "adieresis",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ae",
+            width: 889,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "agrave",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "amacron",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ampersand",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "aogonek",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "aring",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "asciicircum",
+            width: 584,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "asciitilde",
+            width: 584,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "asterisk",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "at",
+            width: 975,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "atilde",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "b",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "backslash",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "bar",
+            width: 280,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "braceleft",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "braceright",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "bracketleft",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "bracketright",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "breve",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "brokenbar",
+            width: 280,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "bullet",
+            width: 350,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "c",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "cacute",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "caron",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ccaron",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ccedilla",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "cedilla",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "cent",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "circumflex",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "colon",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "comma",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "commaaccent",
+            width: 250,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "copyright",
+            width: 737,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "currency",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "d",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dagger",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "daggerdbl",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dcaron",
+            width: 743,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dcroat",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "degree",
+            width: 400,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dieresis",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "divide",
+            width: 584,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dollar",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dotaccent",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dotlessi",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "e",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "eacute",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ecaron",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ecircumflex",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "edieresis",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "edotaccent",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "egrave",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "eight",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ellipsis",
+            width: 1000,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "emacron",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "emdash",
+            width: 1000,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "endash",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "eogonek",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "equal",
+            width: 584,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "eth",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "exclam",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "exclamdown",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "f",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "fi",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "five",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "fl",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "florin",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "four",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "fraction",
+            width: 167,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "g",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "gbreve",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "gcommaaccent",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "germandbls",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "grave",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "greater",
+            width: 584,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "greaterequal",
+            width: 549,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "guillemotleft",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "guillemotright",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "guilsinglleft",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "guilsinglright",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "h",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "hungarumlaut",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "hyphen",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "i",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "iacute",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "icircumflex",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "idieresis",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "igrave",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "imacron",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "iogonek",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "j",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "k",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "kcommaaccent",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "l",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lacute",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lcaron",
+            width: 400,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lcommaaccent",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "less",
+            width: 584,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lessequal",
+            width: 549,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "logicalnot",
+            width: 584,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lozenge",
+            width: 494,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lslash",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "m",
+            width: 889,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "macron",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "minus",
+            width: 584,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "mu",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "multiply",
+            width: 584,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "n",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "nacute",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ncaron",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ncommaaccent",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "nine",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "notequal",
+            width: 549,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ntilde",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "numbersign",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "o",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "oacute",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ocircumflex",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "odieresis",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "oe",
+            width: 944,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ogonek",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ograve",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ohungarumlaut",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "omacron",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "one",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "onehalf",
+            width: 834,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "onequarter",
+            width: 834,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "onesuperior",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ordfeminine",
+            width: 370,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ordmasculine",
+            width: 365,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "oslash",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "otilde",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "p",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "paragraph",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "parenleft",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "parenright",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "partialdiff",
+            width: 494,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "percent",
+            width: 889,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "period",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "periodcentered",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "perthousand",
+            width: 1000,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "plus",
+            width: 584,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "plusminus",
+            width: 584,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "q",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "question",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "questiondown",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotedbl",
+            width: 474,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotedblbase",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotedblleft",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotedblright",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quoteleft",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quoteright",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotesinglbase",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotesingle",
+            width: 238,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "r",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "racute",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "radical",
+            width: 549,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "rcaron",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "rcommaaccent",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "registered",
+            width: 737,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ring",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "s",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "sacute",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "scaron",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "scedilla",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "scommaaccent",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "section",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "semicolon",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "seven",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "six",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "slash",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "space",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "sterling",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "summation",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "t",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "tcaron",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "tcommaaccent",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "thorn",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "three",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "threequarters",
+            width: 834,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "threesuperior",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "tilde",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "trademark",
+            width: 1000,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "two",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "twosuperior",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "u",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "uacute",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ucircumflex",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "udieresis",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ugrave",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "uhungarumlaut",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "umacron",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "underscore",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "uogonek",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "uring",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "v",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "w",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "x",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "y",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "yacute",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ydieresis",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "yen",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "z",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "zacute",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "zcaron",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "zdotaccent",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "zero",
+            width: 556,
+            bbox: None,
+        },
+    ],
+    kern_pairs: &[],
+};
+
+pub(crate) static TIMES_ROMAN: FontMetrics = FontMetrics {
+    font_name: "Times-Roman",
+    bbox: Rect {
+        x_min: -168,
+        y_min: -218,
+        x_max: 1000,
+        y_max: 898,
+    },
+    italic_angle: 0.0,
+    is_fixed_pitch: false,
+    ascent: Some(683),
+    descent: Some(-217),
+    cap_height: Some(662),
+    x_height: Some(450),
+    stem_v: 84,
+    stem_h: 28,
+    underline_position: -100,
+    underline_thickness: 50,
+    glyphs: &[
+        GlyphMetrics {
+            name: "A",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "AE",
+            width: 889,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Aacute",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Abreve",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Acircumflex",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Adieresis",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Agrave",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Amacron",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Aogonek",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Aring",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Atilde",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "B",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "C",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Cacute",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ccaron",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ccedilla",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "D",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Dcaron",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Dcroat",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Delta",
+            width: 612,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "E",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Eacute",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ecaron",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ecircumflex",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Edieresis",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Edotaccent",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Egrave",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Emacron",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Eogonek",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Eth",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Euro",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "F",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "G",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Gbreve",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Gcommaaccent",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "H",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "I",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Iacute",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Icircumflex",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Idieresis",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Idotaccent",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Igrave",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Imacron",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Iogonek",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "J",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "K",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Kcommaaccent",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "L",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Lacute",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Lcaron",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Lcommaaccent",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Lslash",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "M",
+            width: 889,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "N",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Nacute",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ncaron",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ncommaaccent",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ntilde",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "O",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "OE",
+            width: 889,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Oacute",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ocircumflex",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Odieresis",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ograve",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ohungarumlaut",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Omacron",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Oslash",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Otilde",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "P",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Q",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "R",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Racute",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Rcaron",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Rcommaaccent",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "S",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Sacute",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Scaron",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Scedilla",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Scommaaccent",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "T",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Tcaron",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Tcommaaccent",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Thorn",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "U",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Uacute",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ucircumflex",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Udieresis",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ugrave",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Uhungarumlaut",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Umacron",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Uogonek",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Uring",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "V",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "W",
+            width: 944,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "X",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Y",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Yacute",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ydieresis",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Z",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Zacute",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Zcaron",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Zdotaccent",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "aacute",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "abreve",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "acircumflex",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "acute",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "adieresis",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ae",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "agrave",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "amacron",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ampersand",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "aogonek",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "aring",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "asciicircum",
+            width: 469,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "asciitilde",
+            width: 541,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "asterisk",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "at",
+            width: 921,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "atilde",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "b",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "backslash",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "bar",
+            width: 200,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "braceleft",
+            width: 480,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "braceright",
+            width: 480,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "bracketleft",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "bracketright",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "breve",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "brokenbar",
+            width: 200,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "bullet",
+            width: 350,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "c",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "cacute",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "caron",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ccaron",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ccedilla",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "cedilla",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "cent",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "circumflex",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "colon",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "comma",
+            width: 250,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "commaaccent",
+            width: 250,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "copyright",
+            width: 760,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "currency",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "d",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dagger",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "daggerdbl",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dcaron",
+            width: 588,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dcroat",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "degree",
+            width: 400,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dieresis",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "divide",
+            width: 564,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dollar",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dotaccent",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dotlessi",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "e",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "eacute",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ecaron",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ecircumflex",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "edieresis",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "edotaccent",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "egrave",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "eight",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ellipsis",
+            width: 1000,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "emacron",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "emdash",
+            width: 1000,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "endash",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "eogonek",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "equal",
+            width: 564,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "eth",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "exclam",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "exclamdown",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "f",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "fi",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "five",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "fl",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "florin",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "four",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "fraction",
+            width: 167,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "g",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "gbreve",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "gcommaaccent",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "germandbls",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "grave",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "greater",
+            width: 564,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "greaterequal",
+            width: 549,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "guillemotleft",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "guillemotright",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "guilsinglleft",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "guilsinglright",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "h",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "hungarumlaut",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "hyphen",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "i",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "iacute",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "icircumflex",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "idieresis",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "igrave",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "imacron",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "iogonek",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "j",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "k",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "kcommaaccent",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "l",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lacute",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lcaron",
+            width: 344,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lcommaaccent",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "less",
+            width: 564,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lessequal",
+            width: 549,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "logicalnot",
+            width: 564,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lozenge",
+            width: 471,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lslash",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "m",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "macron",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "minus",
+            width: 564,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "mu",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "multiply",
+            width: 564,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "n",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "nacute",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ncaron",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ncommaaccent",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "nine",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "notequal",
+            width: 549,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ntilde",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "numbersign",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "o",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "oacute",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ocircumflex",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "odieresis",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "oe",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ogonek",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ograve",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ohungarumlaut",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "omacron",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "one",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "onehalf",
+            width: 750,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "onequarter",
+            width: 750,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "onesuperior",
+            width: 300,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ordfeminine",
+            width: 276,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ordmasculine",
+            width: 310,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "oslash",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "otilde",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "p",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "paragraph",
+            width: 453,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "parenleft",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "parenright",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "partialdiff",
+            width: 476,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "percent",
+            width: 833,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "period",
+            width: 250,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "periodcentered",
+            width: 250,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "perthousand",
+            width: 1000,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "plus",
+            width: 564,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "plusminus",
+            width: 564,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "q",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "question",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "questiondown",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotedbl",
+            width: 408,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotedblbase",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotedblleft",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotedblright",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quoteleft",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quoteright",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotesinglbase",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotesingle",
+            width: 180,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "r",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "racute",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "radical",
+            width: 453,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "rcaron",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "rcommaaccent",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "registered",
+            width: 760,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ring",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "s",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "sacute",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "scaron",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "scedilla",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "scommaaccent",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "section",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "semicolon",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "seven",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "six",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "slash",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "space",
+            width: 250,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "sterling",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "summation",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "t",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "tcaron",
+            width: 326,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "tcommaaccent",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "thorn",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "three",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "threequarters",
+            width: 750,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "threesuperior",
+            width: 300,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "tilde",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "trademark",
+            width: 980,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "two",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "twosuperior",
+            width: 300,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "u",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "uacute",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ucircumflex",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "udieresis",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ugrave",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "uhungarumlaut",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "umacron",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "underscore",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "uogonek",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "uring",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "v",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "w",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "x",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "y",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "yacute",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ydieresis",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "yen",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "z",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "zacute",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "zcaron",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "zdotaccent",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "zero",
+            width: 500,
+            bbox: None,
+        },
+    ],
+    kern_pairs: &[],
+};
+
+pub(crate) static TIMES_BOLD: FontMetrics = FontMetrics {
+    font_name: "Times-Bold",
+    bbox: Rect {
+        x_min: -168,
+        y_min: -218,
+        x_max: 1000,
+        y_max: 935,
+    },
+    italic_angle: 0.0,
+    is_fixed_pitch: false,
+    ascent: Some(683),
+    descent: Some(-217),
+    cap_height: Some(676),
+    x_height: Some(461),
+    stem_v: 139,
+    stem_h: 44,
+    underline_position: -100,
+    underline_thickness: 50,
+    glyphs: &[
+        GlyphMetrics {
+            name: "A",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "AE",
+            width: 1000,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Aacute",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Abreve",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Acircumflex",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Adieresis",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Agrave",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Amacron",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Aogonek",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Aring",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Atilde",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "B",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "C",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Cacute",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ccaron",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ccedilla",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "D",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Dcaron",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Dcroat",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Delta",
+            width: 612,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "E",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Eacute",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ecaron",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ecircumflex",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Edieresis",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Edotaccent",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Egrave",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Emacron",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Eogonek",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Eth",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Euro",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "F",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "G",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Gbreve",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Gcommaaccent",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "H",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "I",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Iacute",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Icircumflex",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Idieresis",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Idotaccent",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Igrave",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Imacron",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Iogonek",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "J",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "K",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Kcommaaccent",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "L",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Lacute",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Lcaron",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Lcommaaccent",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Lslash",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "M",
+            width: 944,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "N",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Nacute",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ncaron",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ncommaaccent",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ntilde",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "O",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "OE",
+            width: 1000,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Oacute",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ocircumflex",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Odieresis",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ograve",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ohungarumlaut",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Omacron",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Oslash",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Otilde",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "P",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Q",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "R",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Racute",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Rcaron",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Rcommaaccent",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "S",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Sacute",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Scaron",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Scedilla",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Scommaaccent",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "T",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Tcaron",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Tcommaaccent",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Thorn",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "U",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Uacute",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ucircumflex",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Udieresis",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ugrave",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Uhungarumlaut",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Umacron",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Uogonek",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Uring",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "V",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "W",
+            width: 1000,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "X",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Y",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Yacute",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ydieresis",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Z",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Zacute",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Zcaron",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Zdotaccent",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "aacute",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "abreve",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "acircumflex",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "acute",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "adieresis",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ae",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "agrave",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "amacron",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ampersand",
+            width: 833,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "aogonek",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "aring",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "asciicircum",
+            width: 581,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "asciitilde",
+            width: 520,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "asterisk",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "at",
+            width: 930,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "atilde",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "b",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "backslash",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "bar",
+            width: 220,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "braceleft",
+            width: 394,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "braceright",
+            width: 394,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "bracketleft",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "bracketright",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "breve",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "brokenbar",
+            width: 220,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "bullet",
+            width: 350,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "c",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "cacute",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "caron",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ccaron",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ccedilla",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "cedilla",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "cent",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "circumflex",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "colon",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "comma",
+            width: 250,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "commaaccent",
+            width: 250,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "copyright",
+            width: 747,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "currency",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "d",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dagger",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "daggerdbl",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dcaron",
+            width: 672,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dcroat",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "degree",
+            width: 400,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dieresis",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "divide",
+            width: 570,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dollar",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dotaccent",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dotlessi",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "e",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "eacute",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ecaron",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ecircumflex",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "edieresis",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "edotaccent",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "egrave",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "eight",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ellipsis",
+            width: 1000,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "emacron",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "emdash",
+            width: 1000,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "endash",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "eogonek",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "equal",
+            width: 570,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "eth",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "exclam",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "exclamdown",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "f",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "fi",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "five",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "fl",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "florin",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "four",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "fraction",
+            width: 167,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "g",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "gbreve",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "gcommaaccent",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "germandbls",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "grave",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "greater",
+            width: 570,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "greaterequal",
+            width: 549,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "guillemotleft",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "guillemotright",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "guilsinglleft",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "guilsinglright",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "h",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "hungarumlaut",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "hyphen",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "i",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "iacute",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "icircumflex",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "idieresis",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "igrave",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "imacron",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "iogonek",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "j",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "k",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "kcommaaccent",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "l",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lacute",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lcaron",
+            width: 394,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lcommaaccent",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "less",
+            width: 570,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lessequal",
+            width: 549,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "logicalnot",
+            width: 570,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lozenge",
+            width: 494,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lslash",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "m",
+            width: 833,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "macron",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "minus",
+            width: 570,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "mu",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "multiply",
+            width: 570,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "n",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "nacute",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ncaron",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ncommaaccent",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "nine",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "notequal",
+            width: 549,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ntilde",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "numbersign",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "o",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "oacute",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ocircumflex",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "odieresis",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "oe",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ogonek",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ograve",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ohungarumlaut",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "omacron",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "one",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "onehalf",
+            width: 750,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "onequarter",
+            width: 750,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "onesuperior",
+            width: 300,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ordfeminine",
+            width: 300,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ordmasculine",
+            width: 330,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "oslash",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "otilde",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "p",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "paragraph",
+            width: 540,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "parenleft",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "parenright",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "partialdiff",
+            width: 494,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "percent",
+            width: 1000,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "period",
+            width: 250,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "periodcentered",
+            width: 250,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "perthousand",
+            width: 1000,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "plus",
+            width: 570,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "plusminus",
+            width: 570,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "q",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "question",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "questiondown",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotedbl",
+            width: 555,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotedblbase",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotedblleft",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotedblright",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quoteleft",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quoteright",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotesinglbase",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotesingle",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "r",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "racute",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "radical",
+            width: 549,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "rcaron",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "rcommaaccent",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "registered",
+            width: 747,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ring",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "s",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "sacute",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "scaron",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "scedilla",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "scommaaccent",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "section",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "semicolon",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "seven",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "six",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "slash",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "space",
+            width: 250,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "sterling",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "summation",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "t",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "tcaron",
+            width: 416,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "tcommaaccent",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "thorn",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "three",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "threequarters",
+            width: 750,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "threesuperior",
+            width: 300,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "tilde",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "trademark",
+            width: 1000,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "two",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "twosuperior",
+            width: 300,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "u",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "uacute",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ucircumflex",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "udieresis",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ugrave",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "uhungarumlaut",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "umacron",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "underscore",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "uogonek",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "uring",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "v",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "w",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "x",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "y",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "yacute",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ydieresis",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "yen",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "z",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "zacute",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "zcaron",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "zdotaccent",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "zero",
+            width: 500,
+            bbox: None,
+        },
+    ],
+    kern_pairs: &[],
+};
+
+pub(crate) static TIMES_ITALIC: FontMetrics = FontMetrics {
+    font_name: "Times-Italic",
+    bbox: Rect {
+        x_min: -169,
+        y_min: -217,
+        x_max: 1010,
+        y_max: 883,
+    },
+    italic_angle: -15.5,
+    is_fixed_pitch: false,
+    ascent: Some(683),
+    descent: Some(-217),
+    cap_height: Some(653),
+    x_height: Some(441),
+    stem_v: 76,
+    stem_h: 32,
+    underline_position: -100,
+    underline_thickness: 50,
+    glyphs: &[
+        GlyphMetrics {
+            name: "A",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "AE",
+            width: 889,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Aacute",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Abreve",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Acircumflex",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Adieresis",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Agrave",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Amacron",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Aogonek",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Aring",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Atilde",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "B",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "C",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Cacute",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ccaron",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ccedilla",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "D",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Dcaron",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Dcroat",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Delta",
+            width: 612,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "E",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Eacute",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ecaron",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ecircumflex",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Edieresis",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Edotaccent",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Egrave",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Emacron",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Eogonek",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Eth",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Euro",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "F",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "G",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Gbreve",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Gcommaaccent",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "H",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "I",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Iacute",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Icircumflex",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Idieresis",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Idotaccent",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Igrave",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Imacron",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Iogonek",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "J",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "K",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Kcommaaccent",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "L",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Lacute",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Lcaron",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Lcommaaccent",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Lslash",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "M",
+            width: 833,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "N",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Nacute",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ncaron",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ncommaaccent",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ntilde",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "O",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "OE",
+            width: 944,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Oacute",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ocircumflex",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Odieresis",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ograve",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ohungarumlaut",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Omacron",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Oslash",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Otilde",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "P",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Q",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "R",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Racute",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Rcaron",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Rcommaaccent",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "S",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Sacute",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Scaron",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Scedilla",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Scommaaccent",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "T",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Tcaron",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Tcommaaccent",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Thorn",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "U",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Uacute",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ucircumflex",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Udieresis",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ugrave",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Uhungarumlaut",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Umacron",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Uogonek",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Uring",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "V",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "W",
+            width: 833,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "X",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Y",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Yacute",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ydieresis",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Z",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Zacute",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Zcaron",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Zdotaccent",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "aacute",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "abreve",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "acircumflex",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "acute",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "adieresis",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ae",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "agrave",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "amacron",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ampersand",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "aogonek",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "aring",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "asciicircum",
+            width: 422,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "asciitilde",
+            width: 541,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "asterisk",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "at",
+            width: 920,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "atilde",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "b",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "backslash",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "bar",
+            width: 275,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "braceleft",
+            width: 400,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "braceright",
+            width: 400,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "bracketleft",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "bracketright",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "breve",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "brokenbar",
+            width: 275,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "bullet",
+            width: 350,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "c",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "cacute",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "caron",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ccaron",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ccedilla",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "cedilla",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "cent",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "circumflex",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "colon",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "comma",
+            width: 250,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "commaaccent",
+            width: 250,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "copyright",
+            width: 760,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "currency",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "d",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dagger",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "daggerdbl",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dcaron",
+            width: 544,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dcroat",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "degree",
+            width: 400,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dieresis",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "divide",
+            width: 675,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dollar",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dotaccent",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dotlessi",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "e",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "eacute",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ecaron",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ecircumflex",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "edieresis",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "edotaccent",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "egrave",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "eight",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ellipsis",
+            width: 889,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "emacron",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "emdash",
+            width: 889,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "endash",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "eogonek",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "equal",
+            width: 675,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "eth",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "exclam",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "exclamdown",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "f",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "fi",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "five",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "fl",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "florin",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "four",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "fraction",
+            width: 167,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "g",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "gbreve",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "gcommaaccent",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "germandbls",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "grave",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "greater",
+            width: 675,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "greaterequal",
+            width: 549,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "guillemotleft",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "guillemotright",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "guilsinglleft",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "guilsinglright",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "h",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "hungarumlaut",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "hyphen",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "i",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "iacute",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "icircumflex",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "idieresis",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "igrave",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "imacron",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "iogonek",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "j",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "k",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "kcommaaccent",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "l",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lacute",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lcaron",
+            width: 300,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lcommaaccent",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "less",
+            width: 675,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lessequal",
+            width: 549,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "logicalnot",
+            width: 675,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lozenge",
+            width: 471,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lslash",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "m",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "macron",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "minus",
+            width: 675,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "mu",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "multiply",
+            width: 675,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "n",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "nacute",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ncaron",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ncommaaccent",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "nine",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "notequal",
+            width: 549,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ntilde",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "numbersign",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "o",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "oacute",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ocircumflex",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "odieresis",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "oe",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ogonek",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ograve",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ohungarumlaut",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "omacron",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "one",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "onehalf",
+            width: 750,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "onequarter",
+            width: 750,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "onesuperior",
+            width: 300,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ordfeminine",
+            width: 276,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ordmasculine",
+            width: 310,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "oslash",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "otilde",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "p",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "paragraph",
+            width: 523,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "parenleft",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "parenright",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "partialdiff",
+            width: 476,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "percent",
+            width: 833,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "period",
+            width: 250,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "periodcentered",
+            width: 250,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "perthousand",
+            width: 1000,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "plus",
+            width: 675,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "plusminus",
+            width: 675,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "q",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "question",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "questiondown",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotedbl",
+            width: 420,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotedblbase",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotedblleft",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotedblright",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quoteleft",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quoteright",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotesinglbase",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotesingle",
+            width: 214,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "r",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "racute",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "radical",
+            width: 453,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "rcaron",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "rcommaaccent",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "registered",
+            width: 760,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ring",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "s",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "sacute",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "scaron",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "scedilla",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "scommaaccent",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "section",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "semicolon",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "seven",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "six",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "slash",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "space",
+            width: 250,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "sterling",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "summation",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "t",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "tcaron",
+            width: 300,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "tcommaaccent",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "thorn",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "three",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "threequarters",
+            width: 750,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "threesuperior",
+            width: 300,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "tilde",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "trademark",
+            width: 980,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "two",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "twosuperior",
+            width: 300,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "u",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "uacute",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ucircumflex",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "udieresis",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ugrave",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "uhungarumlaut",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "umacron",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "underscore",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "uogonek",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "uring",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "v",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "w",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "x",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "y",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "yacute",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ydieresis",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "yen",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "z",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "zacute",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "zcaron",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "zdotaccent",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "zero",
+            width: 500,
+            bbox: None,
+        },
+    ],
+    kern_pairs: &[],
+};
+
+pub(crate) static TIMES_BOLD_ITALIC: FontMetrics = FontMetrics {
+    font_name: "Times-BoldItalic",
+    bbox: Rect {
+        x_min: -200,
+        y_min: -218,
+        x_max: 996,
+        y_max: 921,
+    },
+    italic_angle: -15.0,
+    is_fixed_pitch: false,
+    ascent: Some(683),
+    descent: Some(-217),
+    cap_height: Some(669),
+    x_height: Some(462),
+    stem_v: 121,
+    stem_h: 42,
+    underline_position: -100,
+    underline_thickness: 50,
+    glyphs: &[
+        GlyphMetrics {
+            name: "A",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "AE",
+            width: 944,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Aacute",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Abreve",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Acircumflex",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Adieresis",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Agrave",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Amacron",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Aogonek",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Aring",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Atilde",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "B",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "C",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Cacute",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ccaron",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ccedilla",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "D",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Dcaron",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Dcroat",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Delta",
+            width: 612,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "E",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Eacute",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ecaron",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ecircumflex",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Edieresis",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Edotaccent",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Egrave",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Emacron",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Eogonek",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Eth",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Euro",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "F",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "G",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Gbreve",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Gcommaaccent",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "H",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "I",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Iacute",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Icircumflex",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Idieresis",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Idotaccent",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Igrave",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Imacron",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Iogonek",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "J",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "K",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Kcommaaccent",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "L",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Lacute",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Lcaron",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Lcommaaccent",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Lslash",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "M",
+            width: 889,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "N",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Nacute",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ncaron",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ncommaaccent",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ntilde",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "O",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "OE",
+            width: 944,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Oacute",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ocircumflex",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Odieresis",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ograve",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ohungarumlaut",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Omacron",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Oslash",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Otilde",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "P",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Q",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "R",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Racute",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Rcaron",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Rcommaaccent",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "S",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Sacute",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Scaron",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Scedilla",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Scommaaccent",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "T",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Tcaron",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Tcommaaccent",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Thorn",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "U",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Uacute",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ucircumflex",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Udieresis",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ugrave",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Uhungarumlaut",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Umacron",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Uogonek",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Uring",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "V",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "W",
+            width: 889,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "X",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Y",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Yacute",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ydieresis",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Z",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Zacute",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Zcaron",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Zdotaccent",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "aacute",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "abreve",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "acircumflex",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "acute",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "adieresis",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ae",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "agrave",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "amacron",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ampersand",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "aogonek",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "aring",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "asciicircum",
+            width: 570,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "asciitilde",
+            width: 570,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "asterisk",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "at",
+            width: 832,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "atilde",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "b",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "backslash",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "bar",
+            width: 220,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "braceleft",
+            width: 348,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "braceright",
+            width: 348,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "bracketleft",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "bracketright",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "breve",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "brokenbar",
+            width: 220,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "bullet",
+            width: 350,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "c",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "cacute",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "caron",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ccaron",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ccedilla",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "cedilla",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "cent",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "circumflex",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "colon",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "comma",
+            width: 250,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "commaaccent",
+            width: 250,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "copyright",
+            width: 747,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "currency",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "d",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dagger",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "daggerdbl",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dcaron",
+            width: 608,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dcroat",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "degree",
+            width: 400,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dieresis",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "divide",
+            width: 570,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dollar",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dotaccent",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dotlessi",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "e",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "eacute",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ecaron",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ecircumflex",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "edieresis",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "edotaccent",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "egrave",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "eight",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ellipsis",
+            width: 1000,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "emacron",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "emdash",
+            width: 1000,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "endash",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "eogonek",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "equal",
+            width: 570,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "eth",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "exclam",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "exclamdown",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "f",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "fi",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "five",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "fl",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "florin",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "four",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "fraction",
+            width: 167,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "g",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "gbreve",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "gcommaaccent",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "germandbls",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "grave",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "greater",
+            width: 570,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "greaterequal",
+            width: 549,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "guillemotleft",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "guillemotright",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "guilsinglleft",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "guilsinglright",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "h",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "hungarumlaut",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "hyphen",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "i",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "iacute",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "icircumflex",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "idieresis",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "igrave",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "imacron",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "iogonek",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "j",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "k",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "kcommaaccent",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "l",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lacute",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lcaron",
+            width: 382,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lcommaaccent",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "less",
+            width: 570,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lessequal",
+            width: 549,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "logicalnot",
+            width: 606,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lozenge",
+            width: 494,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lslash",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "m",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "macron",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "minus",
+            width: 606,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "mu",
+            width: 576,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "multiply",
+            width: 570,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "n",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "nacute",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ncaron",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ncommaaccent",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "nine",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "notequal",
+            width: 549,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ntilde",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "numbersign",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "o",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "oacute",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ocircumflex",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "odieresis",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "oe",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ogonek",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ograve",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ohungarumlaut",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "omacron",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "one",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "onehalf",
+            width: 750,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "onequarter",
+            width: 750,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "onesuperior",
+            width: 300,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ordfeminine",
+            width: 266,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ordmasculine",
+            width: 300,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "oslash",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "otilde",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "p",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "paragraph",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "parenleft",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "parenright",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "partialdiff",
+            width: 494,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "percent",
+            width: 833,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "period",
+            width: 250,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "periodcentered",
+            width: 250,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "perthousand",
+            width: 1000,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "plus",
+            width: 570,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "plusminus",
+            width: 570,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "q",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "question",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "questiondown",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotedbl",
+            width: 555,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotedblbase",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotedblleft",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotedblright",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quoteleft",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quoteright",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotesinglbase",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotesingle",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "r",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "racute",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "radical",
+            width: 549,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "rcaron",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "rcommaaccent",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "registered",
+            width: 747,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ring",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "s",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "sacute",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "scaron",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "scedilla",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "scommaaccent",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "section",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "semicolon",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "seven",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "six",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "slash",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "space",
+            width: 250,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "sterling",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "summation",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "t",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "tcaron",
+            width: 366,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "tcommaaccent",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "thorn",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "three",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "threequarters",
+            width: 750,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "threesuperior",
+            width: 300,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "tilde",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "trademark",
+            width: 1000,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "two",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "twosuperior",
+            width: 300,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "u",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "uacute",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ucircumflex",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "udieresis",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ugrave",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "uhungarumlaut",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "umacron",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "underscore",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "uogonek",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "uring",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "v",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "w",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "x",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "y",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "yacute",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ydieresis",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "yen",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "z",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "zacute",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "zcaron",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "zdotaccent",
+            width: 389,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "zero",
+            width: 500,
+            bbox: None,
+        },
+    ],
+    kern_pairs: &[],
+};
+
+pub(crate) static COURIER: FontMetrics = FontMetrics {
+    font_name: "Courier",
+    bbox: Rect {
+        x_min: -23,
+        y_min: -250,
+        x_max: 715,
+        y_max: 805,
+    },
+    italic_angle: 0.0,
+    is_fixed_pitch: true,
+    ascent: Some(629),
+    descent: Some(-157),
+    cap_height: Some(562),
+    x_height: Some(426),
+    stem_v: 51,
+    stem_h: 51,
+    underline_position: -100,
+    underline_thickness: 50,
+    glyphs: &[
+        GlyphMetrics {
+            name: "A",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "AE",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Aacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Abreve",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Acircumflex",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Adieresis",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Agrave",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Amacron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Aogonek",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Aring",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Atilde",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "B",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "C",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Cacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ccaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ccedilla",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "D",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Dcaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Dcroat",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Delta",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "E",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Eacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ecaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ecircumflex",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Edieresis",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Edotaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Egrave",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Emacron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Eogonek",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Eth",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Euro",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "F",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "G",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Gbreve",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Gcommaaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "H",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "I",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Iacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Icircumflex",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Idieresis",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Idotaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Igrave",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Imacron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Iogonek",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "J",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "K",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Kcommaaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "L",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Lacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Lcaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Lcommaaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Lslash",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "M",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "N",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Nacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ncaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ncommaaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ntilde",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "O",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "OE",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Oacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ocircumflex",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Odieresis",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ograve",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ohungarumlaut",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Omacron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Oslash",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Otilde",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "P",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Q",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "R",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Racute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Rcaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Rcommaaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "S",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Sacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Scaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Scedilla",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Scommaaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "T",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Tcaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Tcommaaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Thorn",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "U",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Uacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ucircumflex",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Udieresis",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ugrave",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Uhungarumlaut",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Umacron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Uogonek",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Uring",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "V",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "W",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "X",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Y",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Yacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ydieresis",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Z",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Zacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Zcaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Zdotaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "aacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "abreve",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "acircumflex",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "acute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "adieresis",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ae",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "agrave",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "amacron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ampersand",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "aogonek",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "aring",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "asciicircum",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "asciitilde",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "asterisk",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "at",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "atilde",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "b",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "backslash",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "bar",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "braceleft",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "braceright",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "bracketleft",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "bracketright",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "breve",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "brokenbar",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "bullet",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "c",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "cacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "caron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ccaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ccedilla",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "cedilla",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "cent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "circumflex",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "colon",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "comma",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "commaaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "copyright",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "currency",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "d",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dagger",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "daggerdbl",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dcaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dcroat",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "degree",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dieresis",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "divide",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dollar",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dotaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dotlessi",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "e",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "eacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ecaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ecircumflex",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "edieresis",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "edotaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "egrave",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "eight",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ellipsis",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "emacron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "emdash",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "endash",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "eogonek",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "equal",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "eth",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "exclam",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "exclamdown",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "f",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "fi",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "five",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "fl",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "florin",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "four",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "fraction",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "g",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "gbreve",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "gcommaaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "germandbls",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "grave",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "greater",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "greaterequal",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "guillemotleft",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "guillemotright",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "guilsinglleft",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "guilsinglright",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "h",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "hungarumlaut",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "hyphen",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "i",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "iacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "icircumflex",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "idieresis",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "igrave",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "imacron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "iogonek",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "j",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "k",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "kcommaaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "l",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lcaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lcommaaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "less",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lessequal",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "logicalnot",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lozenge",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lslash",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "m",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "macron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "minus",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "mu",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "multiply",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "n",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "nacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ncaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ncommaaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "nine",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "notequal",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ntilde",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "numbersign",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "o",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "oacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ocircumflex",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "odieresis",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "oe",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ogonek",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ograve",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ohungarumlaut",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "omacron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "one",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "onehalf",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "onequarter",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "onesuperior",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ordfeminine",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ordmasculine",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "oslash",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "otilde",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "p",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "paragraph",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "parenleft",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "parenright",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "partialdiff",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "percent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "period",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "periodcentered",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "perthousand",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "plus",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "plusminus",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "q",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "question",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "questiondown",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotedbl",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotedblbase",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotedblleft",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotedblright",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quoteleft",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quoteright",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotesinglbase",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotesingle",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "r",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "racute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "radical",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "rcaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "rcommaaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "registered",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ring",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "s",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "sacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "scaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "scedilla",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "scommaaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "section",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "semicolon",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "seven",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "six",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "slash",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "space",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "sterling",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "summation",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "t",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "tcaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "tcommaaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "thorn",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "three",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "threequarters",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "threesuperior",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "tilde",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "trademark",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "two",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "twosuperior",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "u",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "uacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ucircumflex",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "udieresis",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ugrave",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "uhungarumlaut",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "umacron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "underscore",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "uogonek",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "uring",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "v",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "w",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "x",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "y",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "yacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ydieresis",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "yen",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "z",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "zacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "zcaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "zdotaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "zero",
+            width: 600,
+            bbox: None,
+        },
+    ],
+    kern_pairs: &[],
+};
+
+pub(crate) static COURIER_BOLD: FontMetrics = FontMetrics {
+    font_name: "Courier-Bold",
+    bbox: Rect {
+        x_min: -113,
+        y_min: -250,
+        x_max: 749,
+        y_max: 801,
+    },
+    italic_angle: 0.0,
+    is_fixed_pitch: true,
+    ascent: Some(629),
+    descent: Some(-157),
+    cap_height: Some(562),
+    x_height: Some(439),
+    stem_v: 106,
+    stem_h: 84,
+    underline_position: -100,
+    underline_thickness: 50,
+    glyphs: &[
+        GlyphMetrics {
+            name: "A",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "AE",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Aacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Abreve",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Acircumflex",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Adieresis",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Agrave",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Amacron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Aogonek",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Aring",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Atilde",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "B",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "C",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Cacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ccaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ccedilla",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "D",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Dcaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Dcroat",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Delta",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "E",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Eacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ecaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ecircumflex",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Edieresis",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Edotaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Egrave",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Emacron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Eogonek",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Eth",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Euro",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "F",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "G",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Gbreve",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Gcommaaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "H",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "I",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Iacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Icircumflex",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Idieresis",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Idotaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Igrave",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Imacron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Iogonek",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "J",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "K",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Kcommaaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "L",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Lacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Lcaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Lcommaaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Lslash",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "M",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "N",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Nacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ncaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ncommaaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ntilde",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "O",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "OE",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Oacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ocircumflex",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Odieresis",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ograve",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ohungarumlaut",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Omacron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Oslash",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Otilde",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "P",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Q",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "R",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Racute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Rcaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Rcommaaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "S",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Sacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Scaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Scedilla",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Scommaaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "T",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Tcaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Tcommaaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Thorn",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "U",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Uacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ucircumflex",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Udieresis",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ugrave",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Uhungarumlaut",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Umacron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Uogonek",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Uring",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "V",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "W",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "X",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Y",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Yacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ydieresis",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Z",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Zacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Zcaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Zdotaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "aacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "abreve",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "acircumflex",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "acute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "adieresis",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ae",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "agrave",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "amacron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ampersand",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "aogonek",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "aring",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "asciicircum",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "asciitilde",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "asterisk",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "at",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "atilde",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "b",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "backslash",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "bar",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "braceleft",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "braceright",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "bracketleft",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "bracketright",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "breve",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "brokenbar",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "bullet",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "c",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "cacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "caron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ccaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ccedilla",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "cedilla",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "cent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "circumflex",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "colon",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "comma",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "commaaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "copyright",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "currency",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "d",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dagger",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "daggerdbl",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dcaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dcroat",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "degree",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dieresis",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "divide",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dollar",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dotaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dotlessi",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "e",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "eacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ecaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ecircumflex",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "edieresis",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "edotaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "egrave",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "eight",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ellipsis",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "emacron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "emdash",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "endash",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "eogonek",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "equal",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "eth",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "exclam",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "exclamdown",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "f",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "fi",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "five",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "fl",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "florin",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "four",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "fraction",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "g",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "gbreve",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "gcommaaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "germandbls",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "grave",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "greater",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "greaterequal",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "guillemotleft",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "guillemotright",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "guilsinglleft",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "guilsinglright",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "h",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "hungarumlaut",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "hyphen",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "i",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "iacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "icircumflex",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "idieresis",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "igrave",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "imacron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "iogonek",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "j",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "k",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "kcommaaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "l",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lcaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lcommaaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "less",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lessequal",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "logicalnot",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lozenge",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lslash",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "m",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "macron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "minus",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "mu",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "multiply",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "n",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "nacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ncaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ncommaaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "nine",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "notequal",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ntilde",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "numbersign",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "o",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "oacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ocircumflex",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "odieresis",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "oe",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ogonek",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ograve",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ohungarumlaut",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "omacron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "one",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "onehalf",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "onequarter",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "onesuperior",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ordfeminine",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ordmasculine",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "oslash",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "otilde",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "p",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "paragraph",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "parenleft",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "parenright",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "partialdiff",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "percent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "period",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "periodcentered",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "perthousand",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "plus",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "plusminus",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "q",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "question",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "questiondown",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotedbl",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotedblbase",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotedblleft",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotedblright",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quoteleft",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quoteright",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotesinglbase",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotesingle",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "r",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "racute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "radical",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "rcaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "rcommaaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "registered",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ring",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "s",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "sacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "scaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "scedilla",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "scommaaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "section",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "semicolon",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "seven",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "six",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "slash",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "space",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "sterling",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "summation",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "t",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "tcaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "tcommaaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "thorn",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "three",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "threequarters",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "threesuperior",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "tilde",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "trademark",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "two",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "twosuperior",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "u",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "uacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ucircumflex",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "udieresis",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ugrave",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "uhungarumlaut",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "umacron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "underscore",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "uogonek",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "uring",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "v",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "w",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "x",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "y",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "yacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ydieresis",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "yen",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "z",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "zacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "zcaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "zdotaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "zero",
+            width: 600,
+            bbox: None,
+        },
+    ],
+    kern_pairs: &[],
+};
+
+pub(crate) static COURIER_OBLIQUE: FontMetrics = FontMetrics {
+    font_name: "Courier-Oblique",
+    bbox: Rect {
+        x_min: -27,
+        y_min: -250,
+        x_max: 849,
+        y_max: 805,
+    },
+    italic_angle: -12.0,
+    is_fixed_pitch: true,
+    ascent: Some(629),
+    descent: Some(-157),
+    cap_height: Some(562),
+    x_height: Some(426),
+    stem_v: 51,
+    stem_h: 51,
+    underline_position: -100,
+    underline_thickness: 50,
+    glyphs: &[
+        GlyphMetrics {
+            name: "A",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "AE",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Aacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Abreve",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Acircumflex",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Adieresis",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Agrave",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Amacron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Aogonek",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Aring",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Atilde",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "B",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "C",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Cacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ccaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ccedilla",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "D",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Dcaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Dcroat",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Delta",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "E",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Eacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ecaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ecircumflex",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Edieresis",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Edotaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Egrave",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Emacron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Eogonek",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Eth",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Euro",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "F",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "G",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Gbreve",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Gcommaaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "H",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "I",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Iacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Icircumflex",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Idieresis",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Idotaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Igrave",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Imacron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Iogonek",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "J",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "K",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Kcommaaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "L",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Lacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Lcaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Lcommaaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Lslash",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "M",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "N",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Nacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ncaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ncommaaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ntilde",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "O",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "OE",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Oacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ocircumflex",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Odieresis",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ograve",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ohungarumlaut",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Omacron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Oslash",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Otilde",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "P",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Q",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "R",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Racute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Rcaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Rcommaaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "S",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Sacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Scaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Scedilla",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Scommaaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "T",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Tcaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Tcommaaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Thorn",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "U",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Uacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ucircumflex",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Udieresis",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ugrave",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Uhungarumlaut",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Umacron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Uogonek",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Uring",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "V",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "W",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "X",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Y",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Yacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ydieresis",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Z",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Zacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Zcaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Zdotaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "aacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "abreve",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "acircumflex",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "acute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "adieresis",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ae",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "agrave",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "amacron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ampersand",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "aogonek",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "aring",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "asciicircum",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "asciitilde",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "asterisk",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "at",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "atilde",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "b",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "backslash",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "bar",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "braceleft",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "braceright",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "bracketleft",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "bracketright",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "breve",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "brokenbar",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "bullet",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "c",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "cacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "caron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ccaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ccedilla",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "cedilla",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "cent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "circumflex",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "colon",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "comma",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "commaaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "copyright",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "currency",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "d",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dagger",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "daggerdbl",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dcaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dcroat",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "degree",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dieresis",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "divide",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dollar",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dotaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dotlessi",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "e",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "eacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ecaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ecircumflex",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "edieresis",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "edotaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "egrave",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "eight",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ellipsis",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "emacron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "emdash",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "endash",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "eogonek",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "equal",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "eth",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "exclam",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "exclamdown",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "f",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "fi",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "five",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "fl",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "florin",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "four",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "fraction",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "g",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "gbreve",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "gcommaaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "germandbls",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "grave",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "greater",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "greaterequal",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "guillemotleft",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "guillemotright",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "guilsinglleft",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "guilsinglright",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "h",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "hungarumlaut",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "hyphen",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "i",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "iacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "icircumflex",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "idieresis",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "igrave",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "imacron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "iogonek",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "j",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "k",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "kcommaaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "l",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lcaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lcommaaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "less",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lessequal",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "logicalnot",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lozenge",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lslash",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "m",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "macron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "minus",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "mu",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "multiply",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "n",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "nacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ncaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ncommaaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "nine",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "notequal",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ntilde",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "numbersign",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "o",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "oacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ocircumflex",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "odieresis",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "oe",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ogonek",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ograve",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ohungarumlaut",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "omacron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "one",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "onehalf",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "onequarter",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "onesuperior",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ordfeminine",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ordmasculine",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "oslash",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "otilde",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "p",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "paragraph",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "parenleft",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "parenright",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "partialdiff",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "percent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "period",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "periodcentered",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "perthousand",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "plus",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "plusminus",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "q",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "question",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "questiondown",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotedbl",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotedblbase",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotedblleft",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotedblright",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quoteleft",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quoteright",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotesinglbase",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotesingle",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "r",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "racute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "radical",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "rcaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "rcommaaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "registered",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ring",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "s",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "sacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "scaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "scedilla",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "scommaaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "section",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "semicolon",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "seven",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "six",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "slash",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "space",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "sterling",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "summation",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "t",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "tcaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "tcommaaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "thorn",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "three",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "threequarters",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "threesuperior",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "tilde",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "trademark",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "two",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "twosuperior",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "u",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "uacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ucircumflex",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "udieresis",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ugrave",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "uhungarumlaut",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "umacron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "underscore",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "uogonek",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "uring",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "v",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "w",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "x",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "y",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "yacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ydieresis",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "yen",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "z",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "zacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "zcaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "zdotaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "zero",
+            width: 600,
+            bbox: None,
+        },
+    ],
+    kern_pairs: &[],
+};
+
+pub(crate) static COURIER_BOLD_OBLIQUE: FontMetrics = FontMetrics {
+    font_name: "Courier-BoldOblique",
+    bbox: Rect {
+        x_min: -57,
+        y_min: -250,
+        x_max: 869,
+        y_max: 801,
+    },
+    italic_angle: -12.0,
+    is_fixed_pitch: true,
+    ascent: Some(629),
+    descent: Some(-157),
+    cap_height: Some(562),
+    x_height: Some(439),
+    stem_v: 106,
+    stem_h: 84,
+    underline_position: -100,
+    underline_thickness: 50,
+    glyphs: &[
+        GlyphMetrics {
+            name: "A",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "AE",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Aacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Abreve",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Acircumflex",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Adieresis",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Agrave",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Amacron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Aogonek",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Aring",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Atilde",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "B",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "C",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Cacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ccaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ccedilla",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "D",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Dcaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Dcroat",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Delta",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "E",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Eacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ecaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ecircumflex",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Edieresis",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Edotaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Egrave",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Emacron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Eogonek",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Eth",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Euro",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "F",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "G",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Gbreve",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Gcommaaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "H",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "I",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Iacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Icircumflex",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Idieresis",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Idotaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Igrave",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Imacron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Iogonek",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "J",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "K",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Kcommaaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "L",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Lacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Lcaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Lcommaaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Lslash",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "M",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "N",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Nacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ncaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ncommaaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ntilde",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "O",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "OE",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Oacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ocircumflex",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Odieresis",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ograve",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ohungarumlaut",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Omacron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Oslash",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Otilde",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "P",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Q",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "R",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Racute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Rcaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Rcommaaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "S",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Sacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Scaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Scedilla",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Scommaaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "T",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Tcaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Tcommaaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Thorn",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "U",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Uacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ucircumflex",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Udieresis",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ugrave",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Uhungarumlaut",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Umacron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Uogonek",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Uring",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "V",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "W",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "X",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Y",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Yacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ydieresis",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Z",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Zacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Zcaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Zdotaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "aacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "abreve",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "acircumflex",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "acute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "adieresis",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ae",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "agrave",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "amacron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ampersand",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "aogonek",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "aring",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "asciicircum",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "asciitilde",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "asterisk",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "at",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "atilde",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "b",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "backslash",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "bar",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "braceleft",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "braceright",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "bracketleft",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "bracketright",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "breve",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "brokenbar",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "bullet",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "c",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "cacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "caron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ccaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ccedilla",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "cedilla",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "cent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "circumflex",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "colon",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "comma",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "commaaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "copyright",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "currency",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "d",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dagger",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "daggerdbl",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dcaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dcroat",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "degree",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dieresis",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "divide",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dollar",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dotaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dotlessi",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "e",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "eacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ecaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ecircumflex",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "edieresis",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "edotaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "egrave",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "eight",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ellipsis",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "emacron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "emdash",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "endash",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "eogonek",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "equal",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "eth",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "exclam",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "exclamdown",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "f",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "fi",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "five",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "fl",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "florin",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "four",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "fraction",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "g",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "gbreve",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "gcommaaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "germandbls",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "grave",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "greater",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "greaterequal",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "guillemotleft",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "guillemotright",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "guilsinglleft",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "guilsinglright",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "h",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "hungarumlaut",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "hyphen",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "i",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "iacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "icircumflex",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "idieresis",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "igrave",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "imacron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "iogonek",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "j",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "k",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "kcommaaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "l",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lcaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lcommaaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "less",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lessequal",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "logicalnot",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lozenge",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lslash",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "m",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "macron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "minus",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "mu",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "multiply",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "n",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "nacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ncaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ncommaaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "nine",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "notequal",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ntilde",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "numbersign",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "o",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "oacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ocircumflex",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "odieresis",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "oe",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ogonek",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ograve",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ohungarumlaut",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "omacron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "one",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "onehalf",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "onequarter",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "onesuperior",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ordfeminine",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ordmasculine",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "oslash",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "otilde",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "p",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "paragraph",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "parenleft",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "parenright",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "partialdiff",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "percent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "period",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "periodcentered",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "perthousand",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "plus",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "plusminus",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "q",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "question",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "questiondown",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotedbl",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotedblbase",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotedblleft",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotedblright",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quoteleft",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quoteright",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotesinglbase",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "quotesingle",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "r",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "racute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "radical",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "rcaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "rcommaaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "registered",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ring",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "s",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "sacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "scaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "scedilla",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "scommaaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "section",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "semicolon",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "seven",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "six",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "slash",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "space",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "sterling",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "summation",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "t",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "tcaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "tcommaaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "thorn",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "three",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "threequarters",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "threesuperior",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "tilde",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "trademark",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "two",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "twosuperior",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "u",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "uacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ucircumflex",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "udieresis",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ugrave",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "uhungarumlaut",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "umacron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "underscore",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "uogonek",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "uring",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "v",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "w",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "x",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "y",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "yacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ydieresis",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "yen",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "z",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "zacute",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "zcaron",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "zdotaccent",
+            width: 600,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "zero",
+            width: 600,
+            bbox: None,
+        },
+    ],
+    kern_pairs: &[],
+};
+
+pub(crate) static SYMBOL: FontMetrics = FontMetrics {
+    font_name: "Symbol",
+    bbox: Rect {
+        x_min: -180,
+        y_min: -293,
+        x_max: 1090,
+        y_max: 1010,
+    },
+    italic_angle: 0.0,
+    is_fixed_pitch: false,
+    ascent: None,
+    descent: None,
+    cap_height: None,
+    x_height: None,
+    stem_v: 85,
+    stem_h: 92,
+    underline_position: -100,
+    underline_thickness: 50,
+    glyphs: &[
+        GlyphMetrics {
+            name: "Alpha",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Beta",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Chi",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Delta",
+            width: 612,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Epsilon",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Eta",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Euro",
+            width: 750,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Gamma",
+            width: 603,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Ifraktur",
+            width: 686,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Iota",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Kappa",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Lambda",
+            width: 686,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Mu",
+            width: 889,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Nu",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Omega",
+            width: 768,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Omicron",
+            width: 722,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Phi",
+            width: 763,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Pi",
+            width: 768,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Psi",
+            width: 795,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Rfraktur",
+            width: 795,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Rho",
+            width: 556,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Sigma",
+            width: 592,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Tau",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Theta",
+            width: 741,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Upsilon",
+            width: 690,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Upsilon1",
+            width: 620,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Xi",
+            width: 645,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "Zeta",
+            width: 611,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "aleph",
+            width: 823,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "alpha",
+            width: 631,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ampersand",
+            width: 778,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "angle",
+            width: 768,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "angleleft",
+            width: 329,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "angleright",
+            width: 329,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "apple",
+            width: 790,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "approxequal",
+            width: 549,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "arrowboth",
+            width: 1042,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "arrowdblboth",
+            width: 1042,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "arrowdbldown",
+            width: 603,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "arrowdblleft",
+            width: 987,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "arrowdblright",
+            width: 987,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "arrowdblup",
+            width: 603,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "arrowdown",
+            width: 603,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "arrowhorizex",
+            width: 1000,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "arrowleft",
+            width: 987,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "arrowright",
+            width: 987,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "arrowup",
+            width: 603,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "arrowvertex",
+            width: 603,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "asteriskmath",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "bar",
+            width: 200,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "beta",
+            width: 549,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "braceex",
+            width: 494,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "braceleft",
+            width: 480,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "braceleftbt",
+            width: 494,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "braceleftmid",
+            width: 494,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "bracelefttp",
+            width: 494,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "braceright",
+            width: 480,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "bracerightbt",
+            width: 494,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "bracerightmid",
+            width: 494,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "bracerighttp",
+            width: 494,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "bracketleft",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "bracketleftbt",
+            width: 384,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "bracketleftex",
+            width: 384,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "bracketlefttp",
+            width: 384,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "bracketright",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "bracketrightbt",
+            width: 384,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "bracketrightex",
+            width: 384,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "bracketrighttp",
+            width: 384,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "bullet",
+            width: 460,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "carriagereturn",
+            width: 658,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "chi",
+            width: 549,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "circlemultiply",
+            width: 768,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "circleplus",
+            width: 768,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "club",
+            width: 753,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "colon",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "comma",
+            width: 250,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "congruent",
+            width: 549,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "copyrightsans",
+            width: 790,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "copyrightserif",
+            width: 790,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "degree",
+            width: 400,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "delta",
+            width: 494,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "diamond",
+            width: 753,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "divide",
+            width: 549,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "dotmath",
+            width: 250,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "eight",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "element",
+            width: 713,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "ellipsis",
+            width: 1000,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "emptyset",
+            width: 823,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "epsilon",
+            width: 439,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "equal",
+            width: 549,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "equivalence",
+            width: 549,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "eta",
+            width: 603,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "exclam",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "existential",
+            width: 549,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "five",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "florin",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "four",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "fraction",
+            width: 167,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "gamma",
+            width: 411,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "gradient",
+            width: 713,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "greater",
+            width: 549,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "greaterequal",
+            width: 549,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "heart",
+            width: 753,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "infinity",
+            width: 713,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "integral",
+            width: 274,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "integralbt",
+            width: 686,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "integralex",
+            width: 686,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "integraltp",
+            width: 686,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "intersection",
+            width: 768,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "iota",
+            width: 329,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "kappa",
+            width: 549,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lambda",
+            width: 549,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "less",
+            width: 549,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lessequal",
+            width: 549,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "logicaland",
+            width: 603,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "logicalnot",
+            width: 713,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "logicalor",
+            width: 603,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "lozenge",
+            width: 494,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "minus",
+            width: 549,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "minute",
+            width: 247,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "mu",
+            width: 576,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "multiply",
+            width: 549,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "nine",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "notelement",
+            width: 713,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "notequal",
+            width: 549,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "notsubset",
+            width: 713,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "nu",
+            width: 521,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "numbersign",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "omega",
+            width: 686,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "omega1",
+            width: 713,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "omicron",
+            width: 549,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "one",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "parenleft",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "parenleftbt",
+            width: 384,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "parenleftex",
+            width: 384,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "parenlefttp",
+            width: 384,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "parenright",
+            width: 333,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "parenrightbt",
+            width: 384,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "parenrightex",
+            width: 384,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "parenrighttp",
+            width: 384,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "partialdiff",
+            width: 494,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "percent",
+            width: 833,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "period",
+            width: 250,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "perpendicular",
+            width: 658,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "phi",
+            width: 521,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "phi1",
+            width: 603,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "pi",
+            width: 549,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "plus",
+            width: 549,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "plusminus",
+            width: 549,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "product",
+            width: 823,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "propersubset",
+            width: 713,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "propersuperset",
+            width: 713,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "proportional",
+            width: 713,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "psi",
+            width: 686,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "question",
+            width: 444,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "radical",
+            width: 549,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "radicalex",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "reflexsubset",
+            width: 713,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "reflexsuperset",
+            width: 713,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "registersans",
+            width: 790,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "registerserif",
+            width: 790,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "rho",
+            width: 549,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "second",
+            width: 411,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "semicolon",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "seven",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "sigma",
+            width: 603,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "sigma1",
+            width: 439,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "similar",
+            width: 549,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "six",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "slash",
+            width: 278,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "space",
+            width: 250,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "spade",
+            width: 753,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "suchthat",
+            width: 439,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "summation",
+            width: 713,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "tau",
+            width: 439,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "therefore",
+            width: 863,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "theta",
+            width: 521,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "theta1",
+            width: 631,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "three",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "trademarksans",
+            width: 786,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "trademarkserif",
+            width: 890,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "two",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "underscore",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "union",
+            width: 768,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "universal",
+            width: 713,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "upsilon",
+            width: 576,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "weierstrass",
+            width: 987,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "xi",
+            width: 493,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "zero",
+            width: 500,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "zeta",
+            width: 494,
+            bbox: None,
+        },
+    ],
+    kern_pairs: &[],
+};
+
+pub(crate) static ZAPF_DINGBATS: FontMetrics = FontMetrics {
+    font_name: "ZapfDingbats",
+    bbox: Rect {
+        x_min: -1,
+        y_min: -143,
+        x_max: 981,
+        y_max: 820,
+    },
+    italic_angle: 0.0,
+    is_fixed_pitch: false,
+    ascent: None,
+    descent: None,
+    cap_height: None,
+    x_height: None,
+    stem_v: 90,
+    stem_h: 28,
+    underline_position: -100,
+    underline_thickness: 50,
+    glyphs: &[
+        GlyphMetrics {
+            name: "a1",
+            width: 974,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a10",
+            width: 692,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a100",
+            width: 668,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a101",
+            width: 732,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a102",
+            width: 544,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a103",
+            width: 544,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a104",
+            width: 910,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a105",
+            width: 911,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a106",
+            width: 667,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a107",
+            width: 760,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a108",
+            width: 760,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a109",
+            width: 626,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a11",
+            width: 960,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a110",
+            width: 694,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a111",
+            width: 595,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a112",
+            width: 776,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a117",
+            width: 690,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a118",
+            width: 791,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a119",
+            width: 790,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a12",
+            width: 939,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a120",
+            width: 788,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a121",
+            width: 788,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a122",
+            width: 788,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a123",
+            width: 788,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a124",
+            width: 788,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a125",
+            width: 788,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a126",
+            width: 788,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a127",
+            width: 788,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a128",
+            width: 788,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a129",
+            width: 788,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a13",
+            width: 549,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a130",
+            width: 788,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a131",
+            width: 788,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a132",
+            width: 788,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a133",
+            width: 788,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a134",
+            width: 788,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a135",
+            width: 788,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a136",
+            width: 788,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a137",
+            width: 788,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a138",
+            width: 788,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a139",
+            width: 788,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a14",
+            width: 855,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a140",
+            width: 788,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a141",
+            width: 788,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a142",
+            width: 788,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a143",
+            width: 788,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a144",
+            width: 788,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a145",
+            width: 788,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a146",
+            width: 788,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a147",
+            width: 788,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a148",
+            width: 788,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a149",
+            width: 788,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a15",
+            width: 911,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a150",
+            width: 788,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a151",
+            width: 788,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a152",
+            width: 788,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a153",
+            width: 788,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a154",
+            width: 788,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a155",
+            width: 788,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a156",
+            width: 788,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a157",
+            width: 788,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a158",
+            width: 788,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a159",
+            width: 788,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a16",
+            width: 933,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a160",
+            width: 894,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a161",
+            width: 838,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a162",
+            width: 924,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a163",
+            width: 1016,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a164",
+            width: 458,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a165",
+            width: 924,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a166",
+            width: 918,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a167",
+            width: 927,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a168",
+            width: 928,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a169",
+            width: 928,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a17",
+            width: 945,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a170",
+            width: 834,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a171",
+            width: 873,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a172",
+            width: 828,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a173",
+            width: 924,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a174",
+            width: 917,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a175",
+            width: 930,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a176",
+            width: 931,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a177",
+            width: 463,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a178",
+            width: 883,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a179",
+            width: 836,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a18",
+            width: 974,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a180",
+            width: 867,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a181",
+            width: 696,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a182",
+            width: 874,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a183",
+            width: 760,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a184",
+            width: 946,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a185",
+            width: 865,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a186",
+            width: 967,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a187",
+            width: 831,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a188",
+            width: 873,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a189",
+            width: 927,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a19",
+            width: 755,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a190",
+            width: 970,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a191",
+            width: 918,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a192",
+            width: 748,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a193",
+            width: 836,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a194",
+            width: 771,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a195",
+            width: 888,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a196",
+            width: 748,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a197",
+            width: 771,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a198",
+            width: 888,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a199",
+            width: 867,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a2",
+            width: 961,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a20",
+            width: 846,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a200",
+            width: 696,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a201",
+            width: 874,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a202",
+            width: 974,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a203",
+            width: 762,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a204",
+            width: 759,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a205",
+            width: 509,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a206",
+            width: 410,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a21",
+            width: 762,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a22",
+            width: 761,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a23",
+            width: 571,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a24",
+            width: 677,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a25",
+            width: 763,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a26",
+            width: 760,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a27",
+            width: 759,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a28",
+            width: 754,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a29",
+            width: 786,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a3",
+            width: 980,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a30",
+            width: 788,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a31",
+            width: 788,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a32",
+            width: 790,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a33",
+            width: 793,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a34",
+            width: 794,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a35",
+            width: 816,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a36",
+            width: 823,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a37",
+            width: 789,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a38",
+            width: 841,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a39",
+            width: 823,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a4",
+            width: 719,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a40",
+            width: 833,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a41",
+            width: 816,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a42",
+            width: 831,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a43",
+            width: 923,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a44",
+            width: 744,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a45",
+            width: 723,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a46",
+            width: 749,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a47",
+            width: 790,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a48",
+            width: 792,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a49",
+            width: 695,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a5",
+            width: 789,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a50",
+            width: 776,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a51",
+            width: 768,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a52",
+            width: 792,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a53",
+            width: 759,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a54",
+            width: 707,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a55",
+            width: 708,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a56",
+            width: 682,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a57",
+            width: 701,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a58",
+            width: 826,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a59",
+            width: 815,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a6",
+            width: 494,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a60",
+            width: 789,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a61",
+            width: 789,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a62",
+            width: 707,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a63",
+            width: 687,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a64",
+            width: 696,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a65",
+            width: 689,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a66",
+            width: 786,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a67",
+            width: 787,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a68",
+            width: 713,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a69",
+            width: 791,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a7",
+            width: 552,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a70",
+            width: 785,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a71",
+            width: 791,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a72",
+            width: 873,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a73",
+            width: 761,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a74",
+            width: 762,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a75",
+            width: 759,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a76",
+            width: 892,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a77",
+            width: 892,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a78",
+            width: 788,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a79",
+            width: 784,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a8",
+            width: 537,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a81",
+            width: 438,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a82",
+            width: 138,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a83",
+            width: 277,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a84",
+            width: 415,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a85",
+            width: 509,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a86",
+            width: 410,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a87",
+            width: 234,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a88",
+            width: 234,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a89",
+            width: 390,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a9",
+            width: 577,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a90",
+            width: 390,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a91",
+            width: 276,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a92",
+            width: 276,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a93",
+            width: 317,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a94",
+            width: 317,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a95",
+            width: 334,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a96",
+            width: 334,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a97",
+            width: 392,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a98",
+            width: 392,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "a99",
+            width: 668,
+            bbox: None,
+        },
+        GlyphMetrics {
+            name: "space",
+            width: 278,
+            bbox: None,
+        },
+    ],
+    kern_pairs: &[],
+};
@@ -0,0 +1,256 @@
+//! Metrics of the standard 14 fonts.
+//!
+//! PDF files can use these fonts without embedding them and are allowed
+//! to omit their `/Widths`, in which case these metrics have to be used.
+//! Glyphs are looked up by name, so the metrics can be combined with the
+//! tables from [`encoding`](crate::encoding).
+//!
+//! The metrics are generated from Adobe's Core14 AFM files by
+//! `scripts/gen_standard14.py`. The bundled data contains the global
+//! metrics and the widths of all glyphs, but no glyph bounding boxes
+//! or kerning pairs.
+
+mod data;
+
+use crate::Rect;
+
+/// One of the standard 14 fonts.
+#[allow(missing_docs)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Standard14Font {
+    Helvetica,
+    HelveticaBold,
+    HelveticaOblique,
+    HelveticaBoldOblique,
+    TimesRoman,
+    TimesBold,
+    TimesItalic,
+    TimesBoldItalic,
+    Courier,
+    CourierBold,
+    CourierOblique,
+    CourierBoldOblique,
+    Symbol,
+    ZapfDingbats,
+}
+
+impl Standard14Font {
+    /// Returns the standard font for a `/BaseFont` name.
+    ///
+    /// Besides the PostScript names, the common aliases used
+    /// by PDF producers are supported, like `Arial,Bold`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        let font = match name {
+            "Helvetica" | "Arial" => Standard14Font::Helvetica,
+            "Helvetica-Bold" | "Arial,Bold" | "Arial-BoldMT" => Standard14Font::HelveticaBold,
+            "Helvetica-Oblique" | "Arial,Italic" | "Arial-ItalicMT" => {
+                Standard14Font::HelveticaOblique
+            }
+            "Helvetica-BoldOblique" | "Arial,BoldItalic" | "Arial-BoldItalicMT" => {
+                Standard14Font::HelveticaBoldOblique
+            }
+            "Times-Roman" | "TimesNewRoman" | "TimesNewRomanPSMT" => Standard14Font::TimesRoman,
+            "Times-Bold" | "TimesNewRoman,Bold" | "TimesNewRomanPS-BoldMT" => {
+                Standard14Font::TimesBold
+            }
+            "Times-Italic" | "TimesNewRoman,Italic" | "TimesNewRomanPS-ItalicMT" => {
+                Standard14Font::TimesItalic
+            }
+            "Times-BoldItalic" | "TimesNewRoman,BoldItalic" | "TimesNewRomanPS-BoldItalicMT" => {
+                Standard14Font::TimesBoldItalic
+            }
+            "Courier" | "CourierNew" | "CourierNewPSMT" => Standard14Font::Courier,
+            "Courier-Bold" | "CourierNew,Bold" | "CourierNewPS-BoldMT" => {
+                Standard14Font::CourierBold
+            }
+            "Courier-Oblique" | "CourierNew,Italic" | "CourierNewPS-ItalicMT" => {
+                Standard14Font::CourierOblique
+            }
+            "Courier-BoldOblique" | "CourierNew,BoldItalic" | "CourierNewPS-BoldItalicMT" => {
+                Standard14Font::CourierBoldOblique
+            }
+            "Symbol" => Standard14Font::Symbol,
+            "ZapfDingbats" => Standard14Font::ZapfDingbats,
+            _ => return None,
+        };
+
+        Some(font)
+    }
+
+    /// Returns the metrics of the font.
+    pub fn metrics(&self) -> &'static FontMetrics {
+        match self {
+            Standard14Font::Helvetica => &data::HELVETICA,
+            Standard14Font::HelveticaBold => &data::HELVETICA_BOLD,
+            Standard14Font::HelveticaOblique => &data::HELVETICA_OBLIQUE,
+            Standard14Font::HelveticaBoldOblique => &data::HELVETICA_BOLD_OBLIQUE,
+            Standard14Font::TimesRoman => &data::TIMES_ROMAN,
+            Standard14Font::TimesBold => &data::TIMES_BOLD,
+            Standard14Font::TimesItalic => &data::TIMES_ITALIC,
+            Standard14Font::TimesBoldItalic => &data::TIMES_BOLD_ITALIC,
+            Standard14Font::Courier => &data::COURIER,
+            Standard14Font::CourierBold => &data::COURIER_BOLD,
+            Standard14Font::CourierOblique => &data::COURIER_OBLIQUE,
+            Standard14Font::CourierBoldOblique => &data::COURIER_BOLD_OBLIQUE,
+            Standard14Font::Symbol => &data::SYMBOL,
+            Standard14Font::ZapfDingbats => &data::ZAPF_DINGBATS,
+        }
+    }
+
+    /// Returns the PostScript name of the font.
+    pub fn postscript_name(&self) -> &'static str {
+        self.metrics().font_name
+    }
+
+    /// Checks if the font is symbolic, i.e. if it doesn't use the
+    /// standard Latin character set.
+    pub fn is_symbolic(&self) -> bool {
+        matches!(self, Standard14Font::Symbol | Standard14Font::ZapfDingbats)
+    }
+}
+
+/// The metrics of a single glyph.
+#[derive(Clone, Copy, Debug)]
+pub struct GlyphMetrics {
+    /// The glyph name.
+    pub name: &'static str,
+    /// The advance width.
+    pub width: u16,
+    /// The bounding box.
+    pub bbox: Option<Rect>,
+}
+
+/// The metrics of a standard font, in units of 1/1000 em.
+///
+/// The global values correspond to the entries of a font descriptor.
+/// Values that the AFM file doesn't define, like the cap height
+/// of the Symbol font, are `None`.
+#[derive(Debug)]
+pub struct FontMetrics {
+    /// The PostScript name.
+    pub font_name: &'static str,
+    /// The font bounding box.
+    pub bbox: Rect,
+    /// The italic angle in degrees.
+    pub italic_angle: f32,
+    /// Whether all glyphs have the same width.
+    pub is_fixed_pitch: bool,
+    /// The ascent.
+    pub ascent: Option<i16>,
+    /// The descent.
+    pub descent: Option<i16>,
+    /// The height of flat capital letters.
+    pub cap_height: Option<i16>,
+    /// The height of flat lowercase letters.
+    pub x_height: Option<i16>,
+    /// The dominant vertical stem width.
+    pub stem_v: i16,
+    /// The dominant horizontal stem width.
+    pub stem_h: i16,
+    /// The underline position.
+    pub underline_position: i16,
+    /// The underline thickness.
+    pub underline_thickness: i16,
+    // Sorted by name.
+    glyphs: &'static [GlyphMetrics],
+    // Sorted by the left and then the right glyph name.
+    kern_pairs: &'static [(&'static str, &'static str, i16)],
+}
+
+impl FontMetrics {
+    /// Returns the metrics of a glyph.
+    pub fn glyph(&self, name: &str) -> Option<&'static GlyphMetrics> {
+        let index = self
+            .glyphs
+            .binary_search_by(|glyph| glyph.name.cmp(name))
+            .ok()?;
+        self.glyphs.get(index)
+    }
+
+    /// Returns the advance width of a glyph.
+    pub fn glyph_width(&self, name: &str) -> Option<u16> {
+        self.glyph(name).map(|glyph| glyph.width)
+    }
+
+    /// Returns the horizontal kerning between two glyphs.
+    pub fn kerning(&self, left: &str, right: &str) -> Option<i16> {
+        let index = self
+            .kern_pairs
+            .binary_search_by(|(l, r, _)| (*l, *r).cmp(&(left, right)))
+            .ok()?;
+        Some(self.kern_pairs[index].2)
+    }
+
+    /// Returns an iterator over the metrics of all glyphs, sorted by name.
+    pub fn glyphs(&self) -> impl Iterator<Item = &'static GlyphMetrics> {
+        self.glyphs.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encoding::WIN_ANSI;
+
+    #[test]
+    fn metrics() {
+        let helvetica = Standard14Font::from_name("Arial").unwrap().metrics();
+        assert_eq!(helvetica.font_name, "Helvetica");
+        assert_eq!(helvetica.cap_height, Some(718));
+        assert_eq!(helvetica.glyph_width("A"), Some(667));
+        assert_eq!(helvetica.glyph_width(WIN_ANSI[&0x61]), Some(556));
+        assert_eq!(helvetica.glyph_width("notaglyph"), None);
+
+        let courier = Standard14Font::Courier.metrics();
+        assert!(courier.is_fixed_pitch);
+        assert!(courier.glyphs().all(|g| g.width == 600));
+
+        let times_bold = Standard14Font::TimesBold.metrics();
+        assert_eq!(times_bold.glyph_width("A"), Some(722));
+        assert_eq!(
+            Standard14Font::TimesItalic.metrics().glyph_width("Aacute"),
+            Some(611)
+        );
+
+        let symbol = Standard14Font::from_name("Symbol").unwrap();
+        assert!(symbol.is_symbolic());
+        assert_eq!(symbol.metrics().glyph_width("alpha"), Some(631));
+        assert_eq!(
+            Standard14Font::ZapfDingbats.metrics().glyph_width("a1"),
+            Some(974)
+        );
+        assert_eq!(symbol.metrics().x_height, None);
+        assert_eq!(Standard14Font::from_name("Calibri"), None);
+    }
+
+    #[test]
+    fn glyph_counts() {
+        for font in [
+            Standard14Font::Helvetica,
+            Standard14Font::HelveticaBold,
+            Standard14Font::HelveticaOblique,
+            Standard14Font::HelveticaBoldOblique,
+            Standard14Font::TimesRoman,
+            Standard14Font::TimesBold,
+            Standard14Font::TimesItalic,
+            Standard14Font::TimesBoldItalic,
+            Standard14Font::Courier,
+            Standard14Font::CourierBold,
+            Standard14Font::CourierOblique,
+            Standard14Font::CourierBoldOblique,
+        ] {
+            assert_eq!(font.metrics().glyphs().count(), 315);
+        }
+
+        assert_eq!(Standard14Font::Symbol.metrics().glyphs().count(), 190);
+        assert_eq!(Standard14Font::ZapfDingbats.metrics().glyphs().count(), 202);
+    }
+
+    #[test]
+    #[ignore = "the bundled data has no kerning pairs"]
+    fn kerning() {
+        let helvetica = Standard14Font::Helvetica.metrics();
+        assert_eq!(helvetica.kerning("A", "V"), Some(-70));
+        assert_eq!(helvetica.kerning("A", "B"), None);
+    }
+}
@@ -0,0 +1,389 @@
+//! SVG export of glyphs.
+//!
+//! Glyphs are transformed by their font matrix into text space, scaled
+//! to a size and flipped vertically, since the y axis points down in SVG.
+
+use core::fmt::Write;
+
+use crate::cff;
+use crate::outline::{BoundsBuilder, TransformBuilder};
+use crate::type1;
+use crate::{Font, GlyphId, Matrix, OutlineBuilder, RectF};
+
+/// A builder that writes SVG path data.
+#[derive(Clone, Default, Debug)]
+pub struct SvgPathBuilder {
+    data: String,
+}
+
+impl SvgPathBuilder {
+    /// Creates a new, empty path builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the path data, which can be used as the `d` attribute
+    /// of a `path` element.
+    pub fn into_path_data(self) -> String {
+        self.data
+    }
+
+    fn push(&mut self, command: char, points: &[f32]) {
+        if !self.data.is_empty() {
+            self.data.push(' ');
+        }
+
+        self.data.push(command);
+        for (i, n) in points.iter().enumerate() {
+            if i > 0 {
+                self.data.push(' ');
+            }
+
+            write_number(&mut self.data, *n);
+        }
+    }
+}
+
+impl OutlineBuilder for SvgPathBuilder {
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.push('M', &[x, y]);
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        self.push('L', &[x, y]);
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        self.push('Q', &[x1, y1, x, y]);
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        self.push('C', &[x1, y1, x2, y2, x, y]);
+    }
+
+    fn close(&mut self) {
+        self.push('Z', &[]);
+    }
+}
+
+/// Returns the SVG path data of a glyph of a CFF font.
+///
+/// The glyph is transformed by its font matrix, so that one em
+/// is `size` units high, and flipped vertically.
+pub fn cff_glyph_path(table: &cff::Table, glyph_id: GlyphId, size: f32) -> Option<String> {
+    let mut builder = TransformBuilder::new(
+        svg_matrix(table.glyph_matrix(glyph_id), size),
+        SvgPathBuilder::new(),
+    );
+    match table.outline(glyph_id, &mut builder) {
+        Ok(_) | Err(cff::CFFError::ZeroBBox) => Some(builder.into_inner().into_path_data()),
+        Err(_) => None,
+    }
+}
+
+/// Returns the SVG path data of a glyph of a Type 1 font by its name.
+///
+/// The glyph is transformed like in [`cff_glyph_path`].
+pub fn type1_glyph_path(table: &type1::Table, name: &str, size: f32) -> Option<String> {
+    let mut builder =
+        TransformBuilder::new(svg_matrix(table.matrix(), size), SvgPathBuilder::new());
+    table.outline(name, &mut builder)?;
+    Some(builder.into_inner().into_path_data())
+}
+
+/// Returns the SVG path data of a glyph of a font.
+///
+/// The glyph is transformed like in [`cff_glyph_path`].
+pub fn glyph_path(font: &Font, glyph_id: GlyphId, size: f32) -> Option<String> {
+    let mut builder = TransformBuilder::new(
+        svg_matrix(font.glyph_matrix(glyph_id), size),
+        SvgPathBuilder::new(),
+    );
+    font.outline(glyph_id, &mut builder)?;
+    Some(builder.into_inner().into_path_data())
+}
+
+/// An SVG document showing all glyphs of a font in a grid.
+///
+/// Each cell is labeled with the glyph ID and the glyph name, or the CID
+/// in CID-keyed fonts. The baseline and the advance width are drawn in
+/// blue and the exact bounding box of the outline in red.
+///
+/// ```no_run
+/// use pdf_font_parser::svg::GlyphSheet;
+/// use pdf_font_parser::Font;
+///
+/// let data = std::fs::read("font.cff").unwrap();
+/// let font = Font::parse(&data).unwrap();
+/// let svg = GlyphSheet::new(&font).columns(8).cell_size(120.0).render();
+/// std::fs::write("glyphs.svg", svg).unwrap();
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct GlyphSheet<'a, 'b> {
+    font: &'a Font<'b>,
+    columns: u16,
+    cell_size: f32,
+}
+
+impl<'a, 'b> GlyphSheet<'a, 'b> {
+    /// Creates a glyph sheet with 16 columns of 80 units wide cells.
+    pub fn new(font: &'a Font<'b>) -> Self {
+        GlyphSheet {
+            font,
+            columns: 16,
+            cell_size: 80.0,
+        }
+    }
+
+    /// Sets the number of columns.
+    pub fn columns(mut self, columns: u16) -> Self {
+        self.columns = columns.max(1);
+        self
+    }
+
+    /// Sets the width and height of a cell.
+    pub fn cell_size(mut self, cell_size: f32) -> Self {
+        self.cell_size = cell_size;
+        self
+    }
+
+    /// Renders the SVG document.
+    pub fn render(&self) -> String {
+        let count = self.font.number_of_glyphs();
+        // Computed in `u32`, since the sum can overflow a `u16`.
+        let rows = (u32::from(count) + u32::from(self.columns) - 1) / u32::from(self.columns);
+        let size = self.cell_size;
+        // The glyphs are drawn at half the cell size, below a label.
+        let em = size * 0.5;
+        let label_size = size * 0.12;
+
+        let mut svg = String::new();
+        let width = f32::from(self.columns.min(count.max(1))) * size;
+        let height = rows as f32 * size;
+        let _ = writeln!(
+            svg,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" \
+             viewBox=\"0 0 {w} {h}\">",
+            w = number(width),
+            h = number(height)
+        );
+        let _ = writeln!(
+            svg,
+            "<style>text {{ font-family: sans-serif; font-size: {}px; }}</style>",
+            number(label_size)
+        );
+
+        for gid in 0..count {
+            let glyph_id = GlyphId(gid);
+            let x = f32::from(gid % self.columns) * size;
+            let y = f32::from(gid / self.columns) * size;
+            let origin = (x + size * 0.25, y + size * 0.8);
+
+            let _ = writeln!(
+                svg,
+                "<g transform=\"translate({} {})\">",
+                number(origin.0),
+                number(origin.1)
+            );
+            let _ = writeln!(
+                svg,
+                "<rect x=\"{}\" y=\"{}\" width=\"{s}\" height=\"{s}\" fill=\"none\" stroke=\"#ccc\"/>",
+                number(-size * 0.25),
+                number(-size * 0.8),
+                s = number(size)
+            );
+            let _ = writeln!(
+                svg,
+                "<text x=\"{}\" y=\"{}\">{}</text>",
+                number(-size * 0.2),
+                number(-size * 0.8 + label_size * 1.2),
+                escape(&self.label(glyph_id))
+            );
+
+            let matrix = svg_matrix(self.font.glyph_matrix(glyph_id), em);
+            let _ = writeln!(
+                svg,
+                "<line x1=\"{}\" y1=\"0\" x2=\"{}\" y2=\"0\" stroke=\"#00f\" stroke-width=\"0.5\"/>",
+                number(-size * 0.25),
+                number(size * 0.75)
+            );
+            if let Some(width) = self.font.glyph_width(glyph_id) {
+                let advance = matrix.apply(width, 0.0).0 - matrix.apply(0.0, 0.0).0;
+                let _ = writeln!(
+                    svg,
+                    "<line x1=\"0\" y1=\"{top}\" x2=\"0\" y2=\"{bottom}\" stroke=\"#00f\" stroke-width=\"0.5\"/>\n\
+                     <line x1=\"{a}\" y1=\"{top}\" x2=\"{a}\" y2=\"{bottom}\" stroke=\"#00f\" stroke-width=\"0.5\"/>",
+                    a = number(advance),
+                    top = number(-em),
+                    bottom = number(em * 0.3)
+                );
+            }
+
+            let mut builder = TransformBuilder::new(matrix, SvgPathBuilder::new());
+            let mut bounds = TransformBuilder::new(matrix, BoundsBuilder::new());
+            if self.font.outline(glyph_id, &mut builder).is_some() {
+                let _ = self.font.outline(glyph_id, &mut bounds);
+                let data = builder.into_inner().into_path_data();
+                if !data.is_empty() {
+                    let _ = writeln!(svg, "<path d=\"{}\"/>", data);
+                }
+
+                if let Some(bbox) = bounds.into_inner().bbox() {
+                    write_bbox(&mut svg, bbox);
+                }
+            }
+
+            svg.push_str("</g>\n");
+        }
+
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    fn label(&self, glyph_id: GlyphId) -> String {
+        let cid = match self.font {
+            Font::CFF(table) => table.glyph_cid(glyph_id),
+            Font::Type1(_) => None,
+        };
+
+        if let Some(cid) = cid {
+            format!("{} CID {}", glyph_id.0, cid)
+        } else if let Some(name) = self.font.glyph_name(glyph_id) {
+            format!("{} {}", glyph_id.0, name)
+        } else {
+            glyph_id.0.to_string()
+        }
+    }
+}
+
+fn write_bbox(svg: &mut String, bbox: RectF) {
+    let _ = writeln!(
+        svg,
+        "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"none\" stroke=\"#f00\" stroke-width=\"0.5\"/>",
+        number(bbox.x_min),
+        number(bbox.y_min),
+        number(bbox.x_max - bbox.x_min),
+        number(bbox.y_max - bbox.y_min)
+    );
+}
+
+// Returns the matrix that maps glyph units to SVG units.
+fn svg_matrix(font_matrix: Matrix, size: f32) -> Matrix {
+    font_matrix.multiply(&Matrix {
+        sx: size,
+        ky: 0.0,
+        kx: 0.0,
+        sy: -size,
+        tx: 0.0,
+        ty: 0.0,
+    })
+}
+
+fn number(n: f32) -> String {
+    let mut s = String::new();
+    write_number(&mut s, n);
+    s
+}
+
+// Writes a number with at most two decimal places.
+fn write_number(s: &mut String, n: f32) {
+    // Adding zero turns negative zero into positive zero.
+    let n = (n * 100.0).round() / 100.0 + 0.0;
+    let _ = write!(s, "{}", n);
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            _ => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::outline::Outline;
+
+    #[test]
+    fn path_data() {
+        let mut outline = Outline::new();
+        outline.move_to(0.0, 0.0);
+        outline.line_to(500.0, -0.001);
+        outline.quad_to(500.0, 700.0, 250.0, 700.0);
+        outline.curve_to(100.0, 700.0, 0.0, 333.333, 0.0, 0.0);
+        outline.close();
+
+        let mut builder =
+            TransformBuilder::new(svg_matrix(Matrix::default(), 100.0), SvgPathBuilder::new());
+        outline.replay(&mut builder);
+        assert_eq!(
+            builder.into_inner().into_path_data(),
+            "M0 0 L50 0 Q50 -70 25 -70 C10 -70 0 -33.33 0 0 Z"
+        );
+    }
+
+    #[test]
+    fn escape_labels() {
+        assert_eq!(escape("a&<b>\""), "a&amp;&lt;b&gt;&quot;");
+    }
+
+    #[test]
+    fn glyph_sheet() {
+        let data = &[
+            0x01, 0x00, 0x04, 0x01, // header
+            // Name INDEX
+            0x00, 0x01, // count: 1
+            0x01, // offset size: 1
+            0x01, 0x02, // offsets
+            0x41, // A
+            // Top DICT INDEX
+            0x00, 0x01, // count: 1
+            0x01, // offset size: 1
+            0x01, 0x07, // offsets
+            0x1D, 0x00, 0x00, 0x00, 0x19, 0x11, // charstrings offset: 25
+            // String INDEX
+            0x00, 0x00, // count: 0
+            // Global Subrs INDEX
+            0x00, 0x00, // count: 0
+            // CharStrings INDEX
+            0x00, 0x02, // count: 2
+            0x01, // offset size: 1
+            0x01, 0x02, 0x0C, // offsets
+            0x0E, // endchar
+            0xF8, 0x88, // width: 500
+            0x8B, 0x8B, 0x15, // rmoveto: 0 0
+            0xF7, 0x34, 0x8B, 0x05, // rlineto: 160 0
+            0x0E, // endchar
+        ];
+
+        let font = Font::parse(data).unwrap();
+        assert_eq!(glyph_path(&font, GlyphId(0), 1000.0).as_deref(), Some(""));
+        assert_eq!(
+            glyph_path(&font, GlyphId(1), 1000.0).as_deref(),
+            Some("M0 0 L160 0 Z")
+        );
+
+        let svg = GlyphSheet::new(&font).columns(1).cell_size(100.0).render();
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.contains("height=\"200\""));
+        assert!(svg.contains("<text x=\"-20\" y=\"-65.6\">0 .notdef</text>"));
+        assert!(svg.contains(">1 space<"));
+        assert!(svg.contains("<path d=\"M0 0 L8 0 Z\"/>"));
+        assert!(svg.contains("x1=\"25\" y1=\"-50\""));
+        assert!(svg.ends_with("</svg>\n"));
+
+        let svg = GlyphSheet::new(&font)
+            .columns(u16::MAX)
+            .cell_size(100.0)
+            .render();
+        assert!(svg.contains("width=\"200\" height=\"100\""));
+    }
+}
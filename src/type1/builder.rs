@@ -0,0 +1,227 @@
+//! A minimal builder for Type 1 fonts.
+
+use core::fmt::Write;
+use std::io::Write as _;
+
+use super::from_cff::{encrypt_char_string, write_font_info, write_program};
+use super::{FontInfo, Format};
+use crate::{Matrix, RectF};
+
+/// Builds minimal Type 1 font programs from charstrings.
+///
+/// Meant for tests that need fonts with specific charstrings, which can be
+/// written with [`assemble`](crate::asm::assemble). The charstrings are only
+/// encrypted, so fonts can also be malformed on purpose.
+///
+#[derive(Clone, Debug)]
+pub struct FontBuilder {
+    name: String,
+    glyphs: Vec<(String, Vec<u8>)>,
+    subroutines: Vec<Vec<u8>>,
+    encoding: Vec<(u8, String)>,
+    matrix: Matrix,
+    font_info: Option<FontInfo>,
+    font_bbox: Option<RectF>,
+    private_entries: Vec<(String, String)>,
+}
+
+impl FontBuilder {
+    /// Creates a builder for a font without glyphs.
+    pub fn new(name: &str) -> Self {
+        FontBuilder {
+            name: name.to_string(),
+            glyphs: Vec::new(),
+            subroutines: Vec::new(),
+            encoding: Vec::new(),
+            matrix: Matrix::default(),
+            font_info: None,
+            font_bbox: None,
+            private_entries: Vec::new(),
+        }
+    }
+
+    /// Adds a glyph.
+    pub fn glyph(mut self, name: &str, char_string: &[u8]) -> Self {
+        self.glyphs.push((name.to_string(), char_string.to_vec()));
+        self
+    }
+
+    /// Adds a subroutine.
+    ///
+    /// Subroutines are numbered in the order they are added, starting at 0.
+    pub fn subroutine(mut self, char_string: &[u8]) -> Self {
+        self.subroutines.push(char_string.to_vec());
+        self
+    }
+
+    /// Maps a code to a glyph.
+    ///
+    /// Without any mappings, the font uses the Standard Encoding.
+    pub fn encoding(mut self, code: u8, name: &str) -> Self {
+        self.encoding.push((code, name.to_string()));
+        self
+    }
+
+    /// Sets the font matrix.
+    pub fn matrix(mut self, matrix: Matrix) -> Self {
+        self.matrix = matrix;
+        self
+    }
+
+    /// Sets the `FontInfo` dictionary.
+    ///
+    /// Without it, the font has no `FontInfo` dictionary.
+    pub fn font_info(mut self, info: FontInfo) -> Self {
+        self.font_info = Some(info);
+        self
+    }
+
+    /// Sets the font bounding box.
+    pub fn font_bbox(mut self, bbox: RectF) -> Self {
+        self.font_bbox = Some(bbox);
+        self
+    }
+
+    /// Adds an entry to the `Private` dictionary, like `BlueValues` with the value
+    /// `[-10 0 700 710]`. The value is written as it is.
+    pub fn private_entry(mut self, key: &str, value: &str) -> Self {
+        self.private_entries
+            .push((key.to_string(), value.to_string()));
+        self
+    }
+
+    /// Builds the font program.
+    pub fn build(&self, format: Format) -> Vec<u8> {
+        let mut s = String::new();
+        writeln!(s, "%!PS-AdobeFont-1.0: {}", self.name).unwrap();
+        s.push_str("10 dict begin\n");
+        if let Some(info) = &self.font_info {
+            write_font_info(info, &mut s);
+        }
+        writeln!(s, "/FontName /{} def", self.name).unwrap();
+
+        if self.encoding.is_empty() {
+            s.push_str("/Encoding StandardEncoding def\n");
+        } else {
+            s.push_str("/Encoding 256 array\n0 1 255 {1 index exch /.notdef put} for\n");
+            for (code, name) in &self.encoding {
+                writeln!(s, "dup {} /{} put", code, name).unwrap();
+            }
+            s.push_str("readonly def\n");
+        }
+
+        s.push_str("/PaintType 0 def\n/FontType 1 def\n");
+        let Matrix {
+            sx,
+            ky,
+            kx,
+            sy,
+            tx,
+            ty,
+        } = self.matrix;
+        writeln!(
+            s,
+            "/FontMatrix [{} {} {} {} {} {}] readonly def",
+            sx, ky, kx, sy, tx, ty
+        )
+        .unwrap();
+        let bbox = self
+            .font_bbox
+            .map_or([0.0; 4], |b| [b.x_min, b.y_min, b.x_max, b.y_max]);
+        writeln!(
+            s,
+            "/FontBBox {{{} {} {} {}}} readonly def",
+            bbox[0], bbox[1], bbox[2], bbox[3]
+        )
+        .unwrap();
+        s.push_str("currentdict end\ncurrentfile eexec\n");
+
+        let mut private = b"dup /Private 8 dict dup begin\n\
+            /RD {string currentfile exch readstring pop} executeonly def\n\
+            /ND {noaccess def} executeonly def\n\
+            /NP {noaccess put} executeonly def\n\
+            /MinFeature {16 16} def\n\
+            /password 5839 def\n"
+            .to_vec();
+        for (key, value) in &self.private_entries {
+            writeln!(private, "/{} {} def", key, value).unwrap();
+        }
+
+        writeln!(private, "/Subrs {} array", self.subroutines.len()).unwrap();
+        for (index, subr) in self.subroutines.iter().enumerate() {
+            let subr = encrypt_char_string(subr);
+            write!(private, "dup {} {} RD ", index, subr.len()).unwrap();
+            private.extend_from_slice(&subr);
+            private.extend_from_slice(b" NP\n");
+        }
+        private.extend_from_slice(b"ND\n");
+
+        writeln!(
+            private,
+            "2 index /CharStrings {} dict dup begin",
+            self.glyphs.len()
+        )
+        .unwrap();
+        for (name, char_string) in &self.glyphs {
+            let char_string = encrypt_char_string(char_string);
+            write!(private, "/{} {} RD ", name, char_string.len()).unwrap();
+            private.extend_from_slice(&char_string);
+            private.extend_from_slice(b" ND\n");
+        }
+        private.extend_from_slice(b"end\nend\nreadonly put\nnoaccess put\n");
+        private.extend_from_slice(b"dup /FontName get exch definefont pop\n");
+        private.extend_from_slice(b"mark currentfile closefile\n");
+
+        write_program(s.as_bytes(), &private, format)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FontBuilder;
+    use crate::asm::assemble;
+    use crate::disasm::CharStringKind;
+    use crate::outline::{PathRecorder, PathSegment};
+    use crate::type1::{self, Format};
+    use crate::EncodingKind;
+
+    fn type1(text: &str) -> Vec<u8> {
+        assemble(text, CharStringKind::Type1).unwrap()
+    }
+
+    #[test]
+    fn build() {
+        let data = FontBuilder::new("Test")
+            .glyph(".notdef", &type1("0 250 hsbw endchar"))
+            .glyph("A", &type1("20 600 hsbw 0 100 rmoveto 1 callsubr endchar"))
+            .subroutine(&type1("return"))
+            .subroutine(&type1("100 hlineto 100 vlineto closepath return"))
+            .encoding(65, "A")
+            .build(Format::Pfa);
+
+        let table = type1::Table::parse(&data).unwrap();
+        assert_eq!(table.name(), Some("Test"));
+        assert_eq!(table.number_of_glyphs(), 2);
+        assert_eq!(table.glyph_width("A"), Some(600.0));
+        assert_eq!(table.encoding().kind(), EncodingKind::Custom);
+        assert_eq!(table.code_to_string(65), Some("A"));
+
+        let mut recorder = PathRecorder::new();
+        table.outline("A", &mut recorder).unwrap();
+        assert_eq!(
+            recorder.segments(),
+            [
+                PathSegment::MoveTo(20.0, 100.0),
+                PathSegment::LineTo(120.0, 100.0),
+                PathSegment::LineTo(120.0, 200.0),
+                PathSegment::Close,
+            ]
+        );
+
+        let data = FontBuilder::new("Test")
+            .glyph(".notdef", &type1("0 250 hsbw endchar"))
+            .build(Format::Pfb);
+        assert_eq!(data[..2], [0x80, 1]);
+        assert_eq!(data[data.len() - 2..], [0x80, 3]);
+    }
+}
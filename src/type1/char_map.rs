@@ -0,0 +1,64 @@
+//! Lookup of the glyphs that draw characters.
+
+use std::collections::HashMap;
+
+use super::Table;
+use crate::GlyphId;
+
+/// Maps characters to the glyphs of a Type 1 font that draw them.
+///
+/// The glyphs are found through their names according to the Adobe Glyph List.
+/// Glyphs that are reachable through the encoding are preferred.
+/// See [`Table::glyph_name`] for how glyph IDs are assigned.
+#[derive(Clone, Default, Debug)]
+pub struct CharMap {
+    map: HashMap<char, GlyphId>,
+}
+
+impl CharMap {
+    /// Builds the map of a font.
+    pub fn new(table: &Table) -> Self {
+        // Glyphs that are reachable through the encoding take precedence.
+        let encoded = table
+            .encoding()
+            .iter()
+            .filter_map(|(_, name)| table.glyph_index_by_name(name));
+        let all = (0..table.number_of_glyphs()).map(GlyphId);
+        let glyphs = encoded
+            .chain(all)
+            .filter_map(|gid| table.glyph_name(gid).map(|name| (gid, name)));
+        Self {
+            map: crate::agl::char_map(glyphs),
+        }
+    }
+
+    /// Returns the glyph that draws a character.
+    pub fn glyph_for_char(&self, c: char) -> Option<GlyphId> {
+        self.map.get(&c).copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CharMap;
+    use crate::type1::{FontBuilder, Format, Table};
+    use crate::GlyphId;
+
+    #[test]
+    fn char_map() {
+        let mut builder = FontBuilder::new("Test");
+        for name in [".notdef", "A", "A.sc", "a.sc", "uni00C1", "f_i", "g12"] {
+            builder = builder.glyph(name, &[14]);
+        }
+        let data = builder.build(Format::Pfa);
+        let table = Table::parse(&data).unwrap();
+
+        let char_map = CharMap::new(&table);
+        let name = |c| table.glyph_name(char_map.glyph_for_char(c)?);
+        assert_eq!(name('A'), Some("A"));
+        assert_eq!(name('a'), Some("a.sc"));
+        assert_eq!(name('\u{C1}'), Some("uni00C1"));
+        assert_eq!(char_map.map.len(), 3);
+        assert_eq!(char_map.glyph_for_char('B'), None::<GlyphId>);
+    }
+}
@@ -3,35 +3,57 @@ use crate::cff::CFFError;
 use crate::type1::charstring_parser::CharStringParser;
 use crate::type1::operator::{sb_operator, tb_operator};
 use crate::type1::stream::Stream;
-use crate::type1::Parameters;
 use crate::{Builder, OutlineBuilder, RectF};
 use log::{debug, error, trace, warn};
 
 const MAX_ARGUMENTS_STACK_LEN: usize = 48;
 const STACK_LIMIT: u8 = 10;
 
+/// Provides the data a Type 1 charstring can reference.
+///
+/// Type 1 fonts store subroutines and glyphs in PostScript dictionaries,
+/// while CFF fonts with `CharstringType 1` store them in INDEXes.
+pub(crate) trait CharStringResolver {
+    /// Returns a subroutine by its index.
+    fn subroutine(&self, index: u32) -> Option<&[u8]>;
+
+    /// Returns the charstring of a `seac` component by its character code.
+    fn seac_char_string(&self, code: u8) -> Option<&[u8]>;
+}
+
 struct CharStringParserContext<'a> {
-    params: &'a Parameters,
+    resolver: &'a dyn CharStringResolver,
+    width: Option<f32>,
     stems_len: u32,
     has_endchar: bool,
     has_seac: bool,
+    // The origin of the charstring, which is moved for the accent of `seac`.
+    origin_x: f32,
+    origin_y: f32,
 }
 
+/// Parses a Type 1 charstring.
+///
+/// Returns the bounding box of the outline and the advance width set by `hsbw` or `sbw`.
 pub(crate) fn parse_char_string(
     data: &[u8],
-    params: &Parameters,
+    resolver: &dyn CharStringResolver,
     builder: &mut dyn OutlineBuilder,
-) -> Result<(), CFFError> {
+) -> Result<(RectF, Option<f32>), CFFError> {
     let mut ctx = CharStringParserContext {
-        params,
+        resolver,
+        width: None,
         stems_len: 0,
         has_endchar: false,
         has_seac: false,
+        origin_x: 0.0,
+        origin_y: 0.0,
     };
 
     let mut inner_builder = Builder {
         builder,
         bbox: RectF::new(),
+        segments: 0,
     };
 
     let stack = ArgumentsStack {
@@ -53,7 +75,7 @@ pub(crate) fn parse_char_string(
         return Err(CFFError::MissingEndChar);
     }
 
-    Ok(())
+    Ok((parser.builder.bbox, ctx.width))
 }
 
 fn _parse_char_string(
@@ -123,7 +145,7 @@ fn _parse_char_string(
 
                 let index = p.stack.pop() as u32;
 
-                if let Some(subr) = ctx.params.subroutines.get(&index) {
+                if let Some(subr) = ctx.resolver.subroutine(index) {
                     _parse_char_string(ctx, subr, depth + 1, p)?;
                 } else {
                     return Err(CFFError::NoLocalSubroutines);
@@ -160,11 +182,11 @@ fn _parse_char_string(
                             return Err(CFFError::InvalidArgumentsStackLength);
                         }
 
-                        let accent_char = ctx.params.encoding_type.encode(p.stack.pop() as u8);
-                        let base_char = ctx.params.encoding_type.encode(p.stack.pop() as u8);
+                        let accent_char = p.stack.pop() as u8;
+                        let base_char = p.stack.pop() as u8;
                         let dy = p.stack.pop();
                         let dx = p.stack.pop();
-                        let sbx = p.stack.pop();
+                        let asb = p.stack.pop();
 
                         ctx.has_seac = true;
 
@@ -173,26 +195,31 @@ fn _parse_char_string(
                         }
 
                         let base_char_string = ctx
-                            .params
-                            .charstrings
-                            .get(&base_char.ok_or(CFFError::InvalidSeacCode)?.to_string())
+                            .resolver
+                            .seac_char_string(base_char)
                             .ok_or(CFFError::InvalidSeacCode)?;
                         _parse_char_string(ctx, base_char_string, depth + 1, p)?;
-                        p.x = dx + sbx;
-                        p.y = dy;
+
+                        // The accent sets its own sidebearing point,
+                        // so its origin is moved instead of the current point.
+                        ctx.origin_x = dx - asb;
+                        ctx.origin_y = dy;
 
                         let accent_char_string = ctx
-                            .params
-                            .charstrings
-                            .get(&accent_char.ok_or(CFFError::InvalidSeacCode)?.to_string())
+                            .resolver
+                            .seac_char_string(accent_char)
                             .ok_or(CFFError::InvalidSeacCode)?;
                         _parse_char_string(ctx, accent_char_string, depth + 1, p)?;
                         break;
                     }
                     tb_operator::SBW => {
                         trace_op!("SBW");
-                        p.x = p.stack.at(0);
-                        p.y = p.stack.at(1);
+                        if ctx.width.is_none() && p.stack.len() >= 3 {
+                            ctx.width = Some(p.stack.at(2));
+                        }
+
+                        p.x = ctx.origin_x + p.stack.at(0);
+                        p.y = ctx.origin_y + p.stack.at(1);
 
                         p.stack.clear();
                     }
@@ -201,7 +228,9 @@ fn _parse_char_string(
                         let num2 = p.stack.pop();
                         let num1 = p.stack.pop();
 
-                        p.stack.push(num1 / num2)?;
+                        // Division by zero gives 0 instead of an infinite number.
+                        let quotient = if num2 != 0.0 { num1 / num2 } else { 0.0 };
+                        p.stack.push(quotient)?;
                     }
                     tb_operator::CALL_OTHER_SUBR => {
                         trace_op!("CALL_OTHER_SUBR");
@@ -223,8 +252,8 @@ fn _parse_char_string(
                     }
                     tb_operator::SET_CURRENT_POINT => {
                         trace_op!("SET_CURRENT_POINT");
-                        p.x = p.stack.at(0);
-                        p.y = p.stack.at(1);
+                        p.x = ctx.origin_x + p.stack.at(0);
+                        p.y = ctx.origin_y + p.stack.at(1);
 
                         p.stack.clear();
                     }
@@ -233,9 +262,12 @@ fn _parse_char_string(
             }
             sb_operator::HSBW => {
                 trace_op!("HSBW");
+                if ctx.width.is_none() && p.stack.len() >= 2 {
+                    ctx.width = Some(p.stack.at(1));
+                }
 
-                p.x = p.stack.at(0);
-                p.y = 0.0;
+                p.x = ctx.origin_x + p.stack.at(0);
+                p.y = ctx.origin_y;
 
                 p.stack.clear();
             }
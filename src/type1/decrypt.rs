@@ -1,32 +1,24 @@
 use super::stream::Stream;
 
-pub(crate) fn decrypt(data: &[u8]) -> Vec<u8> {
+// Returns `None` if the data ends before the first four bytes.
+pub(crate) fn decrypt(data: &[u8]) -> Option<Vec<u8>> {
     let mut stream = Stream::new(data);
     stream.skip_whitespaces();
 
-    let mut b00 = None;
     let mut r: u32 = 55665;
 
     let mut decrypt = |b: u8| decrypt_byte(b, &mut r);
 
-    for i in 0..1000 {
-        let c = stream.read_byte().unwrap();
-        if !is_white_space_after_token_eexec(c) {
-            b00 = Some(c);
-            break;
-        }
+    let mut b00 = stream.read_byte()?;
+    while is_white_space_after_token_eexec(b00) {
+        b00 = stream.read_byte()?;
     }
 
-    let Some(b00) = b00 else {
-        panic!("b00 was None");
-    };
-
     let mut b = [0u8; 4];
     b[0] = b00;
 
     for i in 1..=3 {
-        let c = stream.read_byte().unwrap();
-        b[i] = c;
+        b[i] = stream.read_byte()?;
     }
 
     let mut is_bin = false;
@@ -44,13 +36,28 @@ pub(crate) fn decrypt(data: &[u8]) -> Vec<u8> {
             decrypt(b[i]);
         }
 
-        for b in stream.tail().unwrap() {
+        for b in stream.tail().unwrap_or_default() {
             out.push(decrypt(*b));
         }
 
-        out
+        Some(out)
     } else {
-        unimplemented!()
+        // The hexadecimal form, which can have whitespace between the digits.
+        let digits: Vec<u8> = b
+            .iter()
+            .chain(stream.tail().unwrap_or_default())
+            .copied()
+            .take_while(|c| c.is_ascii_hexdigit() || is_white_space_after_token_eexec(*c))
+            .filter(u8::is_ascii_hexdigit)
+            .collect();
+
+        Some(
+            digits
+                .chunks_exact(2)
+                .map(|pair| decrypt(hex_value(pair[0]) << 4 | hex_value(pair[1])))
+                .skip(4)
+                .collect(),
+        )
     }
 }
 
@@ -61,6 +68,20 @@ pub(crate) fn decrypt_byte(cipher: u8, r: &mut u32) -> u8 {
     (plain & 0xFF) as u8
 }
 
+pub(crate) fn encrypt_byte(plain: u8, r: &mut u32) -> u8 {
+    let cipher = u32::from(plain) ^ (*r >> 8);
+    *r = ((cipher + *r).wrapping_mul(52845) + 22719) & 0xFFFF;
+    (cipher & 0xFF) as u8
+}
+
+fn hex_value(c: u8) -> u8 {
+    match c {
+        b'0'..=b'9' => c - b'0',
+        b'a'..=b'f' => c - b'a' + 10,
+        _ => c - b'A' + 10,
+    }
+}
+
 fn is_white_space_after_token_eexec(c: u8) -> bool {
     matches!(c, b' ' | b'\t' | b'\n' | b'\r')
 }
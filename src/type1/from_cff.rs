@@ -0,0 +1,758 @@
+//! Conversion of CFF fonts to Type 1.
+
+// Useful links:
+// https://adobe-type-tools.github.io/font-tech-notes/pdfs/T1_SPEC.pdf
+// https://adobe-type-tools.github.io/font-tech-notes/pdfs/5040.Download_Fonts.pdf
+
+use core::convert::TryFrom;
+use core::fmt::Write;
+use std::io::Write as _;
+
+use super::decrypt::encrypt_byte;
+use super::operator::{sb_operator, tb_operator};
+use super::{FontInfo, PrivateDict};
+use crate::cff::cff::Hint;
+use crate::cff::{self, CFFError};
+use crate::outline::{PathRecorder, PathSegment};
+use crate::{EncodingKind, GlyphId, Matrix};
+
+// The standard subroutines for flex and hint replacement.
+// The converted charstrings don't use them, but some interpreters expect them.
+// The subroutines with the replaced hints follow them.
+const STANDARD_SUBRS: [&[u8]; 4] = [
+    // 3 0 callothersubr pop pop setcurrentpoint return
+    &[142, 139, 12, 16, 12, 17, 12, 17, 12, 33, 11],
+    // 0 1 callothersubr return
+    &[139, 140, 12, 16, 11],
+    // 0 2 callothersubr return
+    &[139, 141, 12, 16, 11],
+    // return
+    &[11],
+];
+
+// The OtherSubrs for fonts with hint replacement but without flex,
+// as given in the Type 1 specification. Interpreters without hint replacement
+// get subroutine 3, which keeps the current hints.
+const OTHER_SUBRS: &str = "/OtherSubrs
+[{}{}{}
+{
+systemdict /internaldict known not
+{pop 3}
+{1183615869 systemdict /internaldict get exec
+dup /startlock known
+{/startlock get exec}
+{dup /strtlck known
+{/strtlck get exec}
+{pop 3}
+ifelse}
+ifelse}
+ifelse
+} executeonly
+] noaccess def
+";
+
+// Coordinates are rounded to this fraction of a unit.
+const PRECISION: f32 = 64.0;
+
+const EEXEC_KEY: u32 = 55665;
+const CHAR_STRING_KEY: u32 = 4330;
+const LEN_IV: usize = 4;
+
+/// The format of a Type 1 font program.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Format {
+    /// The printable format, with the encrypted part written as hexadecimal.
+    Pfa,
+    /// The binary format, which splits the font program into segments.
+    Pfb,
+}
+
+/// Converts a CFF font to a Type 1 font program.
+///
+/// Glyphs are translated through their outlines, so all Type 2 only operators,
+/// subroutines and `seac` components are flattened into plain path operators.
+/// Stem hints are kept, with `hintmask` translated to hint replacement.
+/// Counter masks and the hints of `seac` glyphs are not kept.
+///
+/// Returns [`CFFError::UnsupportedFontKind`] for CID fonts, since their glyphs don't have names.
+pub fn from_cff(table: &cff::Table, format: Format) -> Result<Vec<u8>, CFFError> {
+    if table.is_cid() {
+        return Err(CFFError::UnsupportedFontKind);
+    }
+
+    let mut subrs: Vec<Vec<u8>> = STANDARD_SUBRS.iter().map(|subr| subr.to_vec()).collect();
+    let mut char_strings = Vec::new();
+    for glyph_id in 0..table.number_of_glyphs() {
+        let glyph_id = GlyphId(glyph_id);
+        let name = table.glyph_name(glyph_id).ok_or(CFFError::NoGlyph)?;
+        char_strings.push((name, convert_char_string(table, glyph_id, &mut subrs)?));
+    }
+
+    let name = table.name().unwrap_or("Untitled");
+    let clear_text = build_clear_text(table, name);
+    let private = build_private(&table.type1_private_dict(), &subrs, &char_strings);
+
+    Ok(write_program(clear_text.as_bytes(), &private, format))
+}
+
+// Encrypts the private part of a font program and joins it with the clear text.
+pub(super) fn write_program(clear_text: &[u8], private: &[u8], format: Format) -> Vec<u8> {
+    let mut r = EEXEC_KEY;
+    let encrypted: Vec<u8> = [0; LEN_IV]
+        .iter()
+        .chain(private)
+        .map(|b| encrypt_byte(*b, &mut r))
+        .collect();
+
+    // The encrypted part is followed by 512 zeros and `cleartomark`.
+    let mut trailer = String::new();
+    for _ in 0..8 {
+        trailer.push_str(&"0".repeat(64));
+        trailer.push('\n');
+    }
+    trailer.push_str("cleartomark\n");
+
+    let mut data = Vec::new();
+    match format {
+        Format::Pfa => {
+            data.extend_from_slice(clear_text);
+            for line in encrypted.chunks(32) {
+                for b in line {
+                    write!(data, "{:02x}", b).unwrap();
+                }
+                data.push(b'\n');
+            }
+            data.extend_from_slice(trailer.as_bytes());
+        }
+        Format::Pfb => {
+            write_segment(1, clear_text, &mut data);
+            write_segment(2, &encrypted, &mut data);
+            write_segment(1, trailer.as_bytes(), &mut data);
+            data.extend_from_slice(&[0x80, 3]);
+        }
+    }
+
+    data
+}
+
+fn write_segment(kind: u8, segment: &[u8], out: &mut Vec<u8>) {
+    out.extend_from_slice(&[0x80, kind]);
+    out.extend_from_slice(&(segment.len() as u32).to_le_bytes());
+    out.extend_from_slice(segment);
+}
+
+// Returns the unencrypted part of the font program, up to `eexec`.
+fn build_clear_text(table: &cff::Table, name: &str) -> String {
+    let info = table.font_info();
+    let mut s = String::new();
+
+    writeln!(
+        s,
+        "%!PS-AdobeFont-1.0: {} {}",
+        name,
+        info.version.as_deref().unwrap_or("")
+    )
+    .unwrap();
+    s.push_str("11 dict begin\n");
+    write_font_info(&info, &mut s);
+    writeln!(s, "/FontName /{} def", name).unwrap();
+
+    match table.encoding() {
+        Some(encoding) if encoding.kind() != EncodingKind::Standard => {
+            s.push_str("/Encoding 256 array\n0 1 255 {1 index exch /.notdef put} for\n");
+            for (code, glyph_id) in encoding.iter() {
+                if let Some(name) = table.glyph_name(glyph_id) {
+                    writeln!(s, "dup {} /{} put", code, name).unwrap();
+                }
+            }
+            s.push_str("readonly def\n");
+        }
+        _ => s.push_str("/Encoding StandardEncoding def\n"),
+    }
+
+    s.push_str("/PaintType 0 def\n/FontType 1 def\n");
+    let Matrix {
+        sx,
+        ky,
+        kx,
+        sy,
+        tx,
+        ty,
+    } = table.matrix();
+    writeln!(
+        s,
+        "/FontMatrix [{} {} {} {} {} {}] readonly def",
+        sx, ky, kx, sy, tx, ty
+    )
+    .unwrap();
+
+    let bbox = table
+        .font_bbox()
+        .map_or([0.0; 4], |b| [b.x_min, b.y_min, b.x_max, b.y_max]);
+    writeln!(s, "/FontBBox {{{}}} readonly def", join_numbers(&bbox)).unwrap();
+
+    if let Some(unique_id) = table.unique_id() {
+        writeln!(s, "/UniqueID {} def", unique_id).unwrap();
+    }
+
+    s.push_str("currentdict end\ncurrentfile eexec\n");
+    s
+}
+
+pub(super) fn write_font_info(info: &FontInfo, s: &mut String) {
+    let strings = [
+        ("version", &info.version),
+        ("Notice", &info.notice),
+        ("Copyright", &info.copyright),
+        ("FullName", &info.full_name),
+        ("FamilyName", &info.family_name),
+        ("Weight", &info.weight),
+    ];
+
+    let len = strings.iter().filter(|(_, value)| value.is_some()).count() + 4;
+    writeln!(s, "/FontInfo {} dict dup begin", len).unwrap();
+    for (key, value) in strings {
+        if let Some(value) = value {
+            writeln!(s, "/{} ({}) readonly def", key, escape_string(value)).unwrap();
+        }
+    }
+
+    writeln!(s, "/ItalicAngle {} def", info.italic_angle).unwrap();
+    writeln!(s, "/isFixedPitch {} def", info.is_fixed_pitch).unwrap();
+    writeln!(s, "/UnderlinePosition {} def", info.underline_position).unwrap();
+    writeln!(s, "/UnderlineThickness {} def", info.underline_thickness).unwrap();
+    s.push_str("end readonly def\n");
+}
+
+// Escapes a PostScript string literal. Characters outside of Latin-1 are replaced.
+fn escape_string(string: &str) -> String {
+    let mut escaped = String::new();
+    for c in string.chars() {
+        match c {
+            '(' | ')' | '\\' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            ' '..='~' => escaped.push(c),
+            _ => {
+                let b = u8::try_from(u32::from(c)).unwrap_or(b'?');
+                write!(escaped, "\\{:03o}", b).unwrap();
+            }
+        }
+    }
+
+    escaped
+}
+
+// Returns the plain text of the encrypted part of the font program.
+fn build_private(
+    private: &PrivateDict,
+    subrs: &[Vec<u8>],
+    char_strings: &[(&str, Vec<u8>)],
+) -> Vec<u8> {
+    let mut s = String::new();
+    s.push_str("dup /Private 20 dict dup begin\n");
+    s.push_str("/RD {string currentfile exch readstring pop} executeonly def\n");
+    s.push_str("/ND {noaccess def} executeonly def\n");
+    s.push_str("/NP {noaccess put} executeonly def\n");
+
+    // BlueValues is required, even if it's empty.
+    writeln!(
+        s,
+        "/BlueValues [{}] def",
+        join_numbers(&private.blue_values)
+    )
+    .unwrap();
+    let arrays = [
+        ("OtherBlues", &private.other_blues),
+        ("FamilyBlues", &private.family_blues),
+        ("FamilyOtherBlues", &private.family_other_blues),
+        ("StemSnapH", &private.stem_snap_h),
+        ("StemSnapV", &private.stem_snap_v),
+    ];
+    for (key, values) in arrays {
+        if !values.is_empty() {
+            writeln!(s, "/{} [{}] def", key, join_numbers(values)).unwrap();
+        }
+    }
+
+    let numbers = [
+        ("BlueScale", private.blue_scale),
+        ("BlueShift", private.blue_shift),
+        ("BlueFuzz", private.blue_fuzz),
+    ];
+    for (key, value) in numbers {
+        if let Some(value) = value {
+            writeln!(s, "/{} {} def", key, value).unwrap();
+        }
+    }
+
+    // Type 1 stores the dominant stems as arrays.
+    for (key, value) in [("StdHW", private.std_hw), ("StdVW", private.std_vw)] {
+        if let Some(value) = value {
+            writeln!(s, "/{} [{}] def", key, value).unwrap();
+        }
+    }
+
+    if private.force_bold {
+        s.push_str("/ForceBold true def\n");
+    }
+
+    if let Some(language_group) = private.language_group {
+        writeln!(s, "/LanguageGroup {} def", language_group).unwrap();
+    }
+
+    s.push_str("/MinFeature {16 16} def\n/password 5839 def\n");
+    s.push_str(OTHER_SUBRS);
+
+    let mut data = s.into_bytes();
+    writeln!(data, "/Subrs {} array", subrs.len()).unwrap();
+    for (index, subr) in subrs.iter().enumerate() {
+        let subr = encrypt_char_string(subr);
+        write!(data, "dup {} {} RD ", index, subr.len()).unwrap();
+        data.extend_from_slice(&subr);
+        data.extend_from_slice(b" NP\n");
+    }
+    data.extend_from_slice(b"ND\n");
+
+    writeln!(
+        data,
+        "2 index /CharStrings {} dict dup begin",
+        char_strings.len()
+    )
+    .unwrap();
+    for (name, char_string) in char_strings {
+        let char_string = encrypt_char_string(char_string);
+        write!(data, "/{} {} RD ", name, char_string.len()).unwrap();
+        data.extend_from_slice(&char_string);
+        data.extend_from_slice(b" ND\n");
+    }
+    data.extend_from_slice(b"end\nend\nreadonly put\nnoaccess put\n");
+    data.extend_from_slice(b"dup /FontName get exch definefont pop\n");
+    data.extend_from_slice(b"mark currentfile closefile\n");
+
+    data
+}
+
+fn join_numbers(values: &[f32]) -> String {
+    let values: Vec<String> = values.iter().map(|n| n.to_string()).collect();
+    values.join(" ")
+}
+
+pub(super) fn encrypt_char_string(data: &[u8]) -> Vec<u8> {
+    let mut r = CHAR_STRING_KEY;
+    [0; LEN_IV]
+        .iter()
+        .chain(data)
+        .map(|b| encrypt_byte(*b, &mut r))
+        .collect()
+}
+
+fn convert_char_string(
+    table: &cff::Table,
+    glyph_id: GlyphId,
+    subrs: &mut Vec<Vec<u8>>,
+) -> Result<Vec<u8>, CFFError> {
+    let mut path = PathRecorder::new();
+    let (side_bearing, hints) = match table.outline_with_hints(glyph_id, &mut path) {
+        Ok((bbox, hints)) => (bbox.x_min, hints),
+        // Glyphs without an outline, like `space`.
+        Err(CFFError::ZeroBBox) => (0, Vec::new()),
+        Err(e) => return Err(e),
+    };
+    let side_bearing = f32::from(side_bearing);
+    let width = table.glyph_width(glyph_id).unwrap_or(0);
+
+    let mut stems = Vec::new();
+    let mut masks = Vec::new();
+    for (position, hint) in hints {
+        match hint {
+            Hint::Stem {
+                horizontal,
+                position,
+                width,
+            } => stems.push((horizontal, position, width)),
+            Hint::Mask(mask) => masks.push((position, mask)),
+        }
+    }
+
+    let mut writer = CharStringWriter {
+        data: Vec::new(),
+        x: side_bearing,
+        y: 0.0,
+    };
+    writer.number(side_bearing);
+    writer.number(f32::from(width));
+    writer.op(&[sb_operator::HSBW]);
+
+    // All stems are active until the first `hintmask`.
+    let mut masks = masks.into_iter().peekable();
+    let mut active = vec![0; (stems.len() + 7) / 8];
+    for index in 0..stems.len() {
+        active[index / 8] |= 0x80 >> (index % 8);
+    }
+    while let Some((_, mask)) = masks.next_if(|(position, _)| *position == 0) {
+        active = mask;
+    }
+    writer.stems(&stems, &active, side_bearing);
+
+    let mut pending = None;
+    for (index, segment) in path.into_segments().into_iter().enumerate() {
+        while let Some((_, mask)) = masks.next_if(|(position, _)| *position == index) {
+            pending = Some(mask);
+        }
+
+        // Hints are replaced before the next path point.
+        if !matches!(segment, PathSegment::Close) {
+            if let Some(mask) = pending.take().filter(|mask| *mask != active) {
+                let mut subr = CharStringWriter {
+                    data: Vec::new(),
+                    x: 0.0,
+                    y: 0.0,
+                };
+                subr.stems(&stems, &mask, side_bearing);
+                subr.op(&[sb_operator::RETURN]);
+
+                let subr_index = match subrs.iter().position(|s| *s == subr.data) {
+                    Some(subr_index) => subr_index,
+                    None => {
+                        subrs.push(subr.data);
+                        subrs.len() - 1
+                    }
+                };
+
+                // subr# 1 3 callothersubr pop callsubr
+                writer.number(subr_index as f32);
+                writer.number(1.0);
+                writer.number(3.0);
+                writer.op(&[sb_operator::ESCAPE, tb_operator::CALL_OTHER_SUBR]);
+                writer.op(&[sb_operator::ESCAPE, tb_operator::POP]);
+                writer.op(&[sb_operator::CALL_SUBR]);
+                active = mask;
+            }
+        }
+
+        match segment {
+            PathSegment::MoveTo(x, y) => writer.move_to(x, y),
+            PathSegment::LineTo(x, y) => writer.line_to(x, y),
+            PathSegment::QuadTo(x1, y1, x, y) => {
+                // Quadratic curves are elevated to cubic ones.
+                let (x0, y0) = (writer.x, writer.y);
+                writer.curve_to(
+                    x0 + (x1 - x0) * 2.0 / 3.0,
+                    y0 + (y1 - y0) * 2.0 / 3.0,
+                    x + (x1 - x) * 2.0 / 3.0,
+                    y + (y1 - y) * 2.0 / 3.0,
+                    x,
+                    y,
+                );
+            }
+            PathSegment::CurveTo(x1, y1, x2, y2, x, y) => writer.curve_to(x1, y1, x2, y2, x, y),
+            PathSegment::Close => writer.op(&[sb_operator::CLOSE_PATH]),
+        }
+    }
+
+    writer.op(&[sb_operator::ENDCHAR]);
+    Ok(writer.data)
+}
+
+// Writes a Type 1 charstring from absolute coordinates.
+struct CharStringWriter {
+    data: Vec<u8>,
+    // The current point, as written.
+    x: f32,
+    y: f32,
+}
+
+impl CharStringWriter {
+    // Writes the stems that are set in a hint mask.
+    // Type 1 vertical stems are relative to the left sidebearing.
+    fn stems(&mut self, stems: &[(bool, f32, f32)], mask: &[u8], side_bearing: f32) {
+        for (index, &(horizontal, position, width)) in stems.iter().enumerate() {
+            if mask
+                .get(index / 8)
+                .map_or(false, |b| b & (0x80 >> (index % 8)) != 0)
+            {
+                if horizontal {
+                    self.number(position);
+                    self.number(width);
+                    self.op(&[sb_operator::HORIZONTAL_STEM]);
+                } else {
+                    self.number(position - side_bearing);
+                    self.number(width);
+                    self.op(&[sb_operator::VERTICAL_STEM]);
+                }
+            }
+        }
+    }
+
+    fn move_to(&mut self, x: f32, y: f32) {
+        let (dx, dy) = self.delta(x, y);
+        if dy == 0.0 {
+            self.number(dx);
+            self.op(&[sb_operator::HORIZONTAL_MOVE_TO]);
+        } else if dx == 0.0 {
+            self.number(dy);
+            self.op(&[sb_operator::VERTICAL_MOVE_TO]);
+        } else {
+            self.number(dx);
+            self.number(dy);
+            self.op(&[sb_operator::MOVE_TO]);
+        }
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        let (dx, dy) = self.delta(x, y);
+        if dy == 0.0 {
+            self.number(dx);
+            self.op(&[sb_operator::HORIZONTAL_LINE_TO]);
+        } else if dx == 0.0 {
+            self.number(dy);
+            self.op(&[sb_operator::VERTICAL_LINE_TO]);
+        } else {
+            self.number(dx);
+            self.number(dy);
+            self.op(&[sb_operator::LINE_TO]);
+        }
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        for (x, y) in [(x1, y1), (x2, y2), (x, y)] {
+            let (dx, dy) = self.delta(x, y);
+            self.number(dx);
+            self.number(dy);
+        }
+        self.op(&[sb_operator::CURVE_TO]);
+    }
+
+    // Moves the current point and returns the rounded offset.
+    fn delta(&mut self, x: f32, y: f32) -> (f32, f32) {
+        let dx = ((x - self.x) * PRECISION).round() / PRECISION;
+        let dy = ((y - self.y) * PRECISION).round() / PRECISION;
+        self.x += dx;
+        self.y += dy;
+        (dx, dy)
+    }
+
+    fn number(&mut self, n: f32) {
+        if n.fract() == 0.0 {
+            write_int(n as i32, &mut self.data);
+        } else {
+            // Type 1 charstrings only have integers, so fractions are written as a division.
+            write_int((n * PRECISION) as i32, &mut self.data);
+            write_int(PRECISION as i32, &mut self.data);
+            self.op(&[sb_operator::ESCAPE, tb_operator::DIV]);
+        }
+    }
+
+    fn op(&mut self, op: &[u8]) {
+        self.data.extend_from_slice(op);
+    }
+}
+
+fn write_int(n: i32, out: &mut Vec<u8>) {
+    match n {
+        -107..=107 => out.push((n + 139) as u8),
+        108..=1131 => {
+            let n = n - 108;
+            out.extend_from_slice(&[(n >> 8) as u8 + 247, n as u8]);
+        }
+        -1131..=-108 => {
+            let n = -n - 108;
+            out.extend_from_slice(&[(n >> 8) as u8 + 251, n as u8]);
+        }
+        _ => {
+            out.push(255);
+            out.extend_from_slice(&n.to_be_bytes());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{build_private, from_cff, Format};
+    use crate::asm::assemble;
+    use crate::disasm::{CharStringKind, Disassembler};
+    use crate::outline::PathRecorder;
+    use crate::type1::decrypt::decrypt;
+    use crate::type1::PrivateDict;
+    use crate::{cff, type1, EncodingKind, GlyphId, RectF};
+
+    // Builds a CFF font with a custom encoding, FontInfo strings and hinting values.
+    fn build_font() -> Vec<u8> {
+        let type2 = |text| assemble(text, CharStringKind::Type2).unwrap();
+        cff::FontBuilder::new("Test")
+            .glyph(".notdef", &type2("endchar"))
+            .glyph("space", &type2("-350 endchar"))
+            .glyph(
+                "A",
+                &type2(
+                    "0 50 hstemhm 20 80 vstemhm hintmask 0b11000000 20 0 rmoveto 700 vlineto \
+                     hintmask 0b01000000 -107 callgsubr 400 hlineto -700 vlineto endchar",
+                ),
+            )
+            .glyph(
+                "B",
+                &type2(
+                    "100 20 0 rmoveto 30 20 40 0 30 0 30 0 40 0 30 -20 50 flex \
+                     -199.5 0 rlineto 2 3 add vlineto -107 callsubr endchar",
+                ),
+            )
+            .glyph(
+                "acute",
+                &type2("100 500 rmoveto 50 100 rlineto 30 -10 rlineto endchar"),
+            )
+            .glyph("Aacute", &type2("100 50 65 194 endchar"))
+            .global_subroutine(&type2("100 hlineto return"))
+            .local_subroutine(&type2("-5 vlineto return"))
+            .font_info(type1::FontInfo {
+                version: Some("Version 1.001".to_string()),
+                notice: Some("Test (c) font".to_string()),
+                full_name: Some("Test Regular".to_string()),
+                italic_angle: -12.0,
+                ..type1::FontInfo::default()
+            })
+            .font_bbox(RectF {
+                x_min: -10.0,
+                y_min: -20.0,
+                x_max: 700.0,
+                y_max: 710.0,
+            })
+            .encoding(32, "space")
+            .encoding(65, "A")
+            .encoding(66, "B")
+            .encoding(194, "acute")
+            .encoding(193, "Aacute")
+            // BlueValues and StdHW.
+            .private_entry(6, &[-10.0, 10.0, 700.0, 10.0])
+            .private_entry(10, &[50.0])
+            .default_width(500.0)
+            .nominal_width(600.0)
+            .build()
+    }
+
+    fn check_glyphs(cff: &cff::Table, type1: &type1::Table) {
+        assert_eq!(type1.number_of_glyphs(), cff.number_of_glyphs());
+        for glyph_id in 0..cff.number_of_glyphs() {
+            let glyph_id = GlyphId(glyph_id);
+            let name = cff.glyph_name(glyph_id).unwrap();
+            assert_eq!(
+                type1.glyph_width(name),
+                cff.glyph_width(glyph_id).map(f32::from),
+                "glyph {}",
+                name
+            );
+
+            let mut expected = PathRecorder::new();
+            let mut actual = PathRecorder::new();
+            let _ = cff.outline(glyph_id, &mut expected);
+            type1.outline(name, &mut actual);
+            assert_eq!(actual.segments(), expected.segments(), "glyph {}", name);
+        }
+    }
+
+    #[test]
+    fn convert_to_pfa() {
+        let data = build_font();
+        let cff = cff::Table::parse(&data).unwrap();
+        let pfa = from_cff(&cff, Format::Pfa).unwrap();
+        assert!(pfa.starts_with(b"%!PS-AdobeFont-1.0: Test Version 1.001\n"));
+        assert!(pfa.ends_with(b"cleartomark\n"));
+
+        let type1 = type1::Table::parse(&pfa).unwrap();
+        check_glyphs(&cff, &type1);
+
+        assert_eq!(type1.name(), Some("Test"));
+        let info = type1.font_info();
+        assert_eq!(info.version.as_deref(), Some("Version 1.001"));
+        assert_eq!(info.notice.as_deref(), Some("Test (c) font"));
+        assert_eq!(info.full_name.as_deref(), Some("Test Regular"));
+        assert_eq!(info.italic_angle, -12.0);
+        assert_eq!(type1.font_bbox(), cff.font_bbox());
+
+        let private = type1.private_dict();
+        assert_eq!(private.blue_values, [-10.0, 0.0, 700.0, 710.0]);
+        assert_eq!(private.std_hw, Some(50.0));
+
+        let encoding = type1.encoding();
+        assert_eq!(encoding.kind(), EncodingKind::Custom);
+        assert_eq!(encoding.glyph_name(32), Some("space"));
+        assert_eq!(encoding.glyph_name(193), Some("Aacute"));
+        assert_eq!(encoding.glyph_name(97), None);
+    }
+
+    #[test]
+    fn convert_hints() {
+        let data = build_font();
+        let cff = cff::Table::parse(&data).unwrap();
+        let pfa = from_cff(&cff, Format::Pfa).unwrap();
+        let type1 = type1::Table::parse(&pfa).unwrap();
+        let disasm = Disassembler::from_type1(&type1);
+        let glyph_id = type1.glyph_index_by_name("A").unwrap();
+        assert_eq!(
+            disasm.glyph(glyph_id).unwrap(),
+            "20 500 hsbw  % width 500\n\
+             0 50 hstem\n\
+             0 80 vstem\n\
+             0 hmoveto\n\
+             700 vlineto\n\
+             4 1 3 callothersubr  % replace hints\n\
+             pop\n\
+             callsubr  % subr 4\n\
+             100 hlineto\n\
+             400 hlineto\n\
+             -700 vlineto\n\
+             closepath\n\
+             endchar\n"
+        );
+
+        // The replaced hints only keep the vertical stem.
+        let subr = type1.subroutine(4).unwrap();
+        assert_eq!(
+            disasm.char_string(subr, CharStringKind::Type1),
+            "0 80 vstem\nreturn\n"
+        );
+    }
+
+    #[test]
+    fn other_subrs() {
+        let data = build_font();
+        let cff = cff::Table::parse(&data).unwrap();
+        let pfa = from_cff(&cff, Format::Pfa).unwrap();
+
+        // Hint replacement calls the OtherSubrs procedure 3.
+        let start = pfa.windows(6).position(|w| w == b"eexec\n").unwrap() + 6;
+        let private = decrypt(&pfa[start..]).unwrap();
+        let private = String::from_utf8_lossy(&private);
+        assert!(private.contains("\n/OtherSubrs\n[{}{}{}\n{\n"));
+        assert!(private.contains("] noaccess def\n/Subrs 5 array\n"));
+    }
+
+    #[test]
+    fn empty_blue_values() {
+        let private = build_private(&PrivateDict::default(), &[], &[]);
+        let private = String::from_utf8(private).unwrap();
+        assert!(private.contains("\n/BlueValues [] def\n"));
+    }
+
+    #[test]
+    fn convert_to_pfb() {
+        let data = build_font();
+        let cff = cff::Table::parse(&data).unwrap();
+        let pfb = from_cff(&cff, Format::Pfb).unwrap();
+
+        // Joins the ASCII, binary and ASCII segments.
+        let mut font = Vec::new();
+        let mut rest = &pfb[..];
+        for kind in [1, 2, 1] {
+            assert_eq!(rest[..2], [0x80, kind]);
+            let len = u32::from_le_bytes([rest[2], rest[3], rest[4], rest[5]]) as usize;
+            font.extend_from_slice(&rest[6..6 + len]);
+            rest = &rest[6 + len..];
+        }
+        assert_eq!(rest, [0x80, 3]);
+
+        let type1 = type1::Table::parse(&font).unwrap();
+        check_glyphs(&cff, &type1);
+    }
+}
@@ -1,15 +1,22 @@
-mod charstring;
+mod builder;
+mod char_map;
+pub(crate) mod charstring;
 mod charstring_parser;
-mod decrypt;
-mod operator;
-mod standard;
+pub(crate) mod decrypt;
+mod from_cff;
+pub(crate) mod operator;
 pub(crate) mod stream;
 
-use crate::type1::charstring::parse_char_string;
+pub use builder::FontBuilder;
+pub use char_map::CharMap;
+pub use from_cff::{from_cff, Format};
+
+use crate::encoding::STANDARD;
+use crate::type1::charstring::{parse_char_string, CharStringResolver};
 use crate::type1::decrypt::{decrypt, decrypt_byte};
-use crate::type1::standard::STANDARD;
 use crate::type1::stream::Stream;
-use crate::{Matrix, OutlineBuilder};
+use crate::{DummyOutline, EncodingKind, GlyphId, Matrix, OutlineBuilder, Rect, RectF};
+use core::convert::TryFrom;
 use log::error;
 use std::collections::HashMap;
 use std::iter::Copied;
@@ -25,6 +32,24 @@ pub(crate) struct Parameters {
     encoding_type: EncodingType,
     subroutines: HashMap<u32, Vec<u8>>,
     charstrings: HashMap<String, Vec<u8>>,
+    // The glyph names in glyph ID order.
+    glyph_names: Vec<String>,
+    font_name: Option<String>,
+    font_info: FontInfo,
+    font_bbox: Option<[f32; 4]>,
+    unique_id: Option<i32>,
+    private_dict: PrivateDict,
+}
+
+impl CharStringResolver for Parameters {
+    fn subroutine(&self, index: u32) -> Option<&[u8]> {
+        self.subroutines.get(&index).map(Vec::as_slice)
+    }
+
+    fn seac_char_string(&self, code: u8) -> Option<&[u8]> {
+        let name = self.encoding_type.encode(code)?;
+        self.charstrings.get(name).map(Vec::as_slice)
+    }
 }
 
 impl Default for Parameters {
@@ -34,10 +59,76 @@ impl Default for Parameters {
             encoding_type: EncodingType::Standard,
             subroutines: HashMap::new(),
             charstrings: HashMap::new(),
+            glyph_names: Vec::new(),
+            font_name: None,
+            font_info: FontInfo::default(),
+            font_bbox: None,
+            unique_id: None,
+            private_dict: PrivateDict::default(),
+        }
+    }
+}
+
+/// The entries of the `FontInfo` dictionary of a Type 1 font.
+#[derive(Clone, PartialEq, Debug)]
+pub struct FontInfo {
+    /// The version of the font program.
+    pub version: Option<String>,
+    /// The trademark notice.
+    pub notice: Option<String>,
+    /// The copyright notice.
+    pub copyright: Option<String>,
+    /// The full name of the font, like `Times Bold`.
+    pub full_name: Option<String>,
+    /// The family name of the font, like `Times`.
+    pub family_name: Option<String>,
+    /// The weight of the font, like `Bold`.
+    pub weight: Option<String>,
+    /// The angle of the dominant vertical strokes in degrees counterclockwise from the vertical.
+    pub italic_angle: f32,
+    /// Whether all glyphs have the same width.
+    pub is_fixed_pitch: bool,
+    /// The position of the underline.
+    pub underline_position: f32,
+    /// The thickness of the underline.
+    pub underline_thickness: f32,
+}
+
+impl Default for FontInfo {
+    fn default() -> Self {
+        Self {
+            version: None,
+            notice: None,
+            copyright: None,
+            full_name: None,
+            family_name: None,
+            weight: None,
+            italic_angle: 0.0,
+            is_fixed_pitch: false,
+            underline_position: -100.0,
+            underline_thickness: 50.0,
         }
     }
 }
 
+/// The hinting values of the `Private` dictionary.
+#[derive(Clone, Default, Debug)]
+pub(crate) struct PrivateDict {
+    pub(crate) blue_values: Vec<f32>,
+    pub(crate) other_blues: Vec<f32>,
+    pub(crate) family_blues: Vec<f32>,
+    pub(crate) family_other_blues: Vec<f32>,
+    pub(crate) blue_scale: Option<f32>,
+    pub(crate) blue_shift: Option<f32>,
+    pub(crate) blue_fuzz: Option<f32>,
+    pub(crate) std_hw: Option<f32>,
+    pub(crate) std_vw: Option<f32>,
+    pub(crate) stem_snap_h: Vec<f32>,
+    pub(crate) stem_snap_v: Vec<f32>,
+    pub(crate) force_bold: bool,
+    pub(crate) language_group: Option<i32>,
+}
+
 #[derive(Debug, Clone)]
 pub struct Table<'a> {
     data: &'a [u8],
@@ -58,16 +149,25 @@ impl<'a> Table<'a> {
 
         while let Some(token) = s.next_token() {
             match token {
-                b"/FontInfo" => s.skip_dict(),
-                b"/FontName" => s.skip_token(),
+                b"/FontInfo" => params.font_info = s.read_font_info(),
+                b"/FontName" => {
+                    params.font_name = s
+                        .next_token()
+                        .and_then(|t| t.strip_prefix(b"/"))
+                        .and_then(|t| core::str::from_utf8(t).ok())
+                        .map(str::to_string);
+                }
                 b"/PaintType" => s.skip_token(),
                 b"/FontType" => s.skip_token(),
-                b"/FontBBox" => s.skip_token(),
-                b"/UniqueID" => s.skip_token(),
+                b"/FontBBox" => {
+                    let bbox = s.read_number_array();
+                    params.font_bbox = <[f32; 4]>::try_from(bbox.as_slice()).ok();
+                }
+                b"/UniqueID" => params.unique_id = s.read_number().map(|n| n as i32),
                 b"/Metrics" => s.skip_dict(),
                 b"/StrokeWidth" => s.skip_token(),
                 b"/FontMatrix" => {
-                    let matrix = s.read_font_matrix();
+                    let matrix = s.read_font_matrix()?;
                     params.font_matrix = Matrix {
                         sx: matrix[0],
                         kx: matrix[1],
@@ -77,25 +177,32 @@ impl<'a> Table<'a> {
                         ty: matrix[5],
                     };
                 }
-                b"/Encoding" => params.encoding_type = s.read_encoding(),
+                b"/Encoding" => params.encoding_type = s.read_encoding()?,
                 b"eexec" => {
-                    let decrypted = decrypt(s.tail().unwrap());
-                    Self::parse_eexec(&decrypted, &mut params);
-                }
-                b"/Private" => {
-                    println!("reached private dict");
+                    let decrypted = decrypt(s.tail()?)?;
+                    Self::parse_eexec(&decrypted, &mut params)?;
                 }
                 _ => {}
             }
         }
 
+        params.glyph_names = Self::build_glyph_names(&params);
+
         Some(Self {
             data,
             params: Arc::new(params),
         })
     }
 
-    fn parse_eexec(data: &[u8], params: &mut Parameters) {
+    fn build_glyph_names(params: &Parameters) -> Vec<String> {
+        // Type 1 fonts don't have glyph IDs, so the names are sorted
+        // to get a stable order, with `.notdef` being the first glyph.
+        let mut names: Vec<String> = params.charstrings.keys().cloned().collect();
+        names.sort_unstable_by(|a, b| (a != ".notdef", a).cmp(&(b != ".notdef", b)));
+        names
+    }
+
+    fn parse_eexec(data: &[u8], params: &mut Parameters) -> Option<()> {
         let mut s = Stream::new(data);
 
         let mut lenIv = 4;
@@ -103,17 +210,36 @@ impl<'a> Table<'a> {
         while let Some(token) = s.next_token() {
             match token {
                 b"/Subrs" => {
-                    params.subroutines = s.parse_subroutines(lenIv);
+                    params.subroutines = s.parse_subroutines(lenIv)?;
                 }
                 b"/CharStrings" => {
-                    params.charstrings = s.parse_charstrings(lenIv).unwrap();
+                    params.charstrings = s.parse_charstrings(lenIv)?;
                 }
                 b"/lenIV" => {
-                    lenIv = s.next_int() as usize;
+                    lenIv = usize::try_from(s.next_int()?).ok()?;
+                }
+                b"/BlueValues" => params.private_dict.blue_values = s.read_number_array(),
+                b"/OtherBlues" => params.private_dict.other_blues = s.read_number_array(),
+                b"/FamilyBlues" => params.private_dict.family_blues = s.read_number_array(),
+                b"/FamilyOtherBlues" => {
+                    params.private_dict.family_other_blues = s.read_number_array();
+                }
+                b"/BlueScale" => params.private_dict.blue_scale = s.read_number(),
+                b"/BlueShift" => params.private_dict.blue_shift = s.read_number(),
+                b"/BlueFuzz" => params.private_dict.blue_fuzz = s.read_number(),
+                b"/StdHW" => params.private_dict.std_hw = s.read_number_array().first().copied(),
+                b"/StdVW" => params.private_dict.std_vw = s.read_number_array().first().copied(),
+                b"/StemSnapH" => params.private_dict.stem_snap_h = s.read_number_array(),
+                b"/StemSnapV" => params.private_dict.stem_snap_v = s.read_number_array(),
+                b"/ForceBold" => params.private_dict.force_bold = s.next_token() == Some(b"true"),
+                b"/LanguageGroup" => {
+                    params.private_dict.language_group = s.read_number().map(|n| n as i32);
                 }
                 _ => {}
             }
         }
+
+        Some(())
     }
 
     /// Returns a font transformation matrix.
@@ -122,18 +248,105 @@ impl<'a> Table<'a> {
         self.params.font_matrix
     }
 
-    /// Outlines a glyph.
-    pub fn outline(&self, string: &str, builder: &mut dyn OutlineBuilder) -> Option<()> {
+    /// Returns the font name.
+    pub fn name(&self) -> Option<&str> {
+        self.params.font_name.as_deref()
+    }
+
+    /// Returns the entries of the `FontInfo` dictionary.
+    pub fn font_info(&self) -> &FontInfo {
+        &self.params.font_info
+    }
+
+    /// Returns the font bounding box from the `FontBBox` entry.
+    pub fn font_bbox(&self) -> Option<RectF> {
+        let [x_min, y_min, x_max, y_max] = self.params.font_bbox?;
+        Some(RectF {
+            x_min,
+            y_min,
+            x_max,
+            y_max,
+        })
+    }
+
+    /// Returns the unique ID of the font.
+    pub fn unique_id(&self) -> Option<i32> {
+        self.params.unique_id
+    }
+
+    pub(crate) fn private_dict(&self) -> &PrivateDict {
+        &self.params.private_dict
+    }
+
+    pub(crate) fn char_string(&self, name: &str) -> Option<&[u8]> {
+        self.params.charstrings.get(name).map(Vec::as_slice)
+    }
+
+    pub(crate) fn subroutine(&self, index: u32) -> Option<&[u8]> {
+        self.params.subroutine(index)
+    }
+
+    /// Returns the number of glyphs in the font.
+    pub fn number_of_glyphs(&self) -> u16 {
+        u16::try_from(self.params.glyph_names.len()).unwrap_or(u16::MAX)
+    }
+
+    /// Returns a glyph name by its glyph ID.
+    ///
+    /// Type 1 fonts don't have glyph IDs, so `.notdef` is assigned
+    /// the ID 0 and the other glyphs are numbered by name.
+    pub fn glyph_name(&self, glyph_id: GlyphId) -> Option<&str> {
+        self.params
+            .glyph_names
+            .get(usize::from(glyph_id.0))
+            .map(String::as_str)
+    }
+
+    /// Returns a glyph ID by a name.
+    ///
+    /// See [`Table::glyph_name`] for how glyph IDs are assigned.
+    pub fn glyph_index_by_name(&self, name: &str) -> Option<GlyphId> {
+        let names = &self.params.glyph_names;
+        let offset = usize::from(names.first().map(String::as_str) == Some(".notdef"));
+        if name == ".notdef" {
+            return if offset == 1 { Some(GlyphId(0)) } else { None };
+        }
+
+        let index = names[offset..]
+            .binary_search_by(|n| n.as_str().cmp(name))
+            .ok()?;
+        u16::try_from(index + offset).ok().map(GlyphId)
+    }
+
+    /// Outlines a glyph and returns its bounding box.
+    pub fn outline(&self, string: &str, builder: &mut dyn OutlineBuilder) -> Option<Rect> {
         let data = self.params.charstrings.get(string)?;
+        let (bbox, _) = parse_char_string(data, self.params.as_ref(), builder).ok()?;
 
-        parse_char_string(data, &self.params, builder).unwrap();
+        if bbox.is_default() {
+            return Some(Rect::zero());
+        }
 
-        Some(())
+        bbox.to_rect()
+    }
+
+    /// Returns the advance width of a glyph as set by `hsbw` or `sbw`.
+    pub fn glyph_width(&self, string: &str) -> Option<f32> {
+        let data = self.params.charstrings.get(string)?;
+        let (_, width) = parse_char_string(data, self.params.as_ref(), &mut DummyOutline).ok()?;
+        width
     }
 
     pub fn code_to_string(&self, code_point: u8) -> Option<&str> {
         self.params.encoding_type.encode(code_point)
     }
+
+    /// Returns the encoding of the font.
+    pub fn encoding(&self) -> Encoding<'_> {
+        Encoding {
+            encoding_type: &self.params.encoding_type,
+        }
+    }
 }
 
 const ND: &[u8] = b"ND";
@@ -146,8 +359,8 @@ const NP: &[u8] = b"NP";
 const NP_ALT: &[u8] = b"|";
 
 impl<'a> Stream<'a> {
-    fn next_int(&mut self) -> i32 {
-        i32::from_str(std::str::from_utf8(self.next_token().unwrap()).unwrap()).unwrap()
+    fn next_int(&mut self) -> Option<i32> {
+        i32::from_str(std::str::from_utf8(self.next_token()?).ok()?).ok()
     }
 
     fn parse_charstrings(&mut self, len_iv: usize) -> Option<HashMap<String, Vec<u8>>> {
@@ -167,13 +380,13 @@ impl<'a> Stream<'a> {
                 .iter()
                 .all(|b| matches!(*b, b'#') || b.is_ascii_digit())
             {
-                int_token = Some(i32::from_str(std::str::from_utf8(token).unwrap()).unwrap());
+                int_token = Some(i32::from_str(std::str::from_utf8(token).ok()?).ok()?);
             } else if token == RD || token == RD_ALT {
                 break;
             }
         }
 
-        let (first_glyph_name, int_token) = (first_glyph_name.unwrap(), int_token.unwrap());
+        let (first_glyph_name, int_token) = (first_glyph_name?, int_token?);
 
         let mut is_first = true;
 
@@ -192,7 +405,7 @@ impl<'a> Stream<'a> {
 
                 self.read_byte();
             } else {
-                let tok = self.next_token().unwrap();
+                let tok = self.next_token()?;
                 if tok == b"end" {
                     break;
                 }
@@ -203,45 +416,45 @@ impl<'a> Stream<'a> {
                     glyph_name = tok;
                 }
 
-                bin_len = self.next_int();
-                let tok = self.next_token().unwrap();
+                bin_len = self.next_int()?;
+                let tok = self.next_token()?;
 
                 if tok == RD || tok == RD_ALT {
                     self.read_byte();
                 } else {
-                    panic!("invalid charstring in start, expected RD");
+                    error!("invalid charstring start, expected RD, found {:?}", tok);
+                    return None;
                 }
             }
 
-            let encrypted_bytes = self.read_bytes(bin_len as usize).unwrap();
-            let decrypted_bytes = decrypt_charstring(encrypted_bytes, len_iv);
+            let encrypted_bytes = self.read_bytes(usize::try_from(bin_len).ok()?)?;
+            let decrypted_bytes = decrypt_charstring(encrypted_bytes, len_iv)?;
             charstrings.insert(
-                std::str::from_utf8(glyph_name).unwrap().to_string(),
+                std::str::from_utf8(glyph_name).ok()?.to_string(),
                 decrypted_bytes,
             );
 
-            let tok = self.next_token().unwrap();
-            if tok == ND || tok == ND_ALT {
-            } else {
-                panic!("invalid charstring in end, expected ND, found {:?}", tok);
+            let tok = self.next_token()?;
+            if tok != ND && tok != ND_ALT {
+                error!("invalid charstring end, expected ND, found {:?}", tok);
+                return None;
             }
         }
 
         Some(charstrings)
     }
 
-    fn parse_subroutines(&mut self, len_iv: usize) -> HashMap<u32, Vec<u8>> {
+    fn parse_subroutines(&mut self, len_iv: usize) -> Option<HashMap<u32, Vec<u8>>> {
         let mut subroutines = HashMap::new();
 
-        let num_subrs =
-            u32::from_str(std::str::from_utf8(self.next_token().unwrap()).unwrap()).unwrap();
+        let num_subrs = u32::from_str(std::str::from_utf8(self.next_token()?).ok()?).ok()?;
 
         if num_subrs < 1 {
-            return subroutines;
+            return Some(subroutines);
         }
 
         if !self.skip_until_before(b"dup", |b| matches!(b, ND | ND_ALT | b"noaccess")) {
-            return subroutines;
+            return Some(subroutines);
         }
 
         while let Some(token) = self.next_token() {
@@ -253,48 +466,50 @@ impl<'a> Stream<'a> {
                 if self.next_token() == Some(b"def") {
                     break;
                 } else {
-                    panic!("invallid sequence noaccess");
+                    error!("invalid sequence noaccess");
+                    return None;
                 }
             }
 
             if token != b"dup" {
-                panic!("expected dup, got token {:?} instead", &token);
+                error!("expected dup, got token {:?} instead", &token);
+                return None;
             }
 
-            let subr_idx = self.next_int();
-            let bin_len = self.next_int();
+            let subr_idx = u32::try_from(self.next_int()?).ok()?;
+            let bin_len = usize::try_from(self.next_int()?).ok()?;
 
-            let tok = self.next_token().unwrap();
+            let tok = self.next_token()?;
 
             if tok != RD && tok != RD_ALT {
-                panic!("invalid subroutine start token {:?}", tok);
+                error!("invalid subroutine start token {:?}", tok);
+                return None;
             } else {
                 // WHitespace
                 self.read_byte();
             }
 
-            // TODO: Decrypt
-            let encrypted_bytes = self.read_bytes(bin_len as usize).unwrap();
-            subroutines.insert(subr_idx as u32, decrypt_charstring(encrypted_bytes, len_iv));
+            let encrypted_bytes = self.read_bytes(bin_len)?;
+            subroutines.insert(subr_idx, decrypt_charstring(encrypted_bytes, len_iv)?);
 
-            let mut tok = self.next_token().unwrap();
+            let tok = self.next_token()?;
             if tok == NP || tok == NP_ALT {
             } else if tok == b"noaccess" {
-                tok = self.next_token().unwrap();
-                if tok == b"def" {
-                    break;
-                }
-
-                if tok == b"put" {
-                } else {
-                    panic!("invallid subroutine end {:?}", tok);
+                match self.next_token()? {
+                    b"def" => break,
+                    b"put" => {}
+                    tok => {
+                        error!("invalid subroutine end {:?}", tok);
+                        return None;
+                    }
                 }
             } else {
-                panic!("invalid subroutine end token {:?}", tok);
+                error!("invalid subroutine end token {:?}", tok);
+                return None;
             }
         }
 
-        subroutines
+        Some(subroutines)
     }
 
     fn peek_token(&mut self) -> Option<&'a [u8]> {
@@ -387,7 +602,7 @@ impl<'a> Stream<'a> {
         None
     }
 
-    fn read_font_matrix(&mut self) -> [f32; 6] {
+    fn read_font_matrix(&mut self) -> Option<[f32; 6]> {
         let mut entries = [0.0f32; 6];
         let mut idx = 0;
 
@@ -395,10 +610,10 @@ impl<'a> Stream<'a> {
         self.skip_token();
 
         while let Some(token) = self.next_token() {
-            entries[idx] = f32::from_str(std::str::from_utf8(token).unwrap()).unwrap();
+            entries[idx] = f32::from_str(std::str::from_utf8(token).ok()?).ok()?;
 
             idx += 1;
-            if idx == 5 {
+            if idx == 6 {
                 break;
             }
         }
@@ -406,21 +621,21 @@ impl<'a> Stream<'a> {
         // Skip `]`.
         self.skip_token();
 
-        entries
+        Some(entries)
     }
 
-    fn read_encoding(&mut self) -> EncodingType {
+    fn read_encoding(&mut self) -> Option<EncodingType> {
         let mut map = HashMap::new();
 
-        let t1 = self.next_token().unwrap();
-        let t2 = self.next_token().unwrap();
+        let t1 = self.next_token()?;
+        let t2 = self.next_token()?;
 
         if t1 == b"StandardEncoding" && t2 == b"def" {
-            return EncodingType::Standard;
+            return Some(EncodingType::Standard);
         }
 
         if !self.skip_until_before(b"dup", |b| matches!(b, b"def" | b"readonly")) {
-            return EncodingType::Custom(Arc::new(map));
+            return Some(EncodingType::Custom(Arc::new(map)));
         }
 
         while let Some(token) = self.next_token() {
@@ -429,23 +644,144 @@ impl<'a> Stream<'a> {
             }
 
             if token != b"dup" {
-                panic!("Unexpected token {:?}", token);
+                error!("unexpected token {:?} in encoding", token);
+                return None;
             }
 
-            let code =
-                u8::from_str(std::str::from_utf8(self.next_token().unwrap()).unwrap()).unwrap();
-            let glyph_name = std::str::from_utf8(&self.next_token().unwrap()[1..])
-                .unwrap()
-                .to_string();
+            let code = u8::from_str(std::str::from_utf8(self.next_token()?).ok()?).ok()?;
+            let glyph_name = self.next_token()?.strip_prefix(b"/")?;
+            let glyph_name = std::str::from_utf8(glyph_name).ok()?.to_string();
 
-            if self.next_token().unwrap() != b"put" {
-                panic!("Unexpected token {:?}", token);
+            if self.next_token()? != b"put" {
+                error!("unexpected token {:?} in encoding", token);
+                return None;
             }
 
             map.insert(code, glyph_name);
         }
 
-        EncodingType::Custom(Arc::new(map))
+        Some(EncodingType::Custom(Arc::new(map)))
+    }
+
+    fn read_font_info(&mut self) -> FontInfo {
+        let mut info = FontInfo::default();
+        if !self.skip_until(b"begin", |b| matches!(b, b"end")) {
+            return info;
+        }
+
+        while let Some(token) = self.next_token() {
+            match token {
+                b"end" => break,
+                // Skip unknown strings, which could contain `end`.
+                b"(" => {
+                    self.read_string_body();
+                }
+                b"/version" => info.version = self.read_string(),
+                b"/Notice" => info.notice = self.read_string(),
+                b"/Copyright" => info.copyright = self.read_string(),
+                b"/FullName" => info.full_name = self.read_string(),
+                b"/FamilyName" => info.family_name = self.read_string(),
+                b"/Weight" => info.weight = self.read_string(),
+                b"/ItalicAngle" => info.italic_angle = self.read_number().unwrap_or(0.0),
+                b"/isFixedPitch" => info.is_fixed_pitch = self.next_token() == Some(b"true"),
+                b"/UnderlinePosition" => {
+                    info.underline_position = self.read_number().unwrap_or(-100.0);
+                }
+                b"/UnderlineThickness" => {
+                    info.underline_thickness = self.read_number().unwrap_or(50.0);
+                }
+                _ => {}
+            }
+        }
+
+        info
+    }
+
+    fn read_string(&mut self) -> Option<String> {
+        if self.next_token()? != b"(" {
+            return None;
+        }
+
+        // Strings are usually Latin-1 encoded.
+        Some(
+            self.read_string_body()
+                .into_iter()
+                .map(char::from)
+                .collect(),
+        )
+    }
+
+    // Reads a string literal after its opening parenthesis.
+    fn read_string_body(&mut self) -> Vec<u8> {
+        let mut string = Vec::new();
+        let mut depth = 0;
+        while let Some(b) = self.read_byte() {
+            match b {
+                b'(' => {
+                    depth += 1;
+                    string.push(b);
+                }
+                b')' if depth == 0 => break,
+                b')' => {
+                    depth -= 1;
+                    string.push(b);
+                }
+                b'\\' => match self.read_byte() {
+                    Some(b'n') => string.push(b'\n'),
+                    Some(b'r') => string.push(b'\r'),
+                    Some(b't') => string.push(b'\t'),
+                    Some(b'b') => string.push(0x08),
+                    Some(b'f') => string.push(0x0C),
+                    // A line continuation.
+                    Some(b'\r') | Some(b'\n') => {}
+                    Some(c @ b'0'..=b'7') => {
+                        let mut n = u32::from(c - b'0');
+                        for _ in 0..2 {
+                            match self.peek_byte() {
+                                Some(c @ b'0'..=b'7') => {
+                                    self.read_byte();
+                                    n = n * 8 + u32::from(c - b'0');
+                                }
+                                _ => break,
+                            }
+                        }
+                        string.push(n as u8);
+                    }
+                    Some(c) => string.push(c),
+                    None => break,
+                },
+                _ => string.push(b),
+            }
+        }
+
+        string
+    }
+
+    fn read_number(&mut self) -> Option<f32> {
+        f32::from_str(std::str::from_utf8(self.next_token()?).ok()?).ok()
+    }
+
+    // Reads an array of numbers, in brackets or braces.
+    fn read_number_array(&mut self) -> Vec<f32> {
+        let mut numbers = Vec::new();
+        if !matches!(self.next_token(), Some(b"[") | Some(b"{")) {
+            return numbers;
+        }
+
+        while let Some(token) = self.next_token() {
+            if matches!(token, b"]" | b"}") {
+                break;
+            }
+
+            if let Some(n) = std::str::from_utf8(token)
+                .ok()
+                .and_then(|t| f32::from_str(t).ok())
+            {
+                numbers.push(n);
+            }
+        }
+
+        numbers
     }
 
     fn skip_dict(&mut self) {
@@ -494,7 +830,7 @@ impl<'a> Stream<'a> {
                 return true;
             }
 
-            self.next_token().unwrap();
+            self.next_token();
 
             if stop(token) {
                 break;
@@ -505,20 +841,20 @@ impl<'a> Stream<'a> {
     }
 }
 
-fn decrypt_charstring(data: &[u8], len_iv: usize) -> Vec<u8> {
+fn decrypt_charstring(data: &[u8], len_iv: usize) -> Option<Vec<u8>> {
     let mut r = 4330;
     let mut cb: Copied<Iter<u8>> = data.iter().copied();
     let mut decrypted = vec![];
 
-    for i in 0..len_iv {
-        let _ = decrypt_byte(cb.next().unwrap(), &mut r);
+    for _ in 0..len_iv {
+        let _ = decrypt_byte(cb.next()?, &mut r);
     }
 
     for byte in cb {
         decrypted.push(decrypt_byte(byte, &mut r))
     }
 
-    decrypted
+    Some(decrypted)
 }
 
 fn is_whitespace(c: u8) -> bool {
@@ -559,9 +895,56 @@ impl EncodingType {
     }
 }
 
+/// An encoding of a Type 1 font.
+///
+/// Maps 8-bit character codes to glyph names and back.
+#[derive(Debug, Clone, Copy)]
+pub struct Encoding<'a> {
+    encoding_type: &'a EncodingType,
+}
+
+impl<'a> Encoding<'a> {
+    /// Returns the kind of the encoding.
+    ///
+    /// Type 1 fonts either use the Standard Encoding or define their own one.
+    pub fn kind(&self) -> EncodingKind {
+        match self.encoding_type {
+            EncodingType::Standard => EncodingKind::Standard,
+            EncodingType::Custom(_) => EncodingKind::Custom,
+        }
+    }
+
+    /// Returns the name of the glyph mapped to a code.
+    pub fn glyph_name(&self, code: u8) -> Option<&'a str> {
+        self.encoding_type
+            .encode(code)
+            // `.notdef` entries are the same as unmapped ones.
+            .filter(|name| *name != ".notdef")
+    }
+
+    /// Returns an iterator over all mapped codes and their glyph names in ascending code order.
+    pub fn iter(&self) -> impl Iterator<Item = (u8, &'a str)> + 'a {
+        let encoding = *self;
+        (0..=255).filter_map(move |code| encoding.glyph_name(code).map(|name| (code, name)))
+    }
+
+    /// Returns an iterator over all codes that map to a glyph name.
+    pub fn codes<'b>(&self, glyph_name: &'b str) -> impl Iterator<Item = u8> + 'b
+    where
+        'a: 'b,
+    {
+        self.iter()
+            .filter(move |(_, name)| *name == glyph_name)
+            .map(|(code, _)| code)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::type1::stream::Stream;
+    use crate::type1::{from_cff, Encoding, Parameters, Table};
+    use crate::{EncodingKind, GlyphId};
+    use std::sync::Arc;
 
     macro_rules! assert_token {
         ($content:expr, $token:expr) => {
@@ -587,4 +970,93 @@ mod tests {
         assert_token!(content, b"readonly");
         assert_token!(content, b"def");
     }
+
+    #[test]
+    fn custom_encoding() {
+        let encoding_type = Stream::new(
+            b"256 array 0 1 255 {1 index exch /.notdef put} for \
+            dup 65 /A put dup 66 /B put dup 97 /A put readonly def",
+        )
+        .read_encoding()
+        .unwrap();
+        let encoding = Encoding {
+            encoding_type: &encoding_type,
+        };
+
+        assert_eq!(encoding.kind(), EncodingKind::Custom);
+        assert_eq!(encoding.glyph_name(65), Some("A"));
+        assert_eq!(encoding.glyph_name(67), None);
+        assert_eq!(encoding.codes("A").collect::<Vec<_>>(), vec![65, 97]);
+        // The name doesn't have to outlive the encoding.
+        let name = String::from("B");
+        assert_eq!(encoding.codes(&name).collect::<Vec<_>>(), vec![66]);
+        assert_eq!(
+            encoding.iter().collect::<Vec<_>>(),
+            vec![(65, "A"), (66, "B"), (97, "A")]
+        );
+    }
+
+    #[test]
+    fn malformed_private() {
+        let clear_text = b"%!PS\ncurrentfile eexec\n";
+        let data = from_cff::write_program(clear_text, b"/lenIV 4 def", from_cff::Format::Pfa);
+        assert!(Table::parse(&data).is_some());
+
+        let privates: [&[u8]; 5] = [
+            b"/lenIV x def",
+            b"/Subrs x array",
+            b"/Subrs 1 array dup 0 100 RD ",
+            b"/Subrs 1 array dup 0 1 XX x NP",
+            b"/CharStrings 1 dict dup begin /A 100 RD ",
+        ];
+        for private in privates {
+            let data = from_cff::write_program(clear_text, private, from_cff::Format::Pfa);
+            let private = String::from_utf8_lossy(private);
+            assert!(Table::parse(&data).is_none(), "{}", private);
+        }
+    }
+
+    #[test]
+    fn font_info() {
+        let info = Stream::new(
+            b"11 dict dup begin /version (001.002) readonly def \
+            /Notice (Copyright \\(c\\) 1990 (Some) Foundry\\051) readonly def \
+            /Weight (Bold) readonly def /ItalicAngle -11.5 def \
+            /isFixedPitch true def /UnderlinePosition -120 def end readonly def",
+        )
+        .read_font_info();
+
+        assert_eq!(info.version.as_deref(), Some("001.002"));
+        assert_eq!(
+            info.notice.as_deref(),
+            Some("Copyright (c) 1990 (Some) Foundry)")
+        );
+        assert_eq!(info.weight.as_deref(), Some("Bold"));
+        assert_eq!(info.full_name, None);
+        assert_eq!(info.italic_angle, -11.5);
+        assert!(info.is_fixed_pitch);
+        assert_eq!(info.underline_position, -120.0);
+        assert_eq!(info.underline_thickness, 50.0);
+    }
+
+    #[test]
+    fn glyph_ids() {
+        let mut params = Parameters::default();
+        for name in ["b", ".notdef", "a", "space"] {
+            params.charstrings.insert(name.to_string(), vec![]);
+        }
+        params.glyph_names = Table::build_glyph_names(&params);
+
+        let table = Table {
+            data: &[],
+            params: Arc::new(params),
+        };
+        assert_eq!(table.number_of_glyphs(), 4);
+        assert_eq!(table.glyph_name(GlyphId(0)), Some(".notdef"));
+        assert_eq!(table.glyph_name(GlyphId(3)), Some("space"));
+        assert_eq!(table.glyph_index_by_name(".notdef"), Some(GlyphId(0)));
+        assert_eq!(table.glyph_index_by_name("a"), Some(GlyphId(1)));
+        assert_eq!(table.glyph_index_by_name("b"), Some(GlyphId(2)));
+        assert_eq!(table.glyph_index_by_name("c"), None);
+    }
 }